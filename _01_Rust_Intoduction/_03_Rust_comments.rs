@@ -3,14 +3,17 @@ Rust Comments
 ===============
 
 In computer programming, comments are lines of text used
-to describe the purpose of code. For example,
-
-// entry point of the program
-fn main() {
-    // print text on the screen
-    println!("Hello, World!");
+to describe the purpose of code. For example, */
+
+mod entry_point {
+    // entry point of the program
+    pub fn main() {
+        // print text on the screen
+        println!("Hello, World!");
+    }
 }
 
+/*
 Here comments are :
 - // entry point of the program
 - // print text on to the screen are comments.
@@ -34,11 +37,15 @@ Line Comment in Rust
 In Rust, we use two forward slashes, //, to create a line
 comment. For example,
 
-fn main () {
-    // declare a variable
-    let x = 1;
-    println!("x = {}", x);
+*/
+mod line_comment {
+    pub fn main() {
+        // declare a variable
+        let x = 1;
+        println!("x = {}", x);
+    }
 }
+/*
 
 
 Here, // declare a variable is a line comment.
@@ -48,10 +55,14 @@ known as single-line comments.
 We can also use line comments in the same line as the code.
 For example,
 
-fn main() {
-    let x = 1;    // declare a variable
-    println!("x = {}", x);
+*/
+mod inline_comment {
+    pub fn main() {
+        let x = 1; // declare a variable
+        println!("x = {}", x);
+    }
 }
+/*
 
 Here, // declare a variable is also a line comment placed at
 the end of the line containing code.
@@ -62,14 +73,18 @@ Block Comment in Rust
 In Rust, we use the symbol /*...*/ to denote the block comment.
 It starts with /* and ends with */. For example,
 
-fn main() {
-    /*
-    declare a variable
-    and assign value to it
-    */
-    let x = 1;
-    println!("x = {}", x);
+*/
+mod block_comment {
+    pub fn main() {
+        /*
+        declare a variable
+        and assign value to it
+        */
+        let x = 1;
+        println!("x = {}", x);
+    }
 }
+/*
 
 Here,
 
@@ -86,12 +101,16 @@ We can also create multi-line comments using multiple line
 comments.
 For example,
 
-fn main() {
-    // declare a variable
-    // and assign value to it
-    let x = 1;
-    println!("x = {}", x);
+*/
+mod multiple_line_comments {
+    pub fn main() {
+        // declare a variable
+        // and assign value to it
+        let x = 1;
+        println!("x = {}", x);
+    }
 }
+/*
 
 Here, we have used two single-line comments:
 // declare a variable
@@ -107,27 +126,35 @@ Disable Parts of Code Using Rust Comments
 Comments are also useful for temporarily disabling chunks
 of code.Let's see an example:
 
-fn main() {
-    let x = 1;
-    let y = 2;
-    let z = 3;
-    println!("z = {}", z);
+*/
+mod unused_variables {
+    pub fn main() {
+        let x = 1;
+        let y = 2;
+        let z = 3;
+        println!("z = {}", z);
+    }
 }
+/*
 
 This piece of code will throw a warning because both x and
 y variables are unused. Instead of completely removing
 these declarations, we can comment them.
 
-fn main() {
-    /*
-    temporarily disable x and y variable declarations.
-    let x = 1;
-    let y = 2;
-    */
-
-    let z = 3;
-    println!("z = {}", z);
+*/
+mod disabled_code {
+    pub fn main() {
+        /*
+        temporarily disable x and y variable declarations.
+        let x = 1;
+        let y = 2;
+        */
+
+        let z = 3;
+        println!("z = {}", z);
+    }
 }
+/*
 
 
 Now, only the code outside of the block comment will be
@@ -179,3 +206,30 @@ Here are a few ways to improve code comments:
 - Don't overuse comments.
 - Don't use redundant comments.
 */
+
+/*
+Running the Examples
+=====================
+
+Every example above is a module with its own main() function, so
+the lesson compiles as one program. Its main() below runs them one
+after the other, with a blank line after each. To run a single
+example on its own, give its name to rustprog:
+
+    cargo run -p rustprog -- run comments line_comment */
+fn main() {
+    let examples: [fn(); 7] = [
+        entry_point::main,
+        line_comment::main,
+        inline_comment::main,
+        block_comment::main,
+        multiple_line_comments::main,
+        unused_variables::main,
+        disabled_code::main,
+    ];
+
+    for example in examples {
+        example();
+        println!();
+    }
+}
@@ -6,13 +6,15 @@ Rust Print Output
 In Rust, we use the 'print!' macro to print strings, numbers
 and variables on the output screen.
 
-For example:
+For example: */
 
-fn main() {
-    print!("Hello, World!");
+mod hello_world {
+    pub fn main() {
+        print!("Hello, World!");
+    }
 }
 
-
+/*
 Here, 'print!' is a macro that prints the text inside double
 quotes.
 
@@ -28,10 +30,14 @@ Rust print! Macro
 As mentioned earlier, the 'print!' macro prints the text
 inside double quotes. For example,
 
-fn main() {
-    print!("Rust is fun! ");
-    print!("I love Rust programming.");
+*/
+mod print_macro {
+    pub fn main() {
+        print!("Rust is fun! ");
+        print!("I love Rust programming.");
+    }
 }
+/*
 
 Output:
 --------
@@ -49,10 +55,14 @@ at the end.
 Rust println! Macro
 ====================
 
-fn main() {
-    println!("Rust is fun!");
-    println!("I love Rust programming.");
+*/
+mod println_macro {
+    pub fn main() {
+        println!("Rust is fun!");
+        println!("I love Rust programming.");
+    }
 }
+/*
 
 Output:
 ------
@@ -72,15 +82,19 @@ Print Variables
 We can use the same 'print!' and 'println!' macros to print
 variables in Rust. For example:
 
-fn main() {
-    let age = 31;
+*/
+mod print_variables {
+    pub fn main() {
+        let age = 31;
 
-    // print the variable using println!
-    println!("{}", age);
+        // print the variable using println!
+        println!("{}", age);
 
-    // print the variable using print!
-    print!("{}", age);
+        // print the variable using print!
+        print!("{}", age);
+    }
 }
+/*
 
 Output:
 -------
@@ -89,8 +103,10 @@ Output:
 
 In the above example, notice the print statements:
 
+```rust,ignore
 print!("{}", age);
 println!("{}", age);
+```
 
 Here, '{}' is a placeholder which is replaced by the value
 of the variable after the comma. That's why we get 31 as
@@ -99,12 +115,16 @@ output instead of {}.
 We can also add text with the placeholder to format our
 output. For example,
 
-fn main() {
-    let age = 31;
+*/
+mod placeholder_text {
+    pub fn main() {
+        let age = 31;
 
-    // print the variable using println!
-    println!("Age = {}", age);
+        // print the variable using println!
+        println!("Age = {}", age);
+    }
 }
+/*
 
 Output:
 -------
@@ -122,13 +142,17 @@ Print Multiple Variables
 We can use a single 'println!' macro to print multiple
 variables together. For example,
 
-fn main() {
-    let age = 31;
-    let name = "Jack";
-
-    // print the variables using println!
-    println!("Name = {}, Age = {}", name, age);
+*/
+mod multiple_variables {
+    pub fn main() {
+        let age = 31;
+        let name = "Jack";
+
+        // print the variables using println!
+        println!("Name = {}, Age = {}", name, age);
+    }
 }
+/*
 
 Output
 
@@ -147,13 +171,17 @@ by Name and Age variable
 However, we can also specify the numbering for placeholders
 to print variables in different order. For example,
 
-fn main() {
-    let age = 31;
-    let name = "Jack";
-
-    // print the variable using println!
-    println!("Name = {0}, Age = {1}", name, age);
+*/
+mod numbered_placeholders {
+    pub fn main() {
+        let age = 31;
+        let name = "Jack";
+
+        // print the variable using println!
+        println!("Name = {0}, Age = {1}", name, age);
+    }
 }
+/*
 
 Output:
 --------
@@ -166,13 +194,17 @@ Here, the placeholders:
 Similarly, we can also use the variable names directly
 inside the placeholder. For example,
 
-fn main() {
-    let age = 31;
-    let name = "Jack";
-
-    // print the variables using println!
-    println!("Name = {name}, Age = {age}");
+*/
+mod named_placeholders {
+    pub fn main() {
+        let age = 31;
+        let name = "Jack";
+
+        // print the variables using println!
+        println!("Name = {name}, Age = {age}");
+    }
 }
+/*
 
 Output:
 -------
@@ -191,9 +223,13 @@ Print Newline Character
 In Rust, we can print newline character(s) using the '\n'
 escape sequence. For example,
 
-fn main() {
-    print!("Rust is fun!\nI love Rust programming.");
+*/
+mod newline {
+    pub fn main() {
+        print!("Rust is fun!\nI love Rust programming.");
+    }
 }
+/*
 
 Output:
 --------
@@ -204,3 +240,32 @@ Here, '\n' is an escape sequence that adds a new line
 character. Hence, the text after \n is printed in a new line.
 
 */
+
+/*
+Running the Examples
+=====================
+
+Every example above is a module with its own main() function, so
+the lesson compiles as one program. Its main() below runs them one
+after the other, with a blank line after each. To run a single
+example on its own, give its name to rustprog:
+
+    cargo run -p rustprog -- run print_output newline */
+fn main() {
+    let examples: [fn(); 9] = [
+        hello_world::main,
+        print_macro::main,
+        println_macro::main,
+        print_variables::main,
+        placeholder_text::main,
+        multiple_variables::main,
+        numbered_placeholders::main,
+        named_placeholders::main,
+        newline::main,
+    ];
+
+    for example in examples {
+        example();
+        println!();
+    }
+}
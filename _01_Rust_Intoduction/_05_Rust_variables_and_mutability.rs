@@ -147,23 +147,29 @@ variables and uppercase variables are different:
 
 2. Variables must start with either a letter or an underscore.
 
+```rust,ignore
 let age = 31;     	// valid and good practice
 let _age = 31;    	// valid variable
 let 1age = 31;    // invalid variable
+```
 
 3. Variable names can only contain letters, digits and an
 underscore character:
 
+```rust,ignore
 let age1 = 31;        // valid variable
 let age_num = 31;     // valid variable
 let s@lary = 52352;   // invalid variable
+```
 
 
 4. Use underscore if we need to use two words as variable names:
 
+```rust,ignore
 let first name = "Jack";    // invalid variable
 let first_name = "Jack";    // valid variable
 let first-name = "Jack";    // invalid variable
+```
 
 
 Note: Always try to give meaningful names to your variables.
@@ -176,12 +182,12 @@ Rust Constants
 A constant is a special type of variable whose value cannot be
 changed. We use the 'const' keyword to create constants in Rust. */
 
-// fn main() {
-//     // declare a float constant
-//     const PI: f32 = 3.14159;
+fn main() {
+    // declare a float constant
+    const PI: f32 = 3.14159;
 
-//     println!("Value of PI = {}", PI);
-// }
+    println!("Value of PI = {}", PI);
+}
 
 /*
 
@@ -194,15 +200,15 @@ In the above example, we have declared a constant PI with value of
 
 Let's see what happens if we try to change the value of a constant. */
 
-fn main() {
-    // declare a constant
-    const PI: f32 = 3.14;
-    println!("Initial Value of PI: {}", PI);
+// fn main() {
+//     // declare a constant
+//     const PI: f32 = 3.14;
+//     println!("Initial Value of PI: {}", PI);
 
-    // change value of PI
-    PI = 535.23;
-    println!("Update Value of PI: {}", PI);
-}
+//     // change value of PI
+//     PI = 535.23;
+//     println!("Update Value of PI: {}", PI);
+// }
 
 /*
 When we run this code, we will get an error because PI is a constant.
@@ -25,7 +25,7 @@ as scalar types:
 
 
 1. Integer Type
-----------------
+================
 
 In Rust, we use integer data types to store whole numbers.
 
@@ -111,7 +111,7 @@ For more information about this error, try `rustc --explain E0600`.
 
 
 Categories of Integer Data Types in Rust
-=========================================
+-----------------------------------------
 
 Depending on the size of data, we can further classify the signed
 and unsigned integer type into various categories:
@@ -52,9 +52,9 @@ pub fn main() {
     println!("character = {}", character);
     println!("integer = {}", integer);
 
-    println!("Number of byte(s) for u8: {}", std::mem::size_of::<u8>()); // 4 bytes
-    println!("Number of byte(s) for f32: {}", std::mem::size_of::<f32>()); // 4 bytes
-    println!("Number of byte(s) for f64: {}", std::mem::size_of::<f64>()); // 4 bytes
+    println!("Number of byte(s) for u8: {}", std::mem::size_of::<u8>()); // 1
+    println!("Number of byte(s) for f32: {}", std::mem::size_of::<f32>()); // 4
+    println!("Number of byte(s) for f64: {}", std::mem::size_of::<f64>()); // 8
 
     /*
     In Rust, the 'char' data type is internally stored as
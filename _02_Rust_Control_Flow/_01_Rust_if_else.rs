@@ -45,9 +45,11 @@ pub fn main() {
     An if expression executes the code block only if the condition is
     true. The syntax of the if expression in Rust is:
 
+    ```rust,ignore
     if condition {
         // code block to execute
     }
+    ```
 
     If the condition evaluates to
 
@@ -97,11 +99,13 @@ pub fn main() {
 
     The syntax for the if..else expression in Rust is:
 
+    ```rust,ignore
     if condition {
         // executes when condition is true
     } else {
         // executes when condition is false
     }
+    ```
 
     1. If condition evaluates to true,
 
@@ -151,6 +155,7 @@ pub fn main() {
     choices. The syntax for if with else if expression looks
     like this:
 
+    ```rust,ignore
     if condition1 {
         // code block 1
     } else if condition2 {
@@ -158,6 +163,7 @@ pub fn main() {
     } else {
         // code block 3
     }
+    ```
 
     Here,
 
@@ -202,6 +208,7 @@ pub fn main() {
     You can use if..else expressions inside the body of other
     if..else expressions. It is known as nested if..else in Rust.
 
+    ```rust,ignore
     if outer_condition {
         // outer code block
 
@@ -211,6 +218,7 @@ pub fn main() {
             // inner code block 2
         }
     }
+    ```
 
     // outside if code block */
 
@@ -6,9 +6,11 @@ fn main() {
     We use the while loop to execute a code block till the
     condition is true. The syntax for the while expression is:
 
+    ```rust,ignore
     while condition {
         // code block
     }
+    ```
 
     // code block outside while loop
 
@@ -33,9 +35,11 @@ fn main() {
     /*
     In the example above, we have a condition:
 
+    ```rust,ignore
     while counter < 6 {
         // code block
     }
+    ```
 
     Here, the loop keeps running till the counter variable
     is less than 6. Inside the loop, we are increasing the
@@ -103,6 +107,7 @@ fn main() {
     loop. This is known as a nested while loop. A nested
     while loop looks like:
 
+    ```rust,ignore
     while outer_condition {
         // outer code block 1
 
@@ -112,6 +117,7 @@ fn main() {
 
         // outer code block 2
     }
+    ```
 
     Let's print a pattern using a nested while loop */
 
@@ -6,9 +6,11 @@ fn main() {
     The for loop in Rust is used to iterate a range of numbers.
     The syntax of for loop is:
 
+    ```rust,ignore
     for variable in lower_bound_number..upper_bound_number {
         // code block
     }
+    ```
     */
 
     // usage of for loop
@@ -55,9 +57,11 @@ fn main() {
 
     Does Rust have a "C-style" for loop ?
     -------------------------------------
+    ```c
     for (i = 0; i < 10; i++) {
         printf("%d\n", i);
     }
+    ```
 
     By design, Rust does not have the "C-style" for loop.
     The "C-style" for loop has four major components:
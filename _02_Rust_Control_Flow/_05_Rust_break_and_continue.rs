@@ -87,10 +87,12 @@ fn main() {
     In the above example, we have used the break keyword in
     the body of the inner while loop.
 
+    ```rust,ignore
     if j == 3 {
         // terminate the inner loop
         break;
     }
+    ```
 
     When the value of the counter variable j reaches 3, the
     inner while loop terminates. As a result, we only see
@@ -102,11 +104,13 @@ fn main() {
     In Rust, we use the continue statement to skip the current
     iteration of any loop and move to the next iteration;
 
+    ```rust,ignore
     while n < 10 {
         if n == 5 {
             continue;
         }
     }
+    ```
 
     Here, the while loop will skip the current iteration when
     it encounters the continue keyword irrespective of the
@@ -129,9 +133,11 @@ fn main() {
     In this example, we use the while expression to print
     natural numbers. Notice the use of the continue keyword,
 
+    ```rust,ignore
     if number == 3 {
         continue;
     }
+    ```
 
     Here, we skip the iteration when the number variable is
     equals 3. As a result, we don't see 3 in the output.
@@ -168,10 +174,12 @@ fn main() {
     Here, we have used the continue keyword to skip an
     iteration of the inner while loop.
 
+    ```rust,ignore
     if j == 3 {
         // move to the next iteration of the inner loop
         continue;
     }
+    ```
 
     When the value of the counter variable j reaches 3,
     we skip the current inner while iteration and the print!("*")
@@ -203,17 +211,21 @@ fn main() {
     /*
     Here, the continue keyword,
 
+    ```rust,ignore
     if number == 3 {
         continue;
     }
+    ```
 
     skips the iteration when the value of the number variable is 3.
 
     Similarly, the break keyword,
 
+    ```rust,ignore
     if number > 5 {
         break;
     }
+    ```
 
     terminates the loop if the value of the number variable is greater than 5.
      */
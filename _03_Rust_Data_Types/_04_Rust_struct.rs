@@ -31,11 +31,13 @@ fn main() {
     In Rust, we use the 'struct' keyword to define a structure.
     The syntax of a structure is:
 
+    ```rust,ignore
     struct StructName {
         field1: data_type,
         field2: data_type,
         field3: data_type
     }
+    ```
 
     Here,
 
@@ -194,7 +196,9 @@ fn main() {
     /*
     Here, the destructing happens with this expression,
 
+    ```rust,ignore
     let Person5 { name, age, height } = person5;
+    ```
 
     The pattern on the left has declarations, and the right side of
     the expression has a struct instance.
@@ -226,16 +230,16 @@ fn main() {
     let mut point = Point { x: 0, y: 0 };
 
     println!("Before change:");
-    println!("Point x = {}", point.x); // x = 0
-    println!("Point y = {}", point.y); // y = 0
+    println!("Point x = {}", point.x); // Point x = 0
+    println!("Point y = {}", point.y); // Point y = 0
 
     // change the value of x field in mutable point struct
     point.x = 5;
 
     println!();
     println!("After change:");
-    println!("Point x = {}", point.x); // x = 5
-    println!("Point y = {}", point.y); // y = 0
+    println!("Point x = {}", point.x); // Point x = 5
+    println!("Point y = {}", point.y); // Point y = 0
     println!("-------");
 
     /*
@@ -252,15 +256,15 @@ fn main() {
     // instantiating a tuple struct with values
     let point2 = Point2(1, 2);
 
-    println!("{:?}", point2);
-    println!("-------"); // Point(1, 2)
+    println!("{:?}", point2); // Point2(1, 2)
+    println!("-------");
 
     /*
     Output
 
-    Point(1, 2)
+    Point2(1, 2)
 
-    Here, struct Point(i32, i32) is a tuple struct with two fields
+    Here, struct Point2(i32, i32) is a tuple struct with two fields
     of type i32.
 
     Note: Add #[derive(Debug)] above the struct definition to allow
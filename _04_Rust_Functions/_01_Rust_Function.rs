@@ -22,9 +22,11 @@ fn main() {
     In Rust, we use the 'fn' keyword to define a function.
     The syntax of a function is:
 
+    ```rust,ignore
     fn function_name(arguments) {
         // code
     }
+    ```
 
     Let's see an example.
 
@@ -74,10 +76,12 @@ fn main() {
     If you look carefully, you can see the syntax of main() looks
     similar to a function.
 
+    ```rust,ignore
     fn main() {
         // function call
         greet();
     }
+    ```
 
     In Rust, main() is also a function known as a built-in function
     that has a special meaning. It is the entry point (start) of every
@@ -238,7 +242,7 @@ fn main() {
 
     // passing reference of word variable
     let len = calculate_length(&word);
-    println!("The length of '{}' is {}.", word, len) // The length of 'hello' is 5
+    println!("The length of '{}' is {}.", word, len); // The length of 'hello' is 5.
 
     fn calculate_length(s: &String) -> usize {
         return s.len();
@@ -0,0 +1,318 @@
+/*
+Rust Ownership
+=============== */
+
+fn main() {
+    /*
+
+    Rust includes a unique mechanism called ownership to manage
+    memory. Ownership decides when the memory used by a value is
+    given back, without a garbage collector and without the
+    programmer freeing it by hand.
+
+    Before we learn about ownership, let's quickly look at how the
+    scope of a variable decides how long its value lives.
+
+
+    Variable Scope and Drop
+    ========================
+
+    A value lives in memory while the variable that holds it is in
+    scope. For example, */
+
+    // start of the inner code block
+    {
+        // fruit is valid from here on
+        let fruit = String::from("Apple");
+        println!("fruit = {}", fruit); // fruit = Apple
+    }
+    // end of the inner code block: fruit is no longer valid
+
+    /*
+    Here, the String "Apple" is created inside the inner code block.
+    When the block ends, the variable fruit goes out of scope and Rust
+    frees the memory used by the String. This is known as dropping
+    the value.
+
+    Note: A String keeps its text in a part of memory called the
+          heap, so it has memory to give back when it is dropped.
+          Simple values like integers are stored entirely on the
+          stack.
+
+
+    Ownership Rules
+    ================
+
+    Rust has some ownership rules. Keep these rules in mind as we
+    work through the examples:
+
+    - Each value in Rust has an owner.
+    - There can only be one owner at a time.
+    - When the owner goes out of scope, the value will be dropped.
+
+
+    Data Move in Rust
+    ==================
+
+    Sometimes, we might want to assign the value of one variable to
+    another variable. In Rust, assigning a String to another variable
+    moves it: the new variable becomes the owner of the String.
+    For example, */
+
+    // fruit1 is the owner of the String
+    let fruit1 = String::from("Banana");
+
+    // ownership of the String moves from fruit1 to fruit2
+    let fruit2 = fruit1;
+
+    println!("fruit2 = {}", fruit2); // fruit2 = Banana
+
+    /*
+    Here, the ownership of the String moves from fruit1 to fruit2.
+    From now on, fruit1 is no longer valid. If we try to use fruit1
+    after the move, the program fails to compile.
+
+    fn main() {
+        let fruit1 = String::from("Banana");
+        let fruit2 = fruit1;
+
+        println!("fruit1 = {}", fruit1);
+    }
+
+    -----------------------------------------------------------------------
+
+    error[E0382]: borrow of moved value: `fruit1`
+      --> _01_Rust_Ownership.rs:79:29
+       |
+    76 |     let fruit1 = String::from("Banana");
+       |         ------ move occurs because `fruit1` has type `String`, which does not implement the `Copy` trait
+    77 |     let fruit2 = fruit1;
+       |                  ------ value moved here
+    78 |
+    79 |     println!("fruit1 = {}", fruit1);
+       |                             ^^^^^^ value borrowed here after move
+       |
+    help: consider cloning the value if the performance cost is acceptable
+       |
+    77 |     let fruit2 = fruit1.clone();
+       |                        ++++++++
+
+    error: aborting due to 1 previous error
+
+    For more information about this error, try `rustc --explain E0382`.
+
+    -----------------------------------------------------------------------
+
+    The compiler tells us exactly where the String was moved and
+    where we tried to use it afterwards.
+
+    Why does Rust move the String instead of copying it? A String is
+    made of a pointer to its text on the heap, a length and a
+    capacity. Assigning it copies these three values, not the text.
+    If both variables stayed valid, both would point to the same
+    text, and both would try to free it when they go out of scope.
+    With a single owner, the text is freed exactly once.
+
+
+    Data Copy in Rust
+    ==================
+
+    Primitive types like integers, floating point numbers, booleans
+    and characters are copied instead of moved. Their values are
+    stored on the stack, and copying them is as cheap as moving
+    them. */
+
+    let x = 11;
+
+    // the value of x is copied to y
+    let y = x;
+
+    println!("x = {}, y = {}", x, y); // x = 11, y = 11
+
+    /*
+    Here, the value of x is copied to y, so both variables are valid
+    and can be used.
+
+    Types whose values are copied this way implement the Copy trait.
+    We will learn more about it in Rust Clone and Copy.
+
+
+    Clone a Value in Rust
+    ======================
+
+    If we really want two separate Strings, we can make a deep copy
+    of the value with the clone() method. */
+
+    let fruit3 = String::from("Mango");
+
+    // clone the text of fruit3 into a new String
+    let fruit4 = fruit3.clone();
+
+    println!("fruit3 = {}, fruit4 = {}", fruit3, fruit4); // fruit3 = Mango, fruit4 = Mango
+
+    /*
+    Here, clone() copies the text of fruit3 into a new String that
+    is owned by fruit4. Both variables own their own String, so both
+    are valid.
+
+    Note: Cloning copies all the data on the heap, which can be slow
+          for large values. Rust never clones a value unless we ask
+          for it.
+
+
+    Ownership in Functions
+    =======================
+
+    Passing a variable to a function moves or copies it, just like
+    assigning it to another variable does. For example, */
+
+    // the String passed to this function is moved into fruit
+    fn print_fruit(fruit: String) {
+        println!("fruit = {}", fruit);
+    } // fruit goes out of scope here and the String is dropped
+
+    // the integer passed to this function is copied into number
+    fn print_number(number: i32) {
+        println!("number = {}", number);
+    }
+
+    let fruit5 = String::from("Cherry");
+    let number = 10;
+
+    // fruit5 is moved into the function
+    print_fruit(fruit5);
+
+    // number is copied into the function
+    print_number(number);
+
+    // number is still valid here
+    println!("number is still {}", number);
+
+    /*
+    Output:
+
+    fruit = Cherry
+    number = 10
+    number is still 10
+
+    Here, the ownership of the String moves from fruit5 to the
+    parameter fruit of print_fruit(). When the function ends, fruit
+    goes out of scope and the String is dropped.
+
+    The value of number, on the other hand, is copied into the
+    function, so we can still use it after the call.
+
+    If we try to use fruit5 after passing it to the function, we get
+    the same error as before.
+
+    fn print_fruit(fruit: String) {
+        println!("fruit = {}", fruit);
+    }
+    fn main() {
+        let fruit5 = String::from("Cherry");
+        print_fruit(fruit5);
+
+        println!("fruit5 = {}", fruit5);
+    }
+
+    -----------------------------------------------------------------------
+
+    error[E0382]: borrow of moved value: `fruit5`
+       --> _01_Rust_Ownership.rs:215:29
+        |
+    212 |     let fruit5 = String::from("Cherry");
+        |         ------ move occurs because `fruit5` has type `String`, which does not implement the `Copy` trait
+    213 |     print_fruit(fruit5);
+        |                 ------ value moved here
+    214 |
+    215 |     println!("fruit5 = {}", fruit5);
+        |                             ^^^^^^ value borrowed here after move
+        |
+    note: consider changing this parameter type in function `print_fruit` to borrow instead if owning the value isn't necessary
+       --> _01_Rust_Ownership.rs:208:23
+        |
+    208 | fn print_fruit(fruit: String) {
+        |    -----------        ^^^^^^ this parameter takes ownership of the value
+        |    |
+        |    in this function
+    help: consider cloning the value if the performance cost is acceptable
+        |
+    213 |     print_fruit(fruit5.clone());
+        |                       ++++++++
+
+    error: aborting due to 1 previous error
+
+    For more information about this error, try `rustc --explain E0382`.
+
+    -----------------------------------------------------------------------
+
+
+    Return Values and Ownership
+    ============================
+
+    A function can give the ownership of a value back through its
+    return value. */
+
+    // takes the ownership of a String and gives it back
+    fn make_juice(mut fruit: String) -> String {
+        fruit.push_str(" juice");
+
+        return fruit;
+    }
+
+    let fruit6 = String::from("Orange");
+
+    // ownership moves into make_juice() and back out into juice
+    let juice = make_juice(fruit6);
+
+    println!("juice = {}", juice); // juice = Orange juice
+
+    /*
+    Here, the String moves from fruit6 into the make_juice() function,
+    which adds " juice" to it. The function then returns the String,
+    and its ownership moves to the variable juice.
+
+    Taking the ownership of a value and giving it back every time we
+    want to use it in a function is tedious. Rust lets us use a value
+    without taking its ownership through references. We will learn
+    about them in Rust References and Borrowing.
+
+
+    Frequently Asked Questions
+    ===========================
+
+    Why does a closure that uses let new_word = word; take word away?
+
+    A closure follows the same rules as the rest of the code. In
+    Rust closure, the statement let new_word = word; inside the
+    closure moves the String into new_word, so the closure takes the
+    ownership of word. After that, word is no longer valid outside
+    the closure. */
+
+    let word = String::from("Hello");
+
+    // word is moved into the closure
+    let print_str = || {
+        let new_word = word;
+        println!("word = {}", new_word);
+    };
+
+    print_str(); // word = Hello
+
+    /*
+    Because the closure moves word out of itself when it runs, it can
+    only be called once.
+
+    Is the value dropped when the owner is given a new value?
+
+    Yes. When we assign a new value to a variable that owns a String,
+    the old String is dropped right away, as nothing owns it anymore.
+    */
+
+    let mut city = String::from("Paris");
+    println!("city = {}", city); // city = Paris
+
+    // the String "Paris" is dropped here
+    city = String::from("Rome");
+    println!("city = {}", city); // city = Rome
+}
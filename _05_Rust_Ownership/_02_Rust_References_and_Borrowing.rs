@@ -0,0 +1,371 @@
+/*
+Rust References and Borrowing
+============================== */
+
+fn main() {
+    /*
+
+    References in Rust allow us to point to a value without owning
+    it. This means that the original owner of the data stays its
+    owner, and the value is not dropped when the reference goes out
+    of scope.
+
+    Creating a reference is known as borrowing in Rust. Like borrowing
+    a book from a friend, we get to use the value for a while, but it
+    is not ours to keep.
+
+
+    Understanding References in Rust
+    =================================
+
+    In Rust function, we passed a String to the calculate_length()
+    function as &word. Let's look at how this works. */
+
+    // function that borrows a String and returns its length
+    fn calculate_length(s: &String) -> usize {
+        return s.len();
+    } // s goes out of scope here, but the String is not dropped
+
+    let word = String::from("Hello, World!");
+
+    // pass a reference of word to the function
+    let len = calculate_length(&word);
+
+    println!("The length of '{}' is {}.", word, len);
+
+    /*
+    Output:
+
+    The length of 'Hello, World!' is 13.
+
+    Here,
+
+    - &word - creates a reference to the String owned by word
+    - s: &String - the parameter s is a reference to a String
+
+    As the function only borrows the String, the ownership stays
+    with word. That is why we can still print word after the
+    function call.
+
+    The & operator creates a reference, and a type starting with &
+    is a reference type.
+
+
+    Modifying a Reference in Rust
+    ==============================
+
+    By default, a reference is immutable, just like a variable. We
+    cannot change the value a reference points to. For example,
+
+    fn change(s: &String) {
+        s.push_str(", World!");
+    }
+    fn main() {
+        let mut text = String::from("Hello");
+        change(&text);
+    }
+
+    -----------------------------------------------------------------------
+
+    error[E0596]: cannot borrow `*s` as mutable, as it is behind a `&` reference
+      --> _02_Rust_References_and_Borrowing.rs:61:5
+       |
+    61 |     s.push_str(", World!");
+       |     ^ `s` is a `&` reference, so it cannot be borrowed as mutable
+       |
+    help: consider changing this to be a mutable reference
+       |
+    60 | fn change(s: &mut String) {
+       |               +++
+
+    error: aborting due to 1 previous error
+
+    For more information about this error, try `rustc --explain E0596`.
+
+    -----------------------------------------------------------------------
+
+    The String is borrowed through an immutable reference, so the
+    change() function is not allowed to modify it.
+
+
+    Mutable References in Rust
+    ===========================
+
+    To modify a borrowed value, we need a mutable reference. We
+    create one with &mut, and the variable it borrows must be
+    mutable as well. For example, */
+
+    // function that borrows a String mutably and changes it
+    fn change(s: &mut String) {
+        s.push_str(", World!");
+    }
+
+    let mut text = String::from("Hello");
+
+    // pass a mutable reference of text to the function
+    change(&mut text);
+
+    println!("text = {}", text); // text = Hello, World!
+
+    /*
+    Here,
+
+    - &mut text - creates a mutable reference to the String owned by text
+    - s: &mut String - the parameter s is a mutable reference to a String
+
+    The function changes the String through the reference, and the
+    change is seen through text after the call.
+
+
+    Rules of References
+    ====================
+
+    Rust checks how references are used while it compiles the
+    program. The part of the compiler that does this is called the
+    borrow checker, and it enforces two rules:
+
+    - At any given time, we can have either one mutable reference or
+      any number of immutable references to a value.
+    - References must always be valid.
+
+    Let's look at what these rules mean in practice.
+
+
+    1. Many Immutable References
+    -----------------------------
+
+    We can borrow a value immutably as many times as we want, because
+    none of the references can change it. */
+
+    let color = String::from("red");
+
+    let r1 = &color;
+    let r2 = &color;
+
+    println!("r1 = {}, r2 = {}", r1, r2); // r1 = red, r2 = red
+
+    /*
+    2. Only One Mutable Reference
+    ------------------------------
+
+    While a mutable reference to a value is in use, we cannot create
+    another one. For example,
+
+    fn main() {
+        let mut color = String::from("red");
+
+        let r1 = &mut color;
+        let r2 = &mut color;
+
+        println!("r1 = {}, r2 = {}", r1, r2);
+    }
+
+    -----------------------------------------------------------------------
+
+    error[E0499]: cannot borrow `color` as mutable more than once at a time
+       --> _02_Rust_References_and_Borrowing.rs:158:14
+        |
+    157 |     let r1 = &mut color;
+        |              ---------- first mutable borrow occurs here
+    158 |     let r2 = &mut color;
+        |              ^^^^^^^^^^ second mutable borrow occurs here
+    159 |
+    160 |     println!("r1 = {}, r2 = {}", r1, r2);
+        |                                  -- first borrow later used here
+
+    error: aborting due to 1 previous error
+
+    For more information about this error, try `rustc --explain E0499`.
+
+    -----------------------------------------------------------------------
+
+    If two mutable references were allowed, one of them could change
+    the value while the other one is reading it. This is known as a
+    data race, and Rust rejects it when it compiles the program.
+
+
+    3. No Mutable Reference While Immutable Ones Are in Use
+    --------------------------------------------------------
+
+    We also cannot borrow a value mutably while an immutable
+    reference to it is still in use. For example,
+
+    fn main() {
+        let mut color = String::from("red");
+
+        let r1 = &color;
+        let r2 = &mut color;
+        r2.push_str("dish");
+
+        println!("r1 = {}", r1);
+    }
+
+    -----------------------------------------------------------------------
+
+    error[E0502]: cannot borrow `color` as mutable because it is also borrowed as immutable
+       --> _02_Rust_References_and_Borrowing.rs:197:14
+        |
+    196 |     let r1 = &color;
+        |              ------ immutable borrow occurs here
+    197 |     let r2 = &mut color;
+        |              ^^^^^^^^^^ mutable borrow occurs here
+    ...
+    200 |     println!("r1 = {}", r1);
+        |                         -- immutable borrow later used here
+
+    error: aborting due to 1 previous error
+
+    For more information about this error, try `rustc --explain E0502`.
+
+    -----------------------------------------------------------------------
+
+    The reference r1 expects the value not to change while it is in
+    use.
+
+    A borrow only lasts until the last time the reference is used,
+    though. Once r1 is no longer used, we can borrow the value
+    mutably. For example, */
+
+    let mut shade = String::from("red");
+
+    let r3 = &shade;
+    println!("r3 = {}", r3); // r3 = red
+
+    // r3 is not used after this point, so a mutable reference
+    // can be created here
+    let r4 = &mut shade;
+    r4.push_str("dish");
+    println!("r4 = {}", r4); // r4 = reddish
+
+    /*
+    4. References Must Always Be Valid
+    -----------------------------------
+
+    A reference cannot outlive the value it points to. Otherwise, it
+    would point to memory that was already freed. Such a reference
+    is called a dangling reference. For example,
+
+    fn main() {
+        let r;
+
+        {
+            let planet = String::from("Earth");
+            r = &planet;
+        }
+
+        println!("r = {}", r);
+    }
+
+    -----------------------------------------------------------------------
+
+    error[E0597]: `planet` does not live long enough
+       --> _02_Rust_References_and_Borrowing.rs:253:13
+        |
+    252 |         let planet = String::from("Earth");
+        |             ------ binding `planet` declared here
+    253 |         r = &planet;
+        |             ^^^^^^^ borrowed value does not live long enough
+    254 |     }
+        |     - `planet` dropped here while still borrowed
+    255 |
+    256 |     println!("r = {}", r);
+        |                        - borrow later used here
+
+    error: aborting due to 1 previous error
+
+    For more information about this error, try `rustc --explain E0597`.
+
+    -----------------------------------------------------------------------
+
+    Here, planet is dropped at the end of the inner code block, but r
+    is used after it, so the borrow checker rejects the program.
+
+    For the same reason, a function cannot return a reference to a
+    variable it created itself.
+
+    fn dangle() -> &String {
+        let s = String::from("hello");
+
+        return &s;
+    }
+    fn main() {
+        let reference = dangle();
+    }
+
+    -----------------------------------------------------------------------
+
+    error[E0106]: missing lifetime specifier
+       --> _02_Rust_References_and_Borrowing.rs:286:16
+        |
+    286 | fn dangle() -> &String {
+        |                ^ expected named lifetime parameter
+        |
+        = help: this function's return type contains a borrowed value, but there is no value for it to be borrowed from
+    help: consider using the `'static` lifetime, but this is uncommon unless you're returning a borrowed value from a `const` or a `static`
+        |
+    286 | fn dangle() -> &'static String {
+        |                 +++++++
+    help: instead, you are more likely to want to return an owned value
+        |
+    286 - fn dangle() -> &String {
+    286 + fn dangle() -> String {
+        |
+
+    error: aborting due to 1 previous error
+
+    For more information about this error, try `rustc --explain E0106`.
+
+    -----------------------------------------------------------------------
+
+    The String is dropped when dangle() ends, so there is nothing left
+    for a reference to point to. The fix is to return the String
+    itself, which moves its ownership out of the function. */
+
+    // function that gives the ownership of a new String to its caller
+    fn no_dangle() -> String {
+        let s = String::from("hello");
+
+        return s;
+    }
+
+    let greeting = no_dangle();
+
+    println!("greeting = {}", greeting); // greeting = hello
+
+    /*
+
+    Frequently Asked Questions
+    ===========================
+
+    How do we change a value through a reference to an integer?
+
+    To change the value a mutable reference points to, we use the
+    dereference operator * in front of the reference. */
+
+    let mut count = 5;
+
+    let reference = &mut count;
+
+    // change the value that reference points to
+    *reference += 1;
+
+    println!("count = {}", count); // count = 6
+
+    /*
+    Methods like push_str() dereference automatically, which is why we
+    did not need * to change the String in the earlier examples.
+
+    Are references moved like Strings?
+
+    No. Immutable references implement the Copy trait, so assigning
+    one to another variable copies it, and both can be used. Mutable
+    references are moved instead, because only one of them may be in
+    use at a time. */
+
+    let planet = String::from("Mars");
+
+    let first = &planet;
+    let second = first; // the reference is copied
+
+    println!("first = {}, second = {}", first, second); // first = Mars, second = Mars
+}
@@ -0,0 +1,270 @@
+/*
+Rust Clone and Copy
+==================== */
+
+fn main() {
+    /*
+
+    In Rust Ownership, we saw that assigning a String to another
+    variable moves it, while assigning an integer copies it. Whether
+    a value is moved or copied depends on its type:
+
+    - values of types that implement the Copy trait are copied
+    - values of all the other types are moved
+
+    A trait describes something a type can do. The Copy trait says
+    that a value can be duplicated just by copying its bytes, and the
+    Clone trait says that it can be duplicated with the clone()
+    method. We will learn about traits in detail later.
+
+
+    Copy Types in Rust
+    ===================
+
+    The following types implement the Copy trait:
+
+    - the integer types, like i32 and u8
+    - the floating point types, f32 and f64
+    - the boolean type, bool
+    - the character type, char
+    - tuples and arrays whose elements are all Copy types
+    - immutable references, like &String
+
+    Let's see an example. */
+
+    let point = (3, 4);
+    let numbers = [1, 2, 3];
+
+    // both values are copied
+    let point_copy = point;
+    let numbers_copy = numbers;
+
+    println!("point = {:?}, point_copy = {:?}", point, point_copy); // point = (3, 4), point_copy = (3, 4)
+    println!("numbers = {:?}, numbers_copy = {:?}", numbers, numbers_copy); // numbers = [1, 2, 3], numbers_copy = [1, 2, 3]
+
+    /*
+    Here, the tuple point only holds integers, and so does the array
+    numbers. Both are Copy types, so the assignments copy them and the
+    original variables can still be used.
+
+    A tuple that holds a String is not a Copy type, because a String
+    is not. Assigning such a tuple moves it.
+
+    fn main() {
+        let person = (String::from("John"), 18);
+        let person_copy = person;
+
+        println!("person = {:?}", person);
+    }
+
+    -----------------------------------------------------------------------
+
+    error[E0382]: borrow of moved value: `person`
+      --> _03_Rust_Clone_and_Copy.rs:57:31
+       |
+    54 |     let person = (String::from("John"), 18);
+       |         ------ move occurs because `person` has type `(String, i32)`, which does not implement the `Copy` trait
+    55 |     let person_copy = person;
+       |                       ------ value moved here
+    56 |
+    57 |     println!("person = {:?}", person);
+       |                               ^^^^^^ value borrowed here after move
+       |
+    help: consider cloning the value if the performance cost is acceptable
+       |
+    55 |     let person_copy = person.clone();
+       |                             ++++++++
+
+    error: aborting due to 1 previous error
+
+    For more information about this error, try `rustc --explain E0382`.
+
+    -----------------------------------------------------------------------
+
+
+    Clone Trait in Rust
+    ====================
+
+    Most types that are not Copy types implement the Clone trait. We
+    call the clone() method to make a deep copy of their values,
+    including the data they keep on the heap. For example, */
+
+    let person = (String::from("John"), 18);
+
+    // make a deep copy of the tuple, String included
+    let mut twin = person.clone();
+    twin.0 = String::from("Jane");
+
+    println!("person = {:?}", person); // person = ("John", 18)
+    println!("twin = {:?}", twin); // twin = ("Jane", 18)
+
+    /*
+    Here, clone() creates a new tuple with a copy of the String. The
+    two tuples do not share anything, so changing the name of twin
+    does not change person.
+
+    Note: Unlike a copy, a clone always happens where we call
+          clone(). This makes the places where a program duplicates
+          data on the heap easy to find.
+
+
+    Clone and Copy for Structs
+    ===========================
+
+    A struct we define is neither a Copy type nor a Clone type by
+    default, even if all its fields are. Assigning it moves it.
+
+    fn main() {
+        struct Point {
+            x: i32,
+            y: i32,
+        }
+
+        let p1 = Point { x: 1, y: 2 };
+        let p2 = p1;
+
+        println!("p1.x = {}", p1.x);
+    }
+
+    -----------------------------------------------------------------------
+
+    error[E0382]: borrow of moved value: `p1`
+       --> _03_Rust_Clone_and_Copy.rs:126:27
+        |
+    123 |     let p1 = Point { x: 1, y: 2 };
+        |         -- move occurs because `p1` has type `Point`, which does not implement the `Copy` trait
+    124 |     let p2 = p1;
+        |              -- value moved here
+    125 |
+    126 |     println!("p1.x = {}", p1.x);
+        |                           ^^^^ value borrowed here after move
+        |
+    note: if `Point` implemented `Clone`, you could clone the value
+       --> _03_Rust_Clone_and_Copy.rs:118:5
+        |
+    118 |     struct Point {
+        |     ^^^^^^^^^^^^ consider implementing `Clone` for this type
+    ...
+    124 |     let p2 = p1;
+        |              -- you could clone this value
+
+    error: aborting due to 1 previous error
+
+    For more information about this error, try `rustc --explain E0382`.
+
+    -----------------------------------------------------------------------
+
+    To make the struct a Copy type, we add the #[derive(Clone, Copy)]
+    attribute to its definition. The compiler then writes the
+    implementation of both traits for us. */
+
+    #[derive(Debug, Clone, Copy)]
+    struct Point {
+        x: i32,
+        y: i32,
+    }
+
+    let p1 = Point { x: 1, y: 2 };
+
+    // p1 is copied into p2
+    let p2 = p1;
+
+    println!("p1 = {:?}", p1); // p1 = Point { x: 1, y: 2 }
+    println!("p2 = {:?}", p2); // p2 = Point { x: 1, y: 2 }
+
+    /*
+    Here,
+
+    - Clone - lets us duplicate a Point with p1.clone()
+    - Copy - makes assignments copy a Point instead of moving it
+    - Debug - lets us print a Point with {:?}
+
+    A type can only be a Copy type if it is also a Clone type, which
+    is why we derive both.
+
+    A struct can only be a Copy type if all its fields are Copy
+    types. If one of them is a String, we get an error.
+
+    fn main() {
+        #[derive(Clone, Copy)]
+        struct Person {
+            name: String,
+            age: u8,
+        }
+    }
+
+    -----------------------------------------------------------------------
+
+    error[E0204]: the trait `Copy` cannot be implemented for this type
+       --> _03_Rust_Clone_and_Copy.rs:190:12
+        |
+    189 |     #[derive(Clone, Copy)]
+        |                     ---- in this derive macro expansion
+    190 |     struct Person {
+        |            ^^^^^^
+    191 |         name: String,
+        |         ------------ this field does not implement `Copy`
+
+    error: aborting due to 1 previous error
+
+    For more information about this error, try `rustc --explain E0204`.
+
+    -----------------------------------------------------------------------
+
+    Such a struct can still derive Clone, and be duplicated by calling
+    clone(). */
+
+    #[derive(Debug, Clone)]
+    struct Person {
+        name: String,
+        age: u8,
+    }
+
+    let student = Person {
+        name: String::from("Ricky"),
+        age: 21,
+    };
+
+    // clone the student, name included
+    let classmate = student.clone();
+
+    // move the student into graduate
+    let graduate = student;
+
+    println!("classmate = {:?}", classmate);
+    println!("graduate = {:?}", graduate);
+
+    /*
+    Output:
+
+    classmate = Person { name: "Ricky", age: 21 }
+    graduate = Person { name: "Ricky", age: 21 }
+
+    Here, classmate is a clone of student, so student can still be
+    moved into graduate afterwards.
+
+
+    Frequently Asked Questions
+    ===========================
+
+    What is the difference between Copy and Clone?
+
+    - Copy is implicit: a Copy type is copied by every assignment or
+      function call, and only its bytes are copied.
+    - Clone is explicit: a value is only cloned when we call clone(),
+      and cloning can copy data on the heap, which may be expensive.
+
+    Because Point is a Copy type, passing p1 to a function copies it
+    as well, and p1 can still be used after the call. */
+
+    // p is a copy of the Point passed to the function
+    fn move_right(mut p: Point) -> Point {
+        p.x += 1;
+
+        return p;
+    }
+
+    let p3 = move_right(p1);
+
+    println!("p1 = {:?}, p3 = {:?}", p1, p3); // p1 = Point { x: 1, y: 2 }, p3 = Point { x: 2, y: 2 }
+}
@@ -0,0 +1,217 @@
+/*
+Rust Enum
+========== */
+
+fn main() {
+    /*
+
+    An enum (short for enumeration) is a user-defined data type that
+    lists all the possible values of a type. Each of these values is
+    called a variant.
+
+    For example, a traffic light is either red, yellow or green. An
+    enum lets us say exactly that, and nothing else.
+
+
+    Defining an Enum in Rust
+    =========================
+
+    We use the enum keyword to define an enum in Rust. The syntax of
+    an enum is:
+
+    ```rust,ignore
+    enum EnumName {
+        Variant1,
+        Variant2,
+        Variant3,
+    }
+    ```
+
+    Here,
+
+    - enum - keyword to define an enum
+    - EnumName - name of the enum
+    - Variant# - the possible values of the enum
+
+    Let's look at an example. */
+
+    // define an enum with four variants
+    #[derive(Debug)]
+    enum Sport {
+        Basketball,
+        Volleyball,
+        Football,
+        Cricket,
+    }
+
+    // create values of the enum
+    let basketball = Sport::Basketball;
+    let cricket = Sport::Cricket;
+
+    println!("{:?}", basketball); // Basketball
+    println!("{:?}", cricket); // Cricket
+
+    /*
+    Here,
+
+    - Sport::Basketball - the Basketball variant of the Sport enum
+    - #[derive(Debug)] - lets us print the variants with {:?}
+
+    A value of the type Sport can only be one of the four variants.
+    We access a variant with the name of the enum, followed by :: and
+    the name of the variant.
+
+
+    Enum Variants with Data
+    ========================
+
+    A variant can also hold data, like a tuple or a struct does. For
+    example, */
+
+    // define an enum whose variants hold data
+    #[derive(Debug)]
+    enum Animal {
+        Dog(String, f64),
+        Cat { name: String, weight: f64 },
+    }
+
+    let dog = Animal::Dog(String::from("Tucker"), 27.5);
+    let cat = Animal::Cat {
+        name: String::from("Maya"),
+        weight: 5.2,
+    };
+
+    println!("dog = {:?}", dog);
+    println!("cat = {:?}", cat);
+
+    /*
+    Output:
+
+    dog = Dog("Tucker", 27.5)
+    cat = Cat { name: "Maya", weight: 5.2 }
+
+    Here,
+
+    - Dog(String, f64) - a variant that holds a String and an f64,
+      like a tuple
+    - Cat { name: String, weight: f64 } - a variant with named fields,
+      like a struct
+
+    The variants of a single enum can hold different kinds of data,
+    and some of them can hold no data at all. */
+
+    #[derive(Debug)]
+    enum Message {
+        Quit,
+        Move { x: i32, y: i32 },
+        Write(String),
+        ChangeColor(u8, u8, u8),
+    }
+
+    let messages = [
+        Message::Quit,
+        Message::Move { x: 10, y: 20 },
+        Message::Write(String::from("Hello")),
+        Message::ChangeColor(255, 0, 0),
+    ];
+
+    for message in messages {
+        println!("{:?}", message);
+    }
+
+    /*
+    Output:
+
+    Quit
+    Move { x: 10, y: 20 }
+    Write("Hello")
+    ChangeColor(255, 0, 0)
+
+    All four values have the same type, Message, so we can store them
+    in the same array.
+
+    To read the data a variant holds, we need to check which variant
+    a value is first. We will learn how to do this in Rust match.
+
+
+    Comparing Enum Values
+    ======================
+
+    We cannot compare two enum values with == by default.
+
+    fn main() {
+        enum Sport {
+            Basketball,
+            Cricket,
+        }
+
+        let sport = Sport::Cricket;
+
+        if sport == Sport::Cricket {
+            println!("Let's play cricket!");
+        }
+    }
+
+    -----------------------------------------------------------------------
+
+    error[E0369]: binary operation `==` cannot be applied to type `Sport`
+       --> _01_Rust_Enum.rs:150:14
+        |
+    150 |     if sport == Sport::Cricket {
+        |        ----- ^^ -------------- Sport
+        |        |
+        |        Sport
+        |
+    note: an implementation of `PartialEq` might be missing for `Sport`
+       --> _01_Rust_Enum.rs:143:5
+        |
+    143 |     enum Sport {
+        |     ^^^^^^^^^^ must implement `PartialEq`
+    help: consider annotating `Sport` with `#[derive(PartialEq)]`
+        |
+    143 +     #[derive(PartialEq)]
+    144 |     enum Sport {
+        |
+
+    error: aborting due to 1 previous error
+
+    For more information about this error, try `rustc --explain E0369`.
+
+    -----------------------------------------------------------------------
+
+    As the error says, we need to derive the PartialEq trait to use ==
+    with our enum. */
+
+    #[derive(Debug, PartialEq)]
+    enum Weather {
+        Sunny,
+        Rainy,
+    }
+
+    let today = Weather::Rainy;
+
+    if today == Weather::Rainy {
+        println!("Take an umbrella!"); // Take an umbrella!
+    }
+
+    /*
+
+    Frequently Asked Questions
+    ===========================
+
+    Can an enum variant have a number as its value?
+
+    Yes. The variants of an enum without data are numbered 0, 1, 2 and
+    so on. We can choose the numbers ourselves, and read them with
+    the as keyword we learned about in Rust Type Casting. */
+
+    enum HttpStatus {
+        Ok = 200,
+        NotFound = 404,
+        ServerError = 500,
+    }
+
+    println!("Ok = {}", HttpStatus::Ok as i32); // Ok = 200
+    println!("NotFound = {}", HttpStatus::NotFound as i32); // NotFound = 404
+    println!("ServerError = {}", HttpStatus::ServerError as i32); // ServerError = 500
+}
@@ -0,0 +1,164 @@
+/*
+Rust Option
+============ */
+
+fn main() {
+    /*
+
+    Sometimes a value may or may not be there. For example, an array
+    of numbers may or may not contain an even number. Many languages
+    use a special null value for "nothing", but Rust does not have
+    one. Instead, it uses an enum called Option.
+
+
+    The Option Enum
+    ================
+
+    Option is defined by the standard library like this:
+
+    ```rust,ignore
+    enum Option<T> {
+        None,
+        Some(T),
+    }
+    ```
+
+    Here,
+
+    - None - there is no value
+    - Some(T) - there is a value of the type T
+
+    T stands for any type, so Option<i32> is an optional i32 and
+    Option<String> is an optional String. We will learn about such
+    generic types later.
+
+    Option is used so often that we can write Some and None without
+    the Option:: in front of them. For example, */
+
+    let number: Option<i32> = Some(5);
+    let nothing: Option<i32> = None;
+
+    println!("number = {:?}", number); // number = Some(5)
+    println!("nothing = {:?}", nothing); // nothing = None
+
+    /*
+
+    Example: Returning an Option
+    =============================
+
+    A function returns an Option when it may not have a result to
+    give back. */
+
+    // returns the first even number of the slice, if there is one
+    fn first_even(numbers: &[i32]) -> Option<i32> {
+        for &number in numbers {
+            if number % 2 == 0 {
+                return Some(number);
+            }
+        }
+
+        return None;
+    }
+
+    let numbers1 = [3, 7, 8, 10];
+    let numbers2 = [1, 5, 9];
+
+    println!("first even of {:?}: {:?}", numbers1, first_even(&numbers1));
+    println!("first even of {:?}: {:?}", numbers2, first_even(&numbers2));
+
+    /*
+    Output:
+
+    first even of [3, 7, 8, 10]: Some(8)
+    first even of [1, 5, 9]: None
+
+    Here, the return type Option<i32> tells everyone who calls
+    first_even() that there might be no even number, and the caller
+    has to deal with that case.
+
+
+    Option is Not the Value Itself
+    ===============================
+
+    An Option<i32> is not an i32, so we cannot use it like one. For
+    example,
+
+    fn main() {
+        let x: i32 = 5;
+        let y: Option<i32> = Some(5);
+
+        let sum = x + y;
+    }
+
+    -----------------------------------------------------------------------
+
+    error[E0277]: cannot add `Option<i32>` to `i32`
+      --> _02_Rust_Option.rs:90:17
+       |
+    90 |     let sum = x + y;
+       |                 ^ no implementation for `i32 + Option<i32>`
+       |
+       = help: the trait `Add<Option<i32>>` is not implemented for `i32`
+
+    error: aborting due to 1 previous error
+
+    For more information about this error, try `rustc --explain E0277`.
+
+    -----------------------------------------------------------------------
+
+    This is what makes Option safe: we cannot forget that y might be
+    None, because the compiler makes us take the value out of it
+    first.
+
+
+    Getting the Value Out of an Option
+    ===================================
+
+    The Option enum has methods to check and to get its value. */
+
+    let price: Option<u32> = Some(250);
+    let discount: Option<u32> = None;
+
+    // check which variant the values are
+    println!("{}", price.is_some()); // true
+    println!("{}", discount.is_none()); // true
+
+    // get the value, or a default value for None
+    println!("{}", price.unwrap_or(0)); // 250
+    println!("{}", discount.unwrap_or(0)); // 0
+
+    // get the value, which must be there
+    println!("{}", price.unwrap()); // 250
+
+    /*
+    Here,
+
+    - is_some() - returns true for Some
+    - is_none() - returns true for None
+    - unwrap_or(0) - returns the value in Some, or 0 for None
+    - unwrap() - returns the value in Some, and stops the program for
+      None
+
+    Note: Calling unwrap() on None stops the program with the message
+          called `Option::unwrap()` on a `None` value. Only use it
+          when the value is sure to be there.
+
+    To run different code for Some and None, we use match, which we
+    will learn about in Rust match.
+
+
+    Frequently Asked Questions
+    ===========================
+
+    Where else does Rust use Option?
+
+    Many methods of the standard library return an Option. For
+    example, the get() method of an array returns None for an index
+    that is out of bounds, instead of stopping the program the way
+    array[index] does. */
+
+    let colors = ["red", "green", "blue"];
+
+    println!("{:?}", colors.get(1)); // Some("green")
+    println!("{:?}", colors.get(5)); // None
+}
@@ -0,0 +1,388 @@
+/*
+Rust match
+=========== */
+
+fn main() {
+    /*
+
+    The match expression compares a value against a list of patterns
+    and runs the code of the first pattern that matches. It works a
+    bit like a chain of if and else if, but it can also look inside
+    enums, tuples and structs.
+
+
+    Syntax of match
+    ================
+
+    The syntax of the match expression is:
+
+    ```rust,ignore
+    match value {
+        pattern1 => expression1,
+        pattern2 => expression2,
+        ...
+    }
+    ```
+
+    Here,
+
+    - value - the value we want to match
+    - pattern# => expression# - an arm: if the value matches the
+      pattern, the expression is run
+
+    Let's see an example. */
+
+    #[derive(Debug)]
+    enum Sport {
+        Basketball,
+        Volleyball,
+        Football,
+        Cricket,
+    }
+
+    let sport = Sport::Football;
+
+    match sport {
+        Sport::Basketball => println!("5 players a side"),
+        Sport::Volleyball => println!("6 players a side"),
+        Sport::Football => println!("11 players a side"),
+        Sport::Cricket => println!("11 players a side, with a bat"),
+    }
+
+    /*
+    Output:
+
+    11 players a side
+
+    Here, sport is Sport::Football, so the third arm is run. The
+    arms are checked from top to bottom, and only the first arm that
+    matches is run.
+
+
+    match is an Expression
+    =======================
+
+    Like if, match is an expression, so it produces a value. Every
+    arm must produce a value of the same type. */
+
+    let number = 3;
+
+    let name = match number {
+        1 => "one",
+        2 => "two",
+        3 => "three",
+        _ => "many",
+    };
+
+    println!("{} is {}", number, name); // 3 is three
+
+    /*
+    The last arm uses the pattern _, which matches any value. It is
+    known as the wildcard pattern.
+
+
+    match Must Be Exhaustive
+    =========================
+
+    The arms of a match must cover every possible value. If we forget
+    a variant of an enum, the program does not compile.
+
+    fn main() {
+        enum Sport {
+            Basketball,
+            Volleyball,
+            Football,
+            Cricket,
+        }
+
+        let sport = Sport::Cricket;
+
+        match sport {
+            Sport::Basketball => println!("5 players a side"),
+            Sport::Volleyball => println!("6 players a side"),
+            Sport::Football => println!("11 players a side"),
+        }
+    }
+
+    -----------------------------------------------------------------------
+
+    error[E0004]: non-exhaustive patterns: `Sport::Cricket` not covered
+       --> _03_Rust_match.rs:100:11
+        |
+    100 |     match sport {
+        |           ^^^^^ pattern `Sport::Cricket` not covered
+        |
+    note: `Sport` defined here
+       --> _03_Rust_match.rs:91:10
+        |
+     91 |     enum Sport {
+        |          ^^^^^
+    ...
+     95 |         Cricket,
+        |         ------- not covered
+        = note: the matched value is of type `Sport`
+    help: ensure that all possible cases are being handled by adding a match arm with a wildcard pattern or an explicit pattern as shown
+        |
+    103 ~         Sport::Football => println!("11 players a side"),
+    104 ~         Sport::Cricket => todo!(),
+        |
+
+    error: aborting due to 1 previous error
+
+    For more information about this error, try `rustc --explain E0004`.
+
+    -----------------------------------------------------------------------
+
+    The compiler tells us which variant we forgot. This check makes
+    sure that, when we add a variant to an enum later, every match on
+    it is updated too.
+
+    The same goes for integers: an i32 has more than four billion
+    values, so a match on one almost always needs a _ arm.
+
+    fn main() {
+        let number = 3;
+
+        let name = match number {
+            1 => "one",
+            2 => "two",
+            3 => "three",
+        };
+    }
+
+    -----------------------------------------------------------------------
+
+    error[E0004]: non-exhaustive patterns: `i32::MIN..=0_i32` and `4_i32..=i32::MAX` not covered
+       --> _03_Rust_match.rs:146:22
+        |
+    146 |     let name = match number {
+        |                      ^^^^^^ patterns `i32::MIN..=0_i32` and `4_i32..=i32::MAX` not covered
+        |
+        = note: the matched value is of type `i32`
+    help: ensure that all possible cases are being handled by adding a match arm with a wildcard pattern, a match arm with multiple or-patterns as shown, or multiple match arms
+        |
+    149 ~         3 => "three",
+    150 ~         i32::MIN..=0_i32 | 4_i32..=i32::MAX => todo!(),
+        |
+
+    error: aborting due to 1 previous error
+
+    For more information about this error, try `rustc --explain E0004`.
+
+    -----------------------------------------------------------------------
+
+
+    Patterns in match
+    ==================
+
+    Patterns can do more than name a single value. Let's look at the
+    most common ones.
+
+
+    1. Multiple Values and Ranges
+    ------------------------------
+
+    We can match several values in one arm with |, and a range of
+    values with ..=. */
+
+    let day = 6;
+
+    match day {
+        1..=5 => println!("weekday"),
+        6 | 7 => println!("weekend"), // weekend
+        _ => println!("not a day of the week"),
+    }
+
+    /*
+    Here,
+
+    - 1..=5 - matches any number from 1 to 5
+    - 6 | 7 - matches either 6 or 7
+
+
+    2. Binding the Data of a Variant
+    ---------------------------------
+
+    A pattern can give names to the data a variant holds. We can then
+    use these names in the arm. For example, */
+
+    enum Animal {
+        Dog(String, f64),
+        Cat { name: String, weight: f64 },
+    }
+
+    let pets = [
+        Animal::Dog(String::from("Tucker"), 27.5),
+        Animal::Cat {
+            name: String::from("Maya"),
+            weight: 5.2,
+        },
+    ];
+
+    for pet in pets {
+        match pet {
+            Animal::Dog(name, weight) => println!("{} is a dog of {} kg", name, weight),
+            Animal::Cat { name, weight } => println!("{} is a cat of {} kg", name, weight),
+        }
+    }
+
+    /*
+    Output:
+
+    Tucker is a dog of 27.5 kg
+    Maya is a cat of 5.2 kg
+
+    This is also how we get the value out of an Option: */
+
+    let scores = [72, 95, 88];
+
+    match scores.iter().max() {
+        Some(best) => println!("best score = {}", best), // best score = 95
+        None => println!("there are no scores"),
+    }
+
+    /*
+    3. Match Guards
+    ----------------
+
+    A match guard is an extra if condition after a pattern. The arm
+    only matches if the condition is also true. */
+
+    let temperature: Option<i32> = Some(31);
+
+    match temperature {
+        Some(t) if t > 30 => println!("{} degrees, it's hot!", t), // 31 degrees, it's hot!
+        Some(t) => println!("{} degrees", t),
+        None => println!("no reading"),
+    }
+
+    /*
+    The compiler does not look inside guards when it checks that a
+    match is exhaustive. If every arm that could match a value has a
+    guard, the match is not exhaustive.
+
+    fn main() {
+        let temperature: Option<i32> = Some(31);
+
+        match temperature {
+            Some(t) if t > 30 => println!("hot"),
+            Some(t) if t <= 30 => println!("not hot"),
+            None => println!("no reading"),
+        }
+    }
+
+    -----------------------------------------------------------------------
+
+    error[E0004]: non-exhaustive patterns: `Some(_)` not covered
+       --> _03_Rust_match.rs:267:11
+        |
+    267 |     match temperature {
+        |           ^^^^^^^^^^^ pattern `Some(_)` not covered
+        |
+    note: `Option<i32>` defined here
+       --> /rustc/59807616e1fa2540724bfbac14d7976d7e4a3860/library/core/src/option.rs:600:0
+       ::: /rustc/59807616e1fa2540724bfbac14d7976d7e4a3860/library/core/src/option.rs:608:4
+        |
+        = note: not covered
+        = note: the matched value is of type `Option<i32>`
+    help: ensure that all possible cases are being handled by adding a match arm with a wildcard pattern or an explicit pattern as shown
+        |
+    270 ~         None => println!("no reading"),
+    271 ~         Some(_) => todo!(),
+        |
+
+    error: aborting due to 1 previous error
+
+    For more information about this error, try `rustc --explain E0004`.
+
+    -----------------------------------------------------------------------
+
+    We know that the two guards cover every number, but the compiler
+    does not, so the last Some arm must not have a guard.
+
+
+    4. Binding With @
+    ------------------
+
+    The @ operator gives a name to a value while also testing it
+    against a pattern, like a range. */
+
+    let age = 16;
+
+    match age {
+        0 => println!("newborn"),
+        child @ 1..=12 => println!("a child of {}", child),
+        teen @ 13..=19 => println!("a teenager of {}", teen), // a teenager of 16
+        adult => println!("an adult of {}", adult),
+    }
+
+    /*
+    The last arm has no range, so adult matches every other age.
+
+
+    Destructuring Tuples and Structs
+    =================================
+
+    In Rust tuple and Rust struct, we destructured values with let.
+    The same patterns work in match, where they can also contain
+    values to compare against. */
+
+    let tuple = ("John Doe", 18, 178);
+
+    match tuple {
+        (name, 0..=17, _) => println!("{} is too young to vote", name),
+        (name, age, height) => println!("{} is {} and {} cm tall", name, age, height),
+    }
+
+    /*
+    Output:
+
+    John Doe is 18 and 178 cm tall
+
+    Here, the first arm only matches tuples whose second element is
+    between 0 and 17. The _ ignores the third element.
+
+    A struct pattern lists the fields by name, and .. ignores the
+    fields we do not list. */
+
+    struct Person {
+        name: String,
+        age: u8,
+        height: u8,
+    }
+
+    let person = Person {
+        name: String::from("John Doe"),
+        age: 18,
+        height: 178,
+    };
+
+    match person {
+        Person { age: 0..=17, .. } => println!("a minor"),
+        Person { height: 180.., .. } => println!("a tall adult"),
+        Person { name, .. } => println!("{} is an adult", name), // John Doe is an adult
+    }
+
+    /*
+    Here,
+
+    - Person { age: 0..=17, .. } - matches a person aged 0 to 17
+    - Person { height: 180.., .. } - matches a height of 180 or more
+    - Person { name, .. } - matches any person and binds the name field
+
+
+    Frequently Asked Questions
+    ===========================
+
+    Can we match on strings?
+
+    Yes. A string slice can be compared against string literals. */
+
+    let command = "stop";
+
+    match command {
+        "start" => println!("starting"),
+        "stop" => println!("stopping"), // stopping
+        _ => println!("unknown command"),
+    }
+}
@@ -0,0 +1,228 @@
+/*
+Rust if let and while let
+========================== */
+
+fn main() {
+    /*
+
+    A match must cover every possible value. When we only care about
+    one pattern, this makes the code longer than it needs to be. For
+    example, */
+
+    let favorite: Option<&str> = Some("blue");
+
+    match favorite {
+        Some(color) => println!("favorite color = {}", color), // favorite color = blue
+        _ => (),
+    }
+
+    /*
+    Here, the _ => () arm does nothing. It is only there because the
+    match has to be exhaustive. Rust has shorter forms for such cases:
+    if let, while let and let else.
+
+
+    Rust if let
+    ============
+
+    The if let expression runs a block of code only if a value matches
+    a pattern. Its syntax is:
+
+    ```rust,ignore
+    if let pattern = value {
+        // code to run if value matches pattern
+    }
+    ```
+
+    Let's rewrite the example above with if let. */
+
+    let favorite: Option<&str> = Some("green");
+
+    if let Some(color) = favorite {
+        println!("favorite color = {}", color); // favorite color = green
+    }
+
+    /*
+    Here, if favorite is Some, the string slice it holds is bound to
+    color and the block is run. If favorite is None, nothing happens.
+
+
+    if let with else
+    -----------------
+
+    Like if, an if let can have an else block, which runs if the value
+    does not match the pattern. */
+
+    let nickname: Option<&str> = None;
+
+    if let Some(name) = nickname {
+        println!("Hi, {}!", name);
+    } else {
+        println!("Hi, stranger!"); // Hi, stranger!
+    }
+
+    /*
+    if let works with any pattern, not only with Option. */
+
+    enum Shape {
+        Circle(f64),
+        Square(f64),
+    }
+
+    let shape = Shape::Circle(2.0);
+
+    if let Shape::Circle(radius) = shape {
+        println!("a circle of radius {}", radius); // a circle of radius 2
+    }
+
+    /*
+
+    Rust while let
+    ===============
+
+    The while let loop runs as long as a value matches a pattern. It
+    is often used to take values out of something one by one until
+    there are none left.
+
+    For example, the pop() method of a vector removes its last element
+    and returns it as Some, or returns None when the vector is empty.
+    We will learn about vectors in Rust Vector. */
+
+    let mut stack = vec![1, 2, 3];
+
+    while let Some(top) = stack.pop() {
+        println!("top = {}", top);
+    }
+
+    /*
+    Output:
+
+    top = 3
+    top = 2
+    top = 1
+
+    Here, the loop body runs once for each element. When the vector is
+    empty, pop() returns None, the pattern does not match, and the
+    loop stops.
+
+
+    Rust let else
+    ==============
+
+    A let statement can only use a pattern that always matches, like
+    a tuple pattern. A pattern like Some(x) may not match, so it is
+    not allowed on its own.
+
+    fn main() {
+        let favorite: Option<&str> = Some("blue");
+
+        let Some(color) = favorite;
+    }
+
+    -----------------------------------------------------------------------
+
+    error[E0005]: refutable pattern in local binding
+       --> _04_Rust_if_let_and_while_let.rs:119:9
+        |
+    119 |     let Some(color) = favorite;
+        |         ^^^^^^^^^^^ pattern `None` not covered
+        |
+        = note: `let` bindings require an "irrefutable pattern", like a `struct` or an `enum` with only one variant
+        = note: for more information, visit https://doc.rust-lang.org/book/ch19-02-refutability.html
+        = note: the matched value is of type `Option<&str>`
+    help: you might want to use `let...else` to handle the variant that isn't matched
+        |
+    119 |     let Some(color) = favorite else { todo!() };
+        |                                ++++++++++++++++
+
+    error: aborting due to 1 previous error
+
+    For more information about this error, try `rustc --explain E0005`.
+
+    -----------------------------------------------------------------------
+
+    With let else, we add an else block that runs when the pattern
+    does not match. The else block must leave the current code, for
+    example with return, break or continue. Otherwise, the variables
+    of the pattern would have no value after it.
+
+    let else is useful at the start of a function, to check its
+    inputs before the real work begins. */
+
+    // returns the price with tax, or a message if there is no price
+    fn with_tax(price: Option<u32>) -> String {
+        let Some(amount) = price else {
+            return String::from("no price");
+        };
+
+        // amount is a u32 from here on
+        return format!("{} with tax", amount * 110 / 100);
+    }
+
+    println!("{}", with_tax(Some(200))); // 220 with tax
+    println!("{}", with_tax(None)); // no price
+
+    /*
+    Here, if price is None, the else block returns from the function.
+    Otherwise, the value is bound to amount, which can be used in the
+    rest of the function.
+
+    If the else block does not leave the code, we get an error.
+
+    fn with_tax(price: Option<u32>) -> u32 {
+        let Some(amount) = price else {
+            println!("no price");
+        };
+
+        return amount * 110 / 100;
+    }
+    fn main() {
+        println!("{}", with_tax(None));
+    }
+
+    -----------------------------------------------------------------------
+
+    error[E0308]: `else` clause of `let...else` does not diverge
+       --> _04_Rust_if_let_and_while_let.rs:173:35
+        |
+    173 |       let Some(amount) = price else {
+        |  ___________________________________^
+    174 | |         println!("no price");
+    175 | |     };
+        | |_____^ expected `!`, found `()`
+        |
+        = note:   expected type `!`
+                found unit type `()`
+        = help: try adding a diverging expression, such as `return` or `panic!(..)`
+        = help: ...or use `match` instead of `let...else`
+
+    error: aborting due to 1 previous error
+
+    For more information about this error, try `rustc --explain E0308`.
+
+    -----------------------------------------------------------------------
+
+
+    Frequently Asked Questions
+    ===========================
+
+    When should we use match instead of if let?
+
+    Use if let when only one pattern needs code. Use match when two or
+    more patterns need different code, because match also checks that
+    no pattern was forgotten.
+
+    Can if let have a condition like a match guard?
+
+    Yes. Since Rust 2024, we can join if let with other conditions
+    using &&. In earlier editions, we put a plain if inside the if
+    let, like this: */
+
+    let reading: Option<i32> = Some(35);
+
+    if let Some(t) = reading {
+        if t > 30 {
+            println!("{} degrees, it's hot!", t); // 35 degrees, it's hot!
+        }
+    }
+}
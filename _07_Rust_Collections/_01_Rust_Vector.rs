@@ -0,0 +1,270 @@
+/*
+Rust Vector
+============ */
+
+fn main() {
+    /*
+
+    In Rust Array, we learned that an array like [i32; 5] always holds
+    exactly five elements. Its length is part of its type, so it must
+    be known when the program is compiled.
+
+    A vector is a growable array. It stores its elements next to each
+    other on the heap, like a String stores its text, and it can grow
+    or shrink while the program runs. The type of a vector is Vec<T>,
+    where T is the type of its elements.
+
+
+    Creating a Vector in Rust
+    ==========================
+
+    We can create a vector with the vec! macro, which takes the initial
+    elements the same way an array does. */
+
+    // create a vector with three elements
+    let numbers = vec![1, 2, 3];
+
+    // create an empty vector of i32 values
+    let empty: Vec<i32> = Vec::new();
+
+    println!("numbers = {:?}", numbers); // numbers = [1, 2, 3]
+    println!("empty = {:?}", empty); // empty = []
+
+    /*
+    Here,
+
+    - vec![1, 2, 3] - creates a Vec<i32> holding 1, 2 and 3
+    - Vec::new() - creates an empty vector
+
+    An empty vector has no elements to guess the type from, so we write
+    the type Vec<i32> ourselves.
+
+
+    Accessing Elements of a Vector
+    ===============================
+
+    Like an array, a vector is indexed from 0. We can read an element
+    with [index], or with the get() method, which returns an Option. */
+
+    let colors = vec!["blue", "red", "green"];
+
+    println!("first = {}", colors[0]); // first = blue
+    println!("second = {:?}", colors.get(1)); // second = Some("red")
+    println!("tenth = {:?}", colors.get(9)); // tenth = None
+
+    /*
+    Note: colors[9] would stop the program, because the vector has no
+          tenth element. Use get() when an index may be out of bounds.
+
+
+    Adding and Removing Elements
+    =============================
+
+    A vector must be mutable for us to change its elements. */
+
+    let mut even_numbers = vec![2, 4];
+
+    // add elements to the end of the vector
+    even_numbers.push(6);
+    even_numbers.push(8);
+    println!("after push: {:?}", even_numbers); // after push: [2, 4, 6, 8]
+
+    // remove the last element
+    let last = even_numbers.pop();
+    println!("popped {:?}, left {:?}", last, even_numbers); // popped Some(8), left [2, 4, 6]
+
+    // remove the element at index 0
+    let first = even_numbers.remove(0);
+    println!("removed {}, left {:?}", first, even_numbers); // removed 2, left [4, 6]
+
+    println!("length = {}", even_numbers.len()); // length = 2
+
+    /*
+    Here,
+
+    - push(6) - adds 6 to the end of the vector
+    - pop() - removes the last element and returns it in Some, or
+      returns None if the vector is empty
+    - remove(0) - removes the element at index 0 and moves all the
+      elements after it one place to the left
+    - len() - returns the number of elements
+
+
+    Looping Through a Vector
+    =========================
+
+    We loop through a vector with a for loop. Looping over &scores
+    borrows the vector, so we can still use it after the loop. */
+
+    let mut scores = vec![72, 95, 88];
+
+    for score in &scores {
+        println!("score = {}", score);
+    }
+
+    // borrow the vector mutably to change its elements
+    for score in &mut scores {
+        *score += 5;
+    }
+
+    println!("scores = {:?}", scores);
+
+    /*
+    Output:
+
+    score = 72
+    score = 95
+    score = 88
+    scores = [77, 100, 93]
+
+    In the second loop, score is a mutable reference to an element, so
+    we use * to change the element it points to.
+
+
+    Vector vs Array
+    ================
+
+    An array cannot grow, so it has no push() method.
+
+    fn main() {
+        let mut numbers = [1, 2, 3];
+
+        numbers.push(4);
+    }
+
+    -----------------------------------------------------------------------
+
+    error[E0599]: no method named `push` found for array `[{integer}; 3]` in the current scope
+       --> _01_Rust_Vector.rs:132:13
+        |
+    132 |     numbers.push(4);
+        |             ^^^^ method not found in `[{integer}; 3]`
+
+    error: aborting due to 1 previous error
+
+    For more information about this error, try `rustc --explain E0599`.
+
+    -----------------------------------------------------------------------
+
+    Use an array when the number of elements is fixed, like the days
+    of a week, and a vector when it is only known while the program
+    runs, like the lines of a file.
+
+    A vector is also subject to the borrowing rules we learned in Rust
+    References and Borrowing. We cannot push to a vector while a
+    reference to one of its elements is in use, because push() may
+    move the elements to a bigger place in memory.
+
+    fn main() {
+        let mut numbers = vec![1, 2, 3];
+
+        let first = &numbers[0];
+        numbers.push(4);
+
+        println!("first = {}", first);
+    }
+
+    -----------------------------------------------------------------------
+
+    error[E0502]: cannot borrow `numbers` as mutable because it is also borrowed as immutable
+       --> _01_Rust_Vector.rs:162:5
+        |
+    161 |     let first = &numbers[0];
+        |                  ------- immutable borrow occurs here
+    162 |     numbers.push(4);
+        |     ^^^^^^^^^^^^^^^ mutable borrow occurs here
+    163 |
+    164 |     println!("first = {}", first);
+        |                            ----- immutable borrow later used here
+
+    error: aborting due to 1 previous error
+
+    For more information about this error, try `rustc --explain E0502`.
+
+    -----------------------------------------------------------------------
+
+
+    Example: Exam Results
+    ======================
+
+    Let's put these together. The function below takes the scores of
+    an exam and returns the scores of the students who passed, along
+    with the average score. */
+
+    // returns the passing scores and the average of all the scores
+    fn exam_results(scores: &[u32], pass_mark: u32) -> (Vec<u32>, f64) {
+        let mut passed = Vec::new();
+        let mut total = 0;
+
+        for &score in scores {
+            total += score;
+            if score >= pass_mark {
+                passed.push(score);
+            }
+        }
+
+        let average = total as f64 / scores.len() as f64;
+
+        return (passed, average);
+    }
+
+    let exam = vec![45, 82, 67, 38, 91, 74];
+    let (passed, average) = exam_results(&exam, 50);
+
+    println!("passed: {:?}", passed);
+    println!("{} of {} passed", passed.len(), exam.len());
+    println!("average = {:.1}", average);
+
+    /*
+    Output:
+
+    passed: [82, 67, 91, 74]
+    4 of 6 passed
+    average = 66.2
+
+    Here, the function borrows the scores as a slice, &[u32]. A &Vec<u32>
+    can be passed where a slice is expected, just like a reference to
+    an array.
+
+
+    Performance of a Vector
+    ========================
+
+    A vector keeps some spare room at its end, called its capacity.
+    push() only has to find a bigger place in memory when the spare
+    room runs out, and then it doubles the capacity. This makes push()
+    fast on average.
+
+    Adding to the front is a different story: insert(0, value) moves
+    every element one place to the right, so it gets slower the longer
+    the vector is.
+
+    The benchmarks of this chapter compare both. Run them with:
+
+        cargo bench -p ch07_collections -- vector
+
+    On one machine, adding 10000 numbers printed:
+
+        push, starting from Vec::new()             17.6 µs
+        push, starting from with_capacity()        16.1 µs
+        insert(0, ...)                           2614.4 µs
+
+    Adding to the front was about 150 times slower. If we know how many
+    elements a vector will hold, Vec::with_capacity() reserves the room
+    up front, which saves a little more. If we need to add to the front
+    often, a VecDeque is a better fit; we will learn about it in Rust
+    VecDeque.
+
+
+    Frequently Asked Questions
+    ===========================
+
+    How do we check if a vector contains a value?
+
+    We use the contains() method, which takes a reference to the value. */
+
+    let languages = vec!["Rust", "Python", "C"];
+
+    println!("{}", languages.contains(&"Rust")); // true
+    println!("{}", languages.contains(&"Java")); // false
+}
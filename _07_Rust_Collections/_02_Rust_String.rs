@@ -0,0 +1,283 @@
+/*
+Rust String
+============ */
+
+fn main() {
+    /*
+
+    Rust has two main types for text:
+
+    - String - a growable piece of text that owns its data on the heap
+    - &str - a string slice, which borrows a piece of text owned by
+      someone else
+
+    A String is a collection too: it is a vector of bytes that always
+    holds valid UTF-8 text. In this lesson, we will see how the two
+    types work together.
+
+
+    String Literals and String Slices
+    ==================================
+
+    A string literal like "Hello" is a &str. Its text is stored in the
+    program itself, and the &str points to it. */
+
+    let greeting: &str = "Hello";
+
+    println!("greeting = {}", greeting); // greeting = Hello
+
+    /*
+    A &str cannot grow or change, because it does not own its text. We
+    cannot use a string literal where a String is expected either.
+
+    fn main() {
+        let name: String = "Ferris";
+    }
+
+    -----------------------------------------------------------------------
+
+    error[E0308]: mismatched types
+      --> _02_Rust_String.rs:34:24
+       |
+    34 |     let name: String = "Ferris";
+       |               ------   ^^^^^^^^ expected `String`, found `&str`
+       |               |
+       |               expected due to this
+       |
+    help: try using a conversion method
+       |
+    34 |     let name: String = "Ferris".to_string();
+       |                                ++++++++++++
+
+    error: aborting due to 1 previous error
+
+    For more information about this error, try `rustc --explain E0308`.
+
+    -----------------------------------------------------------------------
+
+
+    Creating a String in Rust
+    ==========================
+
+    We create a String from a &str with String::from() or to_string(),
+    or start with an empty one with String::new(). */
+
+    let language = String::from("Rust");
+    let version = "2021".to_string();
+    let mut notes = String::new();
+
+    notes.push_str("fast");
+    notes.push_str(" and safe");
+    notes.push('!');
+
+    println!("{} {}: {}", language, version, notes); // Rust 2021: fast and safe!
+
+    /*
+    Here,
+
+    - push_str(" and safe") - adds a &str to the end of the String
+    - push('!') - adds a single character to the end of the String
+
+
+    Slicing a String
+    =================
+
+    In Rust Slice, we took slices of arrays. We can take a slice of a
+    String in the same way, and get a &str that borrows part of its
+    text. */
+
+    let sentence = String::from("Hello, World!");
+
+    let hello: &str = &sentence[0..5];
+    let world: &str = &sentence[7..12];
+
+    println!("{} and {}", hello, world); // Hello and World
+
+    /*
+    The numbers of a string slice count bytes, not characters. English
+    letters take one byte each, but other characters take up to four.
+
+    Note: A slice that starts or ends inside a character stops the
+          program. For the same reason, Rust does not let us index a
+          String with a single number.
+
+    fn main() {
+        let sentence = String::from("Hello, World!");
+        let first = sentence[0];
+    }
+
+    -----------------------------------------------------------------------
+
+    error[E0277]: the type `str` cannot be indexed by `{integer}`
+       --> _02_Rust_String.rs:106:26
+        |
+    106 |     let first = sentence[0];
+        |                          ^ string indices are ranges of `usize`
+        |
+        = help: the trait `SliceIndex<str>` is not implemented for `{integer}`
+        = note: you can use `.chars().nth()` or `.bytes().nth()`
+                for more information, see chapter 8 in The Book: <https://doc.rust-lang.org/book/ch08-02-strings.html#indexing-into-strings>
+        = note: required for `String` to implement `Index<{integer}>`
+
+    error: aborting due to 1 previous error
+
+    For more information about this error, try `rustc --explain E0277`.
+
+    -----------------------------------------------------------------------
+
+    To work with characters, we use the chars() method instead. */
+
+    let word = String::from("héllo");
+
+    println!("bytes = {}", word.len()); // bytes = 6
+    println!("characters = {}", word.chars().count()); // characters = 5
+
+    for c in word.chars() {
+        print!("{} ", c);
+    }
+    println!();
+
+    /*
+    Output:
+
+    bytes = 6
+    characters = 5
+    h é l l o
+
+    Here, é takes two bytes, so the String is 6 bytes long even though
+    it only has 5 characters.
+
+
+    Joining Strings
+    ================
+
+    There are two common ways to join strings: the + operator and the
+    format! macro. */
+
+    let first_name = String::from("John");
+    let last_name = String::from("Doe");
+
+    // first_name is moved into full_name
+    let full_name = first_name + " " + &last_name;
+
+    // format! only borrows its arguments
+    let label = format!("{} ({})", full_name, last_name);
+
+    println!("{}", label); // John Doe (Doe)
+
+    /*
+    Here,
+
+    - first_name + " " + &last_name - takes the ownership of
+      first_name, adds the text to it and gives it back, so first_name
+      can no longer be used
+    - format!() - works like println!, but returns the text as a new
+      String instead of printing it
+
+
+    String or &str in Function Parameters?
+    =======================================
+
+    A function that only reads text should take a &str. We can then
+    pass it a string literal, a slice, or a &String, which Rust turns
+    into a &str for us. */
+
+    // returns the first word of the text
+    fn first_word(text: &str) -> &str {
+        for (index, c) in text.char_indices() {
+            if c == ' ' {
+                return &text[..index];
+            }
+        }
+
+        return text;
+    }
+
+    let owned = String::from("quick brown fox");
+
+    println!("{}", first_word("lazy dog")); // lazy
+    println!("{}", first_word(&owned)); // quick
+
+    /*
+    Here, char_indices() gives each character along with the byte it
+    starts at, so the slice always ends on a character boundary.
+
+
+    Example: Title Case
+    ====================
+
+    Let's write a function that capitalizes every word of a text. It
+    reads a &str and builds a new String. */
+
+    // returns the text with the first letter of every word in upper case
+    fn title_case(text: &str) -> String {
+        let mut result = String::new();
+
+        for word in text.split_whitespace() {
+            if !result.is_empty() {
+                result.push(' ');
+            }
+
+            let mut chars = word.chars();
+            if let Some(first) = chars.next() {
+                result.push_str(&first.to_uppercase().to_string());
+                result.push_str(chars.as_str());
+            }
+        }
+
+        return result;
+    }
+
+    let title = title_case("the   rust programming  language");
+
+    println!("{}", title); // The Rust Programming Language
+
+    /*
+    Here,
+
+    - split_whitespace() - gives the words of the text, skipping any
+      number of spaces between them
+    - chars.next() - takes the first character of a word, as an Option
+    - chars.as_str() - gives the rest of the word as a &str
+
+
+    Performance of a String
+    ========================
+
+    Like a vector, a String has spare room at its end, so push_str()
+    is fast on average. format!() always creates a new String, and
+    copies all the text into it.
+
+    Also, len() is instant because a String knows its length in bytes,
+    but chars().count() has to go through the whole text to count the
+    characters.
+
+    Run the benchmark with:
+
+        cargo bench -p ch07_collections -- string
+
+    On one machine, joining 10000 numbers into a text and measuring
+    the length of a text with 120000 characters printed:
+
+        push_str() into one String                335.4 µs
+        format!() a new String each time        26077.1 µs
+        len()                                       0.1 µs
+        chars().count()                             9.8 µs
+
+    Building the text with format!() in a loop was about 80 times
+    slower, as every step copied everything built so far. To build a
+    String piece by piece, keep one String and push to it.
+
+
+    Frequently Asked Questions
+    ===========================
+
+    How do we compare a String with a &str?
+
+    We can compare them directly with ==. */
+
+    let answer = String::from("yes");
+
+    println!("{}", answer == "yes"); // true
+    println!("{}", answer.to_uppercase() == "YES"); // true
+}
@@ -0,0 +1,270 @@
+/*
+Rust HashMap
+============= */
+
+fn main() {
+    /*
+
+    A HashMap stores data as key-value pairs. Each key is linked to a
+    value, like a word in a dictionary is linked to its meaning, and
+    we use the key to find the value quickly.
+
+    HashMap is part of the standard library, but it is not imported
+    by default. We import it with the use keyword. */
+
+    use std::collections::HashMap;
+
+    /*
+    Here, std::collections is the module of the standard library that
+    holds the collections of this chapter.
+
+
+    Creating a HashMap in Rust
+    ===========================
+
+    We create an empty HashMap with HashMap::new(), and add key-value
+    pairs to it with the insert() method. */
+
+    let mut fruits: HashMap<i32, String> = HashMap::new();
+
+    fruits.insert(1, String::from("Apple"));
+    fruits.insert(2, String::from("Banana"));
+
+    println!("number of fruits = {}", fruits.len()); // number of fruits = 2
+
+    /*
+    Here,
+
+    - HashMap<i32, String> - a HashMap with i32 keys and String values
+    - insert(1, ...) - adds the value "Apple" with the key 1
+
+    Note: A HashMap does not keep its pairs in any particular order.
+          Printing a whole HashMap may show them in a different order
+          each time the program runs.
+
+
+    Accessing Values in a HashMap
+    ==============================
+
+    We use the get() method to read the value of a key. It returns an
+    Option, because the key may not be in the HashMap. */
+
+    let mut capitals = HashMap::new();
+
+    capitals.insert("Nepal", "Kathmandu");
+    capitals.insert("Japan", "Tokyo");
+    capitals.insert("Peru", "Lima");
+
+    println!("{:?}", capitals.get("Japan")); // Some("Tokyo")
+    println!("{:?}", capitals.get("Chile")); // None
+
+    // check if a key is present
+    println!("{}", capitals.contains_key("Peru")); // true
+
+    /*
+    Here, Rust finds out the types of the keys and values from the
+    first insert(), so we do not write them ourselves.
+
+
+    Updating and Removing Values
+    =============================
+
+    Inserting a key that is already in the HashMap replaces its value.
+    The remove() method removes a key along with its value. */
+
+    capitals.insert("Peru", "Cusco");
+    println!("{:?}", capitals.get("Peru")); // Some("Cusco")
+
+    let removed = capitals.remove("Nepal");
+    println!("removed {:?}", removed); // removed Some("Kathmandu")
+    println!("{}", capitals.len()); // 2
+
+    /*
+    Often, we want to change a value based on the one already there,
+    or insert a starting value if there is none. The entry() method
+    does both. */
+
+    let mut stock: HashMap<&str, u32> = HashMap::new();
+
+    // no apples yet, so 0 is inserted before adding 5
+    *stock.entry("apple").or_insert(0) += 5;
+    // the apples are there now, so 3 is added to 5
+    *stock.entry("apple").or_insert(0) += 3;
+
+    println!("apples = {}", stock["apple"]); // apples = 8
+
+    /*
+    Here,
+
+    - entry("apple") - finds the place of the key "apple"
+    - or_insert(0) - inserts 0 if the key is missing, and returns a
+      mutable reference to the value
+    - stock["apple"] - reads the value of a key that must be there
+
+
+    Looping Through a HashMap
+    ==========================
+
+    A for loop gives each key-value pair as a tuple. To print the pairs
+    in the same order every time, we can copy them into a vector and
+    sort it first. */
+
+    let mut ages = HashMap::new();
+
+    ages.insert("Maya", 29);
+    ages.insert("John", 18);
+    ages.insert("Ricky", 21);
+
+    let mut sorted: Vec<(&str, i32)> = Vec::new();
+    for (name, age) in &ages {
+        sorted.push((*name, *age));
+    }
+    sorted.sort();
+
+    for (name, age) in sorted {
+        println!("{} is {}", name, age);
+    }
+
+    /*
+    Output:
+
+    John is 18
+    Maya is 29
+    Ricky is 21
+
+    sort() orders the tuples by their first element, the name. If we
+    need the keys in order all the time, a BTreeMap is a better fit;
+    we will learn about it in Rust BTreeMap.
+
+
+    HashMap and Ownership
+    ======================
+
+    Values like String are moved into the HashMap by insert(), so we
+    cannot use them afterwards.
+
+    fn main() {
+        use std::collections::HashMap;
+
+        let mut scores = HashMap::new();
+        let team = String::from("Blue");
+
+        scores.insert(team, 10);
+
+        println!("team = {}", team);
+    }
+
+    -----------------------------------------------------------------------
+
+    error[E0382]: borrow of moved value: `team`
+       --> _03_Rust_HashMap.rs:154:27
+        |
+    150 |     let team = String::from("Blue");
+        |         ---- move occurs because `team` has type `String`, which does not implement the `Copy` trait
+    151 |
+    152 |     scores.insert(team, 10);
+        |                   ---- value moved here
+    153 |
+    154 |     println!("team = {}", team);
+        |                           ^^^^ value borrowed here after move
+        |
+    help: consider cloning the value if the performance cost is acceptable
+        |
+    152 |     scores.insert(team.clone(), 10);
+        |                       ++++++++
+
+    error: aborting due to 1 previous error
+
+    For more information about this error, try `rustc --explain E0382`.
+
+    -----------------------------------------------------------------------
+
+    If we still need the String, we can insert a clone of it instead,
+    or make the HashMap hold references like &team.
+
+
+    Example: Counting Words
+    ========================
+
+    A HashMap is the usual way to count things. Let's count how many
+    times each word appears in a text. */
+
+    // returns how many times each word appears in the text
+    fn word_counts(text: &str) -> HashMap<String, u32> {
+        let mut counts = HashMap::new();
+
+        for word in text.split_whitespace() {
+            let word = word.to_lowercase();
+            *counts.entry(word).or_insert(0) += 1;
+        }
+
+        return counts;
+    }
+
+    let text = "The cat sat on the mat and the dog sat too";
+    let counts = word_counts(text);
+
+    println!("the: {}", counts["the"]);
+    println!("sat: {}", counts["sat"]);
+    println!("dog: {}", counts["dog"]);
+    println!("different words: {}", counts.len());
+
+    /*
+    Output:
+
+    the: 3
+    sat: 2
+    dog: 1
+    different words: 8
+
+    Here, to_lowercase() makes "The" and "the" count as the same word.
+
+
+    Performance of a HashMap
+    =========================
+
+    A HashMap turns every key into a number called a hash, and uses it
+    to jump straight to the place of the key. Finding a key takes about
+    the same time however many pairs the HashMap holds.
+
+    Without a HashMap, we could keep the pairs in a vector and search
+    through it, but every search would have to compare the key with
+    the pairs one by one.
+
+    Run the benchmark with:
+
+        cargo bench -p ch07_collections -- hashmap
+
+    On one machine, looking up each of 10000 keys printed:
+
+        look up every key in a HashMap            182.2 µs
+        look up every key in a Vec of pairs     23478.6 µs
+
+    The vector was about 130 times slower, and the gap grows with the
+    number of pairs. For a handful of pairs, though, a vector is just
+    as good.
+
+
+    Frequently Asked Questions
+    ===========================
+
+    What types can be keys of a HashMap?
+
+    Any type whose values can be compared with == and turned into a
+    hash, such as integers, char, bool, String and &str. Floating point
+    numbers cannot be keys, because NaN is not equal to itself.
+
+    How do we change a value we know is in the HashMap?
+
+    The get_mut() method returns a mutable reference to the value, in
+    an Option. */
+
+    let mut balance = HashMap::new();
+    balance.insert("savings", 100);
+
+    if let Some(amount) = balance.get_mut("savings") {
+        *amount += 50;
+    }
+
+    println!("savings = {}", balance["savings"]); // savings = 150
+}
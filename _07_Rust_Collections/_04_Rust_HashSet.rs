@@ -0,0 +1,212 @@
+/*
+Rust HashSet
+============= */
+
+fn main() {
+    /*
+
+    A HashSet is a collection of unique values. It is like a HashMap
+    with keys but no values: a value is either in the set or not, and
+    adding it a second time changes nothing.
+
+    Like HashMap, HashSet has to be imported from std::collections. */
+
+    use std::collections::HashSet;
+
+    /*
+
+    Creating a HashSet in Rust
+    ===========================
+
+    We create an empty HashSet with HashSet::new(), and add values to
+    it with the insert() method. */
+
+    let mut colors = HashSet::new();
+
+    println!("{}", colors.insert("red")); // true
+    println!("{}", colors.insert("blue")); // true
+
+    // "red" is already in the set
+    println!("{}", colors.insert("red")); // false
+
+    println!("number of colors = {}", colors.len()); // number of colors = 2
+
+    /*
+    Here, insert() returns true if the value was added, and false if
+    it was already in the set. The set still holds a single "red".
+
+    Note: Like a HashMap, a HashSet does not keep its values in any
+          particular order.
+
+
+    Checking and Removing Values
+    =============================
+
+    The contains() method checks if a value is in the set, and the
+    remove() method removes it. */
+
+    let mut visited = HashSet::new();
+
+    visited.insert("Paris");
+    visited.insert("Rome");
+    visited.insert("Tokyo");
+
+    println!("{}", visited.contains("Rome")); // true
+    println!("{}", visited.contains("Lima")); // false
+
+    visited.remove("Rome");
+    println!("{}", visited.contains("Rome")); // false
+
+    /*
+    A HashSet has no first or second value, so we cannot read a value
+    by its index like we do with a vector.
+
+    fn main() {
+        use std::collections::HashSet;
+
+        let mut visited = HashSet::new();
+        visited.insert("Paris");
+
+        println!("{}", visited[0]);
+    }
+
+    -----------------------------------------------------------------------
+
+    error[E0608]: cannot index into a value of type `HashSet<&str>`
+      --> _04_Rust_HashSet.rs:70:27
+       |
+    70 |     println!("{}", visited[0]);
+       |                           ^^^
+
+    error: aborting due to 1 previous error
+
+    For more information about this error, try `rustc --explain E0608`.
+
+    -----------------------------------------------------------------------
+
+
+    Set Operations
+    ===============
+
+    HashSet has methods that compare two sets, like the sets we know
+    from mathematics:
+
+    - union() - the values that are in either set
+    - intersection() - the values that are in both sets
+    - difference() - the values of the first set that are not in the
+      second one
+
+    Each of them gives the values one by one. We collect them into a
+    vector and sort it, so that they print in the same order every
+    time. */
+
+    let chess: HashSet<&str> = HashSet::from(["Ana", "Ben", "Cleo", "Dev"]);
+    let drama: HashSet<&str> = HashSet::from(["Ben", "Dev", "Eli"]);
+
+    let mut both: Vec<&str> = chess.intersection(&drama).copied().collect();
+    both.sort();
+
+    let mut only_chess: Vec<&str> = chess.difference(&drama).copied().collect();
+    only_chess.sort();
+
+    let mut everyone: Vec<&str> = chess.union(&drama).copied().collect();
+    everyone.sort();
+
+    println!("in both clubs: {:?}", both);
+    println!("only in chess: {:?}", only_chess);
+    println!("in any club: {:?}", everyone);
+
+    /*
+    Output:
+
+    in both clubs: ["Ben", "Dev"]
+    only in chess: ["Ana", "Cleo"]
+    in any club: ["Ana", "Ben", "Cleo", "Dev", "Eli"]
+
+    Here,
+
+    - HashSet::from([...]) - creates a set from an array
+    - copied() - turns the &&str references given by the methods into
+      &str values
+    - collect() - puts the values into the vector
+
+
+    Example: Removing Duplicates
+    =============================
+
+    Since a HashSet only holds unique values, it can tell us if we have
+    seen a value before. The function below keeps the first copy of
+    every email address of a list, and keeps the list in order. */
+
+    // returns the addresses without repeats, in their original order
+    fn unique_emails(emails: &[&str]) -> Vec<String> {
+        let mut seen = HashSet::new();
+        let mut unique = Vec::new();
+
+        for email in emails {
+            let email = email.to_lowercase();
+            if seen.insert(email.clone()) {
+                unique.push(email);
+            }
+        }
+
+        return unique;
+    }
+
+    let signups = [
+        "ana@mail.com",
+        "ben@mail.com",
+        "ANA@mail.com",
+        "cleo@mail.com",
+        "ben@mail.com",
+    ];
+
+    let unique = unique_emails(&signups);
+
+    println!("{} signups, {} people", signups.len(), unique.len());
+    println!("{:?}", unique);
+
+    /*
+    Output:
+
+    5 signups, 3 people
+    ["ana@mail.com", "ben@mail.com", "cleo@mail.com"]
+
+    Here, insert() returns false for an address that is already in the
+    set, so it is not pushed to the vector again.
+
+
+    Performance of a HashSet
+    =========================
+
+    A HashSet finds a value with its hash, just like a HashMap finds a
+    key. A vector also has a contains() method, but it compares the
+    value with every element until it finds it.
+
+    Run the benchmark with:
+
+        cargo bench -p ch07_collections -- hashset
+
+    On one machine, checking 10000 numbers against 10000 values printed:
+
+        contains() on a HashSet                   178.8 µs
+        contains() on a Vec                      8912.3 µs
+
+    The vector was about 50 times slower. When a program checks if
+    values are in a collection over and over, a HashSet pays off.
+
+
+    Frequently Asked Questions
+    ===========================
+
+    How do we check if one set is part of another?
+
+    The is_subset() method returns true if every value of a set is also
+    in the other set. */
+
+    let needed = HashSet::from(["flour", "eggs"]);
+    let pantry = HashSet::from(["flour", "eggs", "milk"]);
+
+    println!("{}", needed.is_subset(&pantry)); // true
+    println!("{}", pantry.is_subset(&needed)); // false
+}
@@ -0,0 +1,218 @@
+/*
+Rust BTreeMap
+============== */
+
+fn main() {
+    /*
+
+    A BTreeMap stores key-value pairs, just like a HashMap. The
+    difference is that a BTreeMap keeps its keys sorted. Looping
+    through it, or printing it, always gives the pairs from the
+    smallest key to the largest.
+
+    BTreeMap is imported from std::collections. */
+
+    use std::collections::BTreeMap;
+
+    /*
+
+    Creating a BTreeMap in Rust
+    ============================
+
+    A BTreeMap has the same insert(), get(), remove() and len()
+    methods as a HashMap. */
+
+    let mut scores = BTreeMap::new();
+
+    scores.insert("Ricky", 88);
+    scores.insert("Ana", 95);
+    scores.insert("John", 72);
+
+    println!("{:?}", scores); // {"Ana": 95, "John": 72, "Ricky": 88}
+    println!("{:?}", scores.get("John")); // Some(72)
+
+    /*
+    Here, the pairs print in alphabetical order of their keys, no
+    matter in what order we inserted them.
+
+
+    Looping Through a BTreeMap
+    ===========================
+
+    A for loop gives the pairs in the order of their keys. */
+
+    let mut population = BTreeMap::new();
+
+    population.insert(1990, 5.3);
+    population.insert(2010, 6.9);
+    population.insert(1970, 3.7);
+    population.insert(2000, 6.1);
+
+    for (year, billions) in &population {
+        println!("{}: {} billion", year, billions);
+    }
+
+    /*
+    Output:
+
+    1970: 3.7 billion
+    1990: 5.3 billion
+    2000: 6.1 billion
+    2010: 6.9 billion
+
+
+    Smallest and Largest Keys
+    ==========================
+
+    Because the keys are sorted, a BTreeMap can give us its first and
+    last pair straight away. Both methods return an Option, which is
+    None if the map is empty. */
+
+    println!("{:?}", population.first_key_value()); // Some((1970, 3.7))
+    println!("{:?}", population.last_key_value()); // Some((2010, 6.9))
+
+    /*
+
+    Ranges of Keys
+    ===============
+
+    The range() method gives only the pairs whose keys are in a range,
+    also in order. */
+
+    for (year, billions) in population.range(1985..2005) {
+        println!("between 1985 and 2005: {} in {}", billions, year);
+    }
+
+    /*
+    Output:
+
+    between 1985 and 2005: 5.3 in 1990
+    between 1985 and 2005: 6.1 in 2000
+
+    Here, 1985..2005 includes 1985 but not 2005, just like the ranges
+    of a for loop.
+
+
+    Keys of a BTreeMap Must Be Ordered
+    ===================================
+
+    To keep its keys sorted, a BTreeMap must be able to put any two
+    keys in order. Floating point numbers cannot be keys, because NaN
+    cannot be put in order with other numbers.
+
+    fn main() {
+        use std::collections::BTreeMap;
+
+        let mut prices = BTreeMap::new();
+        prices.insert(2.5, "bread");
+    }
+
+    -----------------------------------------------------------------------
+
+    error[E0277]: the trait bound `{float}: Ord` is not satisfied
+       --> _05_Rust_BTreeMap.rs:107:12
+        |
+    107 |     prices.insert(2.5, "bread");
+        |            ^^^^^^ the trait `Ord` is not implemented for `{float}`
+        |
+        = help: the following other types implement trait `Ord`:
+                  i128
+                  i16
+                  i32
+                  i64
+                  i8
+                  isize
+                  u128
+                  u16
+                and 4 others
+
+    error: aborting due to 1 previous error
+
+    For more information about this error, try `rustc --explain E0277`.
+
+    -----------------------------------------------------------------------
+
+    Integers, char, bool, String, &str, and tuples and arrays of
+    these can all be keys.
+
+
+    Example: A Calendar
+    ====================
+
+    A tuple of (month, day) is ordered by month first, then by day.
+    This makes a BTreeMap a simple calendar: the events come out in
+    date order, and a range gives all the events of a month. */
+
+    let mut calendar: BTreeMap<(u32, u32), &str> = BTreeMap::new();
+
+    calendar.insert((3, 14), "Pi Day");
+    calendar.insert((1, 1), "New Year");
+    calendar.insert((3, 1), "Rent due");
+    calendar.insert((12, 25), "Holiday");
+    calendar.insert((3, 20), "Spring");
+
+    // all the events of March, from (3, 1) up to (4, 1)
+    for ((month, day), event) in calendar.range((3, 1)..(4, 1)) {
+        println!("{}/{}: {}", month, day, event);
+    }
+
+    // the first event after March 15
+    if let Some(((month, day), event)) = calendar.range((3, 16)..).next() {
+        println!("next after 3/15: {} on {}/{}", event, month, day);
+    }
+
+    /*
+    Output:
+
+    3/1: Rent due
+    3/14: Pi Day
+    3/20: Spring
+    next after 3/15: Spring on 3/20
+
+    Here,
+
+    - (3, 1)..(4, 1) - every date from March 1 up to, but not
+      including, April 1
+    - (3, 16).. - every date from March 16 on
+    - next() - takes the first pair of the range, as an Option
+
+
+    Performance of a BTreeMap
+    ==========================
+
+    A BTreeMap finds a key by comparing it with a few keys on its way
+    down a tree, so a lookup is a little slower than in a HashMap. In
+    return, its keys are always sorted, and we never have to sort
+    them ourselves.
+
+    Run the benchmark with:
+
+        cargo bench -p ch07_collections -- btreemap
+
+    On one machine, with 10000 keys, it printed:
+
+        look up every key in a HashMap            171.1 µs
+        look up every key in a BTreeMap           719.5 µs
+        keys in order from a HashMap              313.2 µs
+        keys in order from a BTreeMap              31.2 µs
+
+    Lookups in the BTreeMap were about 4 times slower, but getting the
+    keys in order was about 10 times faster than copying and sorting
+    the keys of the HashMap. Use a HashMap for lookups alone, and a
+    BTreeMap when the order of the keys matters.
+
+
+    Frequently Asked Questions
+    ===========================
+
+    Is there a sorted set too?
+
+    Yes. A BTreeSet is to HashSet what BTreeMap is to HashMap: it holds
+    unique values and keeps them sorted. */
+
+    use std::collections::BTreeSet;
+
+    let letters: BTreeSet<char> = "hello world".chars().filter(|c| *c != ' ').collect();
+
+    println!("{:?}", letters); // {'d', 'e', 'h', 'l', 'o', 'r', 'w'}
+}
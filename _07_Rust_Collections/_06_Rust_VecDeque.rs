@@ -0,0 +1,200 @@
+/*
+Rust VecDeque
+============== */
+
+fn main() {
+    /*
+
+    A VecDeque (short for vector double-ended queue) is a growable
+    list, like a vector, that can add and remove elements quickly at
+    both ends. It is the collection to use for a queue, where elements
+    join at the back and leave from the front.
+
+    VecDeque is imported from std::collections. */
+
+    use std::collections::VecDeque;
+
+    /*
+
+    Creating a VecDeque in Rust
+    ============================
+
+    We create an empty VecDeque with VecDeque::new(), and add elements
+    to either end of it. */
+
+    let mut line = VecDeque::new();
+
+    // add elements to the back
+    line.push_back("Ana");
+    line.push_back("Ben");
+
+    // add an element to the front
+    line.push_front("Cleo");
+
+    println!("{:?}", line); // ["Cleo", "Ana", "Ben"]
+
+    /*
+    Here,
+
+    - push_back("Ana") - adds "Ana" to the back of the queue
+    - push_front("Cleo") - adds "Cleo" to the front of the queue
+
+
+    Removing Elements
+    ==================
+
+    pop_front() and pop_back() remove an element from either end. Like
+    the pop() method of a vector, they return an Option, which is None
+    if the VecDeque is empty. */
+
+    let first = line.pop_front();
+    let last = line.pop_back();
+
+    println!("{:?} and {:?} left", first, last); // Some("Cleo") and Some("Ben") left
+    println!("{:?}", line); // ["Ana"]
+
+    /*
+
+    Reading Elements
+    =================
+
+    We can look at the elements at both ends without removing them,
+    and read any element by its index, like in a vector. */
+
+    let numbers = VecDeque::from([10, 20, 30, 40]);
+
+    println!("front = {:?}", numbers.front()); // front = Some(10)
+    println!("back = {:?}", numbers.back()); // back = Some(40)
+    println!("numbers[2] = {}", numbers[2]); // numbers[2] = 30
+    println!("length = {}", numbers.len()); // length = 4
+
+    /*
+    A vector has no pop_front() method, because it cannot remove its
+    first element quickly.
+
+    fn main() {
+        let mut numbers = vec![10, 20, 30];
+
+        numbers.pop_front();
+    }
+
+    -----------------------------------------------------------------------
+
+    error[E0599]: no method named `pop_front` found for struct `Vec<{integer}>` in the current scope
+      --> _06_Rust_VecDeque.rs:78:13
+       |
+    78 |     numbers.pop_front();
+       |             ^^^^^^^^^ method not found in `Vec<{integer}>`
+
+    error: aborting due to 1 previous error
+
+    For more information about this error, try `rustc --explain E0599`.
+
+    -----------------------------------------------------------------------
+
+    The closest a vector has is remove(0), which moves all the other
+    elements one place to the left.
+
+
+    Example: A Print Queue
+    =======================
+
+    A printer prints its jobs in the order they arrive. New jobs wait at
+    the back of the queue, and urgent ones skip to the front. */
+
+    let mut jobs: VecDeque<&str> = VecDeque::new();
+
+    jobs.push_back("report.pdf");
+    jobs.push_back("photo.png");
+    jobs.push_front("ticket.pdf"); // urgent
+    jobs.push_back("notes.txt");
+
+    while let Some(job) = jobs.pop_front() {
+        println!("printing {} ({} waiting)", job, jobs.len());
+    }
+
+    /*
+    Output:
+
+    printing ticket.pdf (3 waiting)
+    printing report.pdf (2 waiting)
+    printing photo.png (1 waiting)
+    printing notes.txt (0 waiting)
+
+    Here, the while let loop from Rust if let and while let takes the
+    jobs out of the queue until pop_front() returns None.
+
+
+    Example: Moving Average
+    ========================
+
+    A VecDeque also makes a good sliding window: to keep the last few
+    values of a stream, we push each new value to the back and pop the
+    oldest one from the front. */
+
+    // returns the average of the last `size` readings after each new reading
+    fn moving_average(readings: &[f64], size: usize) -> Vec<f64> {
+        let mut window = VecDeque::new();
+        let mut averages = Vec::new();
+
+        for &reading in readings {
+            window.push_back(reading);
+            if window.len() > size {
+                window.pop_front();
+            }
+
+            let mut sum = 0.0;
+            for value in &window {
+                sum += value;
+            }
+            averages.push(sum / window.len() as f64);
+        }
+
+        return averages;
+    }
+
+    let temperatures = [21.0, 24.0, 27.0, 24.0, 18.0];
+
+    println!("{:?}", moving_average(&temperatures, 3)); // [21.0, 22.5, 24.0, 25.0, 23.0]
+
+    /*
+    Here, the window never holds more than 3 readings, so each average
+    only looks at the most recent ones.
+
+
+    Performance of a VecDeque
+    ==========================
+
+    A VecDeque keeps its elements in a ring: when the front element is
+    removed, the VecDeque only moves its idea of where the front is.
+    Nothing else moves, so pop_front() is as fast as pop().
+
+    A vector used as a queue has to move every element on each
+    remove(0), so it gets slower the longer the queue is.
+
+    Run the benchmark with:
+
+        cargo bench -p ch07_collections -- vecdeque
+
+    On one machine, emptying a queue of 10000 numbers printed:
+
+        queue with VecDeque::pop_front()           11.0 µs
+        queue with Vec::remove(0)                2517.8 µs
+
+    The vector was more than 200 times slower. Use a VecDeque whenever
+    elements leave from the front; use a vector when they only come and
+    go at the back.
+
+
+    Frequently Asked Questions
+    ===========================
+
+    How do we turn a VecDeque into a vector?
+
+    We can collect its elements into a Vec, or use Vec::from(). */
+
+    let queue = VecDeque::from([1, 2, 3]);
+    let list: Vec<i32> = Vec::from(queue);
+
+    println!("{:?}", list); // [1, 2, 3]
+}
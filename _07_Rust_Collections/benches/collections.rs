@@ -0,0 +1,177 @@
+//! The benchmarks behind the performance notes of the collections lessons.
+//!
+//!     cargo bench -p ch07_collections
+//!     cargo bench -p ch07_collections -- hashmap
+//!
+//! Every group times two ways of doing the same job, one lesson per group.
+//! Each job is run a few times and the fastest run is shown, which keeps
+//! the numbers steady enough to compare on a busy machine. Only the ratio
+//! between the two lines of a group means anything; the numbers
+//! themselves depend on the machine.
+
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+/// Number of elements every job works with.
+const N: u32 = 10_000;
+
+/// Number of times every job is run.
+const RUNS: u32 = 5;
+
+const GROUPS: [(&str, fn()); 6] = [
+    ("vector", vector),
+    ("string", string),
+    ("hashmap", hashmap),
+    ("hashset", hashset),
+    ("btreemap", btreemap),
+    ("vecdeque", vecdeque),
+];
+
+fn main() {
+    // cargo passes --bench; anything else picks the groups to run
+    let filters: Vec<String> = std::env::args()
+        .skip(1)
+        .filter(|arg| !arg.starts_with('-'))
+        .collect();
+    for (name, group) in GROUPS {
+        if filters.is_empty() || filters.iter().any(|filter| name.contains(filter.as_str())) {
+            println!("{name} ({N} elements)");
+            group();
+            println!();
+        }
+    }
+}
+
+/// Runs `job` [`RUNS`] times and prints the time of the fastest run.
+fn time<T>(label: &str, mut job: impl FnMut() -> T) {
+    let mut best = Duration::MAX;
+    for _ in 0..RUNS {
+        let start = Instant::now();
+        black_box(job());
+        best = best.min(start.elapsed());
+    }
+    println!("    {label:<36} {:>10.1} µs", best.as_secs_f64() * 1e6);
+}
+
+fn vector() {
+    time("push, starting from Vec::new()", || {
+        let mut numbers = Vec::new();
+        for i in 0..N {
+            numbers.push(black_box(i));
+        }
+        numbers
+    });
+    time("push, starting from with_capacity()", || {
+        let mut numbers = Vec::with_capacity(N as usize);
+        for i in 0..N {
+            numbers.push(black_box(i));
+        }
+        numbers
+    });
+    time("insert(0, ...)", || {
+        let mut numbers = Vec::new();
+        for i in 0..N {
+            numbers.insert(0, black_box(i));
+        }
+        numbers
+    });
+}
+
+fn string() {
+    time("push_str() into one String", || {
+        let mut text = String::new();
+        for i in 0..N {
+            text.push_str(&i.to_string());
+            text.push(',');
+        }
+        text
+    });
+    time("format!() a new String each time", || {
+        let mut text = String::new();
+        for i in 0..N {
+            text = format!("{text}{i},");
+        }
+        text
+    });
+    let text = "héllo wörld ".repeat(N as usize);
+    time("len()", || black_box(&text).len());
+    time("chars().count()", || black_box(&text).chars().count());
+}
+
+fn hashmap() {
+    let map: HashMap<u32, u32> = (0..N).map(|i| (i, i * 2)).collect();
+    let pairs: Vec<(u32, u32)> = (0..N).map(|i| (i, i * 2)).collect();
+    time("look up every key in a HashMap", || {
+        let mut sum = 0;
+        for key in 0..N {
+            sum += map[&black_box(key)];
+        }
+        sum
+    });
+    time("look up every key in a Vec of pairs", || {
+        let mut sum = 0;
+        for key in 0..N {
+            let key = black_box(key);
+            sum += pairs.iter().find(|(k, _)| *k == key).unwrap().1;
+        }
+        sum
+    });
+}
+
+fn hashset() {
+    let set: HashSet<u32> = (0..N).map(|i| i * 3).collect();
+    let list: Vec<u32> = (0..N).map(|i| i * 3).collect();
+    time("contains() on a HashSet", || {
+        (0..N).filter(|i| set.contains(&black_box(*i))).count()
+    });
+    time("contains() on a Vec", || {
+        (0..N).filter(|i| list.contains(&black_box(*i))).count()
+    });
+}
+
+fn btreemap() {
+    let hash: HashMap<u32, u32> = (0..N).map(|i| (i * 7 % N, i)).collect();
+    let tree: BTreeMap<u32, u32> = (0..N).map(|i| (i * 7 % N, i)).collect();
+    time("look up every key in a HashMap", || {
+        let mut sum = 0;
+        for key in 0..N {
+            sum += hash[&black_box(key)];
+        }
+        sum
+    });
+    time("look up every key in a BTreeMap", || {
+        let mut sum = 0;
+        for key in 0..N {
+            sum += tree[&black_box(key)];
+        }
+        sum
+    });
+    time("keys in order from a HashMap", || {
+        let mut keys: Vec<u32> = hash.keys().copied().collect();
+        keys.sort();
+        keys
+    });
+    time("keys in order from a BTreeMap", || {
+        tree.keys().copied().collect::<Vec<u32>>()
+    });
+}
+
+fn vecdeque() {
+    time("queue with VecDeque::pop_front()", || {
+        let mut queue: VecDeque<u32> = (0..N).collect();
+        let mut sum = 0;
+        while let Some(front) = queue.pop_front() {
+            sum += front;
+        }
+        sum
+    });
+    time("queue with Vec::remove(0)", || {
+        let mut queue: Vec<u32> = (0..N).collect();
+        let mut sum = 0;
+        while !queue.is_empty() {
+            sum += queue.remove(0);
+        }
+        sum
+    });
+}
@@ -0,0 +1,214 @@
+/*
+Rust Error Handling
+==================== */
+
+fn main() {
+    /*
+
+    An error is an unexpected situation that stops a program from
+    doing what it was asked to do. Rust groups errors into two kinds:
+
+    - unrecoverable errors - the program cannot go on, for example
+      because of a bug, so it stops with a panic
+    - recoverable errors - the program can deal with them, for example
+      by asking for a different input, so they are returned as a value
+      of the Result type
+
+    In this lesson, we will learn about both.
+
+
+    Unrecoverable Errors With panic!
+    =================================
+
+    When a program reaches a state it cannot handle, it panics: it
+    prints an error message and stops. We have already caused panics
+    without knowing it. For example, in Rust Operators we divided
+    integers with /. Dividing by zero panics.
+
+    ```rust,no_run
+    fn average(total: i32, count: i32) -> i32 {
+        return total / count;
+    }
+    fn main() {
+        println!("average = {}", average(250, 0));
+    }
+    ```
+
+    Running this program prints:
+
+    -----------------------------------------------------------------------
+
+    thread 'main' panicked at _01_Rust_Error_Handling.rs:30:12:
+    attempt to divide by zero
+    note: run with `RUST_BACKTRACE=1` environment variable to display a backtrace
+
+    -----------------------------------------------------------------------
+
+    Reading an array past its end, or calling unwrap() on None, panics
+    in the same way.
+
+    We can also panic on purpose with the panic! macro. It takes a
+    message, just like println!.
+
+    ```rust,no_run
+    fn main() {
+        let settings_found = false;
+
+        if !settings_found {
+            panic!("the settings file is missing");
+        }
+
+        println!("this line is never printed");
+    }
+    ```
+
+    A panic is meant for bugs and for situations the program has no way
+    to recover from. For everything else, Rust uses the Result type.
+
+
+    Recoverable Errors With Result
+    ===============================
+
+    Result is an enum of the standard library, a lot like Option:
+
+    ```rust,ignore
+    enum Result<T, E> {
+        Ok(T),
+        Err(E),
+    }
+    ```
+
+    Here,
+
+    - Ok(T) - the operation succeeded, and holds a value of the type T
+    - Err(E) - the operation failed, and holds an error of the type E
+
+    For example, the parse() method turns text into a number. The text
+    may not hold a number, so parse() returns a Result. */
+
+    let good: Result<i32, _> = "42".parse::<i32>();
+    let bad: Result<i32, _> = "forty-two".parse::<i32>();
+
+    println!("{:?}", good); // Ok(42)
+    println!("{:?}", bad); // Err(ParseIntError { kind: InvalidDigit })
+
+    /*
+    Here,
+
+    - parse::<i32>() - tries to read the text as an i32
+    - _ - lets Rust fill in the type of the error, which is
+      ParseIntError
+
+    Like an Option, a Result is not the value itself, so we have to
+    check which variant it is. We usually do that with match. */
+
+    let input = "3x";
+
+    match input.parse::<i32>() {
+        Ok(number) => println!("twice {} is {}", number, number * 2),
+        Err(error) => println!("'{}' is not a number: {}", input, error), // '3x' is not a number: invalid digit found in string
+    }
+
+    /*
+    Printing the error with {} gives a message meant for people, while
+    {:?} shows how the error is built.
+
+
+    Returning a Result From a Function
+    ===================================
+
+    Our own functions return a Result when they can fail. Let's rewrite
+    the average() function, so that it returns an error instead of
+    panicking. */
+
+    // returns the average, or an error message if there is nothing to average
+    fn average(total: i32, count: i32) -> Result<i32, String> {
+        if count == 0 {
+            return Err(String::from("cannot average zero values"));
+        }
+
+        return Ok(total / count);
+    }
+
+    for count in [5, 0] {
+        match average(250, count) {
+            Ok(value) => println!("average = {}", value),
+            Err(message) => println!("error: {}", message),
+        }
+    }
+
+    /*
+    Output:
+
+    average = 50
+    error: cannot average zero values
+
+    Here, the function returns Result<i32, String>: an i32 if it
+    succeeds, and a String describing the problem if it fails. The
+    caller decides what to do with the error.
+
+    Rust warns us if we ignore a Result, because it may hold an error
+    that we forgot to handle.
+
+
+    Getting the Value Out of a Result
+    ==================================
+
+    Like Option, Result has methods for the common cases. */
+
+    let port: Result<u16, _> = "8080".parse::<u16>();
+    let timeout: Result<u32, _> = "ten".parse::<u32>();
+
+    println!("{}", port.is_ok()); // true
+    println!("{}", timeout.is_err()); // true
+
+    // use a default value for Err
+    println!("{}", timeout.unwrap_or(30)); // 30
+
+    // get the value, which must be there
+    println!("{}", port.expect("the port is a number")); // 8080
+
+    /*
+    Here,
+
+    - is_ok() and is_err() - check which variant the Result is
+    - unwrap_or(30) - returns the value in Ok, or 30 for Err
+    - expect("...") - returns the value in Ok, and panics with our
+      message for Err
+
+    unwrap() works like expect(), but panics with a message of its
+    own. Both are fine when an error would be a bug, or in small
+    examples; for input that may be wrong, handle the Err variant
+    instead.
+
+
+    panic! or Result?
+    ==================
+
+    - Return a Result when failure is a normal part of the job: text
+      that may not be a number, a file that may be missing, a value
+      the user typed in.
+    - Panic when failure means the program itself is wrong, and going
+      on would only make things worse.
+
+    In the next lessons, we will see how to pass errors on with the ?
+    operator, how to convert numbers without the surprises of as and
+    of integer arithmetic, and how to define our own error types.
+
+
+    Frequently Asked Questions
+    ===========================
+
+    How are Option and Result related?
+
+    Both say that a value may be missing. Option only says that it is
+    missing, while Result also says why. The ok() method turns a
+    Result into an Option, dropping the error, and ok_or() turns an
+    Option into a Result, with the error we give it. */
+
+    let parsed: Option<i32> = "17".parse::<i32>().ok();
+    let first: Result<&str, &str> = ["a", "b"].first().copied().ok_or("the list is empty");
+
+    println!("{:?}", parsed); // Some(17)
+    println!("{:?}", first); // Ok("a")
+}
@@ -0,0 +1,261 @@
+/*
+Rust Question Mark Operator
+============================ */
+
+fn main() {
+    /*
+
+    A function that calls other functions which can fail often wants to
+    pass their errors on to its own caller. The question mark operator
+    ? does this in a single character.
+
+
+    Passing Errors On With match
+    =============================
+
+    Let's write a function that adds two numbers given as text. Each
+    parse() can fail, and on failure we return its error. */
+
+    use std::num::ParseIntError;
+
+    // returns the sum of two numbers written as text
+    fn add_texts(a: &str, b: &str) -> Result<i32, ParseIntError> {
+        let first = match a.parse::<i32>() {
+            Ok(number) => number,
+            Err(error) => return Err(error),
+        };
+
+        let second = match b.parse::<i32>() {
+            Ok(number) => number,
+            Err(error) => return Err(error),
+        };
+
+        return Ok(first + second);
+    }
+
+    println!("{:?}", add_texts("20", "22")); // Ok(42)
+    println!("{:?}", add_texts("20", "x")); // Err(ParseIntError { kind: InvalidDigit })
+
+    /*
+    Here, ParseIntError is the type of the errors of parse() for
+    integers. It lives in std::num, so we import it with use.
+
+    The two match expressions do the same thing: take the value out of
+    Ok, or return early with the Err. This is so common that Rust has
+    an operator for it.
+
+
+    The ? Operator
+    ===============
+
+    Putting ? after a Result:
+
+    - gives the value inside it, if it is Ok
+    - returns from the function with the error, if it is Err
+
+    Let's rewrite add_texts() with ?. */
+
+    // returns the sum of two numbers written as text
+    fn add(a: &str, b: &str) -> Result<i32, ParseIntError> {
+        let first = a.parse::<i32>()?;
+        let second = b.parse::<i32>()?;
+
+        return Ok(first + second);
+    }
+
+    println!("{:?}", add("20", "22")); // Ok(42)
+    println!("{:?}", add("x", "22")); // Err(ParseIntError { kind: InvalidDigit })
+
+    /*
+    Here, if a holds "x", a.parse::<i32>()? returns the error right
+    away, and the second parse() never runs.
+
+    We can also use ? in the middle of an expression, and chain method
+    calls after it. */
+
+    // returns the sum of the comma-separated numbers of the text
+    fn sum_list(text: &str) -> Result<i32, ParseIntError> {
+        let mut sum = 0;
+
+        for part in text.split(',') {
+            sum += part.trim().parse::<i32>()?;
+        }
+
+        return Ok(sum);
+    }
+
+    println!("{:?}", sum_list("1, 2, 3, 4")); // Ok(10)
+    println!("{:?}", sum_list("1, two, 3")); // Err(ParseIntError { kind: InvalidDigit })
+
+    /*
+
+    ? Needs a Function That Returns a Result
+    =========================================
+
+    As ? may return an error from the function, the function must
+    return a Result itself. We cannot use ? in a function that returns
+    nothing, like the main() functions we have written so far.
+
+    fn main() {
+        let number: i32 = "42".parse()?;
+
+        println!("number = {}", number);
+    }
+
+    -----------------------------------------------------------------------
+
+    error[E0277]: the `?` operator can only be used in a function that returns `Result` or `Option` (or another type that implements `FromResidual`)
+       --> _02_Rust_Question_Mark_Operator.rs:100:35
+        |
+     99 | fn main() {
+        | --------- this function should return `Result` or `Option` to accept `?`
+    100 |     let number: i32 = "42".parse()?;
+        |                                   ^ cannot use the `?` operator in a function that returns `()`
+        |
+    help: consider adding return type
+        |
+     99 ~ fn main() -> Result<(), Box<dyn std::error::Error>> {
+    100 |     let number: i32 = "42".parse()?;
+    101 |
+    102 |     println!("number = {}", number);
+    103 +     Ok(())
+        |
+
+    error: aborting due to 1 previous error
+
+    For more information about this error, try `rustc --explain E0277`.
+
+    -----------------------------------------------------------------------
+
+    As the help suggests, main() may return a Result too. If it returns
+    an Err, the program prints the error and exits with a failure.
+
+    fn main() -> Result<(), std::num::ParseIntError> {
+        let number: i32 = "42".parse()?;
+
+        println!("number = {}", number);
+
+        return Ok(());
+    }
+
+    Here, Ok(()) holds the empty value (), because main() has no value
+    to return when it succeeds.
+
+
+    ? Does Not Mix Error Types by Itself
+    =====================================
+
+    The error that ? returns must fit the error type of the function.
+    A ParseIntError is not a String, so this does not compile:
+
+    fn main() {
+        fn parse_age(text: &str) -> Result<u8, String> {
+            let age: u8 = text.parse()?;
+
+            return Ok(age);
+        }
+
+        println!("{:?}", parse_age("42"));
+    }
+
+    -----------------------------------------------------------------------
+
+    error[E0277]: `?` couldn't convert the error to `String`
+       --> _02_Rust_Question_Mark_Operator.rs:153:35
+        |
+    152 |     fn parse_age(text: &str) -> Result<u8, String> {
+        |                                 ------------------ expected `String` because of this
+    153 |         let age: u8 = text.parse()?;
+        |                            -------^ the trait `From<ParseIntError>` is not implemented for `String`
+        |                            |
+        |                            this can't be annotated with `?` because it has type `Result<_, ParseIntError>`
+        |
+        = note: the question mark operation (`?`) implicitly performs a conversion on the error value using the `From` trait
+
+    error: aborting due to 1 previous error
+
+    For more information about this error, try `rustc --explain E0277`.
+
+    -----------------------------------------------------------------------
+
+    The map_err() method changes the error of a Result into something
+    else before ? passes it on. */
+
+    // returns the age in the text, or a message saying what is wrong
+    fn parse_age(text: &str) -> Result<u8, String> {
+        let age: u8 = text
+            .parse()
+            .map_err(|error| format!("'{}' is not an age: {}", text, error))?;
+
+        if age > 150 {
+            return Err(format!("{} is too old to be an age", age));
+        }
+
+        return Ok(age);
+    }
+
+    for text in ["42", "-1", "149", "151"] {
+        match parse_age(text) {
+            Ok(age) => println!("age = {}", age),
+            Err(message) => println!("error: {}", message),
+        }
+    }
+
+    /*
+    Output:
+
+    age = 42
+    error: '-1' is not an age: invalid digit found in string
+    age = 149
+    error: 151 is too old to be an age
+
+    Here, the closure passed to map_err() turns the ParseIntError into
+    a String, which does fit the function's error type. In Rust Custom
+    Error Types, we will see how ? can do such conversions by itself.
+
+
+    ? With Option
+    ==============
+
+    ? also works on an Option, in a function that returns an Option.
+    On None, it returns None from the function. */
+
+    // returns the initials of a first and last name, like "J. D."
+    fn initials(full_name: &str) -> Option<String> {
+        let mut words = full_name.split_whitespace();
+
+        let first = words.next()?.chars().next()?;
+        let last = words.next()?.chars().next()?;
+
+        return Some(format!("{}. {}.", first, last));
+    }
+
+    println!("{:?}", initials("John Doe")); // Some("J. D.")
+    println!("{:?}", initials("Cher")); // None
+
+    /*
+    Here, "Cher" has no second word, so the second words.next()
+    returns None, and so does the function.
+
+
+    Frequently Asked Questions
+    ===========================
+
+    Can we use ? on an Option in a function that returns a Result?
+
+    Not directly, because None holds no error to return. We first turn
+    the Option into a Result with ok_or(), giving the error to use. */
+
+    // returns the first number of the text
+    fn first_number(text: &str) -> Result<i32, String> {
+        let word = text
+            .split_whitespace()
+            .next()
+            .ok_or(String::from("the text is empty"))?;
+
+        return word.parse::<i32>().map_err(|error| error.to_string());
+    }
+
+    println!("{:?}", first_number("7 days")); // Ok(7)
+    println!("{:?}", first_number("")); // Err("the text is empty")
+}
@@ -0,0 +1,252 @@
+/*
+Rust Safe Conversions
+====================== */
+
+fn main() {
+    /*
+
+    In Rust Type Casting, we converted numbers with the as keyword, and
+    in Rust Operators, we did arithmetic on them. Both can go wrong when
+    a value does not fit its type. In this lesson, we will learn the
+    conversions and operations that report such problems, instead of
+    hiding them or panicking.
+
+
+    When Type Casting Goes Wrong
+    =============================
+
+    as always gives a value, even when the number does not fit the new
+    type. The result is then a different number. */
+
+    let big: i32 = 300;
+    let negative: i32 = -1;
+    let huge: f64 = 1234.5;
+
+    println!("{}", big as u8); // 44
+    println!("{}", negative as u32); // 4294967295
+    println!("{}", huge as u8); // 255
+
+    /*
+    Here,
+
+    - 300 as u8 - a u8 holds at most 255, so as keeps only the lowest
+      8 bits of 300, which give 44
+    - -1 as u32 - a u32 cannot be negative, so the bits of -1 are read
+      as the largest u32
+    - 1234.5 as u8 - a float that is too large becomes the largest u8
+
+    None of these lines gives an error, so a wrong value can travel far
+    through a program before anyone notices.
+
+
+    Converting With try_from() and try_into()
+    ==========================================
+
+    Every integer type has a try_from() function. It returns a Result,
+    which is an Err if the number does not fit. */
+
+    let small = u8::try_from(200_i32);
+    let too_big = u8::try_from(300_i32);
+    let too_small = u32::try_from(-1_i32);
+
+    println!("{:?}", small); // Ok(200)
+    println!("{:?}", too_big); // Err(TryFromIntError(()))
+    println!("{:?}", too_small); // Err(TryFromIntError(()))
+
+    /*
+    The try_into() method does the same conversion, from the other
+    side. We give the type we want with a type annotation. */
+
+    let count: i64 = 42;
+    let index: Result<usize, _> = count.try_into();
+
+    println!("{:?}", index); // Ok(42)
+
+    /*
+    Printing the error with {} gives its message. */
+
+    match u8::try_from(1000_i32) {
+        Ok(value) => println!("value = {}", value),
+        Err(error) => println!("error: {}", error), // error: out of range integral type conversion attempted
+    }
+
+    /*
+    A conversion that can never fail, like u8 to i32, also has
+    from() and into(), which return the value itself. */
+
+    let byte: u8 = 200;
+    let wide: i32 = i32::from(byte);
+    let wider: i64 = byte.into();
+
+    println!("{} {}", wide, wider); // 200 200
+
+    /*
+    from() only exists when every value fits, so it cannot be used the
+    other way round.
+
+    fn main() {
+        let number: i32 = 200;
+        let byte = u8::from(number);
+
+        println!("{}", byte);
+    }
+
+    -----------------------------------------------------------------------
+
+    error[E0277]: the trait bound `u8: From<i32>` is not satisfied
+      --> _03_Rust_Safe_Conversions.rs:89:16
+       |
+    89 |     let byte = u8::from(number);
+       |                ^^ the trait `From<i32>` is not implemented for `u8`
+
+    error: aborting due to 1 previous error
+
+    For more information about this error, try `rustc --explain E0277`.
+
+    -----------------------------------------------------------------------
+
+
+    Overflow in Arithmetic
+    =======================
+
+    When the result of +, - or * does not fit its type, it overflows.
+    In a debug build, Rust panics on overflow.
+
+    ```rust,no_run
+    fn add(a: u8, b: u8) -> u8 {
+        return a + b;
+    }
+    fn main() {
+        println!("{}", add(250, 10));
+    }
+    ```
+
+    Running this program prints:
+
+    -----------------------------------------------------------------------
+
+    thread 'main' panicked at _03_Rust_Safe_Conversions.rs:117:12:
+    attempt to add with overflow
+    note: run with `RUST_BACKTRACE=1` environment variable to display a backtrace
+
+    -----------------------------------------------------------------------
+
+    In a release build, which is built with cargo build --release, the
+    check is left out to make the program faster, and the result wraps
+    around silently instead.
+
+    To choose what happens on overflow, the integer types have three
+    families of methods. */
+
+    let level: u8 = 250;
+
+    // returns None if the result does not fit
+    println!("{:?}", level.checked_add(10)); // None
+    println!("{:?}", level.checked_add(5)); // Some(255)
+
+    // stops at the largest or smallest value
+    println!("{}", level.saturating_add(10)); // 255
+    println!("{}", 3_u8.saturating_sub(10)); // 0
+
+    // wraps around, like as does
+    println!("{}", level.wrapping_add(10)); // 4
+
+    /*
+    Here,
+
+    - checked_ - returns an Option, which is None on overflow
+    - saturating_ - stays at the limit of the type
+    - wrapping_ - starts again from the other end of the type
+
+    Each family has add, sub, mul and more, such as checked_mul() and
+    saturating_sub().
+
+
+    Dividing Safely
+    ================
+
+    Dividing an integer by zero panics, as we saw in Rust Error
+    Handling. checked_div() returns None instead. */
+
+    println!("{:?}", 250_i32.checked_div(5)); // Some(50)
+    println!("{:?}", 250_i32.checked_div(0)); // None
+
+    /*
+    Since the checked_ methods return an Option, they work well with ?
+    in a function that returns an Option. */
+
+    // returns part as a percentage of whole, or None if it cannot be computed
+    fn percent(part: u32, whole: u32) -> Option<u32> {
+        let scaled = part.checked_mul(100)?;
+
+        return scaled.checked_div(whole);
+    }
+
+    println!("{:?}", percent(45, 60)); // Some(75)
+    println!("{:?}", percent(45, 0)); // None
+    println!("{:?}", percent(50_000_000, 60)); // None
+
+    /*
+    Here, 50000000 * 100 does not fit a u32, so checked_mul() returns
+    None, and ? returns it from the function.
+
+
+    Example: Reading a Byte
+    ========================
+
+    Let's combine parse() from Rust Error Handling with try_from(). The
+    function below reads a color channel, which must be from 0 to 255,
+    and says what is wrong with the text otherwise. */
+
+    // returns the color channel written in the text
+    fn parse_channel(text: &str) -> Result<u8, String> {
+        let number: i64 = text
+            .trim()
+            .parse()
+            .map_err(|error| format!("'{}' is not a number: {}", text, error))?;
+
+        return u8::try_from(number).map_err(|_| format!("{} is not between 0 and 255", number));
+    }
+
+    for text in ["128", " 255", "256", "-3", "red"] {
+        match parse_channel(text) {
+            Ok(channel) => println!("channel = {}", channel),
+            Err(message) => println!("error: {}", message),
+        }
+    }
+
+    /*
+    Output:
+
+    channel = 128
+    channel = 255
+    error: 256 is not between 0 and 255
+    error: -3 is not between 0 and 255
+    error: 'red' is not a number: invalid digit found in string
+
+    Here, we parse into an i64 first, so that numbers outside 0 to 255
+    get their own, clearer message. We could also parse into a u8
+    directly, but then "256" would only say that the number is too
+    large for the type.
+
+
+    Frequently Asked Questions
+    ===========================
+
+    How do we turn a digit character into a number?
+
+    The to_digit() method of char returns an Option, which is None if
+    the character is not a digit in the given base. */
+
+    println!("{:?}", '7'.to_digit(10)); // Some(7)
+    println!("{:?}", 'x'.to_digit(10)); // None
+    println!("{:?}", 'f'.to_digit(16)); // Some(15)
+
+    /*
+    Unlike as u8, which gives 55, the code of the character '7',
+    to_digit() gives the number the character stands for. */
+
+    let seven = '7';
+
+    println!("{}", seven as u8); // 55
+}
@@ -0,0 +1,334 @@
+/*
+Rust Custom Error Types
+======================== */
+
+fn main() {
+    /*
+
+    So far, our functions returned a String as their error. A String is
+    easy to print, but the caller cannot tell one kind of error from
+    another without reading the text. In this lesson, we will define
+    our own error types, so that every kind of error is a value the
+    caller can match on.
+
+
+    An Error Enum
+    ==============
+
+    An enum with one variant per kind of error is the usual way to
+    write an error type. A variant can hold the details of its error. */
+
+    use std::num::ParseIntError;
+
+    #[derive(Debug)]
+    enum AgeError {
+        Empty,
+        NotANumber(ParseIntError),
+        TooOld(u32),
+    }
+
+    // returns the age in the text
+    fn parse_age(text: &str) -> Result<u32, AgeError> {
+        let text = text.trim();
+
+        if text.is_empty() {
+            return Err(AgeError::Empty);
+        }
+
+        let age: u32 = match text.parse() {
+            Ok(age) => age,
+            Err(error) => return Err(AgeError::NotANumber(error)),
+        };
+
+        if age > 150 {
+            return Err(AgeError::TooOld(age));
+        }
+
+        return Ok(age);
+    }
+
+    println!("{:?}", parse_age("42")); // Ok(42)
+    println!("{:?}", parse_age(" ")); // Err(Empty)
+    println!("{:?}", parse_age("4x")); // Err(NotANumber(ParseIntError { kind: InvalidDigit }))
+    println!("{:?}", parse_age("200")); // Err(TooOld(200))
+
+    /*
+    Here, #[derive(Debug)] lets us print an AgeError with {:?}, like
+    the structs of Rust Struct.
+
+    The caller can now react to each kind of error with match. */
+
+    match parse_age("200") {
+        Ok(age) => println!("age = {}", age),
+        Err(AgeError::TooOld(age)) => println!("{} is too old, try again", age), // 200 is too old, try again
+        Err(error) => println!("giving up: {:?}", error),
+    }
+
+    /*
+
+    Printing the Error With Display
+    ================================
+
+    {:?} shows how the error is built, which is not a message for the
+    people using our program. To print an AgeError with {}, it must
+    implement Display.
+
+    fn main() {
+        #[derive(Debug)]
+        enum AgeError {
+            Empty,
+        }
+
+        println!("{}", AgeError::Empty);
+    }
+
+    -----------------------------------------------------------------------
+
+    error[E0277]: `AgeError` doesn't implement `std::fmt::Display`
+      --> _04_Rust_Custom_Error_Types.rs:82:20
+       |
+    82 |     println!("{}", AgeError::Empty);
+       |               --   ^^^^^^^^^^^^^^^ `AgeError` cannot be formatted with the default formatter
+       |               |
+       |               required by this formatting parameter
+       |
+    help: the trait `std::fmt::Display` is not implemented for `AgeError`
+      --> _04_Rust_Custom_Error_Types.rs:78:5
+       |
+    78 |     enum AgeError {
+       |     ^^^^^^^^^^^^^
+       = note: in format strings you may be able to use `{:?}` (or {:#?} for pretty-print) instead
+
+    error: aborting due to 1 previous error
+
+    For more information about this error, try `rustc --explain E0277`.
+
+    -----------------------------------------------------------------------
+
+    Display is a trait: a set of methods that a type promises to have.
+    We will learn about traits in the Traits and Generics chapter. For
+    now, it is enough to know that implementing Display means writing
+    an fmt() method, inside an impl block like the ones of Rust Struct.
+    */
+
+    use std::fmt;
+
+    impl fmt::Display for AgeError {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            match self {
+                AgeError::Empty => write!(f, "no age was given"),
+                AgeError::NotANumber(error) => write!(f, "the age is not a number: {}", error),
+                AgeError::TooOld(age) => write!(f, "{} is too old to be an age", age),
+            }
+        }
+    }
+
+    for text in ["42", "", "forty", "151"] {
+        match parse_age(text) {
+            Ok(age) => println!("age = {}", age),
+            Err(error) => println!("error: {}", error),
+        }
+    }
+
+    /*
+    Output:
+
+    age = 42
+    error: no age was given
+    error: the age is not a number: invalid digit found in string
+    error: 151 is too old to be an age
+
+    Here,
+
+    - impl fmt::Display for AgeError - implements Display for our enum
+    - f - where the text goes; write!(f, ...) works like format!()
+    - fmt::Result - tells if writing the text worked
+
+
+    The Error Trait
+    ================
+
+    The standard library has an Error trait, for types that are errors.
+    A type with Debug and Display only needs an empty impl block to
+    implement it. */
+
+    impl std::error::Error for AgeError {}
+
+    /*
+    Implementing Error lets our type go wherever Rust expects an error,
+    as we will see with Box<dyn Error> below.
+
+
+    Converting Errors for ? With From
+    ==================================
+
+    In Rust Question Mark Operator, ? could not turn a ParseIntError
+    into a String. ? converts errors with the From trait: if our error
+    type implements From<ParseIntError>, ? turns a ParseIntError into
+    our type by itself. */
+
+    impl From<ParseIntError> for AgeError {
+        fn from(error: ParseIntError) -> AgeError {
+            return AgeError::NotANumber(error);
+        }
+    }
+
+    // returns the age in the text, using ? for the parse
+    fn read_age(text: &str) -> Result<u32, AgeError> {
+        let text = text.trim();
+
+        if text.is_empty() {
+            return Err(AgeError::Empty);
+        }
+
+        let age: u32 = text.parse()?;
+
+        if age > 150 {
+            return Err(AgeError::TooOld(age));
+        }
+
+        return Ok(age);
+    }
+
+    println!("{:?}", read_age("37")); // Ok(37)
+    println!("{:?}", read_age("-5")); // Err(NotANumber(ParseIntError { kind: InvalidDigit }))
+
+    /*
+    Here, the match on text.parse() is gone: the ParseIntError goes
+    through AgeError::from() on its way out of the function.
+
+    Without the From impl, the ? would not compile, just like in Rust
+    Question Mark Operator.
+
+    fn main() {
+        #[derive(Debug)]
+        enum AgeError {
+            NotANumber,
+        }
+
+        fn read_age(text: &str) -> Result<u32, AgeError> {
+            let age = text.parse::<u32>()?;
+
+            return Ok(age);
+        }
+
+        println!("{:?}", read_age("37"));
+    }
+
+    -----------------------------------------------------------------------
+
+    error[E0277]: `?` couldn't convert the error to `AgeError`
+       --> _04_Rust_Custom_Error_Types.rs:210:38
+        |
+    209 |     fn read_age(text: &str) -> Result<u32, AgeError> {
+        |                                --------------------- expected `AgeError` because of this
+    210 |         let age = text.parse::<u32>()?;
+        |                        --------------^ the trait `From<ParseIntError>` is not implemented for `AgeError`
+        |                        |
+        |                        this can't be annotated with `?` because it has type `Result<_, ParseIntError>`
+        |
+    note: `AgeError` needs to implement `From<ParseIntError>`
+       --> _04_Rust_Custom_Error_Types.rs:205:5
+        |
+    205 |     enum AgeError {
+        |     ^^^^^^^^^^^^^
+        = note: the question mark operation (`?`) implicitly performs a conversion on the error value using the `From` trait
+
+    error: aborting due to 1 previous error
+
+    For more information about this error, try `rustc --explain E0277`.
+
+    -----------------------------------------------------------------------
+
+
+    Any Error With Box<dyn Error>
+    ==============================
+
+    Sometimes a function can fail in several unrelated ways, and the
+    caller only wants to print the error. Such a function can return
+    Box<dyn std::error::Error>, which holds any type that implements
+    Error. ? converts every error into it. */
+
+    use std::error::Error;
+
+    // returns the age, in years, of a person born in the year in the text
+    fn age_in(text: &str, year: u32) -> Result<u32, Box<dyn Error>> {
+        let born: u32 = text.parse()?;
+
+        if born > year {
+            return Err(format!("{} is in the future", born).into());
+        }
+
+        let age = read_age(&(year - born).to_string())?;
+
+        return Ok(age);
+    }
+
+    println!("{:?}", age_in("1990", 2024)); // Ok(34)
+
+    match age_in("19x0", 2024) {
+        Ok(age) => println!("age = {}", age),
+        Err(error) => println!("error: {}", error), // error: invalid digit found in string
+    }
+
+    match age_in("1800", 2024) {
+        Ok(age) => println!("age = {}", age),
+        Err(error) => println!("error: {}", error), // error: 224 is too old to be an age
+    }
+
+    match age_in("2030", 2024) {
+        Ok(age) => println!("age = {}", age),
+        Err(error) => println!("error: {}", error), // error: 2030 is in the future
+    }
+
+    /*
+    Here, the first ? gives a ParseIntError and the second an
+    AgeError, and both are put in a Box<dyn Error>. A String can be
+    turned into one too, with into().
+
+    - Box - holds a value of any size
+    - dyn Error - any type that implements the Error trait
+
+    Box<dyn Error> is handy in main() and in small programs. A library
+    should prefer an error enum, so that its callers can still match
+    on the kind of error.
+
+
+    Frequently Asked Questions
+    ===========================
+
+    Can an error type say which error caused it?
+
+    Yes. The Error trait has a source() method, which returns the error
+    behind ours, if any. By default it returns None; we can override it
+    in the impl block. */
+
+    #[derive(Debug)]
+    struct ConfigError {
+        line: usize,
+        cause: ParseIntError,
+    }
+
+    impl fmt::Display for ConfigError {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "bad number on line {}", self.line)
+        }
+    }
+
+    impl Error for ConfigError {
+        fn source(&self) -> Option<&(dyn Error + 'static)> {
+            return Some(&self.cause);
+        }
+    }
+
+    let error = ConfigError {
+        line: 3,
+        cause: "x".parse::<i32>().unwrap_err(),
+    };
+
+    println!("{}", error); // bad number on line 3
+
+    if let Some(cause) = error.source() {
+        println!("caused by: {}", cause); // caused by: invalid digit found in string
+    }
+}
@@ -0,0 +1,316 @@
+/*
+Rust Error Handling Example
+============================ */
+
+fn main() {
+    /*
+
+    In this lesson, we will put the whole chapter together in a small
+    program. It reads the inventory of a shop, written as text with one
+    item per line:
+
+        name,quantity,price in cents
+
+    The program must not panic on bad input. Instead, it reports every
+    wrong line with its line number, and still totals the good ones.
+
+
+    The Item and the Errors
+    ========================
+
+    An item holds the three fields of a line. Each kind of mistake in a
+    line gets a variant of the LineError enum, as in Rust Custom Error
+    Types. */
+
+    use std::error::Error;
+    use std::fmt;
+    use std::num::ParseIntError;
+
+    #[derive(Debug)]
+    struct Item {
+        name: String,
+        quantity: u32,
+        price: u32,
+    }
+
+    #[derive(Debug)]
+    enum LineError {
+        MissingField(&'static str),
+        InvalidNumber {
+            field: &'static str,
+            text: String,
+            source: ParseIntError,
+        },
+        TooValuable(String),
+    }
+
+    impl fmt::Display for LineError {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            match self {
+                LineError::MissingField(field) => write!(f, "missing {}", field),
+                LineError::InvalidNumber { field, text, .. } => {
+                    write!(f, "invalid {} '{}'", field, text)
+                }
+                LineError::TooValuable(name) => write!(f, "the value of {} is too large", name),
+            }
+        }
+    }
+
+    impl Error for LineError {
+        fn source(&self) -> Option<&(dyn Error + 'static)> {
+            match self {
+                LineError::InvalidNumber { source, .. } => Some(source),
+                _ => None,
+            }
+        }
+    }
+
+    /*
+    Here,
+
+    - MissingField - the line has fewer than three fields; it holds
+      the name of the first missing one
+    - InvalidNumber - a field is not a number; it holds the text and
+      the ParseIntError, which source() gives back
+    - TooValuable - quantity times price does not fit a u32
+
+    The fields of InvalidNumber have names, like the fields of a
+    struct. We match them with { field, text, .. }, and .. skips the
+    ones we do not need.
+
+    The message leaves out the ParseIntError. An error prints only its
+    own part, and whoever wants the error behind it asks source().
+
+
+    Reading One Line
+    =================
+
+    Each step that can fail returns a Result, and ? passes the first
+    error on. ok_or() from Rust Error Handling turns a missing field
+    into an error, and map_err() from Rust Question Mark Operator adds
+    the details to a ParseIntError. */
+
+    // reads a field of the line as a number
+    fn parse_number(field: &'static str, text: &str) -> Result<u32, LineError> {
+        return text
+            .trim()
+            .parse()
+            .map_err(|source| LineError::InvalidNumber {
+                field,
+                text: text.trim().to_string(),
+                source,
+            });
+    }
+
+    // reads one line of the inventory
+    fn parse_line(line: &str) -> Result<Item, LineError> {
+        let mut fields = line.split(',');
+
+        let name = fields.next().ok_or(LineError::MissingField("name"))?;
+        let quantity = fields.next().ok_or(LineError::MissingField("quantity"))?;
+        let price = fields.next().ok_or(LineError::MissingField("price"))?;
+
+        let item = Item {
+            name: name.trim().to_string(),
+            quantity: parse_number("quantity", quantity)?,
+            price: parse_number("price", price)?,
+        };
+
+        // the value of the item must fit a u32, as in Rust Safe Conversions
+        if item.quantity.checked_mul(item.price).is_none() {
+            return Err(LineError::TooValuable(item.name));
+        }
+
+        return Ok(item);
+    }
+
+    println!("{:?}", parse_line("pencil,120,25")); // Ok(Item { name: "pencil", quantity: 120, price: 25 })
+
+    match parse_line("eraser,x,50") {
+        Ok(item) => println!("{:?}", item),
+        Err(error) => println!("error: {}", error), // error: invalid quantity 'x'
+    }
+
+    /*
+
+    Adding the Line Number
+    =======================
+
+    parse_line() does not know which line it reads. The caller does,
+    so it wraps the LineError in an InventoryError that adds the line
+    number. */
+
+    #[derive(Debug)]
+    struct InventoryError {
+        line: usize,
+        error: LineError,
+    }
+
+    impl fmt::Display for InventoryError {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "line {}", self.line)
+        }
+    }
+
+    impl Error for InventoryError {
+        fn source(&self) -> Option<&(dyn Error + 'static)> {
+            return Some(&self.error);
+        }
+    }
+
+    // reads every line of the inventory, keeping the items and the errors apart
+    fn parse_inventory(text: &str) -> (Vec<Item>, Vec<InventoryError>) {
+        let mut items = Vec::new();
+        let mut errors = Vec::new();
+
+        for (index, line) in text.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            match parse_line(line) {
+                Ok(item) => items.push(item),
+                Err(error) => errors.push(InventoryError {
+                    line: index + 1,
+                    error,
+                }),
+            }
+        }
+
+        return (items, errors);
+    }
+
+    /*
+    Here, enumerate() counts the lines from 0, so the line number is
+    index + 1. Empty lines are skipped, but still counted.
+
+
+    The Total Value
+    ================
+
+    The total of all items could overflow too, so total_value() uses
+    checked_add() and returns an Option. */
+
+    // returns the value of all the items together, in cents
+    fn total_value(items: &[Item]) -> Option<u32> {
+        let mut total: u32 = 0;
+
+        for item in items {
+            let value = item.quantity.checked_mul(item.price)?;
+            total = total.checked_add(value)?;
+        }
+
+        return Some(total);
+    }
+
+    /*
+
+    Putting It All Together
+    ========================
+
+    Now we read an inventory with some mistakes in it. */
+
+    let inventory = "\
+pencil,120,25
+notebook,40,199
+eraser,x,50
+ruler,15
+
+stapler,3,1500
+crane,200000,4000000";
+
+    let (items, errors) = parse_inventory(inventory);
+
+    for item in &items {
+        println!("{}: {} x {} cents", item.name, item.quantity, item.price);
+    }
+
+    for error in &errors {
+        println!("{}: {}", error, error.error);
+    }
+
+    println!("{} items, {} errors", items.len(), errors.len());
+
+    match total_value(&items) {
+        Some(cents) => println!("total value: ${}.{:02}", cents / 100, cents % 100),
+        None => println!("the total value is too large"),
+    }
+
+    /*
+    Output:
+
+    pencil: 120 x 25 cents
+    notebook: 40 x 199 cents
+    stapler: 3 x 1500 cents
+    line 3: invalid quantity 'x'
+    line 4: missing price
+    line 7: the value of crane is too large
+    3 items, 3 errors
+    total value: $154.60
+
+    Here,
+
+    - the backslash after "\ - leaves out the line break at the start
+      of the text
+    - {:02} - prints the cents with two digits, so 5 cents print as 05
+
+    Not a single line of the program can panic on bad input: every
+    mistake became a value, was given a line number, and was printed.
+
+
+    Following the Source of an Error
+    =================================
+
+    Each error can point to the error behind it with source(). A loop
+    can follow these links to print the whole chain. */
+
+    // prints an error, and every error that caused it
+    fn print_chain(error: &dyn Error) {
+        println!("error: {}", error);
+
+        let mut cause = error.source();
+        while let Some(inner) = cause {
+            println!("  caused by: {}", inner);
+            cause = inner.source();
+        }
+    }
+
+    print_chain(&errors[0]);
+
+    /*
+    Output:
+
+    error: line 3
+      caused by: invalid quantity 'x'
+      caused by: invalid digit found in string
+
+    Here, &errors[0] is an &InventoryError, which Rust turns into a
+    &dyn Error, because InventoryError implements Error.
+
+    Each message is printed once. Had InventoryError also printed its
+    LineError, the chain would repeat the same text on every line.
+
+
+    Frequently Asked Questions
+    ===========================
+
+    How do we stop at the first error instead?
+
+    collect() can gather an iterator of Results into a single Result.
+    It is an Ok with all the values, or the first Err it meets. */
+
+    let good: Result<Vec<Item>, LineError> = ["pen,1,2", "cup,3,4"]
+        .iter()
+        .map(|line| parse_line(line))
+        .collect();
+    let bad: Result<Vec<Item>, LineError> = ["pen,1,2", "cup,3"]
+        .iter()
+        .map(|line| parse_line(line))
+        .collect();
+
+    println!("{}", good.is_ok()); // true
+
+    if let Err(error) = bad {
+        println!("error: {}", error); // error: missing price
+    }
+}
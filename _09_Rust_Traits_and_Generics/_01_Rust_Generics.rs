@@ -0,0 +1,335 @@
+/*
+Rust Generics
+============== */
+
+fn main() {
+    /*
+
+    In Rust Function, we wrote functions like add3() that only work
+    with i32 values. To add two f64 values, we would need a second
+    function with the same body. Generics let us write one function
+    that works with many types.
+
+
+    Functions Work With One Type
+    =============================
+
+    The add3() function of Rust Function does not accept anything but
+    an i32.
+
+    fn main() {
+        fn add3(a: i32, b: i32) -> i32 {
+            let sum = a + b;
+
+            return sum;
+        }
+
+        println!("{}", add3(1.5, 2.5));
+    }
+
+    -----------------------------------------------------------------------
+
+    error[E0308]: arguments to this function are incorrect
+      --> _01_Rust_Generics.rs:27:20
+       |
+    27 |     println!("{}", add3(1.5, 2.5));
+       |                    ^^^^ ---  --- expected `i32`, found floating-point number
+       |                         |
+       |                         expected `i32`, found floating-point number
+       |
+    note: function defined here
+      --> _01_Rust_Generics.rs:21:8
+       |
+    21 |     fn add3(a: i32, b: i32) -> i32 {
+       |        ^^^^ ------  ------
+
+    error: aborting due to 1 previous error
+
+    For more information about this error, try `rustc --explain E0308`.
+
+    -----------------------------------------------------------------------
+
+
+    Generic Functions
+    ==================
+
+    A generic function has type parameters, written in angle brackets
+    <> after its name. A type parameter stands for a type that the
+    caller picks. */
+
+    // returns the two values of the pair in the other order
+    fn swap<T, U>(pair: (T, U)) -> (U, T) {
+        return (pair.1, pair.0);
+    }
+
+    println!("{:?}", swap((1, "one"))); // ("one", 1)
+    println!("{:?}", swap((2.5, true))); // (true, 2.5)
+
+    /*
+    Here,
+
+    - <T, U> - the type parameters of swap(), named T and U
+    - (T, U) - a tuple whose first value has the type T, and whose
+      second value has the type U
+
+    For swap((1, "one")), Rust fills in i32 for T and &str for U.
+
+    We have used generic types all along: Vec<T>, Option<T> and
+    Result<T, E> are written with type parameters too.
+
+
+    Trait Bounds
+    =============
+
+    swap() only moves its values around, which works for any type.
+    Adding two values does not: Rust does not know if every type T can
+    be added.
+
+    fn main() {
+        fn add<T>(a: T, b: T) -> T {
+            return a + b;
+        }
+
+        println!("{}", add(1, 2));
+    }
+
+    -----------------------------------------------------------------------
+
+    error[E0369]: cannot add `T` to `T`
+      --> _01_Rust_Generics.rs:90:18
+       |
+    90 |         return a + b;
+       |                - ^ - T
+       |                |
+       |                T
+       |
+    help: consider restricting type parameter `T` with trait `Add`
+       |
+    89 |     fn add<T: std::ops::Add<Output = T>>(a: T, b: T) -> T {
+       |             +++++++++++++++++++++++++++
+
+    error: aborting due to 1 previous error
+
+    For more information about this error, try `rustc --explain E0369`.
+
+    -----------------------------------------------------------------------
+
+    In Rust, + works for the types that implement the Add trait of
+    std::ops. We say so with a trait bound, written after the type
+    parameter with a colon. */
+
+    use std::ops::Add;
+
+    // returns the sum of a and b, for any type that can be added
+    fn add<T: Add<Output = T>>(a: T, b: T) -> T {
+        return a + b;
+    }
+
+    println!("{}", add(3, 5)); // 8
+    println!("{}", add(1.5, 2.25)); // 3.75
+
+    /*
+    Here,
+
+    - T: Add<Output = T> - T must implement Add, and adding two T
+      values must give a T
+    - add(3, 5) - T is i32
+    - add(1.5, 2.25) - T is f64
+
+    One add() function now does the job of add3() for every type of
+    number.
+
+    A trait bound also limits what callers can pass. A bool cannot be
+    added, so add(true, false) does not compile either.
+
+
+    Printing and Comparing
+    =======================
+
+    Printing with {} needs the Display trait, and comparing with < or >
+    needs the PartialOrd trait. A function that uses them on a type
+    parameter needs these bounds too.
+
+    fn main() {
+        fn largest<T>(list: &[T]) -> &T {
+            let mut largest = &list[0];
+
+            for item in list {
+                if item > largest {
+                    largest = item;
+                }
+            }
+
+            return largest;
+        }
+
+        println!("{}", largest(&[3, 7, 2]));
+    }
+
+    -----------------------------------------------------------------------
+
+    error[E0369]: binary operation `>` cannot be applied to type `&T`
+       --> _01_Rust_Generics.rs:158:21
+        |
+    158 |             if item > largest {
+        |                ---- ^ ------- &T
+        |                |
+        |                &T
+        |
+    help: consider restricting type parameter `T` with trait `PartialOrd`
+        |
+    154 |     fn largest<T: std::cmp::PartialOrd>(list: &[T]) -> &T {
+        |                 ++++++++++++++++++++++
+
+    error: aborting due to 1 previous error
+
+    For more information about this error, try `rustc --explain E0369`.
+
+    -----------------------------------------------------------------------
+
+    We add the bound that the error suggests. */
+
+    // returns the largest element of a list that is not empty
+    fn largest<T: PartialOrd>(list: &[T]) -> &T {
+        let mut largest = &list[0];
+
+        for item in list {
+            if item > largest {
+                largest = item;
+            }
+        }
+
+        return largest;
+    }
+
+    println!("{}", largest(&[3, 7, 2])); // 7
+    println!("{}", largest(&[1.5, -4.0])); // 1.5
+    println!("{}", largest(&['r', 'u', 's', 't'])); // u
+    println!("{}", largest(&["pear", "apple", "plum"])); // plum
+
+    /*
+    Here, largest() works for numbers, characters and text, because
+    they all implement PartialOrd. Text is compared in alphabetical
+    order.
+
+    Printing a T with {} without the Display bound fails in the same
+    way.
+
+    fn main() {
+        fn print_twice<T>(value: T) {
+            println!("{} {}", value, value);
+        }
+
+        print_twice(5);
+    }
+
+    -----------------------------------------------------------------------
+
+    error[E0277]: `T` doesn't implement `std::fmt::Display`
+       --> _01_Rust_Generics.rs:220:27
+        |
+    220 |         println!("{} {}", value, value);
+        |                   --      ^^^^^ `T` cannot be formatted with the default formatter
+        |                   |
+        |                   required by this formatting parameter
+        |
+        = note: in format strings you may be able to use `{:?}` (or {:#?} for pretty-print) instead
+    help: consider restricting type parameter `T` with trait `Display`
+        |
+    219 |     fn print_twice<T: std::fmt::Display>(value: T) {
+        |                     +++++++++++++++++++
+
+    error: aborting due to 1 previous error
+
+    For more information about this error, try `rustc --explain E0277`.
+
+    -----------------------------------------------------------------------
+
+
+    Several Bounds and where Clauses
+    =================================
+
+    We join several bounds with +. When the bounds get long, we can
+    move them into a where clause after the return type, which keeps
+    the first line short. */
+
+    use std::fmt::Display;
+
+    // prints the largest element of a list with a label
+    fn print_largest<T>(label: &str, list: &[T])
+    where
+        T: PartialOrd + Display,
+    {
+        println!("largest {} = {}", label, largest(list));
+    }
+
+    print_largest("age", &[18, 42, 33]); // largest age = 42
+    print_largest("name", &["Ana", "Ricky", "John"]); // largest name = Ricky
+
+    /*
+    Here, T: PartialOrd + Display means that T must implement both
+    traits: PartialOrd to call largest(), and Display to print the
+    result.
+
+
+    Generic Structs
+    ================
+
+    Structs can have type parameters too. The methods of an impl block
+    can then use the type parameter, with the bounds they need. */
+
+    #[derive(Debug)]
+    struct Pair<T> {
+        first: T,
+        second: T,
+    }
+
+    impl<T: PartialOrd + Display> Pair<T> {
+        // prints the larger of the two values
+        fn print_larger(&self) {
+            if self.first >= self.second {
+                println!("the larger value is {}", self.first);
+            } else {
+                println!("the larger value is {}", self.second);
+            }
+        }
+    }
+
+    let scores = Pair {
+        first: 72,
+        second: 95,
+    };
+    let names = Pair {
+        first: String::from("Ana"),
+        second: String::from("Ben"),
+    };
+
+    scores.print_larger(); // the larger value is 95
+    names.print_larger(); // the larger value is Ben
+
+    println!("{:?}", scores); // Pair { first: 72, second: 95 }
+
+    /*
+    Here,
+
+    - struct Pair<T> - both fields have the same type T
+    - impl<T: PartialOrd + Display> Pair<T> - the methods of this block
+      exist for every Pair<T> whose T can be compared and printed
+
+    Both fields must have the same type, so Pair { first: 1, second:
+    "two" } does not compile.
+
+
+    Frequently Asked Questions
+    ===========================
+
+    Are generic functions slower than functions for one type?
+
+    No. When we call a generic function, Rust writes a copy of it for
+    each type we call it with, as if we had written add3() for i32 and
+    another one for f64 ourselves. We can also pick the type ourselves
+    with ::<>, like we did with parse::<i32>(). */
+
+    println!("{}", add::<u8>(200, 55)); // 255
+    println!("{}", largest::<i64>(&[-5, -2, -9])); // -2
+}
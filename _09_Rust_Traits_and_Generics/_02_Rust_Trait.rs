@@ -0,0 +1,304 @@
+/*
+Rust Trait
+=========== */
+
+fn main() {
+    /*
+
+    A trait describes something that a type can do, as a list of
+    methods. We have met traits of the standard library, like Copy,
+    Display and PartialOrd. In this lesson, we will define traits of
+    our own, and implement them for the Person struct of Rust Struct.
+
+
+    Defining a Trait in Rust
+    =========================
+
+    We define a trait with the trait keyword, followed by the methods
+    that a type must have to implement it. The methods have no body
+    here, only their first line. */
+
+    trait Introduce {
+        fn introduce(&self) -> String;
+    }
+
+    /*
+    Here, a type that implements Introduce must have an introduce()
+    method that borrows the value and returns a String.
+
+
+    Implementing a Trait
+    =====================
+
+    We implement a trait for a type with an impl ... for ... block,
+    which gives the body of each method. */
+
+    struct Person {
+        name: String,
+        age: u8,
+        height: u8,
+    }
+
+    struct Robot {
+        model: String,
+    }
+
+    impl Introduce for Person {
+        fn introduce(&self) -> String {
+            return format!(
+                "I am {}, {} years old and {} cm tall",
+                self.name, self.age, self.height
+            );
+        }
+    }
+
+    impl Introduce for Robot {
+        fn introduce(&self) -> String {
+            return format!("I am a {} robot", self.model);
+        }
+    }
+
+    let person = Person {
+        name: String::from("John Doe"),
+        age: 18,
+        height: 178,
+    };
+    let robot = Robot {
+        model: String::from("R2"),
+    };
+
+    println!("{}", person.introduce()); // I am John Doe, 18 years old and 178 cm tall
+    println!("{}", robot.introduce()); // I am a R2 robot
+
+    /*
+    Here, Person and Robot both have an introduce() method, each with
+    its own body.
+
+    An impl block must give every method of the trait that has no body.
+
+    fn main() {
+        trait Introduce {
+            fn introduce(&self) -> String;
+        }
+
+        struct Robot {
+            model: String,
+        }
+
+        impl Introduce for Robot {}
+    }
+
+    -----------------------------------------------------------------------
+
+    error[E0046]: not all trait items implemented, missing: `introduce`
+      --> _02_Rust_Trait.rs:88:5
+       |
+    81 |         fn introduce(&self) -> String;
+       |         ------------------------------ `introduce` from trait
+    ...
+    88 |     impl Introduce for Robot {}
+       |     ^^^^^^^^^^^^^^^^^^^^^^^^ missing `introduce` in implementation
+
+    error: aborting due to 1 previous error
+
+    For more information about this error, try `rustc --explain E0046`.
+
+    -----------------------------------------------------------------------
+
+
+    Default Methods
+    ================
+
+    A method of a trait can have a body. Types that implement the trait
+    get this default method for free, and may replace it with their
+    own. A default method can call the other methods of the trait. */
+
+    trait Greet {
+        fn name(&self) -> String;
+
+        // a default method
+        fn greet(&self) -> String {
+            return format!("Hello, {}!", self.name());
+        }
+    }
+
+    impl Greet for Person {
+        fn name(&self) -> String {
+            return self.name.clone();
+        }
+    }
+
+    impl Greet for Robot {
+        fn name(&self) -> String {
+            return self.model.clone();
+        }
+
+        // replaces the default method
+        fn greet(&self) -> String {
+            return format!("BEEP. GREETINGS FROM {}.", self.model);
+        }
+    }
+
+    println!("{}", person.greet()); // Hello, John Doe!
+    println!("{}", robot.greet()); // BEEP. GREETINGS FROM R2.
+
+    /*
+    Here,
+
+    - impl Greet for Person - only gives name(), so Person uses the
+      default greet()
+    - impl Greet for Robot - gives both methods, so its own greet()
+      replaces the default one
+
+
+    Traits as Bounds
+    =================
+
+    In Rust Generics, we used the traits of the standard library as
+    bounds. Our own traits work the same way: the function below accepts
+    any type that implements Greet. */
+
+    // prints the greeting of anything that can greet, twice
+    fn greet_twice<T: Greet>(item: &T) {
+        println!("{} {}", item.greet(), item.greet());
+    }
+
+    greet_twice(&person); // Hello, John Doe! Hello, John Doe!
+    greet_twice(&robot); // BEEP. GREETINGS FROM R2. BEEP. GREETINGS FROM R2.
+
+    /*
+    Without the bound, Rust does not know that a T has a greet() method.
+
+    fn main() {
+        trait Greet {
+            fn greet(&self) -> String;
+        }
+
+        fn print_greeting<T>(item: &T) {
+            println!("{}", item.greet());
+        }
+    }
+
+    -----------------------------------------------------------------------
+
+    error[E0599]: no method named `greet` found for reference `&T` in the current scope
+       --> _02_Rust_Trait.rs:178:29
+        |
+    178 |         println!("{}", item.greet());
+        |                             ^^^^^ method not found in `&T`
+        |
+        = help: items from traits can only be used if the type parameter is bounded by the trait
+    help: the following trait defines an item `greet`, perhaps you need to restrict type parameter `T` with it:
+        |
+    177 |     fn print_greeting<T: Greet>(item: &T) {
+        |                        +++++++
+
+    error: aborting due to 1 previous error
+
+    For more information about this error, try `rustc --explain E0599`.
+
+    -----------------------------------------------------------------------
+
+    With the bound, the function only accepts types that implement
+    the trait.
+
+    fn main() {
+        trait Greet {
+            fn greet(&self) -> String;
+        }
+
+        fn greet_twice<T: Greet>(item: &T) {
+            println!("{} {}", item.greet(), item.greet());
+        }
+
+        struct Rock {
+            weight: u32,
+        }
+
+        greet_twice(&Rock { weight: 3 });
+    }
+
+    -----------------------------------------------------------------------
+
+    error[E0277]: the trait bound `Rock: Greet` is not satisfied
+       --> _02_Rust_Trait.rs:218:17
+        |
+    218 |     greet_twice(&Rock { weight: 3 });
+        |     ----------- ^^^^^^^^^^^^^^^^^^^ unsatisfied trait bound
+        |     |
+        |     required by a bound introduced by this call
+        |
+    help: the trait `Greet` is not implemented for `Rock`
+       --> _02_Rust_Trait.rs:214:5
+        |
+    214 |     struct Rock {
+        |     ^^^^^^^^^^^
+    help: this trait has no implementations, consider adding one
+       --> _02_Rust_Trait.rs:206:5
+        |
+    206 |     trait Greet {
+        |     ^^^^^^^^^^^
+    note: required by a bound in `greet_twice`
+       --> _02_Rust_Trait.rs:210:23
+        |
+    210 |     fn greet_twice<T: Greet>(item: &T) {
+        |                       ^^^^^ required by this bound in `greet_twice`
+
+    error: aborting due to 1 previous error
+
+    For more information about this error, try `rustc --explain E0277`.
+
+    -----------------------------------------------------------------------
+
+
+    Implementing Traits of the Standard Library
+    ============================================
+
+    We can implement the traits of the standard library for our own
+    types too. In Rust Custom Error Types, we implemented Display to
+    print an error with {}. Let's do the same for Person. */
+
+    use std::fmt;
+
+    impl fmt::Display for Person {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "{} ({})", self.name, self.age)
+        }
+    }
+
+    println!("{}", person); // John Doe (18)
+
+    // any function with a Display bound now accepts a Person
+    fn shout<T: fmt::Display>(value: T) -> String {
+        return value.to_string().to_uppercase();
+    }
+
+    println!("{}", shout(&person)); // JOHN DOE (18)
+
+    /*
+    Here, to_string() comes with Display: every type that implements
+    Display can be turned into a String.
+
+
+    Frequently Asked Questions
+    ===========================
+
+    Can we implement our own trait for a type of the standard library?
+
+    Yes. Our trait can be implemented for any type, including i32 and
+    String. */
+
+    impl Introduce for i32 {
+        fn introduce(&self) -> String {
+            return format!("I am the number {}", self);
+        }
+    }
+
+    println!("{}", 7.introduce()); // I am the number 7
+
+    /*
+    The other way round also works, as we did with Display for Person.
+    The only thing Rust does not allow is implementing a trait of the
+    standard library for a type of the standard library, like Display
+    for Vec<i32>: at least one of the two must be our own. */
+}
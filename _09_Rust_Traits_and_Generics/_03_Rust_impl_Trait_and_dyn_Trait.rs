@@ -0,0 +1,381 @@
+/*
+Rust impl Trait and dyn Trait
+============================== */
+
+fn main() {
+    /*
+
+    In Rust Trait, we wrote functions with a type parameter T and a
+    trait bound. Rust has two shorter ways of saying "a value of some
+    type that implements this trait":
+
+    - impl Trait - one type, which Rust knows when it compiles the
+      program
+    - dyn Trait - any type, which is only known when the program runs
+
+    In this lesson, we will use both with a Shape trait. */
+
+    trait Shape {
+        fn area(&self) -> f64;
+        fn name(&self) -> String;
+    }
+
+    struct Circle {
+        radius: f64,
+    }
+
+    struct Square {
+        side: f64,
+    }
+
+    impl Shape for Circle {
+        fn area(&self) -> f64 {
+            return 3.14 * self.radius * self.radius;
+        }
+
+        fn name(&self) -> String {
+            return format!("circle of radius {}", self.radius);
+        }
+    }
+
+    impl Shape for Square {
+        fn area(&self) -> f64 {
+            return self.side * self.side;
+        }
+
+        fn name(&self) -> String {
+            return format!("square of side {}", self.side);
+        }
+    }
+
+    /*
+
+    impl Trait as a Parameter
+    ==========================
+
+    Writing impl Shape as the type of a parameter means the same as a
+    type parameter with a Shape bound, without naming the type. */
+
+    // prints the name and area of a shape
+    fn describe(shape: &impl Shape) {
+        println!("{} has an area of {:.2}", shape.name(), shape.area());
+    }
+
+    // the same function, written with a type parameter
+    fn describe_generic<T: Shape>(shape: &T) {
+        println!("{} has an area of {:.2}", shape.name(), shape.area());
+    }
+
+    let circle = Circle { radius: 2.0 };
+    let square = Square { side: 3.0 };
+
+    describe(&circle); // circle of radius 2 has an area of 12.56
+    describe_generic(&square); // square of side 3 has an area of 9.00
+
+    /*
+    Here, {:.2} prints the area with 2 digits after the decimal point.
+
+    The bound still holds: describe() does not accept a type that does
+    not implement Shape.
+
+    fn main() {
+        trait Shape {
+            fn area(&self) -> f64;
+        }
+
+        fn describe(shape: &impl Shape) {
+            println!("area = {}", shape.area());
+        }
+
+        struct Line {
+            length: f64,
+        }
+
+        describe(&Line { length: 4.0 });
+    }
+
+    -----------------------------------------------------------------------
+
+    error[E0277]: the trait bound `Line: Shape` is not satisfied
+      --> _03_Rust_impl_Trait_and_dyn_Trait.rs:94:14
+       |
+    94 |     describe(&Line { length: 4.0 });
+       |     -------- ^^^^^^^^^^^^^^^^^^^^^ unsatisfied trait bound
+       |     |
+       |     required by a bound introduced by this call
+       |
+    help: the trait `Shape` is not implemented for `Line`
+      --> _03_Rust_impl_Trait_and_dyn_Trait.rs:90:5
+       |
+    90 |     struct Line {
+       |     ^^^^^^^^^^^
+    help: this trait has no implementations, consider adding one
+      --> _03_Rust_impl_Trait_and_dyn_Trait.rs:82:5
+       |
+    82 |     trait Shape {
+       |     ^^^^^^^^^^^
+    note: required by a bound in `describe`
+      --> _03_Rust_impl_Trait_and_dyn_Trait.rs:86:30
+       |
+    86 |     fn describe(shape: &impl Shape) {
+       |                              ^^^^^ required by this bound in `describe`
+
+    error: aborting due to 1 previous error
+
+    For more information about this error, try `rustc --explain E0277`.
+
+    -----------------------------------------------------------------------
+
+
+    impl Trait as a Return Type
+    ============================
+
+    A function can also return impl Trait. The caller then only knows
+    that the value implements the trait, not which type it is. */
+
+    // returns the shape of a coin of the given radius
+    fn coin(radius: f64) -> impl Shape {
+        return Circle { radius };
+    }
+
+    let cent = coin(1.0);
+
+    describe(&cent); // circle of radius 1 has an area of 3.14
+
+    /*
+    The type must still be a single one. A function returning
+    impl Shape cannot return a Circle in one case and a Square in
+    another.
+
+    fn main() {
+        trait Shape {
+            fn area(&self) -> f64;
+        }
+
+        struct Circle {
+            radius: f64,
+        }
+
+        struct Square {
+            side: f64,
+        }
+
+        impl Shape for Circle {
+            fn area(&self) -> f64 {
+                return 3.14 * self.radius * self.radius;
+            }
+        }
+
+        impl Shape for Square {
+            fn area(&self) -> f64 {
+                return self.side * self.side;
+            }
+        }
+
+        fn make_shape(round: bool) -> impl Shape {
+            if round {
+                return Circle { radius: 1.0 };
+            }
+
+            return Square { side: 1.0 };
+        }
+
+        println!("{}", make_shape(true).area());
+    }
+
+    -----------------------------------------------------------------------
+
+    error[E0308]: mismatched types
+       --> _03_Rust_impl_Trait_and_dyn_Trait.rs:180:16
+        |
+    175 |     fn make_shape(round: bool) -> impl Shape {
+        |                                   ---------- expected `Circle` because of return type
+    ...
+    180 |         return Square { side: 1.0 };
+        |                ^^^^^^^^^^^^^^^^^^^^ expected `Circle`, found `Square`
+
+    error: aborting due to 1 previous error
+
+    For more information about this error, try `rustc --explain E0308`.
+
+    -----------------------------------------------------------------------
+
+    For that, we need dyn Trait.
+
+
+    Trait Objects With dyn Trait
+    =============================
+
+    A trait object is a value of any type that implements the trait,
+    behind a reference or a Box. Its type is written dyn Shape, and
+    Rust picks the right area() method while the program runs. */
+
+    // returns a circle or a square, as a trait object
+    fn make_shape(round: bool) -> Box<dyn Shape> {
+        if round {
+            return Box::new(Circle { radius: 1.0 });
+        }
+
+        return Box::new(Square { side: 1.0 });
+    }
+
+    println!("{:.2}", make_shape(true).area()); // 3.14
+    println!("{:.2}", make_shape(false).area()); // 1.00
+
+    /*
+    Here, Box<dyn Shape> holds either a Circle or a Square, so both
+    return statements give the same type.
+
+    Trait objects also let a vector hold values of different types. A
+    plain vector cannot, because all its elements must have one type.
+
+    fn main() {
+        struct Circle {
+            radius: f64,
+        }
+
+        struct Square {
+            side: f64,
+        }
+
+        let shapes = vec![Circle { radius: 1.0 }, Square { side: 2.0 }];
+    }
+
+    -----------------------------------------------------------------------
+
+    error[E0308]: mismatched types
+       --> _03_Rust_impl_Trait_and_dyn_Trait.rs:241:47
+        |
+    241 |     let shapes = vec![Circle { radius: 1.0 }, Square { side: 2.0 }];
+        |                                               ^^^^^^^^^^^^^^^^^^^^ expected `Circle`, found `Square`
+
+    error: aborting due to 1 previous error
+
+    For more information about this error, try `rustc --explain E0308`.
+
+    -----------------------------------------------------------------------
+
+    A vector of Box<dyn Shape> works, as long as every element
+    implements Shape. */
+
+    let shapes: Vec<Box<dyn Shape>> = vec![
+        Box::new(Circle { radius: 1.0 }),
+        Box::new(Square { side: 2.0 }),
+        Box::new(Circle { radius: 0.5 }),
+    ];
+
+    let mut total = 0.0;
+
+    for shape in &shapes {
+        println!("{}: {:.2}", shape.name(), shape.area());
+        total += shape.area();
+    }
+
+    println!("total area = {:.2}", total);
+
+    /*
+    Output:
+
+    circle of radius 1: 3.14
+    square of side 2: 4.00
+    circle of radius 0.5: 0.79
+    total area = 7.93
+
+    A type that does not implement Shape cannot become a dyn Shape.
+
+    fn main() {
+        trait Shape {
+            fn area(&self) -> f64;
+        }
+
+        struct Line {
+            length: f64,
+        }
+
+        let shapes: Vec<Box<dyn Shape>> = vec![Box::new(Line { length: 4.0 })];
+    }
+
+    -----------------------------------------------------------------------
+
+    error[E0277]: the trait bound `Line: Shape` is not satisfied
+       --> _03_Rust_impl_Trait_and_dyn_Trait.rs:295:44
+        |
+    295 |     let shapes: Vec<Box<dyn Shape>> = vec![Box::new(Line { length: 4.0 })];
+        |                                            ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ unsatisfied trait bound
+        |
+    help: the trait `Shape` is not implemented for `Line`
+       --> _03_Rust_impl_Trait_and_dyn_Trait.rs:291:5
+        |
+    291 |     struct Line {
+        |     ^^^^^^^^^^^
+    help: this trait has no implementations, consider adding one
+       --> _03_Rust_impl_Trait_and_dyn_Trait.rs:287:5
+        |
+    287 |     trait Shape {
+        |     ^^^^^^^^^^^
+        = note: required for the cast from `Box<Line>` to `Box<dyn Shape>`
+
+    error: aborting due to 1 previous error
+
+    For more information about this error, try `rustc --explain E0277`.
+
+    -----------------------------------------------------------------------
+
+
+    References to Trait Objects
+    ============================
+
+    A function can take &dyn Shape, a reference to any shape. */
+
+    // returns the shape with the largest area
+    fn largest_area<'a>(first: &'a dyn Shape, second: &'a dyn Shape) -> &'a dyn Shape {
+        if first.area() >= second.area() {
+            return first;
+        }
+
+        return second;
+    }
+
+    let big = largest_area(&circle, &square);
+
+    println!("the largest is the {}", big.name()); // the largest is the circle of radius 2
+
+    /*
+    Here, 'a says that the returned reference lives as long as the two
+    references passed in. We need it because the function returns one
+    of them.
+
+
+    impl Trait or dyn Trait?
+    =========================
+
+    - impl Trait - Rust writes a copy of the function for each type,
+      like for generics, so calls are as fast as they can be. Each call
+      works with a single type.
+    - dyn Trait - a single function works with every type, and the
+      right method is looked up while the program runs, which costs a
+      little time. Use it to mix types, like in the vector above.
+
+
+    Frequently Asked Questions
+    ===========================
+
+    Can a closure be returned with impl Trait?
+
+    Yes. Every closure has a type of its own, with no name we could
+    write, so impl Fn is the way to return one. */
+
+    // returns a closure that multiplies its argument by factor
+    fn multiplier(factor: i32) -> impl Fn(i32) -> i32 {
+        return move |x| x * factor;
+    }
+
+    let triple = multiplier(3);
+
+    println!("{}", triple(7)); // 21
+
+    /*
+    Here, the move keyword makes the closure take ownership of factor,
+    so that it can still use factor after multiplier() has returned.
+    */
+}
@@ -0,0 +1,426 @@
+/*
+Rust Derive Traits
+=================== */
+
+fn main() {
+    /*
+
+    Some traits are so common, and so easy to write, that Rust can
+    write them for us. The #[derive(...)] attribute above a struct or
+    an enum asks the compiler to implement the traits listed in it.
+
+    We have used it since Rust Struct to print structs with {:?}. In
+    this lesson, we will look at the traits we derive most often:
+    Debug, Clone and PartialEq.
+
+
+    Debug
+    ======
+
+    Debug lets us print a value with {:?}, or {:#?} to print it over
+    several lines. Without it, a struct cannot be printed at all.
+
+    fn main() {
+        struct Person {
+            name: String,
+            age: u8,
+        }
+
+        let person = Person {
+            name: String::from("Ricky"),
+            age: 21,
+        };
+
+        println!("{:?}", person);
+    }
+
+    -----------------------------------------------------------------------
+
+    error[E0277]: `Person` doesn't implement `Debug`
+      --> _04_Rust_Derive_Traits.rs:34:22
+       |
+    34 |     println!("{:?}", person);
+       |               ----   ^^^^^^ `Person` cannot be formatted using `{:?}` because it doesn't implement `Debug`
+       |               |
+       |               required by this formatting parameter
+       |
+       = help: the trait `Debug` is not implemented for `Person`
+       = note: add `#[derive(Debug)]` to `Person` or manually `impl Debug for Person`
+    help: consider annotating `Person` with `#[derive(Debug)]`
+       |
+    24 +     #[derive(Debug)]
+    25 |     struct Person {
+       |
+
+    error: aborting due to 1 previous error
+
+    For more information about this error, try `rustc --explain E0277`.
+
+    -----------------------------------------------------------------------
+
+    We follow the help, and derive Debug. */
+
+    #[derive(Debug)]
+    struct Point {
+        x: i32,
+        y: i32,
+    }
+
+    let point = Point { x: 3, y: -1 };
+
+    println!("{:?}", point); // Point { x: 3, y: -1 }
+    println!("{:#?}", point);
+
+    /*
+    Output:
+
+    Point {
+        x: 3,
+        y: -1,
+    }
+
+    The derived Debug prints the name of the type, and the name and
+    value of each field.
+
+
+    PartialEq
+    ==========
+
+    PartialEq lets us compare two values with == and !=. The derived
+    version says that two values are equal when all their fields are
+    equal.
+
+    fn main() {
+        struct Point {
+            x: i32,
+            y: i32,
+        }
+
+        let a = Point { x: 1, y: 2 };
+        let b = Point { x: 1, y: 2 };
+
+        println!("{}", a == b);
+    }
+
+    -----------------------------------------------------------------------
+
+    error[E0369]: binary operation `==` cannot be applied to type `Point`
+       --> _04_Rust_Derive_Traits.rs:102:22
+        |
+    102 |     println!("{}", a == b);
+        |                    - ^^ - Point
+        |                    |
+        |                    Point
+        |
+    note: an implementation of `PartialEq` might be missing for `Point`
+       --> _04_Rust_Derive_Traits.rs:94:5
+        |
+     94 |     struct Point {
+        |     ^^^^^^^^^^^^ must implement `PartialEq`
+    help: consider annotating `Point` with `#[derive(PartialEq)]`
+        |
+     94 +     #[derive(PartialEq)]
+     95 |     struct Point {
+        |
+
+    error: aborting due to 1 previous error
+
+    For more information about this error, try `rustc --explain E0369`.
+
+    -----------------------------------------------------------------------
+    */
+
+    #[derive(Debug, PartialEq)]
+    struct Color {
+        red: u8,
+        green: u8,
+        blue: u8,
+    }
+
+    let sky = Color {
+        red: 135,
+        green: 206,
+        blue: 235,
+    };
+    let ocean = Color {
+        red: 0,
+        green: 105,
+        blue: 148,
+    };
+    let also_sky = Color {
+        red: 135,
+        green: 206,
+        blue: 235,
+    };
+
+    println!("{}", sky == also_sky); // true
+    println!("{}", sky != ocean); // true
+
+    /*
+    Deriving PartialEq also lets us use methods that need it, like
+    contains() of a vector, and assert_eq!(). */
+
+    let palette = vec![sky, ocean];
+
+    println!("{}", palette.contains(&also_sky)); // true
+
+    /*
+
+    Clone
+    ======
+
+    Clone gives a type the clone() method, which makes a full copy of a
+    value, as we saw in Rust Clone and Copy. */
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Person {
+        name: String,
+        age: u8,
+    }
+
+    let ricky = Person {
+        name: String::from("Ricky"),
+        age: 21,
+    };
+
+    let mut twin = ricky.clone();
+
+    println!("{}", ricky == twin); // true
+
+    twin.name.push_str(" Jr.");
+
+    println!("{:?}", ricky); // Person { name: "Ricky", age: 21 }
+    println!("{:?}", twin); // Person { name: "Ricky Jr.", age: 21 }
+
+    /*
+    Here, twin has a String of its own, so changing it leaves ricky as
+    it was.
+
+    A derived trait needs every field to implement the trait too. A
+    struct with a field that cannot be cloned cannot derive Clone.
+
+    fn main() {
+        struct Wallet {
+            cents: u32,
+        }
+
+        #[derive(Clone)]
+        struct Person {
+            name: String,
+            wallet: Wallet,
+        }
+    }
+
+    -----------------------------------------------------------------------
+
+    error[E0277]: the trait bound `Wallet: Clone` is not satisfied
+       --> _04_Rust_Derive_Traits.rs:210:9
+        |
+    207 |     #[derive(Clone)]
+        |              ----- in this derive macro expansion
+    ...
+    210 |         wallet: Wallet,
+        |         ^^^^^^^^^^^^^^ the trait `Clone` is not implemented for `Wallet`
+        |
+    help: consider annotating `Wallet` with `#[derive(Clone)]`
+        |
+    203 +     #[derive(Clone)]
+    204 |     struct Wallet {
+        |
+
+    error: aborting due to 1 previous error
+
+    For more information about this error, try `rustc --explain E0277`.
+
+    -----------------------------------------------------------------------
+
+    Deriving Clone for Wallet as well fixes the error.
+
+
+    Derive Traits and Generics
+    ===========================
+
+    Derived traits work as bounds, like any other trait. The function
+    below finds the position of a value in a list, which needs ==, so T
+    must implement PartialEq. */
+
+    // returns the index of the first element equal to target
+    fn position_of<T: PartialEq>(list: &[T], target: &T) -> Option<usize> {
+        for (index, item) in list.iter().enumerate() {
+            if item == target {
+                return Some(index);
+            }
+        }
+
+        return None;
+    }
+
+    let team = [
+        Person {
+            name: String::from("Ana"),
+            age: 30,
+        },
+        Person {
+            name: String::from("Ben"),
+            age: 25,
+        },
+    ];
+    let ben = Person {
+        name: String::from("Ben"),
+        age: 25,
+    };
+
+    println!("{:?}", position_of(&team, &ben)); // Some(1)
+    println!("{:?}", position_of(&[1, 2, 3], &7)); // None
+
+    /*
+    Without the bound, == cannot be used on T.
+
+    fn main() {
+        fn position_of<T>(list: &[T], target: &T) -> Option<usize> {
+            for (index, item) in list.iter().enumerate() {
+                if item == target {
+                    return Some(index);
+                }
+            }
+
+            return None;
+        }
+
+        println!("{:?}", position_of(&[1, 2, 3], &2));
+    }
+
+    -----------------------------------------------------------------------
+
+    error[E0369]: binary operation `==` cannot be applied to type `&T`
+       --> _04_Rust_Derive_Traits.rs:282:21
+        |
+    282 |             if item == target {
+        |                ---- ^^ ------ &T
+        |                |
+        |                &T
+        |
+    help: consider restricting type parameter `T` with trait `PartialEq`
+        |
+    280 |     fn position_of<T: std::cmp::PartialEq>(list: &[T], target: &T) -> Option<usize> {
+        |                     +++++++++++++++++++++
+
+    error: aborting due to 1 previous error
+
+    For more information about this error, try `rustc --explain E0369`.
+
+    -----------------------------------------------------------------------
+
+    And with the bound, a type that does not derive PartialEq is not
+    accepted.
+
+    fn main() {
+        fn position_of<T: PartialEq>(list: &[T], target: &T) -> Option<usize> {
+            for (index, item) in list.iter().enumerate() {
+                if item == target {
+                    return Some(index);
+                }
+            }
+
+            return None;
+        }
+
+        struct Ticket {
+            seat: u32,
+        }
+
+        let tickets = [Ticket { seat: 1 }, Ticket { seat: 2 }];
+
+        println!("{:?}", position_of(&tickets, &Ticket { seat: 2 }));
+    }
+
+    -----------------------------------------------------------------------
+
+    error[E0277]: can't compare `Ticket` with `Ticket`
+       --> _04_Rust_Derive_Traits.rs:334:22
+        |
+    334 |     println!("{:?}", position_of(&tickets, &Ticket { seat: 2 }));
+        |                      ^^^^^^^^^^^ no implementation for `Ticket == Ticket`
+        |
+        = help: the trait `PartialEq` is not implemented for `Ticket`
+    note: required by a bound in `position_of`
+       --> _04_Rust_Derive_Traits.rs:318:23
+        |
+    318 |     fn position_of<T: PartialEq>(list: &[T], target: &T) -> Option<usize> {
+        |                       ^^^^^^^^^ required by this bound in `position_of`
+    help: consider annotating `Ticket` with `#[derive(PartialEq)]`
+        |
+    328 +     #[derive(PartialEq)]
+    329 |     struct Ticket {
+        |
+
+    error: aborting due to 1 previous error
+
+    For more information about this error, try `rustc --explain E0277`.
+
+    -----------------------------------------------------------------------
+
+
+    Other Traits We Can Derive
+    ===========================
+
+    - Copy - assignments copy the value instead of moving it, as in
+      Rust Clone and Copy
+    - Eq - == always works, which is not true of f64, where NaN is not
+      equal to itself
+    - PartialOrd and Ord - compare with < and >, field by field, from
+      the first field to the last
+    - Hash - lets the type be a key of a HashMap or a value of a
+      HashSet, together with Eq
+    - Default - gives a default() function, which sets every field to
+      its default value, like 0 or an empty String
+
+    For example, a version number compares by its major number first,
+    then by its minor number. */
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+    struct Version {
+        major: u32,
+        minor: u32,
+    }
+
+    let old = Version { major: 1, minor: 9 };
+    let new = Version { major: 2, minor: 0 };
+
+    println!("{}", old < new); // true
+    println!("{:?}", Version::default()); // Version { major: 0, minor: 0 }
+
+    use std::collections::HashSet;
+
+    let mut installed = HashSet::new();
+    installed.insert(old);
+    installed.insert(new);
+    installed.insert(Version { major: 1, minor: 9 });
+
+    println!("{} versions installed", installed.len()); // 2 versions installed
+
+    /*
+    Here, the third insert() adds nothing, because an equal Version is
+    already in the set.
+
+
+    Frequently Asked Questions
+    ===========================
+
+    Can we derive Display?
+
+    No. There is no single right way to show a value to people, so Rust
+    leaves Display to us, as in Rust Custom Error Types. Debug is
+    different: it is meant for programmers, so its form does not matter
+    much, and Rust can write it. */
+
+    use std::fmt;
+
+    impl fmt::Display for Version {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "v{}.{}", self.major, self.minor)
+        }
+    }
+
+    println!("{} {:?}", new, new); // v2.0 Version { major: 2, minor: 0 }
+}
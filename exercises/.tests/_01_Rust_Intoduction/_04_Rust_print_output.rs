@@ -0,0 +1,24 @@
+// Hidden tests for the exercises of lesson 1.4, Rust Print Output.
+
+mod introduce {
+    use crate::*;
+
+    #[test]
+    fn jack() {
+        assert_eq!(introduce("Jack", 31), "Name = Jack, Age = 31");
+    }
+
+    #[test]
+    fn another_name() {
+        assert_eq!(introduce("Ada", 36), "Name = Ada, Age = 36");
+    }
+}
+
+mod two_lines {
+    use crate::*;
+
+    #[test]
+    fn separated_by_a_newline() {
+        assert_eq!(two_lines(), "Rust is fun!\nI love Rust programming.");
+    }
+}
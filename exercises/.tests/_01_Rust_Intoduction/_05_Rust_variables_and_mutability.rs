@@ -0,0 +1,34 @@
+// Hidden tests for the exercises of lesson 1.5, Rust Variables and Mutability.
+
+mod circle_area {
+    use crate::*;
+
+    #[test]
+    fn radius_two() {
+        assert!((circle_area(2.0) - 12.56636).abs() < 1e-4, "circle_area(2.0)");
+    }
+
+    #[test]
+    fn radius_zero() {
+        assert_eq!(circle_area(0.0), 0.0);
+    }
+}
+
+mod add_repeatedly {
+    use crate::*;
+
+    #[test]
+    fn counts_up() {
+        assert_eq!(add_repeatedly(1, 2, 3), 7);
+    }
+
+    #[test]
+    fn zero_times() {
+        assert_eq!(add_repeatedly(5, 10, 0), 5);
+    }
+
+    #[test]
+    fn counts_down() {
+        assert_eq!(add_repeatedly(0, -4, 5), -20);
+    }
+}
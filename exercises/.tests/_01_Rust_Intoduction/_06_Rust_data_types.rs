@@ -0,0 +1,28 @@
+// Hidden tests for the exercises of lesson 1.6, Rust Data Types.
+
+mod is_vowel {
+    use crate::*;
+
+    #[test]
+    fn vowels() {
+        for letter in ['a', 'e', 'i', 'o', 'u'] {
+            assert!(is_vowel(letter), "{letter} is a vowel");
+        }
+    }
+
+    #[test]
+    fn consonants() {
+        for letter in ['b', 'z', 'y', '1'] {
+            assert!(!is_vowel(letter), "{letter} is not a vowel");
+        }
+    }
+}
+
+mod i8_non_negative_values {
+    use crate::*;
+
+    #[test]
+    fn one_hundred_twenty_eight() {
+        assert_eq!(i8_non_negative_values(), 128);
+    }
+}
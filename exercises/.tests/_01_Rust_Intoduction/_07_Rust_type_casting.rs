@@ -0,0 +1,31 @@
+// Hidden tests for the exercises of lesson 1.7, Rust Type Casting.
+
+mod letter_at {
+    use crate::*;
+
+    #[test]
+    fn first_and_last() {
+        assert_eq!(letter_at(0), 'A');
+        assert_eq!(letter_at(25), 'Z');
+    }
+
+    #[test]
+    fn middle() {
+        assert_eq!(letter_at(17), 'R');
+    }
+}
+
+mod whole_part {
+    use crate::*;
+
+    #[test]
+    fn drops_the_decimals() {
+        assert_eq!(whole_part(54.321), 54);
+        assert_eq!(whole_part(64.99), 64);
+    }
+
+    #[test]
+    fn negative() {
+        assert_eq!(whole_part(-2.5), -2);
+    }
+}
@@ -0,0 +1,34 @@
+// Hidden tests for the exercises of lesson 1.8, Rust Operators.
+
+mod is_even {
+    use crate::*;
+
+    #[test]
+    fn even_numbers() {
+        assert!(is_even(0));
+        assert!(is_even(8));
+        assert!(is_even(-2));
+    }
+
+    #[test]
+    fn odd_numbers() {
+        assert!(!is_even(21));
+        assert!(!is_even(-7));
+    }
+}
+
+mod is_leap_year {
+    use crate::*;
+
+    #[test]
+    fn every_four_years() {
+        assert!(is_leap_year(2024));
+        assert!(!is_leap_year(2023));
+    }
+
+    #[test]
+    fn centuries() {
+        assert!(!is_leap_year(1900));
+        assert!(is_leap_year(2000));
+    }
+}
@@ -0,0 +1,29 @@
+// Hidden tests for the exercises of lesson 2.1, Rust If else.
+
+mod sign {
+    use crate::*;
+
+    #[test]
+    fn all_three_cases() {
+        assert_eq!(sign(7), "positive");
+        assert_eq!(sign(-3), "negative");
+        assert_eq!(sign(0), "zero");
+    }
+}
+
+mod largest_of_three {
+    use crate::*;
+
+    #[test]
+    fn in_any_position() {
+        assert_eq!(largest_of_three(9, 2, 5), 9);
+        assert_eq!(largest_of_three(2, 9, 5), 9);
+        assert_eq!(largest_of_three(2, 5, 9), 9);
+    }
+
+    #[test]
+    fn ties_and_negatives() {
+        assert_eq!(largest_of_three(4, 4, 1), 4);
+        assert_eq!(largest_of_three(-5, -1, -9), -1);
+    }
+}
@@ -0,0 +1,21 @@
+// Hidden tests for the exercises of lesson 2.2, Rust loop.
+
+mod first_power_above {
+    use crate::*;
+
+    #[test]
+    fn powers_of_two() {
+        assert_eq!(first_power_above(2, 100), 128);
+        assert_eq!(first_power_above(2, 128), 256);
+    }
+
+    #[test]
+    fn powers_of_ten() {
+        assert_eq!(first_power_above(10, 5), 10);
+    }
+
+    #[test]
+    fn one_is_the_first_power() {
+        assert_eq!(first_power_above(3, 0), 1);
+    }
+}
@@ -0,0 +1,35 @@
+// Hidden tests for the exercises of lesson 2.3, Rust while loop.
+
+mod multiplication_table {
+    use crate::*;
+
+    #[test]
+    fn table_of_three() {
+        assert_eq!(
+            multiplication_table(3),
+            [3, 6, 9, 12, 15, 18, 21, 24, 27, 30]
+        );
+    }
+
+    #[test]
+    fn table_of_zero() {
+        assert_eq!(multiplication_table(0), [0; 10]);
+    }
+}
+
+mod count_digits {
+    use crate::*;
+
+    #[test]
+    fn small_numbers() {
+        assert_eq!(count_digits(0), 1);
+        assert_eq!(count_digits(7), 1);
+        assert_eq!(count_digits(10), 2);
+    }
+
+    #[test]
+    fn large_numbers() {
+        assert_eq!(count_digits(12345), 5);
+        assert_eq!(count_digits(u32::MAX), 10);
+    }
+}
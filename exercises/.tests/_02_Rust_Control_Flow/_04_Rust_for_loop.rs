@@ -0,0 +1,36 @@
+// Hidden tests for the exercises of lesson 2.4, Rust for Loop.
+
+mod sum_range {
+    use crate::*;
+
+    #[test]
+    fn first_ten_naturals() {
+        assert_eq!(sum_range(1, 10), 55);
+    }
+
+    #[test]
+    fn single_number() {
+        assert_eq!(sum_range(7, 7), 7);
+    }
+
+    #[test]
+    fn empty_range() {
+        assert_eq!(sum_range(5, 4), 0);
+    }
+}
+
+mod factorial {
+    use crate::*;
+
+    #[test]
+    fn small_numbers() {
+        assert_eq!(factorial(0), 1);
+        assert_eq!(factorial(1), 1);
+        assert_eq!(factorial(5), 120);
+    }
+
+    #[test]
+    fn twenty() {
+        assert_eq!(factorial(20), 2_432_902_008_176_640_000);
+    }
+}
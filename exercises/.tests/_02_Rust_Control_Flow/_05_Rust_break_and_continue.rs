@@ -0,0 +1,31 @@
+// Hidden tests for the exercises of lesson 2.5, Rust break and continue.
+
+mod sum_without_threes {
+    use crate::*;
+
+    #[test]
+    fn up_to_ten() {
+        assert_eq!(sum_without_threes(10), 37);
+    }
+
+    #[test]
+    fn nothing_to_add() {
+        assert_eq!(sum_without_threes(0), 0);
+        assert_eq!(sum_without_threes(2), 3);
+    }
+}
+
+mod first_multiple_of_77 {
+    use crate::*;
+
+    #[test]
+    fn from_one() {
+        assert_eq!(first_multiple_of_77(1), 77);
+    }
+
+    #[test]
+    fn from_a_multiple() {
+        assert_eq!(first_multiple_of_77(154), 154);
+        assert_eq!(first_multiple_of_77(155), 231);
+    }
+}
@@ -0,0 +1,26 @@
+// Hidden tests for the exercises of lesson 3.1, Rust Array.
+
+mod largest {
+    use crate::*;
+
+    #[test]
+    fn anywhere_in_the_array() {
+        assert_eq!(largest([1, 9, 3, 4, 5]), 9);
+        assert_eq!(largest([9, 1, 3, 4, 5]), 9);
+        assert_eq!(largest([1, 3, 4, 5, 9]), 9);
+    }
+
+    #[test]
+    fn all_negative() {
+        assert_eq!(largest([-8, -3, -5, -4, -9]), -3);
+    }
+}
+
+mod reversed {
+    use crate::*;
+
+    #[test]
+    fn five_numbers() {
+        assert_eq!(reversed([1, 2, 3, 4, 5]), [5, 4, 3, 2, 1]);
+    }
+}
@@ -0,0 +1,33 @@
+// Hidden tests for the exercises of lesson 3.2, Rust slice.
+
+mod sum {
+    use crate::*;
+
+    #[test]
+    fn whole_array() {
+        assert_eq!(sum(&[1, 2, 3, 4, 5]), 15);
+    }
+
+    #[test]
+    fn part_of_an_array() {
+        let numbers = [1, 2, 3, 4, 5];
+        assert_eq!(sum(&numbers[1..4]), 9);
+        assert_eq!(sum(&numbers[..0]), 0);
+    }
+}
+
+mod inner {
+    use crate::*;
+
+    #[test]
+    fn drops_both_ends() {
+        assert_eq!(inner(&[1, 2, 3, 4, 5]), [2, 3, 4]);
+        assert_eq!(inner(&[1, 2, 3]), [2]);
+    }
+
+    #[test]
+    fn too_short() {
+        assert_eq!(inner(&[1, 2]), []);
+        assert_eq!(inner(&[]), []);
+    }
+}
@@ -0,0 +1,24 @@
+// Hidden tests for the exercises of lesson 3.3, Rust tuple.
+
+mod min_max {
+    use crate::*;
+
+    #[test]
+    fn several_numbers() {
+        assert_eq!(min_max(&[3, -1, 8, 0]), (-1, 8));
+    }
+
+    #[test]
+    fn one_number() {
+        assert_eq!(min_max(&[42]), (42, 42));
+    }
+}
+
+mod swap {
+    use crate::*;
+
+    #[test]
+    fn swaps() {
+        assert_eq!(swap((1, String::from("one"))), (String::from("one"), 1));
+    }
+}
@@ -0,0 +1,56 @@
+// Hidden tests for the exercises of lesson 3.4, Rust struct.
+
+mod new_rectangle {
+    use crate::*;
+
+    #[test]
+    fn sets_both_sides() {
+        let rectangle = new_rectangle(3, 4);
+        assert_eq!((rectangle.width, rectangle.height), (3, 4));
+    }
+}
+
+mod area {
+    use crate::*;
+
+    #[test]
+    fn width_times_height() {
+        let rectangle = Rectangle {
+            width: 30,
+            height: 50,
+        };
+        assert_eq!(area(&rectangle), 1500);
+    }
+}
+
+mod can_hold {
+    use crate::*;
+
+    #[test]
+    fn smaller_rectangles_fit() {
+        let outer = Rectangle {
+            width: 8,
+            height: 7,
+        };
+        let inner = Rectangle {
+            width: 5,
+            height: 1,
+        };
+        assert!(can_hold(&outer, &inner));
+        assert!(can_hold(&outer, &outer));
+    }
+
+    #[test]
+    fn larger_rectangles_do_not() {
+        let outer = Rectangle {
+            width: 8,
+            height: 7,
+        };
+        let wide = Rectangle {
+            width: 9,
+            height: 1,
+        };
+        assert!(!can_hold(&outer, &wide));
+        assert!(!can_hold(&wide, &outer));
+    }
+}
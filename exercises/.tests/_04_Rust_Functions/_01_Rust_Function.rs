@@ -0,0 +1,34 @@
+// Hidden tests for the exercises of lesson 4.1, Rust function.
+
+mod celsius_to_fahrenheit {
+    use crate::*;
+
+    #[test]
+    fn freezing_and_boiling() {
+        assert_eq!(celsius_to_fahrenheit(0.0), 32.0);
+        assert_eq!(celsius_to_fahrenheit(100.0), 212.0);
+    }
+
+    #[test]
+    fn below_zero() {
+        assert_eq!(celsius_to_fahrenheit(-40.0), -40.0);
+    }
+}
+
+mod is_prime {
+    use crate::*;
+
+    #[test]
+    fn primes() {
+        for number in [2, 3, 5, 7, 97, 7919] {
+            assert!(is_prime(number), "{number} is prime");
+        }
+    }
+
+    #[test]
+    fn not_primes() {
+        for number in [0, 1, 4, 9, 91, 7917] {
+            assert!(!is_prime(number), "{number} is not prime");
+        }
+    }
+}
@@ -0,0 +1,16 @@
+// Hidden tests for the exercises of lesson 4.2, Rust Variable scope.
+
+mod double_of {
+    use crate::*;
+
+    #[test]
+    fn positive() {
+        assert_eq!(double_of("21"), 42);
+    }
+
+    #[test]
+    fn negative_and_zero() {
+        assert_eq!(double_of("-8"), -16);
+        assert_eq!(double_of("0"), 0);
+    }
+}
@@ -0,0 +1,31 @@
+// Hidden tests for the exercises of lesson 4.3, Rust closure.
+
+mod apply_twice {
+    use crate::*;
+
+    #[test]
+    fn adds_twice() {
+        assert_eq!(apply_twice(|x| x + 3, 1), 7);
+    }
+
+    #[test]
+    fn captures_its_environment() {
+        let factor = 10;
+        assert_eq!(apply_twice(|x| x * factor, 2), 200);
+    }
+}
+
+mod count_matching {
+    use crate::*;
+
+    #[test]
+    fn even_numbers() {
+        assert_eq!(count_matching(&[1, 2, 3, 4, 6], |n| n % 2 == 0), 3);
+    }
+
+    #[test]
+    fn none_match() {
+        assert_eq!(count_matching(&[1, 2, 3], |n| n > 10), 0);
+        assert_eq!(count_matching(&[], |_| true), 0);
+    }
+}
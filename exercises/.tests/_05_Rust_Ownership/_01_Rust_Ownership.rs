@@ -0,0 +1,26 @@
+// Hidden tests for the exercises of lesson 5.1, Rust Ownership.
+
+mod append {
+    use crate::*;
+
+    #[test]
+    fn adds_the_suffix() {
+        let text = append(String::from("Orange"), " juice");
+        assert_eq!(text, "Orange juice");
+    }
+
+    #[test]
+    fn empty_suffix() {
+        assert_eq!(append(String::from("Kiwi"), ""), "Kiwi");
+    }
+}
+
+mod swap_owners {
+    use crate::*;
+
+    #[test]
+    fn swaps() {
+        let (first, second) = swap_owners(String::from("left"), String::from("right"));
+        assert_eq!((first.as_str(), second.as_str()), ("right", "left"));
+    }
+}
@@ -0,0 +1,44 @@
+// Hidden tests for the exercises of lesson 5.2, Rust References and Borrowing.
+
+mod count_letter {
+    use crate::*;
+
+    #[test]
+    fn counts() {
+        let text = String::from("banana");
+        assert_eq!(count_letter(&text, 'a'), 3);
+        assert_eq!(count_letter(&text, 'z'), 0);
+        assert_eq!(text, "banana");
+    }
+}
+
+mod double {
+    use crate::*;
+
+    #[test]
+    fn doubles_in_place() {
+        let mut number = 21;
+        double(&mut number);
+        assert_eq!(number, 42);
+        double(&mut number);
+        assert_eq!(number, 84);
+    }
+
+    #[test]
+    fn negative() {
+        let mut number = -3;
+        double(&mut number);
+        assert_eq!(number, -6);
+    }
+}
+
+mod greet {
+    use crate::*;
+
+    #[test]
+    fn appends_the_name() {
+        let mut greeting = String::from("Hello");
+        greet(&mut greeting, "Ferris");
+        assert_eq!(greeting, "Hello, Ferris");
+    }
+}
@@ -0,0 +1,24 @@
+// Hidden tests for the exercises of lesson 5.3, Rust Clone and Copy.
+
+mod with_shout {
+    use crate::*;
+
+    #[test]
+    fn keeps_the_original() {
+        let (word, shout) = with_shout(String::from("hello"));
+        assert_eq!(word, "hello");
+        assert_eq!(shout, "HELLO");
+    }
+}
+
+mod shift {
+    use crate::*;
+
+    #[test]
+    fn moves_the_copy() {
+        let point = Point { x: 1, y: 2 };
+        let moved = shift(point, 3, -1);
+        assert_eq!(moved, Point { x: 4, y: 1 });
+        assert_eq!(point, Point { x: 1, y: 2 });
+    }
+}
@@ -0,0 +1,25 @@
+// Hidden tests for the exercises of lesson 6.1, Rust Enum.
+
+mod turn_right {
+    use crate::*;
+
+    #[test]
+    fn turns_clockwise() {
+        assert_eq!(turn_right(Direction::North), Direction::East);
+        assert_eq!(turn_right(Direction::East), Direction::South);
+        assert_eq!(turn_right(Direction::South), Direction::West);
+        assert_eq!(turn_right(Direction::West), Direction::North);
+    }
+}
+
+mod is_vertical {
+    use crate::*;
+
+    #[test]
+    fn north_and_south() {
+        assert!(is_vertical(Direction::North));
+        assert!(is_vertical(Direction::South));
+        assert!(!is_vertical(Direction::East));
+        assert!(!is_vertical(Direction::West));
+    }
+}
@@ -0,0 +1,31 @@
+// Hidden tests for the exercises of lesson 6.2, Rust Option.
+
+mod first_negative {
+    use crate::*;
+
+    #[test]
+    fn finds_the_first() {
+        assert_eq!(first_negative(&[4, -2, 7, -9]), Some(-2));
+    }
+
+    #[test]
+    fn none_without_negatives() {
+        assert_eq!(first_negative(&[0, 1, 2]), None);
+        assert_eq!(first_negative(&[]), None);
+    }
+}
+
+mod divide {
+    use crate::*;
+
+    #[test]
+    fn divides() {
+        assert_eq!(divide(10, 3), Some(3));
+        assert_eq!(divide(-8, 2), Some(-4));
+    }
+
+    #[test]
+    fn by_zero() {
+        assert_eq!(divide(1, 0), None);
+    }
+}
@@ -0,0 +1,48 @@
+// Hidden tests for the exercises of lesson 6.3, Rust match.
+
+mod area {
+    use crate::*;
+
+    #[test]
+    fn every_shape() {
+        assert_eq!(area(&Shape::Square(3.0)), 9.0);
+        let rectangle = Shape::Rectangle {
+            width: 2.0,
+            height: 5.0,
+        };
+        assert_eq!(area(&rectangle), 10.0);
+        let triangle = Shape::Triangle {
+            base: 4.0,
+            height: 3.0,
+        };
+        assert_eq!(area(&triangle), 6.0);
+    }
+}
+
+mod grade {
+    use crate::*;
+
+    #[test]
+    fn boundaries() {
+        let grades = [(100, 'A'), (90, 'A'), (89, 'B'), (80, 'B'), (75, 'C')];
+        for (score, letter) in grades {
+            assert_eq!(grade(score), letter, "score {score}");
+        }
+        let grades = [(69, 'D'), (60, 'D'), (59, 'F'), (0, 'F')];
+        for (score, letter) in grades {
+            assert_eq!(grade(score), letter, "score {score}");
+        }
+    }
+}
+
+mod locate {
+    use crate::*;
+
+    #[test]
+    fn every_place() {
+        assert_eq!(locate((0, 0)), "origin");
+        assert_eq!(locate((5, 0)), "x axis");
+        assert_eq!(locate((0, -3)), "y axis");
+        assert_eq!(locate((2, 2)), "elsewhere");
+    }
+}
@@ -0,0 +1,26 @@
+// Hidden tests for the exercises of lesson 6.4, Rust if let and while let.
+
+mod sum_until_none {
+    use crate::*;
+
+    #[test]
+    fn stops_at_none() {
+        assert_eq!(sum_until_none(&[Some(1), Some(2), None, Some(10)]), 3);
+    }
+
+    #[test]
+    fn without_none() {
+        assert_eq!(sum_until_none(&[Some(4), Some(5)]), 9);
+        assert_eq!(sum_until_none(&[]), 0);
+    }
+}
+
+mod describe_age {
+    use crate::*;
+
+    #[test]
+    fn known_and_unknown() {
+        assert_eq!(describe_age(Some(21)), "21 years old");
+        assert_eq!(describe_age(None), "unknown age");
+    }
+}
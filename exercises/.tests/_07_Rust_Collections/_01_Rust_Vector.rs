@@ -0,0 +1,31 @@
+// Hidden tests for the exercises of lesson 7.1, Rust Vector.
+
+mod evens {
+    use crate::*;
+
+    #[test]
+    fn keeps_the_order() {
+        assert_eq!(evens(&[5, 2, 8, -4, 7, 0]), vec![2, 8, -4, 0]);
+    }
+
+    #[test]
+    fn no_evens() {
+        assert_eq!(evens(&[1, 3]), Vec::<i32>::new());
+        assert_eq!(evens(&[]), Vec::<i32>::new());
+    }
+}
+
+mod running_total {
+    use crate::*;
+
+    #[test]
+    fn adds_up() {
+        assert_eq!(running_total(&[1, 2, 3, 4]), vec![1, 3, 6, 10]);
+        assert_eq!(running_total(&[5, -5, 2]), vec![5, 0, 2]);
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(running_total(&[]), Vec::<i32>::new());
+    }
+}
@@ -0,0 +1,27 @@
+// Hidden tests for the exercises of lesson 7.2, Rust String.
+
+mod initials {
+    use crate::*;
+
+    #[test]
+    fn every_word() {
+        assert_eq!(initials("John ronald Tolkien"), "JRT");
+        assert_eq!(initials("  ada   lovelace "), "AL");
+    }
+
+    #[test]
+    fn beyond_ascii() {
+        assert_eq!(initials("émile zola"), "ÉZ");
+    }
+}
+
+mod reverse_words {
+    use crate::*;
+
+    #[test]
+    fn reverses() {
+        assert_eq!(reverse_words("one  two three"), "three two one");
+        assert_eq!(reverse_words("single"), "single");
+        assert_eq!(reverse_words(""), "");
+    }
+}
@@ -0,0 +1,29 @@
+// Hidden tests for the exercises of lesson 7.3, Rust HashMap.
+
+mod char_counts {
+    use crate::*;
+
+    #[test]
+    fn counts_without_spaces() {
+        let counts = char_counts("hello world");
+        assert_eq!(counts[&'l'], 3);
+        assert_eq!(counts[&'o'], 2);
+        assert_eq!(counts[&'h'], 1);
+        assert_eq!(counts.get(&' '), None);
+        assert_eq!(counts.len(), 7);
+    }
+}
+
+mod totals_by_category {
+    use crate::*;
+
+    #[test]
+    fn adds_each_category() {
+        let items = [("fruit", 3), ("bread", 2), ("fruit", 4), ("milk", 1)];
+        let totals = totals_by_category(&items);
+        assert_eq!(totals["fruit"], 7);
+        assert_eq!(totals["bread"], 2);
+        assert_eq!(totals["milk"], 1);
+        assert_eq!(totals.len(), 3);
+    }
+}
@@ -0,0 +1,35 @@
+// Hidden tests for the exercises of lesson 7.4, Rust HashSet.
+
+mod has_duplicates {
+    use crate::*;
+
+    #[test]
+    fn finds_repeats() {
+        assert!(has_duplicates(&[1, 2, 3, 2]));
+        assert!(!has_duplicates(&[1, 2, 3]));
+        assert!(!has_duplicates(&[]));
+    }
+}
+
+mod common_chars {
+    use crate::*;
+
+    #[test]
+    fn sorted_and_unique() {
+        assert_eq!(common_chars("hello", "world"), vec!['l', 'o']);
+        assert_eq!(common_chars("abc", "xyz"), Vec::<char>::new());
+    }
+}
+
+mod vocabulary {
+    use crate::*;
+
+    #[test]
+    fn lower_case_words() {
+        let words = vocabulary("The cat saw the Cat");
+        assert_eq!(words.len(), 3);
+        assert!(words.contains("the"));
+        assert!(words.contains("cat"));
+        assert!(words.contains("saw"));
+    }
+}
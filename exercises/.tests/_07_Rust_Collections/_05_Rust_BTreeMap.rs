@@ -0,0 +1,31 @@
+// Hidden tests for the exercises of lesson 7.5, Rust BTreeMap.
+
+mod group_by_length {
+    use crate::*;
+
+    #[test]
+    fn groups_in_order() {
+        let groups = group_by_length(&["cat", "horse", "dog", "ox", "zebra"]);
+        let lengths: Vec<usize> = groups.keys().copied().collect();
+        assert_eq!(lengths, vec![2, 3, 5]);
+        assert_eq!(groups[&3], vec!["cat", "dog"]);
+        assert_eq!(groups[&5], vec!["horse", "zebra"]);
+    }
+}
+
+mod last_before {
+    use crate::*;
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn latest_earlier_event() {
+        let mut schedule = BTreeMap::new();
+        schedule.insert(9, String::from("standup"));
+        schedule.insert(12, String::from("lunch"));
+        schedule.insert(15, String::from("review"));
+        assert_eq!(last_before(&schedule, 13), Some(String::from("lunch")));
+        assert_eq!(last_before(&schedule, 12), Some(String::from("standup")));
+        assert_eq!(last_before(&schedule, 9), None);
+        assert_eq!(last_before(&schedule, 20), Some(String::from("review")));
+    }
+}
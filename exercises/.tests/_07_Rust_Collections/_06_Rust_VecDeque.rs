@@ -0,0 +1,23 @@
+// Hidden tests for the exercises of lesson 7.6, Rust VecDeque.
+
+mod rotate {
+    use crate::*;
+
+    #[test]
+    fn moves_the_front_to_the_back() {
+        assert_eq!(rotate(&[1, 2, 3, 4], 1), vec![2, 3, 4, 1]);
+        assert_eq!(rotate(&[1, 2, 3, 4], 6), vec![3, 4, 1, 2]);
+        assert_eq!(rotate(&[], 3), Vec::<i32>::new());
+    }
+}
+
+mod last_n {
+    use crate::*;
+
+    #[test]
+    fn keeps_the_last() {
+        assert_eq!(last_n(&[1, 2, 3, 4, 5], 2), vec![4, 5]);
+        assert_eq!(last_n(&[1, 2], 5), vec![1, 2]);
+        assert_eq!(last_n(&[1, 2, 3], 0), Vec::<i32>::new());
+    }
+}
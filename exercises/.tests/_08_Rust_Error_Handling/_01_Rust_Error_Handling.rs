@@ -0,0 +1,32 @@
+// Hidden tests for the exercises of lesson 8.1, Rust Error Handling.
+
+mod safe_divide {
+    use crate::*;
+
+    #[test]
+    fn divides() {
+        assert_eq!(safe_divide(250, 5), Ok(50));
+        assert_eq!(safe_divide(-9, 2), Ok(-4));
+    }
+
+    #[test]
+    fn refuses_zero() {
+        assert_eq!(safe_divide(1, 0), Err(String::from("cannot divide by zero")));
+    }
+}
+
+mod parse_or_zero {
+    use crate::*;
+
+    #[test]
+    fn parses_numbers() {
+        assert_eq!(parse_or_zero("42"), 42);
+        assert_eq!(parse_or_zero("  -7 "), -7);
+    }
+
+    #[test]
+    fn falls_back_to_zero() {
+        assert_eq!(parse_or_zero("seven"), 0);
+        assert_eq!(parse_or_zero(""), 0);
+    }
+}
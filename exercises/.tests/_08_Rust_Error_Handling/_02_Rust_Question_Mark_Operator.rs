@@ -0,0 +1,34 @@
+// Hidden tests for the exercises of lesson 8.2, Rust Question Mark Operator.
+
+mod sum_words {
+    use crate::*;
+
+    #[test]
+    fn sums_the_numbers() {
+        assert_eq!(sum_words("1 2 3"), Ok(6));
+        assert_eq!(sum_words("  10   -4 "), Ok(6));
+        assert_eq!(sum_words(""), Ok(0));
+    }
+
+    #[test]
+    fn returns_the_parse_error() {
+        let error = sum_words("1 two 3").unwrap_err();
+        assert_eq!(error.to_string(), "invalid digit found in string");
+    }
+}
+
+mod last_digit {
+    use crate::*;
+
+    #[test]
+    fn reads_the_last_digit() {
+        assert_eq!(last_digit("room 42"), Some(2));
+        assert_eq!(last_digit("7"), Some(7));
+    }
+
+    #[test]
+    fn none_without_a_digit() {
+        assert_eq!(last_digit("42 rooms"), None);
+        assert_eq!(last_digit(""), None);
+    }
+}
@@ -0,0 +1,35 @@
+// Hidden tests for the exercises of lesson 8.3, Rust Safe Conversions.
+
+mod to_byte {
+    use crate::*;
+
+    #[test]
+    fn converts_values_that_fit() {
+        assert_eq!(to_byte(0), Some(0));
+        assert_eq!(to_byte(255), Some(255));
+    }
+
+    #[test]
+    fn refuses_values_that_do_not_fit() {
+        assert_eq!(to_byte(256), None);
+        assert_eq!(to_byte(-1), None);
+        assert_eq!(to_byte(i64::MAX), None);
+    }
+}
+
+mod total_seconds {
+    use crate::*;
+
+    #[test]
+    fn adds_hours_and_minutes() {
+        assert_eq!(total_seconds(1, 30), Some(5400));
+        assert_eq!(total_seconds(0, 0), Some(0));
+    }
+
+    #[test]
+    fn none_on_overflow() {
+        assert_eq!(total_seconds(2_000_000, 0), None);
+        assert_eq!(total_seconds(0, 100_000_000), None);
+        assert_eq!(total_seconds(1_193_046, 60), None);
+    }
+}
@@ -0,0 +1,36 @@
+// Hidden tests for the exercises of lesson 8.4, Rust Custom Error Types.
+
+mod message {
+    use crate::*;
+
+    #[test]
+    fn describes_each_error() {
+        let parse_error = "x".parse::<i32>().unwrap_err();
+        assert_eq!(TemperatureError::Empty.to_string(), "no temperature was given");
+        assert_eq!(
+            TemperatureError::NotANumber(parse_error).to_string(),
+            "not a number: invalid digit found in string"
+        );
+        assert_eq!(
+            TemperatureError::BelowAbsoluteZero(-300).to_string(),
+            "-300 is below absolute zero"
+        );
+    }
+}
+
+mod parse_celsius {
+    use crate::*;
+
+    #[test]
+    fn parses_temperatures() {
+        assert_eq!(parse_celsius("21"), Ok(21));
+        assert_eq!(parse_celsius(" -273 "), Ok(-273));
+    }
+
+    #[test]
+    fn returns_the_right_error() {
+        assert_eq!(parse_celsius("  "), Err(TemperatureError::Empty));
+        assert!(matches!(parse_celsius("warm"), Err(TemperatureError::NotANumber(_))));
+        assert_eq!(parse_celsius("-274"), Err(TemperatureError::BelowAbsoluteZero(-274)));
+    }
+}
@@ -0,0 +1,33 @@
+// Hidden tests for the exercises of lesson 8.5, Rust Error Handling Example.
+
+mod parse_score {
+    use crate::*;
+
+    #[test]
+    fn reads_a_line() {
+        assert_eq!(parse_score("ana,87"), Ok((String::from("ana"), 87)));
+        assert_eq!(parse_score(" ben , 9 "), Ok((String::from("ben"), 9)));
+    }
+
+    #[test]
+    fn reports_bad_lines() {
+        assert_eq!(parse_score("cleo"), Err(String::from("missing score")));
+        assert_eq!(parse_score("dev,x"), Err(String::from("invalid score 'x'")));
+    }
+}
+
+mod parse_scores {
+    use crate::*;
+
+    #[test]
+    fn reads_every_line() {
+        let scores = parse_scores("ana,87\n\nben,9\n").unwrap();
+        assert_eq!(scores, vec![(String::from("ana"), 87), (String::from("ben"), 9)]);
+        assert_eq!(parse_scores(""), Ok(Vec::new()));
+    }
+
+    #[test]
+    fn stops_at_the_first_error() {
+        assert_eq!(parse_scores("ana,87\n\ncleo\ndev,x"), Err(String::from("line 3: missing score")));
+    }
+}
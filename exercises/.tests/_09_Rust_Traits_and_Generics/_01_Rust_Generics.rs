@@ -0,0 +1,28 @@
+// Hidden tests for the exercises of lesson 9.1, Rust Generics.
+
+mod smallest {
+    use crate::*;
+
+    #[test]
+    fn works_for_many_types() {
+        assert_eq!(smallest(&[4, -2, 9]), Some(-2));
+        assert_eq!(smallest(&[2.5, 0.5, 1.0]), Some(0.5));
+        assert_eq!(smallest(&['r', 'u', 's', 't']), Some('r'));
+    }
+
+    #[test]
+    fn none_for_an_empty_list() {
+        assert_eq!(smallest::<i32>(&[]), None);
+    }
+}
+
+mod sum_all {
+    use crate::*;
+
+    #[test]
+    fn sums_integers_and_floats() {
+        assert_eq!(sum_all(&[1, 2, 3, 4], 0), 10);
+        assert_eq!(sum_all(&[0.5, 0.25], 0.0), 0.75);
+        assert_eq!(sum_all(&[], 7), 7);
+    }
+}
@@ -0,0 +1,56 @@
+// Hidden tests for the exercises of lesson 9.2, Rust Trait.
+
+fn book(title: &str) -> crate::Book {
+    crate::Book {
+        title: title.to_string(),
+        author: String::from("Anonymous"),
+    }
+}
+
+struct Planet(&'static str);
+
+impl crate::Describe for Planet {
+    fn name(&self) -> String {
+        self.0.to_string()
+    }
+
+    fn describe(&self) -> String {
+        format!("the planet {}", self.0)
+    }
+}
+
+mod describe_all {
+    use super::*;
+    use crate::*;
+
+    #[test]
+    fn uses_the_default_method() {
+        assert_eq!(
+            describe_all(&[book("Dune"), book("Emma")]),
+            vec!["this is Dune", "this is Emma"]
+        );
+    }
+
+    #[test]
+    fn uses_a_replaced_method() {
+        assert_eq!(describe_all(&[Planet("Mars")]), vec!["the planet Mars"]);
+        assert!(describe_all::<Planet>(&[]).is_empty());
+    }
+}
+
+mod longest_name {
+    use super::*;
+    use crate::*;
+
+    #[test]
+    fn finds_the_longest() {
+        let books = [book("Dune"), book("Ulysses"), book("Emma"), book("Beloved")];
+        assert_eq!(longest_name(&books), Some(String::from("Ulysses")));
+        assert_eq!(longest_name(&[Planet("Io")]), Some(String::from("Io")));
+    }
+
+    #[test]
+    fn none_without_items() {
+        assert_eq!(longest_name::<Book>(&[]), None);
+    }
+}
@@ -0,0 +1,34 @@
+// Hidden tests for the exercises of lesson 9.3, Rust impl Trait and dyn Trait.
+
+mod total_area {
+    use crate::*;
+
+    #[test]
+    fn adds_different_shapes() {
+        let shapes: Vec<Box<dyn Shape>> = vec![
+            Box::new(Rectangle { width: 2.0, height: 3.0 }),
+            Box::new(Circle { radius: 1.0 }),
+            Box::new(Rectangle { width: 0.5, height: 4.0 }),
+        ];
+        let total = total_area(&shapes);
+        assert!((total - (8.0 + std::f64::consts::PI)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn zero_without_shapes() {
+        assert_eq!(total_area(&[]), 0.0);
+    }
+}
+
+mod larger_area {
+    use crate::*;
+
+    #[test]
+    fn compares_different_shapes() {
+        let circle = Circle { radius: 1.0 };
+        let wide = Rectangle { width: 4.0, height: 1.0 };
+        assert_eq!(larger_area(&circle, &wide), 4.0);
+        assert_eq!(larger_area(&wide, &circle), 4.0);
+        assert_eq!(larger_area(&circle, &Circle { radius: 0.5 }), std::f64::consts::PI);
+    }
+}
@@ -0,0 +1,40 @@
+// Hidden tests for the exercises of lesson 9.4, Rust Derive Traits.
+
+mod count_equal {
+    use crate::*;
+
+    #[test]
+    fn counts_cards() {
+        let ace = Card { rank: 1, suit: 'S' };
+        let hand = [
+            ace.clone(),
+            Card { rank: 1, suit: 'H' },
+            ace.clone(),
+        ];
+        assert_eq!(count_equal(&hand, &ace), 2);
+        assert_eq!(count_equal(&hand, &Card { rank: 9, suit: 'D' }), 0);
+    }
+
+    #[test]
+    fn counts_numbers() {
+        assert_eq!(count_equal(&[1, 2, 1, 1], &1), 3);
+        assert_eq!(count_equal(&[], &1), 0);
+    }
+}
+
+mod doubled {
+    use crate::*;
+
+    #[test]
+    fn repeats_each_element() {
+        assert_eq!(doubled(&[1, 2]), vec![1, 1, 2, 2]);
+        assert_eq!(doubled(&[String::from("a")]), vec!["a", "a"]);
+        assert_eq!(doubled::<Card>(&[]), Vec::new());
+    }
+
+    #[test]
+    fn clones_cards() {
+        let king = Card { rank: 13, suit: 'C' };
+        assert_eq!(doubled(&[king.clone()]), vec![king.clone(), king]);
+    }
+}
@@ -0,0 +1,17 @@
+// Exercises for lesson 1.4, Rust Print Output.
+//
+// Replace every `todo!()` with your own code, then grade your answers:
+//
+//     cargo run -p rustprog -- grade 1.4
+
+/// Returns `Name = <name>, Age = <age>`, built with the `format!` macro,
+/// which takes the same placeholders as `println!`.
+pub fn introduce(name: &str, age: u32) -> String {
+    todo!()
+}
+
+/// Returns the two lines `Rust is fun!` and `I love Rust programming.`
+/// as a single string, separated by the `\n` escape sequence.
+pub fn two_lines() -> String {
+    todo!()
+}
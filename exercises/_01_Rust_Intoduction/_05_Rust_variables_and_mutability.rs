@@ -0,0 +1,17 @@
+// Exercises for lesson 1.5, Rust Variables and Mutability.
+//
+// Replace every `todo!()` with your own code, then grade your answers:
+//
+//     cargo run -p rustprog -- grade 1.5
+
+/// Returns the area of a circle of the given radius, `PI * radius *
+/// radius`. Declare `PI` as a constant with the value `3.14159`.
+pub fn circle_area(radius: f32) -> f32 {
+    todo!()
+}
+
+/// Returns `start` after adding `step` to it `times` times. Keep the
+/// running value in a mutable variable.
+pub fn add_repeatedly(start: i32, step: i32, times: u32) -> i32 {
+    todo!()
+}
@@ -0,0 +1,17 @@
+// Exercises for lesson 1.6, Rust Data Types.
+//
+// Replace every `todo!()` with your own code, then grade your answers:
+//
+//     cargo run -p rustprog -- grade 1.6
+
+/// Returns whether `letter` is one of the lowercase vowels `a`, `e`,
+/// `i`, `o` and `u`.
+pub fn is_vowel(letter: char) -> bool {
+    todo!()
+}
+
+/// Returns the largest value an `i8` can hold, plus one, as an `i16`:
+/// the number of non-negative values of an `i8`.
+pub fn i8_non_negative_values() -> i16 {
+    todo!()
+}
@@ -0,0 +1,17 @@
+// Exercises for lesson 1.8, Rust Operators.
+//
+// Replace every `todo!()` with your own code, then grade your answers:
+//
+//     cargo run -p rustprog -- grade 1.8
+
+/// Returns whether `number` is even, with the remainder operator `%`.
+pub fn is_even(number: i32) -> bool {
+    todo!()
+}
+
+/// Returns whether `year` is a leap year: divisible by 4 but not by 100,
+/// unless it is also divisible by 400. Combine the conditions with the
+/// logical operators.
+pub fn is_leap_year(year: u32) -> bool {
+    todo!()
+}
@@ -0,0 +1,16 @@
+// Exercises for lesson 2.1, Rust If else.
+//
+// Replace every `todo!()` with your own code, then grade your answers:
+//
+//     cargo run -p rustprog -- grade 2.1
+
+/// Returns `"positive"`, `"negative"` or `"zero"` depending on `number`,
+/// with an `if..else if..else` expression.
+pub fn sign(number: i32) -> &'static str {
+    todo!()
+}
+
+/// Returns the largest of the three numbers.
+pub fn largest_of_three(a: i32, b: i32, c: i32) -> i32 {
+    todo!()
+}
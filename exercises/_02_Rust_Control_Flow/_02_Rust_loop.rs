@@ -0,0 +1,12 @@
+// Exercises for lesson 2.2, Rust loop.
+//
+// Replace every `todo!()` with your own code, then grade your answers:
+//
+//     cargo run -p rustprog -- grade 2.2
+
+/// Returns the first power of `base` (1, `base`, `base * base`, ...)
+/// that is greater than `limit`. Use `loop` and leave it with `break`;
+/// `base` is at least 2.
+pub fn first_power_above(base: u32, limit: u32) -> u32 {
+    todo!()
+}
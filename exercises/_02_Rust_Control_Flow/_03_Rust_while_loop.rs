@@ -0,0 +1,16 @@
+// Exercises for lesson 2.3, Rust while loop.
+//
+// Replace every `todo!()` with your own code, then grade your answers:
+//
+//     cargo run -p rustprog -- grade 2.3
+
+/// Returns the multiplication table of `number`: `number * 1` up to
+/// `number * 10`, built with a `while` loop.
+pub fn multiplication_table(number: u32) -> Vec<u32> {
+    todo!()
+}
+
+/// Returns how many decimal digits `number` has; 0 has one digit.
+pub fn count_digits(number: u32) -> u32 {
+    todo!()
+}
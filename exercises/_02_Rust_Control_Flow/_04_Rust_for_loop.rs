@@ -0,0 +1,16 @@
+// Exercises for lesson 2.4, Rust for Loop.
+//
+// Replace every `todo!()` with your own code, then grade your answers:
+//
+//     cargo run -p rustprog -- grade 2.4
+
+/// Returns the sum of the numbers from `lo` to `hi`, both included, or 0
+/// when `lo` is greater than `hi`. Use a `for` loop over a range.
+pub fn sum_range(lo: u32, hi: u32) -> u32 {
+    todo!()
+}
+
+/// Returns `n!`, the product of the numbers from 1 to `n`; `0!` is 1.
+pub fn factorial(n: u64) -> u64 {
+    todo!()
+}
@@ -0,0 +1,17 @@
+// Exercises for lesson 2.5, Rust break and continue.
+//
+// Replace every `todo!()` with your own code, then grade your answers:
+//
+//     cargo run -p rustprog -- grade 2.5
+
+/// Returns the sum of the numbers from 1 to `limit` that are not
+/// multiples of 3, skipping those with `continue`.
+pub fn sum_without_threes(limit: u32) -> u32 {
+    todo!()
+}
+
+/// Returns the first number from `start` upwards that is divisible by
+/// both 7 and 11, stopping the loop with `break`.
+pub fn first_multiple_of_77(start: u32) -> u32 {
+    todo!()
+}
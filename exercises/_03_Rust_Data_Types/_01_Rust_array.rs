@@ -0,0 +1,15 @@
+// Exercises for lesson 3.1, Rust Array.
+//
+// Replace every `todo!()` with your own code, then grade your answers:
+//
+//     cargo run -p rustprog -- grade 3.1
+
+/// Returns the largest number in the array.
+pub fn largest(numbers: [i32; 5]) -> i32 {
+    todo!()
+}
+
+/// Returns the array with its elements in reverse order.
+pub fn reversed(numbers: [i32; 5]) -> [i32; 5] {
+    todo!()
+}
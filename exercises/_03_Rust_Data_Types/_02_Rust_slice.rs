@@ -0,0 +1,16 @@
+// Exercises for lesson 3.2, Rust slice.
+//
+// Replace every `todo!()` with your own code, then grade your answers:
+//
+//     cargo run -p rustprog -- grade 3.2
+
+/// Returns the sum of the numbers in the slice.
+pub fn sum(numbers: &[i32]) -> i32 {
+    todo!()
+}
+
+/// Returns the slice without its first and last elements; the empty
+/// slice when it has fewer than three.
+pub fn inner(numbers: &[i32]) -> &[i32] {
+    todo!()
+}
@@ -0,0 +1,16 @@
+// Exercises for lesson 3.3, Rust tuple.
+//
+// Replace every `todo!()` with your own code, then grade your answers:
+//
+//     cargo run -p rustprog -- grade 3.3
+
+/// Returns the smallest and the largest number of a non-empty slice, as
+/// a tuple `(min, max)`.
+pub fn min_max(numbers: &[i32]) -> (i32, i32) {
+    todo!()
+}
+
+/// Returns the pair with its two elements swapped.
+pub fn swap(pair: (i32, String)) -> (String, i32) {
+    todo!()
+}
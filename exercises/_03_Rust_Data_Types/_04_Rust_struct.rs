@@ -0,0 +1,27 @@
+// Exercises for lesson 3.4, Rust struct.
+//
+// Replace every `todo!()` with your own code, then grade your answers:
+//
+//     cargo run -p rustprog -- grade 3.4
+
+/// A rectangle, measured in whole units.
+#[derive(Debug)]
+pub struct Rectangle {
+    pub width: u32,
+    pub height: u32,
+}
+
+/// Returns a new `Rectangle` with the given sides.
+pub fn new_rectangle(width: u32, height: u32) -> Rectangle {
+    todo!()
+}
+
+/// Returns the area of the rectangle.
+pub fn area(rectangle: &Rectangle) -> u32 {
+    todo!()
+}
+
+/// Returns whether `inner` fits inside `outer` without turning it.
+pub fn can_hold(outer: &Rectangle, inner: &Rectangle) -> bool {
+    todo!()
+}
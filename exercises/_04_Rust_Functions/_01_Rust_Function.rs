@@ -0,0 +1,17 @@
+// Exercises for lesson 4.1, Rust function.
+//
+// Replace every `todo!()` with your own code, then grade your answers:
+//
+//     cargo run -p rustprog -- grade 4.1
+
+/// Returns the temperature in degrees Fahrenheit, `celsius * 9 / 5 +
+/// 32`.
+pub fn celsius_to_fahrenheit(celsius: f64) -> f64 {
+    todo!()
+}
+
+/// Returns whether `number` is prime: greater than 1 and divisible only
+/// by 1 and itself.
+pub fn is_prime(number: u32) -> bool {
+    todo!()
+}
@@ -0,0 +1,12 @@
+// Exercises for lesson 4.2, Rust Variable scope.
+//
+// Replace every `todo!()` with your own code, then grade your answers:
+//
+//     cargo run -p rustprog -- grade 4.2
+
+/// Returns twice the number written in `input`, e.g. 42 for `"21"`.
+/// Shadow `input` with the parsed `i32` rather than naming a new variable;
+/// `input` always holds a valid number.
+pub fn double_of(input: &str) -> i32 {
+    todo!()
+}
@@ -0,0 +1,15 @@
+// Exercises for lesson 4.3, Rust closure.
+//
+// Replace every `todo!()` with your own code, then grade your answers:
+//
+//     cargo run -p rustprog -- grade 4.3
+
+/// Returns `f(f(x))`: the closure `f` applied twice.
+pub fn apply_twice(f: impl Fn(i32) -> i32, x: i32) -> i32 {
+    todo!()
+}
+
+/// Returns how many of the numbers `condition` holds for.
+pub fn count_matching(numbers: &[i32], condition: impl Fn(i32) -> bool) -> usize {
+    todo!()
+}
@@ -0,0 +1,17 @@
+// Exercises for lesson 5.1, Rust Ownership.
+//
+// Replace every `todo!()` with your own code, then grade your answers:
+//
+//     cargo run -p rustprog -- grade 5.1
+
+/// Takes the ownership of `text` and gives it back with `suffix` added
+/// to its end.
+pub fn append(text: String, suffix: &str) -> String {
+    todo!()
+}
+
+/// Takes the ownership of both Strings and returns them in the opposite
+/// order.
+pub fn swap_owners(first: String, second: String) -> (String, String) {
+    todo!()
+}
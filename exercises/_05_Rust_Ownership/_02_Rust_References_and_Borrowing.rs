@@ -0,0 +1,20 @@
+// Exercises for lesson 5.2, Rust References and Borrowing.
+//
+// Replace every `todo!()` with your own code, then grade your answers:
+//
+//     cargo run -p rustprog -- grade 5.2
+
+/// Returns how many times `letter` appears in the borrowed `text`.
+pub fn count_letter(text: &String, letter: char) -> usize {
+    todo!()
+}
+
+/// Doubles the number behind the mutable reference.
+pub fn double(number: &mut i32) {
+    todo!()
+}
+
+/// Adds `", "` and then `name` to the end of the borrowed `greeting`.
+pub fn greet(greeting: &mut String, name: &str) {
+    todo!()
+}
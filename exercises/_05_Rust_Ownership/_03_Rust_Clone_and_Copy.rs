@@ -0,0 +1,24 @@
+// Exercises for lesson 5.3, Rust Clone and Copy.
+//
+// Replace every `todo!()` with your own code, then grade your answers:
+//
+//     cargo run -p rustprog -- grade 5.3
+
+/// Returns the original `word` together with a clone of it in upper
+/// case, so that the caller keeps both.
+pub fn with_shout(word: String) -> (String, String) {
+    todo!()
+}
+
+/// A point on a grid. It is a Copy type, so passing it to a function
+/// copies it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Point {
+    pub x: i32,
+    pub y: i32,
+}
+
+/// Returns `point` moved `dx` to the right and `dy` up.
+pub fn shift(point: Point, dx: i32, dy: i32) -> Point {
+    todo!()
+}
@@ -0,0 +1,25 @@
+// Exercises for lesson 6.1, Rust Enum.
+//
+// Replace every `todo!()` with your own code, then grade your answers:
+//
+//     cargo run -p rustprog -- grade 6.1
+
+/// One of the four points of a compass.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+/// Returns the direction we face after turning right from `direction`:
+/// North turns into East, East into South and so on.
+pub fn turn_right(direction: Direction) -> Direction {
+    todo!()
+}
+
+/// Returns whether `direction` is North or South.
+pub fn is_vertical(direction: Direction) -> bool {
+    todo!()
+}
@@ -0,0 +1,16 @@
+// Exercises for lesson 6.2, Rust Option.
+//
+// Replace every `todo!()` with your own code, then grade your answers:
+//
+//     cargo run -p rustprog -- grade 6.2
+
+/// Returns the first negative number of `numbers`, or `None` if there is
+/// none.
+pub fn first_negative(numbers: &[i32]) -> Option<i32> {
+    todo!()
+}
+
+/// Returns `dividend / divisor`, or `None` if `divisor` is zero.
+pub fn divide(dividend: i32, divisor: i32) -> Option<i32> {
+    todo!()
+}
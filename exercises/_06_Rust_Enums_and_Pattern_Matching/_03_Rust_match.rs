@@ -0,0 +1,31 @@
+// Exercises for lesson 6.3, Rust match.
+//
+// Replace every `todo!()` with your own code, then grade your answers:
+//
+//     cargo run -p rustprog -- grade 6.3
+
+/// A shape, measured in whole units.
+#[derive(Debug)]
+pub enum Shape {
+    Square(f64),
+    Rectangle { width: f64, height: f64 },
+    Triangle { base: f64, height: f64 },
+}
+
+/// Returns the area of the shape.
+pub fn area(shape: &Shape) -> f64 {
+    todo!()
+}
+
+/// Returns the letter grade of a score out of 100: 'A' for 90 and
+/// above, 'B' for 80 to 89, 'C' for 70 to 79, 'D' for 60 to 69 and 'F'
+/// below 60.
+pub fn grade(score: u32) -> char {
+    todo!()
+}
+
+/// Returns where the point `(x, y)` lies: "origin", "x axis", "y axis"
+/// or "elsewhere".
+pub fn locate(point: (i32, i32)) -> String {
+    todo!()
+}
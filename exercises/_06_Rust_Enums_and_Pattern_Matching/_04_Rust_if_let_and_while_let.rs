@@ -0,0 +1,16 @@
+// Exercises for lesson 6.4, Rust if let and while let.
+//
+// Replace every `todo!()` with your own code, then grade your answers:
+//
+//     cargo run -p rustprog -- grade 6.4
+
+/// Returns the sum of the values up to the first `None`, leaving out the
+/// values after it.
+pub fn sum_until_none(values: &[Option<i32>]) -> i32 {
+    todo!()
+}
+
+/// Returns "<age> years old", or "unknown age" if `age` is `None`.
+pub fn describe_age(age: Option<u32>) -> String {
+    todo!()
+}
@@ -0,0 +1,17 @@
+// Exercises for lesson 7.1, Rust Vector.
+//
+// Replace every `todo!()` with your own code, then grade your answers:
+//
+//     cargo run -p rustprog -- grade 7.1
+
+/// Returns the even numbers of `numbers`, in their original order.
+pub fn evens(numbers: &[i32]) -> Vec<i32> {
+    todo!()
+}
+
+/// Returns the running totals of `numbers`: each element is the sum of
+/// the numbers up to and including that position, so `[1, 2, 3]` gives
+/// `[1, 3, 6]`.
+pub fn running_total(numbers: &[i32]) -> Vec<i32> {
+    todo!()
+}
@@ -0,0 +1,17 @@
+// Exercises for lesson 7.2, Rust String.
+//
+// Replace every `todo!()` with your own code, then grade your answers:
+//
+//     cargo run -p rustprog -- grade 7.2
+
+/// Returns the first letter of every word of `name`, in upper case, so
+/// "John ronald Tolkien" gives "JRT".
+pub fn initials(name: &str) -> String {
+    todo!()
+}
+
+/// Returns the words of `text` in the opposite order, separated by a
+/// single space, so "one  two three" gives "three two one".
+pub fn reverse_words(text: &str) -> String {
+    todo!()
+}
@@ -0,0 +1,19 @@
+// Exercises for lesson 7.3, Rust HashMap.
+//
+// Replace every `todo!()` with your own code, then grade your answers:
+//
+//     cargo run -p rustprog -- grade 7.3
+
+use std::collections::HashMap;
+
+/// Returns how many times each character other than a space appears in
+/// `text`.
+pub fn char_counts(text: &str) -> HashMap<char, usize> {
+    todo!()
+}
+
+/// Returns the total price of every category of the `(category, price)`
+/// items.
+pub fn totals_by_category(items: &[(&str, u32)]) -> HashMap<String, u32> {
+    todo!()
+}
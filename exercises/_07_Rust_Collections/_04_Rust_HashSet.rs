@@ -0,0 +1,23 @@
+// Exercises for lesson 7.4, Rust HashSet.
+//
+// Replace every `todo!()` with your own code, then grade your answers:
+//
+//     cargo run -p rustprog -- grade 7.4
+
+use std::collections::HashSet;
+
+/// Returns whether any value appears more than once in `values`.
+pub fn has_duplicates(values: &[i32]) -> bool {
+    todo!()
+}
+
+/// Returns the characters that appear in both `first` and `second`,
+/// each one once, sorted.
+pub fn common_chars(first: &str, second: &str) -> Vec<char> {
+    todo!()
+}
+
+/// Returns the set of words of `text`, in lower case.
+pub fn vocabulary(text: &str) -> HashSet<String> {
+    todo!()
+}
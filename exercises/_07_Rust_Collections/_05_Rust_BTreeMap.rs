@@ -0,0 +1,19 @@
+// Exercises for lesson 7.5, Rust BTreeMap.
+//
+// Replace every `todo!()` with your own code, then grade your answers:
+//
+//     cargo run -p rustprog -- grade 7.5
+
+use std::collections::BTreeMap;
+
+/// Returns the words grouped by their length in characters, each group
+/// in the order the words appear in `words`.
+pub fn group_by_length(words: &[&str]) -> BTreeMap<usize, Vec<String>> {
+    todo!()
+}
+
+/// Returns the last event of the schedule that starts before `time`, or
+/// `None` if there is none. The keys of the schedule are start times.
+pub fn last_before(schedule: &BTreeMap<u32, String>, time: u32) -> Option<String> {
+    todo!()
+}
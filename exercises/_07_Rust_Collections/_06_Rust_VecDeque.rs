@@ -0,0 +1,18 @@
+// Exercises for lesson 7.6, Rust VecDeque.
+//
+// Replace every `todo!()` with your own code, then grade your answers:
+//
+//     cargo run -p rustprog -- grade 7.6
+
+/// Returns `values` after moving the front element to the back `steps`
+/// times, so `[1, 2, 3, 4]` rotated by 1 gives `[2, 3, 4, 1]`.
+pub fn rotate(values: &[i32], steps: usize) -> Vec<i32> {
+    todo!()
+}
+
+/// Returns the last `n` values of `values`, reading them one by one and
+/// only keeping `n` of them at a time. All the values are returned if
+/// there are fewer than `n`.
+pub fn last_n(values: &[i32], n: usize) -> Vec<i32> {
+    todo!()
+}
@@ -0,0 +1,17 @@
+// Exercises for lesson 8.1, Rust Error Handling.
+//
+// Replace every `todo!()` with your own code, then grade your answers:
+//
+//     cargo run -p rustprog -- grade 8.1
+
+/// Returns `a` divided by `b`, or the error "cannot divide by zero" when
+/// `b` is 0.
+pub fn safe_divide(a: i32, b: i32) -> Result<i32, String> {
+    todo!()
+}
+
+/// Returns the number written in `text`, ignoring spaces around it, or 0
+/// if the text is not a number.
+pub fn parse_or_zero(text: &str) -> i32 {
+    todo!()
+}
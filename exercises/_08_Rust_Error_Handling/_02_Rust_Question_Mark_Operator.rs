@@ -0,0 +1,20 @@
+// Exercises for lesson 8.2, Rust Question Mark Operator.
+//
+// Replace every `todo!()` with your own code, then grade your answers:
+//
+//     cargo run -p rustprog -- grade 8.2
+
+use std::num::ParseIntError;
+
+/// Returns the sum of the numbers in `text`, which are separated by
+/// spaces, or the error of the first word that is not a number. An
+/// empty text sums to 0.
+pub fn sum_words(text: &str) -> Result<i32, ParseIntError> {
+    todo!()
+}
+
+/// Returns the value of the last character of `text` as a decimal digit,
+/// or None if the text is empty or ends with something else.
+pub fn last_digit(text: &str) -> Option<u32> {
+    todo!()
+}
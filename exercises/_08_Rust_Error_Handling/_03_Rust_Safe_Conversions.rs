@@ -0,0 +1,16 @@
+// Exercises for lesson 8.3, Rust Safe Conversions.
+//
+// Replace every `todo!()` with your own code, then grade your answers:
+//
+//     cargo run -p rustprog -- grade 8.3
+
+/// Returns `value` as a u8, or None if it is not between 0 and 255.
+pub fn to_byte(value: i64) -> Option<u8> {
+    todo!()
+}
+
+/// Returns the number of seconds in `hours` hours and `minutes` minutes,
+/// or None if the result does not fit a u32.
+pub fn total_seconds(hours: u32, minutes: u32) -> Option<u32> {
+    todo!()
+}
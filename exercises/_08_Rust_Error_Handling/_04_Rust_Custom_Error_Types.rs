@@ -0,0 +1,36 @@
+// Exercises for lesson 8.4, Rust Custom Error Types.
+//
+// Replace every `todo!()` with your own code, then grade your answers:
+//
+//     cargo run -p rustprog -- grade 8.4
+
+use std::fmt;
+use std::num::ParseIntError;
+
+/// What can be wrong with a temperature written in Celsius.
+#[derive(Debug, PartialEq)]
+pub enum TemperatureError {
+    Empty,
+    NotANumber(ParseIntError),
+    BelowAbsoluteZero(i32),
+}
+
+impl fmt::Display for TemperatureError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", message(self))
+    }
+}
+
+/// Returns the message for `error`: "no temperature was given", "not a
+/// number: " followed by the ParseIntError, or for example "-300 is
+/// below absolute zero". Printing an error with {} shows this message.
+pub fn message(error: &TemperatureError) -> String {
+    todo!()
+}
+
+/// Returns the temperature in `text`, ignoring spaces around it. The
+/// text must not be empty, must be a number, and must not be below
+/// -273.
+pub fn parse_celsius(text: &str) -> Result<i32, TemperatureError> {
+    todo!()
+}
@@ -0,0 +1,19 @@
+// Exercises for lesson 8.5, Rust Error Handling Example.
+//
+// Replace every `todo!()` with your own code, then grade your answers:
+//
+//     cargo run -p rustprog -- grade 8.5
+
+/// Returns the name and score of a line like "ana,87". The errors are
+/// "missing score" when there is no comma, and for example "invalid
+/// score 'x'" when the score is not a number.
+pub fn parse_score(line: &str) -> Result<(String, u32), String> {
+    todo!()
+}
+
+/// Returns the name and score of every line of `text`, skipping empty
+/// lines. On the first bad line, returns its error with the line number
+/// in front, like "line 3: missing score".
+pub fn parse_scores(text: &str) -> Result<Vec<(String, u32)>, String> {
+    todo!()
+}
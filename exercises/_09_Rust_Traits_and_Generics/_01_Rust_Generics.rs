@@ -0,0 +1,18 @@
+// Exercises for lesson 9.1, Rust Generics.
+//
+// Replace every `todo!()` with your own code, then grade your answers:
+//
+//     cargo run -p rustprog -- grade 9.1
+
+use std::ops::Add;
+
+/// Returns the smallest element of `list`, or None if it is empty.
+pub fn smallest<T: PartialOrd + Copy>(list: &[T]) -> Option<T> {
+    todo!()
+}
+
+/// Returns the sum of the elements of `list`, starting from `zero`, so
+/// that it works for integers (with 0) and floats (with 0.0) alike.
+pub fn sum_all<T: Add<Output = T> + Copy>(list: &[T], zero: T) -> T {
+    todo!()
+}
@@ -0,0 +1,37 @@
+// Exercises for lesson 9.2, Rust Trait.
+//
+// Replace every `todo!()` with your own code, then grade your answers:
+//
+//     cargo run -p rustprog -- grade 9.2
+
+/// Something that has a name and can describe itself.
+pub trait Describe {
+    fn name(&self) -> String;
+
+    fn describe(&self) -> String {
+        format!("this is {}", self.name())
+    }
+}
+
+/// A book, described by its title.
+pub struct Book {
+    pub title: String,
+    pub author: String,
+}
+
+impl Describe for Book {
+    fn name(&self) -> String {
+        self.title.clone()
+    }
+}
+
+/// Returns the description of each item of `items`, in order.
+pub fn describe_all<T: Describe>(items: &[T]) -> Vec<String> {
+    todo!()
+}
+
+/// Returns the longest name of the items, the first one if several are
+/// as long, or None if there are no items.
+pub fn longest_name<T: Describe>(items: &[T]) -> Option<String> {
+    todo!()
+}
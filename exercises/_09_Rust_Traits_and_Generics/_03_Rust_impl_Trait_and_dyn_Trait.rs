@@ -0,0 +1,43 @@
+// Exercises for lesson 9.3, Rust impl Trait and dyn Trait.
+//
+// Replace every `todo!()` with your own code, then grade your answers:
+//
+//     cargo run -p rustprog -- grade 9.3
+
+use std::f64::consts::PI;
+
+/// A flat shape with an area.
+pub trait Shape {
+    fn area(&self) -> f64;
+}
+
+pub struct Circle {
+    pub radius: f64,
+}
+
+pub struct Rectangle {
+    pub width: f64,
+    pub height: f64,
+}
+
+impl Shape for Circle {
+    fn area(&self) -> f64 {
+        PI * self.radius * self.radius
+    }
+}
+
+impl Shape for Rectangle {
+    fn area(&self) -> f64 {
+        self.width * self.height
+    }
+}
+
+/// Returns the area of all the shapes together.
+pub fn total_area(shapes: &[Box<dyn Shape>]) -> f64 {
+    todo!()
+}
+
+/// Returns the larger of the areas of the two shapes.
+pub fn larger_area(first: &impl Shape, second: &impl Shape) -> f64 {
+    todo!()
+}
@@ -0,0 +1,23 @@
+// Exercises for lesson 9.4, Rust Derive Traits.
+//
+// Replace every `todo!()` with your own code, then grade your answers:
+//
+//     cargo run -p rustprog -- grade 9.4
+
+/// A playing card, like the 10 of hearts.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Card {
+    pub rank: u8,
+    pub suit: char,
+}
+
+/// Returns how many elements of `list` are equal to `target`.
+pub fn count_equal<T: PartialEq>(list: &[T], target: &T) -> usize {
+    todo!()
+}
+
+/// Returns a new list with every element of `list` twice in a row, so
+/// `[a, b]` gives `[a, a, b, b]`.
+pub fn doubled<T: Clone>(list: &[T]) -> Vec<T> {
+    todo!()
+}
@@ -0,0 +1,123 @@
+//! The exercises that go with the lessons, one module per lesson.
+//!
+//! Fill in the `todo!()`s of a lesson's file, then grade it against the
+//! hidden tests with `cargo run -p rustprog -- grade <lesson>`. Building
+//! this crate (`cargo build -p exercises`) only checks that the answers
+//! compile.
+
+#[path = "_01_Rust_Intoduction/_04_Rust_print_output.rs"]
+pub mod print_output;
+
+#[path = "_01_Rust_Intoduction/_05_Rust_variables_and_mutability.rs"]
+pub mod variables_and_mutability;
+
+#[path = "_01_Rust_Intoduction/_06_Rust_data_types.rs"]
+pub mod data_types;
+
+#[path = "_01_Rust_Intoduction/_07_Rust_type_casting.rs"]
+pub mod type_casting;
+
+#[path = "_01_Rust_Intoduction/_08_Rust_operators.rs"]
+pub mod operators;
+
+#[path = "_02_Rust_Control_Flow/_01_Rust_if_else.rs"]
+pub mod if_else;
+
+#[path = "_02_Rust_Control_Flow/_02_Rust_loop.rs"]
+pub mod loops;
+
+#[path = "_02_Rust_Control_Flow/_03_Rust_while_loop.rs"]
+pub mod while_loop;
+
+#[path = "_02_Rust_Control_Flow/_04_Rust_for_loop.rs"]
+pub mod for_loop;
+
+#[path = "_02_Rust_Control_Flow/_05_Rust_break_and_continue.rs"]
+pub mod break_and_continue;
+
+#[path = "_03_Rust_Data_Types/_01_Rust_array.rs"]
+pub mod array;
+
+#[path = "_03_Rust_Data_Types/_02_Rust_slice.rs"]
+pub mod slice;
+
+#[path = "_03_Rust_Data_Types/_03_Rust_tuple.rs"]
+pub mod tuple;
+
+#[path = "_03_Rust_Data_Types/_04_Rust_struct.rs"]
+pub mod structs;
+
+#[path = "_04_Rust_Functions/_01_Rust_Function.rs"]
+pub mod function;
+
+#[path = "_04_Rust_Functions/_02_Rust_Variable_Scope.rs"]
+pub mod variable_scope;
+
+#[path = "_04_Rust_Functions/_03_Rust_Closure.rs"]
+pub mod closure;
+
+#[path = "_05_Rust_Ownership/_01_Rust_Ownership.rs"]
+pub mod ownership;
+
+#[path = "_05_Rust_Ownership/_02_Rust_References_and_Borrowing.rs"]
+pub mod references_and_borrowing;
+
+#[path = "_05_Rust_Ownership/_03_Rust_Clone_and_Copy.rs"]
+pub mod clone_and_copy;
+
+#[path = "_06_Rust_Enums_and_Pattern_Matching/_01_Rust_Enum.rs"]
+pub mod enums;
+
+#[path = "_06_Rust_Enums_and_Pattern_Matching/_02_Rust_Option.rs"]
+pub mod option;
+
+#[path = "_06_Rust_Enums_and_Pattern_Matching/_03_Rust_match.rs"]
+pub mod matching;
+
+#[path = "_06_Rust_Enums_and_Pattern_Matching/_04_Rust_if_let_and_while_let.rs"]
+pub mod if_let_and_while_let;
+
+#[path = "_07_Rust_Collections/_01_Rust_Vector.rs"]
+pub mod vector;
+
+#[path = "_07_Rust_Collections/_02_Rust_String.rs"]
+pub mod string;
+
+#[path = "_07_Rust_Collections/_03_Rust_HashMap.rs"]
+pub mod hashmap;
+
+#[path = "_07_Rust_Collections/_04_Rust_HashSet.rs"]
+pub mod hashset;
+
+#[path = "_07_Rust_Collections/_05_Rust_BTreeMap.rs"]
+pub mod btreemap;
+
+#[path = "_07_Rust_Collections/_06_Rust_VecDeque.rs"]
+pub mod vecdeque;
+
+#[path = "_08_Rust_Error_Handling/_01_Rust_Error_Handling.rs"]
+pub mod error_handling;
+
+#[path = "_08_Rust_Error_Handling/_02_Rust_Question_Mark_Operator.rs"]
+pub mod question_mark_operator;
+
+#[path = "_08_Rust_Error_Handling/_03_Rust_Safe_Conversions.rs"]
+pub mod safe_conversions;
+
+#[path = "_08_Rust_Error_Handling/_04_Rust_Custom_Error_Types.rs"]
+pub mod custom_error_types;
+
+#[path = "_08_Rust_Error_Handling/_05_Rust_Error_Handling_Example.rs"]
+pub mod error_handling_example;
+
+#[path = "_09_Rust_Traits_and_Generics/_01_Rust_Generics.rs"]
+pub mod generics;
+
+#[path = "_09_Rust_Traits_and_Generics/_02_Rust_Trait.rs"]
+pub mod traits;
+
+#[path = "_09_Rust_Traits_and_Generics/_03_Rust_impl_Trait_and_dyn_Trait.rs"]
+pub mod impl_trait_and_dyn_trait;
+
+#[path = "_09_Rust_Traits_and_Generics/_04_Rust_Derive_Traits.rs"]
+pub mod derive_traits;
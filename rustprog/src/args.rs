@@ -0,0 +1,68 @@
+//! Minimal command-line argument handling shared by the subcommands.
+
+use crate::error::{Error, Result};
+
+/// Remaining arguments of a subcommand. Options are taken out by name and
+/// whatever is left must be positional.
+#[derive(Debug, Clone, Default)]
+pub struct Args {
+    items: Vec<String>,
+}
+
+impl Args {
+    pub fn new(items: impl IntoIterator<Item = String>) -> Args {
+        Args {
+            items: items.into_iter().collect(),
+        }
+    }
+
+    /// Removes `--name` and reports whether it was present.
+    pub fn flag(&mut self, name: &str) -> bool {
+        let before = self.items.len();
+        self.items.retain(|item| item != name);
+        self.items.len() != before
+    }
+
+    /// Removes `--name value` or `--name=value` and returns the value.
+    pub fn value(&mut self, name: &str) -> Result<Option<String>> {
+        let prefix = format!("{name}=");
+        for index in 0..self.items.len() {
+            if let Some(value) = self.items[index].strip_prefix(&prefix) {
+                let value = value.to_string();
+                self.items.remove(index);
+                return Ok(Some(value));
+            }
+            if self.items[index] == name {
+                if index + 1 >= self.items.len() {
+                    return Err(Error::Usage(format!("{name} expects a value")));
+                }
+                self.items.remove(index);
+                return Ok(Some(self.items.remove(index)));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Like [`Args::value`], parsed as a number.
+    pub fn number(&mut self, name: &str) -> Result<Option<u32>> {
+        match self.value(name)? {
+            None => Ok(None),
+            Some(value) => value
+                .parse()
+                .map(Some)
+                .map_err(|_| Error::Usage(format!("{name} expects a number, got '{value}'"))),
+        }
+    }
+
+    /// Everything left over, rejecting options nobody asked for.
+    pub fn positional(self) -> Result<Vec<String>> {
+        if let Some(unknown) = self
+            .items
+            .iter()
+            .find(|item| item.starts_with("--") && item.len() > 2)
+        {
+            return Err(Error::Usage(format!("unknown option '{unknown}'")));
+        }
+        Ok(self.items)
+    }
+}
@@ -0,0 +1,1075 @@
+//! `rustprog` subcommands.
+
+use std::env;
+use std::fs;
+use std::io::{self, BufRead, Write};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use crate::args::Args;
+use crate::course::{Course, Lesson};
+use crate::document::Document;
+use crate::error::{Error, Result};
+use crate::grade::{self, Verdict};
+use crate::i18n::{self, Translation};
+use crate::layout;
+use crate::lesson::LessonSource;
+use crate::lint::{self, Severity};
+use crate::manifest;
+use crate::mdbook;
+use crate::notebook;
+use crate::playground::{self, Piece, Playground, Report};
+use crate::progress::{self, Progress, QuizScore};
+use crate::quiz::{self, Quiz};
+use crate::runner;
+use crate::search::{self, Index};
+use crate::serve::Server;
+use crate::site;
+use crate::text;
+use crate::trace;
+use crate::verify;
+
+pub const USAGE: &str = "\
+usage: rustprog [--root <dir>] <command> [options]
+
+commands:
+    list [--chapter <n>]                 show the numbered lesson tree
+    run <lesson> [<example>]             run a lesson by number (3.2) or name (slice),
+                                         or a single example of it
+    run --chapter <n> | --all            run every lesson of a chapter, or all of them
+    read <lesson> [--lang <code>]        show a lesson in the terminal, in English or
+                                         in a translation from i18n/<code>
+    playground <lesson> [<example>]      copy a lesson, one of its examples or the snippet
+               [--line <n>] [--reset]    on line n into a scratch crate, open it in $EDITOR
+               [--watch]                 and run it each time you save and close it; with
+               [--timeout <secs>]        --watch, run it on every save instead of opening
+               [--memory <mb>]           the editor (default limits: 5s, 512 MB)
+    trace <lesson> [--line <n>]          step through the loops and branches of a lesson,
+          [--animate] [--delay <ms>]     showing the values of its variables and where
+          [--steps <n>]                  each break jumps to; --line picks the statement
+                                         on that line, --animate plays it step by step
+                                         (default: 600ms a step, at most 500 steps)
+    layout <type>... [--lesson <lesson>] show the size, alignment, field offsets and padding
+                                         of types as measured by rustc: primitives, arrays,
+                                         tuples or structs of the lessons, such as Person3;
+                                         with --lesson alone, every type the lesson uses
+    verify [<lesson> | --chapter <n>]    check documented output and errors against the code
+    lint [<lesson> | --chapter <n>]      check the lessons follow the course conventions:
+                                         titles, headings, numbering and Output blocks
+    search <term>... [--limit <n>]       find where the lessons talk about something, best
+           [--rebuild]                   hits first (default: 10); the index is kept in
+                                         target/rustprog and rebuilt when a lesson changes
+    snippets [<lesson> | --chapter <n>]  list the code snippets of the prose and how
+                                         each one is checked
+    site [--out <dir>] [--lang <code>]   render the course as a static HTML site
+                                         (default: target/site under the course root,
+                                         target/site-<code> for a translation)
+    serve [--port <n>] [--out <dir>]     build the site and serve it on localhost, with a
+          [--timeout <secs>]             Run button on every code block that compiles and
+          [--memory <mb>]                runs it here (default: port 8000, 5s, 512 MB);
+          [--lang <code>]                --lang as for site
+    book [--out <dir>] [--lang <code>]   export the course as the source of an mdBook
+                                         (default: target/book under the course root,
+                                         target/book-<code> for a translation)
+    notebook [<lesson> | --chapter <n>]  export lessons as Jupyter notebooks for the evcxr
+             [--out <dir>]               Rust kernel (default: target/notebooks under the
+                                         course root)
+    manifest [--out <dir>]               write course.json, describing every lesson, and
+                                         its JSON Schema (default: target under the
+                                         course root)
+    i18n --lang <code> [--update]        show how much of the prose is translated, and
+         [<lesson> | --chapter <n>]      the sections left to do; --update first creates
+                                         or updates the catalogs in i18n/<code>
+    grade [<lesson> | --chapter <n>]     grade your answers in exercises/ against the
+          [--timeout <secs>]             hidden tests (default timeout: 10s per exercise)
+    quiz <lesson>                        answer the questions on a lesson in the terminal
+    quiz --check [<lesson> | --chapter <n>]
+                                         check that the question files read and their
+                                         programs build
+    progress [--chapter <n>]             show the lessons you ran, the exercises you
+                                         passed and your quiz scores, chapter by chapter
+
+The course root defaults to $RUSTPROG_ROOT, then to the nearest parent of the
+current directory that contains the chapter directories. Progress is kept in
+$RUSTPROG_PROGRESS, by default rustprog/progress.json in your data directory.";
+
+/// Entry point used by the `rustprog` binary.
+pub fn run(args: impl IntoIterator<Item = String>) -> Result<()> {
+    let mut args: Vec<String> = args.into_iter().collect();
+    let root = take_root(&mut args)?;
+    if args.is_empty() {
+        return Err(Error::Usage("missing command".to_string()));
+    }
+    let command = args.remove(0);
+    if matches!(command.as_str(), "help" | "--help" | "-h") {
+        println!("{USAGE}");
+        return Ok(());
+    }
+
+    let course = Course::discover(find_root(root)?)?;
+    let args = Args::new(args);
+    match command.as_str() {
+        "list" => list(&course, args),
+        "run" => run_lessons(&course, args),
+        "read" => read_lesson(&course, args),
+        "playground" => open_playground(&course, args),
+        "trace" => trace_lesson(&course, args),
+        "layout" => show_layouts(&course, args),
+        "verify" => verify_lessons(&course, args),
+        "lint" => lint_lessons(&course, args),
+        "search" => search_lessons(&course, args),
+        "snippets" => list_snippets(&course, args),
+        "site" => build_site(&course, args),
+        "serve" => serve_site(&course, args),
+        "book" => export_book(&course, args),
+        "notebook" => export_notebooks(&course, args),
+        "manifest" => write_manifest(&course, args),
+        "i18n" => translate_lessons(&course, args),
+        "grade" => grade_exercises(&course, args),
+        "quiz" => take_quiz(&course, args),
+        "progress" => show_progress(&course, args),
+        other => Err(Error::Usage(format!("unknown command '{other}'"))),
+    }
+}
+
+/// Takes a leading `--root <dir>` off the command line.
+fn take_root(args: &mut Vec<String>) -> Result<Option<PathBuf>> {
+    match args.first().map(String::as_str) {
+        Some("--root") if args.len() < 2 => Err(Error::Usage("--root expects a value".to_string())),
+        Some("--root") => Ok(Some(PathBuf::from(
+            args.drain(..2).nth(1).unwrap_or_default(),
+        ))),
+        Some(first) if first.starts_with("--root=") => {
+            let root = args.remove(0);
+            Ok(Some(PathBuf::from(&root["--root=".len()..])))
+        }
+        _ => Ok(None),
+    }
+}
+
+/// Picks the course root from `--root`, `$RUSTPROG_ROOT` or the current directory.
+fn find_root(explicit: Option<PathBuf>) -> Result<PathBuf> {
+    if let Some(root) = explicit.or_else(|| env::var_os("RUSTPROG_ROOT").map(PathBuf::from)) {
+        return Ok(root);
+    }
+    let cwd = env::current_dir()?;
+    cwd.ancestors()
+        .find(|dir| is_course_root(dir))
+        .map(Path::to_path_buf)
+        .ok_or_else(|| Error::Failed(format!("no course found above {}", cwd.display())))
+}
+
+fn is_course_root(dir: &Path) -> bool {
+    Course::discover(dir).is_ok_and(|course| !course.chapters.is_empty())
+}
+
+/// Lessons selected by `--chapter <n>`, or the whole course without it.
+fn chapter_lessons(course: &Course, chapter: Option<u32>) -> Result<Vec<&Lesson>> {
+    match chapter {
+        None => Ok(course.lessons().collect()),
+        Some(number) => course
+            .chapter(number)
+            .map(|chapter| chapter.lessons.iter().collect())
+            .ok_or_else(|| Error::Usage(format!("there is no chapter {number}"))),
+    }
+}
+
+fn list(course: &Course, mut args: Args) -> Result<()> {
+    let selected = args.number("--chapter")?;
+    args.positional()?;
+    chapter_lessons(course, selected)?;
+
+    for chapter in &course.chapters {
+        if selected.is_some_and(|number| number != chapter.number) {
+            continue;
+        }
+        println!(
+            "{}. {} ({})",
+            chapter.number,
+            chapter.name(),
+            chapter.dir_name
+        );
+        for (index, lesson) in chapter.lessons.iter().enumerate() {
+            let branch = if index + 1 == chapter.lessons.len() {
+                "└──"
+            } else {
+                "├──"
+            };
+            println!(
+                "   {branch} {:<5} {:<30} {}",
+                lesson.id(),
+                lesson.name(),
+                lesson.bin_name()
+            );
+            let examples = LessonSource::read(&lesson.path)?.examples();
+            if !examples.is_empty() {
+                let trunk = if index + 1 == chapter.lessons.len() {
+                    " "
+                } else {
+                    "│"
+                };
+                let names: Vec<&str> = examples
+                    .iter()
+                    .map(|example| example.name.as_str())
+                    .collect();
+                println!("   {trunk}         examples: {}", names.join(", "));
+            }
+        }
+    }
+    Ok(())
+}
+
+fn run_lessons(course: &Course, mut args: Args) -> Result<()> {
+    let all = args.flag("--all");
+    let chapter = args.number("--chapter")?;
+    let positional = args.positional()?;
+
+    let lessons = match (positional.as_slice(), all, chapter) {
+        ([query], false, None) => vec![course.find(query)?],
+        ([query, example], false, None) => {
+            return run_example(course, course.find(query)?, example);
+        }
+        ([], true, _) | ([], false, Some(_)) => chapter_lessons(course, chapter)?,
+        ([], false, None) => {
+            return Err(Error::Usage(
+                "run needs a lesson, --chapter or --all".to_string(),
+            ))
+        }
+        _ => {
+            return Err(Error::Usage(
+                "run takes either one lesson (and example) or --chapter/--all".to_string(),
+            ))
+        }
+    };
+
+    let mut failed = Vec::new();
+    for lesson in &lessons {
+        if lessons.len() > 1 {
+            println!(
+                "==> {} {}",
+                lesson.id(),
+                lesson.relative_path(course).display()
+            );
+        }
+        let status = runner::run_lesson(course, lesson, &[])?;
+        record(|progress| progress.mark_viewed(course, lesson, progress::now()));
+        if !status.success() {
+            failed.push(lesson.id());
+        }
+        if lessons.len() > 1 {
+            println!();
+        }
+    }
+
+    if failed.is_empty() {
+        Ok(())
+    } else {
+        Err(Error::Failed(format!(
+            "lesson(s) failed: {}",
+            failed.join(", ")
+        )))
+    }
+}
+
+/// Runs the example module `name` of `lesson` on its own.
+fn run_example(course: &Course, lesson: &Lesson, name: &str) -> Result<()> {
+    let examples = LessonSource::read(&lesson.path)?.examples();
+    if !examples.iter().any(|example| example.name == name) {
+        let names: Vec<&str> = examples
+            .iter()
+            .map(|example| example.name.as_str())
+            .collect();
+        return Err(Error::Usage(if names.is_empty() {
+            format!("lesson {} has no examples", lesson.id())
+        } else {
+            format!(
+                "lesson {} has no example '{name}' (try {})",
+                lesson.id(),
+                names.join(", ")
+            )
+        }));
+    }
+    let status = runner::run_example(lesson, name)?;
+    record(|progress| progress.mark_viewed(course, lesson, progress::now()));
+    if status.is_some_and(|status| status.success()) {
+        Ok(())
+    } else {
+        Err(Error::Failed(format!(
+            "example {name} of lesson {} failed",
+            lesson.id()
+        )))
+    }
+}
+
+fn open_playground(course: &Course, mut args: Args) -> Result<()> {
+    let line = args.number("--line")?;
+    let reset = args.flag("--reset");
+    let watch = args.flag("--watch");
+    let mut limits = playground::LIMITS;
+    if let Some(secs) = args.number("--timeout")? {
+        limits.timeout = Duration::from_secs(secs.into());
+    }
+    if let Some(mb) = args.number("--memory")? {
+        limits.memory_mb = mb.into();
+    }
+    let (lesson, piece) = match (args.positional()?.as_slice(), line) {
+        ([query], None) => (course.find(query)?, Piece::Lesson),
+        ([query], Some(line)) => (course.find(query)?, Piece::Snippet(line as usize)),
+        ([query, example], None) => (course.find(query)?, Piece::Example(example.clone())),
+        _ => {
+            return Err(Error::Usage(
+                "playground takes a lesson and either an example or --line".to_string(),
+            ))
+        }
+    };
+    let text = fs::read_to_string(&lesson.path)?;
+    let Some(excerpt) = playground::excerpt(&text, &piece) else {
+        return Err(Error::Usage(match &piece {
+            Piece::Snippet(line) => format!(
+                "lesson {} has no snippet on line {line} (see rustprog snippets {})",
+                lesson.id(),
+                lesson.id()
+            ),
+            Piece::Example(name) => format!("lesson {} has no example '{name}'", lesson.id()),
+            Piece::Lesson => format!("lesson {} cannot be copied", lesson.id()),
+        }));
+    };
+    let playground = Playground::create(course, lesson, &excerpt, reset)?;
+    let main = playground.main_path();
+    println!("playground: {}", main.display());
+    record(|progress| progress.mark_viewed(course, lesson, progress::now()));
+
+    let show = |report: Report| println!("{report}\n");
+    if watch {
+        println!("running on every save; press Ctrl-C to stop\n");
+        let mut saved = playground::modified(&main)?;
+        show(playground.run(&limits)?);
+        loop {
+            std::thread::sleep(Duration::from_millis(300));
+            let now = playground::modified(&main)?;
+            if now != saved {
+                saved = now;
+                show(playground.run(&limits)?);
+            }
+        }
+    }
+
+    let editor = playground::editor();
+    let stdin = io::stdin();
+    loop {
+        let status = playground::edit(&editor, &main)
+            .map_err(|err| Error::Failed(format!("cannot start editor '{editor}': {err}")))?;
+        if !status.success() {
+            return Err(Error::Failed(format!("editor '{editor}' failed")));
+        }
+        show(playground.run(&limits)?);
+        print!("Enter to edit again, q to quit: ");
+        io::stdout().flush()?;
+        let mut reply = String::new();
+        if stdin.lock().read_line(&mut reply)? == 0 || reply.trim() == "q" {
+            return Ok(());
+        }
+    }
+}
+
+/// Lessons selected by a single `<lesson>` or `--chapter <n>` for `command`.
+fn selected_lessons<'a>(
+    course: &'a Course,
+    mut args: Args,
+    command: &str,
+) -> Result<Vec<&'a Lesson>> {
+    let chapter = args.number("--chapter")?;
+    match (args.positional()?.as_slice(), chapter) {
+        ([query], None) => Ok(vec![course.find(query)?]),
+        ([], chapter) => chapter_lessons(course, chapter),
+        _ => Err(Error::Usage(format!(
+            "{command} takes either one lesson or --chapter"
+        ))),
+    }
+}
+
+fn verify_lessons(course: &Course, args: Args) -> Result<()> {
+    let lessons = selected_lessons(course, args, "verify")?;
+
+    let mut failed = Vec::new();
+    for lesson in lessons {
+        let mut report = verify::verify_lesson(&lesson.path)?;
+        report.merge(verify::verify_snippets(&lesson.path)?);
+        let path = lesson.relative_path(course);
+        if report.passed() {
+            println!(
+                "ok   {} {} ({} checked, {} skipped)",
+                lesson.id(),
+                path.display(),
+                report.checked,
+                report.skipped
+            );
+            continue;
+        }
+        println!("FAIL {} {}", lesson.id(), path.display());
+        for mismatch in &report.mismatches {
+            print!("{}:{mismatch}", path.display());
+        }
+        failed.push(lesson.id());
+    }
+
+    if failed.is_empty() {
+        Ok(())
+    } else {
+        Err(Error::Failed(format!(
+            "documentation differs from the code in: {}",
+            failed.join(", ")
+        )))
+    }
+}
+
+fn lint_lessons(course: &Course, mut args: Args) -> Result<()> {
+    let selected = args.number("--chapter")?;
+    let positional = args.positional()?;
+    let mut findings = Vec::new();
+    match (positional.as_slice(), selected) {
+        ([query], None) => {
+            let lesson = course.find(query)?;
+            findings.extend(lint::lint_file(&course.root, &lesson.path)?);
+        }
+        ([], selected) => {
+            if selected.is_none() {
+                findings.extend(lint::lint_course(course));
+            }
+            for lesson in chapter_lessons(course, selected)? {
+                findings.extend(lint::lint_file(&course.root, &lesson.path)?);
+            }
+            for chapter in &course.chapters {
+                if selected.is_none_or(|number| number == chapter.number) {
+                    findings.extend(lint::lint_chapter(course, chapter));
+                }
+            }
+        }
+        _ => {
+            return Err(Error::Usage(
+                "lint takes either one lesson or --chapter".to_string(),
+            ))
+        }
+    }
+    findings.sort_by(|a, b| (&a.path, a.line).cmp(&(&b.path, b.line)));
+
+    for finding in &findings {
+        println!("{finding}");
+    }
+    let errors = findings
+        .iter()
+        .filter(|finding| finding.severity == Severity::Error)
+        .count();
+    let warnings = findings.len() - errors;
+    if errors > 0 {
+        Err(Error::Failed(format!(
+            "{errors} error(s), {warnings} warning(s)"
+        )))
+    } else {
+        println!("no errors, {warnings} warning(s)");
+        Ok(())
+    }
+}
+
+fn search_lessons(course: &Course, mut args: Args) -> Result<()> {
+    let limit = args.number("--limit")?.unwrap_or(10) as usize;
+    let rebuild = args.flag("--rebuild");
+    let query = args.positional()?.join(" ");
+    if query.trim().is_empty() {
+        return Err(Error::Usage("search expects a term".to_string()));
+    }
+
+    let index = if rebuild {
+        let index = Index::build(course)?;
+        index.save(&search::index_path(course))?;
+        index
+    } else {
+        Index::open(course)?.0
+    };
+    let hits = index.search(&query);
+    if hits.is_empty() {
+        return Err(Error::Failed(format!("nothing found for '{query}'")));
+    }
+    for hit in hits.iter().take(limit) {
+        let place: Vec<&str> = [&hit.entry.section, &hit.entry.function]
+            .into_iter()
+            .flatten()
+            .map(String::as_str)
+            .collect();
+        print!("{}:{}", hit.lesson.path, hit.entry.line);
+        if !place.is_empty() {
+            print!(" ({})", place.join(", in "));
+        }
+        println!("\n    {}", hit.entry.text);
+    }
+    if hits.len() > limit {
+        println!("... and {} more", hits.len() - limit);
+    }
+    Ok(())
+}
+
+fn list_snippets(course: &Course, args: Args) -> Result<()> {
+    for lesson in selected_lessons(course, args, "snippets")? {
+        let source = LessonSource::read(&lesson.path)?;
+        let snippets = source.snippets();
+        if snippets.is_empty() {
+            continue;
+        }
+        let diagnostics = source.diagnostics();
+        println!("{} {}", lesson.id(), lesson.relative_path(course).display());
+        for snippet in &snippets {
+            let mode = source.snippet_mode(snippet, &snippets, &diagnostics);
+            let first = snippet.code.lines().next().unwrap_or("");
+            println!(
+                "    {:<9} {:<18} {first}",
+                format!("{}-{}", snippet.start, snippet.end),
+                mode.to_string()
+            );
+        }
+    }
+    Ok(())
+}
+
+/// The translation picked by `--lang`, or the lessons as written.
+fn translation(course: &Course, args: &mut Args) -> Result<Translation> {
+    let Some(language) = args.value("--lang")? else {
+        return Ok(Translation::source());
+    };
+    load_translation(course, &language)
+}
+
+fn load_translation(course: &Course, language: &str) -> Result<Translation> {
+    if !i18n::is_language_code(language) {
+        return Err(Error::Usage(format!(
+            "'{language}' is not a language code such as fr"
+        )));
+    }
+    Translation::load(course, language).map_err(|err| match err.kind() {
+        io::ErrorKind::NotFound => Error::Usage(format!(
+            "there is no translation into '{language}' (rustprog i18n --lang {language} \
+             --update starts one)"
+        )),
+        _ => err.into(),
+    })
+}
+
+/// `target/<name>` under the course root, or `target/<name>-<lang>` for a
+/// translation.
+fn default_out(course: &Course, name: &str, translation: &Translation) -> PathBuf {
+    let target = course.root.join("target");
+    match translation.language() {
+        i18n::SOURCE_LANGUAGE => target.join(name),
+        language => target.join(format!("{name}-{language}")),
+    }
+}
+
+fn read_lesson(course: &Course, mut args: Args) -> Result<()> {
+    let translation = translation(course, &mut args)?;
+    let [query] = args
+        .positional()?
+        .try_into()
+        .map_err(|_| Error::Usage("read takes one lesson".to_string()))?;
+    let lesson = course.find(&query)?;
+    let document = translation.document(lesson, &Document::read(&lesson.path)?);
+    let title = document.title.clone().unwrap_or_else(|| lesson.name());
+    print!(
+        "{}",
+        text::render(&format!("{} {title}", lesson.id()), &document, &translation)
+    );
+    Ok(())
+}
+
+fn trace_lesson(course: &Course, mut args: Args) -> Result<()> {
+    let line = args.number("--line")?;
+    let animate = args.flag("--animate");
+    let delay = Duration::from_millis(args.number("--delay")?.unwrap_or(600).into());
+    let steps = args
+        .number("--steps")?
+        .map_or(trace::STEPS, |steps| steps as usize);
+    let [query] = args
+        .positional()?
+        .try_into()
+        .map_err(|_| Error::Usage("trace takes one lesson".to_string()))?;
+    let lesson = course.find(&query)?;
+    let text = fs::read_to_string(&lesson.path)?;
+    let trace = trace::trace(&text, &trace::LIMITS, steps)?
+        .map_err(|stderr| Error::Failed(format!("{} does not compile:\n{stderr}", lesson.id())))?;
+    if trace.statements.is_empty() {
+        return Err(Error::Failed(format!(
+            "{} has no loops or branches to trace",
+            lesson.id()
+        )));
+    }
+    let statements: Vec<usize> = match line {
+        Some(line) => vec![trace.statement_at(line as usize).ok_or_else(|| {
+            Error::Usage(format!(
+                "no loop or branch of {} on line {line}",
+                lesson.id()
+            ))
+        })?],
+        None => (0..trace.statements.len()).collect(),
+    };
+    if !trace.has_values {
+        eprintln!("note: the variables could not be printed with {{:?}}; tracing without them");
+    }
+
+    for statement in statements {
+        let (first, last) = trace.statements[statement].lines;
+        let heading = trace.statements[statement].heading.as_deref().unwrap_or("");
+        let title = format!("{} lines {first}-{last} {heading}", lesson.id());
+        if trace.steps_of(statement).next().is_none() {
+            println!("{}\n    not reached\n", title.trim_end());
+            continue;
+        }
+        if animate {
+            for frame in trace.frames(statement, &text) {
+                // Clear the screen and go back to its top.
+                print!("\x1b[2J\x1b[H{}\n\n{frame}", title.trim_end());
+                io::stdout().flush()?;
+                std::thread::sleep(delay);
+            }
+            println!();
+        } else {
+            println!("{}\n\n{}", title.trim_end(), trace.table(statement));
+        }
+    }
+    if trace.stopped {
+        println!("stopped after {} steps", trace.steps.len());
+    }
+    Ok(())
+}
+
+fn show_layouts(course: &Course, mut args: Args) -> Result<()> {
+    let lesson = args.value("--lesson")?;
+    let mut types = args.positional()?;
+    let mut definitions = layout::definitions(course)?;
+    if let Some(query) = lesson {
+        let lesson = course.find(&query)?;
+        let source = LessonSource::read(&lesson.path)?;
+        if types.is_empty() {
+            types = layout::lesson_types(lesson, &source);
+        }
+        // The lesson's own structs first, in case another one has a struct
+        // of the same name.
+        definitions.sort_by_key(|definition| definition.lesson != lesson.id());
+    }
+    if types.is_empty() {
+        return Err(Error::Usage(
+            "layout expects types, or --lesson <lesson>".to_string(),
+        ));
+    }
+    let layouts = layout::measure(&types, &definitions, &playground::LIMITS)?
+        .map_err(|stderr| Error::Failed(format!("cannot measure the types:\n{stderr}")))?;
+    for (index, layout) in layouts.iter().enumerate() {
+        if index > 0 {
+            println!();
+        }
+        print!("{layout}");
+    }
+    Ok(())
+}
+
+fn translate_lessons(course: &Course, mut args: Args) -> Result<()> {
+    let language = args
+        .value("--lang")?
+        .ok_or_else(|| Error::Usage("i18n expects --lang <code>".to_string()))?;
+    if language == i18n::SOURCE_LANGUAGE {
+        return Err(Error::Usage(format!(
+            "the lessons are written in {language}; pick the language to translate into"
+        )));
+    }
+    if !i18n::is_language_code(&language) {
+        return Err(Error::Usage(format!(
+            "'{language}' is not a language code such as fr"
+        )));
+    }
+    let update = args.flag("--update");
+    let lessons = selected_lessons(course, args, "i18n")?;
+    if update {
+        let updated = i18n::update(course, &language, &lessons)?;
+        let dir = i18n::catalog_dir(course, &language);
+        println!(
+            "updated {} catalogs in {}: {} new messages, {} obsolete",
+            updated.catalogs,
+            dir.strip_prefix(&course.root).unwrap_or(&dir).display(),
+            updated.added,
+            updated.obsolete
+        );
+    }
+    let translation = load_translation(course, &language)?;
+
+    let (mut translated, mut total) = (0, 0);
+    for lesson in &lessons {
+        let document = Document::read(&lesson.path)?;
+        let coverage = translation.coverage(lesson, &document);
+        let state = if coverage.is_complete() {
+            "done"
+        } else if coverage.translated == 0 {
+            "not started"
+        } else {
+            ""
+        };
+        println!(
+            "{:<5} {:<30} {coverage:>12}  {state}",
+            lesson.id(),
+            lesson.name()
+        );
+        if coverage.translated > 0 {
+            for (section, left) in &coverage.untranslated {
+                println!("      {section}: {left} untranslated");
+            }
+        }
+        translated += coverage.translated;
+        total += coverage.total;
+    }
+    println!(
+        "{language}: {translated} of {total} messages translated ({}%)",
+        translated * 100 / total.max(1)
+    );
+    Ok(())
+}
+
+fn build_site(course: &Course, mut args: Args) -> Result<()> {
+    let translation = translation(course, &mut args)?;
+    let out = args
+        .value("--out")?
+        .map_or_else(|| default_out(course, "site", &translation), PathBuf::from);
+    args.positional()?;
+    let pages = site::build_translated(course, &translation, &out)?;
+    println!("wrote {pages} pages to {}", out.display());
+    Ok(())
+}
+
+fn serve_site(course: &Course, mut args: Args) -> Result<()> {
+    let port = args.number("--port")?.unwrap_or(8000);
+    let translation = translation(course, &mut args)?;
+    let out = args
+        .value("--out")?
+        .map_or_else(|| default_out(course, "site", &translation), PathBuf::from);
+    let mut limits = playground::LIMITS;
+    if let Some(secs) = args.number("--timeout")? {
+        limits.timeout = Duration::from_secs(secs.into());
+    }
+    if let Some(mb) = args.number("--memory")? {
+        limits.memory_mb = mb.into();
+    }
+    args.positional()?;
+    let port = u16::try_from(port)
+        .map_err(|_| Error::Usage(format!("--port {port} is not a port number")))?;
+    let pages = site::build_translated(course, &translation, &out)?;
+    let server = Server::bind(("127.0.0.1", port), &out, limits)?;
+    println!("wrote {pages} pages to {}", out.display());
+    println!(
+        "serving http://{}/; press Ctrl-C to stop",
+        server.local_addr()?
+    );
+    server.serve()?;
+    Ok(())
+}
+
+fn export_book(course: &Course, mut args: Args) -> Result<()> {
+    let translation = translation(course, &mut args)?;
+    let out = args
+        .value("--out")?
+        .map_or_else(|| default_out(course, "book", &translation), PathBuf::from);
+    args.positional()?;
+    let pages = mdbook::export_translated(course, &translation, &out)?;
+    println!("wrote {pages} pages to {}", out.join("src").display());
+    Ok(())
+}
+
+fn export_notebooks(course: &Course, mut args: Args) -> Result<()> {
+    let out = args.value("--out")?.map_or_else(
+        || course.root.join("target").join("notebooks"),
+        PathBuf::from,
+    );
+    let lessons = selected_lessons(course, args, "notebook")?;
+    notebook::export(course, &lessons, &out)?;
+    if let [lesson] = lessons.as_slice() {
+        println!(
+            "wrote {}",
+            notebook::notebook_path(course, lesson, &out).display()
+        );
+    } else {
+        println!("wrote {} notebooks to {}", lessons.len(), out.display());
+    }
+    Ok(())
+}
+
+fn write_manifest(course: &Course, mut args: Args) -> Result<()> {
+    let out = args
+        .value("--out")?
+        .map_or_else(|| course.root.join("target"), PathBuf::from);
+    args.positional()?;
+    let lessons = manifest::write(course, &out)?;
+    println!(
+        "wrote {lessons} lessons to {} and its schema to {}",
+        out.join("course.json").display(),
+        out.join(manifest::SCHEMA_FILE).display()
+    );
+    Ok(())
+}
+
+fn grade_exercises(course: &Course, mut args: Args) -> Result<()> {
+    let timeout = args
+        .number("--timeout")?
+        .map_or(grade::TIMEOUT, |secs| Duration::from_secs(secs.into()));
+    let lessons = selected_lessons(course, args, "grade")?;
+
+    let (mut passed, mut total) = (0, 0);
+    for lesson in &lessons {
+        let Some(grade) = grade::grade_lesson(course, lesson, timeout)? else {
+            if lessons.len() == 1 {
+                return Err(Error::Failed(format!(
+                    "lesson {} has no exercises",
+                    lesson.id()
+                )));
+            }
+            continue;
+        };
+        let path = grade::exercise_path(course, lesson);
+        println!(
+            "{} {}",
+            lesson.id(),
+            path.strip_prefix(&course.root).unwrap_or(&path).display()
+        );
+        for exercise in &grade.exercises {
+            let label = match exercise.verdict {
+                Verdict::Passed { .. } => "pass",
+                Verdict::Failed(_) => "FAIL",
+                Verdict::TimedOut(_) => "TIME",
+                Verdict::Untested => "----",
+            };
+            println!("    {label} {} ({})", exercise.name, exercise.verdict);
+        }
+        if let Some(errors) = &grade.build_error {
+            for line in errors.lines() {
+                println!("    | {line}");
+            }
+        }
+        record(|progress| {
+            let now = progress::now();
+            for exercise in &grade.exercises {
+                if let Verdict::Passed { .. } = exercise.verdict {
+                    progress.mark_passed(course, lesson, &exercise.name, now);
+                }
+            }
+        });
+        passed += grade.passed();
+        total += grade.exercises.len();
+    }
+
+    if passed == total {
+        println!("all {total} exercises pass");
+        Ok(())
+    } else {
+        Err(Error::Failed(format!("{passed} of {total} exercises pass")))
+    }
+}
+
+fn take_quiz(course: &Course, mut args: Args) -> Result<()> {
+    if args.flag("--check") {
+        return check_quizzes(course, args);
+    }
+    let lesson = match args.positional()?.as_slice() {
+        [query] => course.find(query)?,
+        _ => return Err(Error::Usage("quiz takes one lesson".to_string())),
+    };
+    let path = quiz::quiz_path(course, lesson);
+    if !path.is_file() {
+        return Err(Error::Failed(format!("lesson {} has no quiz", lesson.id())));
+    }
+    let quiz = Quiz::read(&path)?;
+    println!("Quiz on {} {}\n", lesson.id(), lesson.name());
+    let score = quiz::take(
+        &quiz,
+        &mut io::stdin().lock(),
+        &mut io::stdout().lock(),
+        quiz::TIMEOUT,
+    )?;
+    if score.answered < score.total {
+        return Err(Error::Failed(format!(
+            "quiz stopped after {} of {} questions",
+            score.answered, score.total
+        )));
+    }
+    println!("You scored {} out of {}.", score.correct, score.total);
+    record(|progress| {
+        let score = QuizScore {
+            correct: score.correct as u64,
+            total: score.total as u64,
+            at: progress::now(),
+        };
+        progress.mark_quiz(course, lesson, score);
+    });
+    Ok(())
+}
+
+/// Works out the answer of every question, which compiles and runs their
+/// programs, without asking anything.
+fn check_quizzes(course: &Course, args: Args) -> Result<()> {
+    let lessons = selected_lessons(course, args, "quiz --check")?;
+    let mut failed = Vec::new();
+    for lesson in lessons {
+        let path = quiz::quiz_path(course, lesson);
+        if !path.is_file() {
+            continue;
+        }
+        let relative = path.strip_prefix(&course.root).unwrap_or(&path);
+        let problems: Vec<String> = match Quiz::read(&path) {
+            Err(err) => vec![err.to_string()],
+            Ok(quiz) => quiz
+                .questions
+                .iter()
+                .filter_map(|question| question.answer(quiz::TIMEOUT).err())
+                .map(|err| err.to_string())
+                .collect(),
+        };
+        if problems.is_empty() {
+            println!("ok   {} {}", lesson.id(), relative.display());
+            continue;
+        }
+        println!("FAIL {} {}", lesson.id(), relative.display());
+        for problem in problems {
+            for line in problem.lines() {
+                println!("    {line}");
+            }
+        }
+        failed.push(lesson.id());
+    }
+
+    if failed.is_empty() {
+        Ok(())
+    } else {
+        Err(Error::Failed(format!(
+            "broken questions in: {}",
+            failed.join(", ")
+        )))
+    }
+}
+
+/// Updates the progress file; failing to is worth a warning, not an error.
+fn record(update: impl FnOnce(&mut Progress)) {
+    let Some(path) = progress::default_path() else {
+        return;
+    };
+    let saved = Progress::load(&path).and_then(|mut progress| {
+        update(&mut progress);
+        progress.save(&path)
+    });
+    if let Err(err) = saved {
+        eprintln!("rustprog: could not save progress: {err}");
+    }
+}
+
+fn show_progress(course: &Course, mut args: Args) -> Result<()> {
+    let selected = args.number("--chapter")?;
+    args.positional()?;
+    chapter_lessons(course, selected)?;
+    let path = progress::default_path()
+        .ok_or_else(|| Error::Failed("no data directory to keep progress in".to_string()))?;
+    let progress = Progress::load(&path)?;
+
+    let width = course
+        .chapters
+        .iter()
+        .map(|chapter| chapter.name().len() + 4)
+        .max()
+        .unwrap_or(0);
+    let mut last: Option<(u64, &Lesson)> = None;
+    for chapter in &course.chapters {
+        let (mut viewed, mut passed, mut exercises) = (0, 0, 0);
+        let mut lines = Vec::new();
+        for lesson in &chapter.lessons {
+            let record = progress.lesson(lesson);
+            let names = exercise_names(course, lesson)?;
+            let lesson_passed = names
+                .iter()
+                .filter(|name| record.is_some_and(|record| record.passed.contains_key(*name)))
+                .count();
+            let seen = record.and_then(|record| record.viewed);
+            viewed += usize::from(seen.is_some());
+            passed += lesson_passed;
+            exercises += names.len();
+            if let Some(record) = record {
+                let latest = record
+                    .passed
+                    .values()
+                    .copied()
+                    .chain(seen)
+                    .chain(record.quiz.map(|quiz| quiz.at))
+                    .max();
+                if let Some(at) = latest.filter(|at| last.is_none_or(|(last, _)| *at > last)) {
+                    last = Some((at, lesson));
+                }
+            }
+
+            let done = seen.is_some() && lesson_passed == names.len();
+            let mut line = format!(
+                "   {} {:<5} {:<30} {:<17}",
+                if done { "✓" } else { " " },
+                lesson.id(),
+                lesson.name(),
+                seen.map_or_else(
+                    || "not viewed".to_string(),
+                    |at| format!("viewed {}", progress::date(at))
+                )
+            );
+            let exercises_done = if names.is_empty() {
+                String::new()
+            } else {
+                format!("{lesson_passed}/{} exercises", names.len())
+            };
+            line += &format!("  {exercises_done:<13}");
+            if let Some(quiz) = record.and_then(|record| record.quiz) {
+                line += &format!("  quiz {}/{}", quiz.correct, quiz.total);
+            }
+            lines.push(line);
+        }
+
+        if selected.is_some_and(|number| number != chapter.number) {
+            continue;
+        }
+        let total = chapter.lessons.len() + exercises;
+        println!(
+            "{:<width$} {} {:>3}%  {viewed}/{} lessons viewed, {passed}/{exercises} exercises passed",
+            format!("{}. {}", chapter.number, chapter.name()),
+            bar(viewed + passed, total),
+            (viewed + passed) * 100 / total.max(1),
+            chapter.lessons.len(),
+        );
+        if selected.is_some() {
+            for line in lines {
+                println!("{}", line.trim_end());
+            }
+        }
+    }
+
+    println!();
+    if let Some((at, lesson)) = last {
+        println!(
+            "last activity: {} ({} {})",
+            progress::date(at),
+            lesson.id(),
+            lesson.name()
+        );
+    }
+    println!("progress file: {}", path.display());
+    Ok(())
+}
+
+/// The exercises of `lesson`, if it has any.
+fn exercise_names(course: &Course, lesson: &Lesson) -> Result<Vec<String>> {
+    let path = grade::exercise_path(course, lesson);
+    if !path.is_file() {
+        return Ok(Vec::new());
+    }
+    Ok(grade::exercise_names(&std::fs::read_to_string(path)?))
+}
+
+/// A 20 character completion bar for `done` out of `total`.
+fn bar(done: usize, total: usize) -> String {
+    const WIDTH: usize = 20;
+    let filled = (done * WIDTH + total / 2) / total.max(1);
+    format!("[{}{}]", "█".repeat(filled), "░".repeat(WIDTH - filled))
+}
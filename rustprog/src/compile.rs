@@ -0,0 +1,319 @@
+//! Compiling and running single-file programs with `rustc`.
+//!
+//! Lessons and the examples quoted in them only use the standard library,
+//! so they can be built straight with `rustc` into a scratch directory
+//! without going through Cargo.
+
+use std::env;
+use std::ffi::OsString;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::process::{Child, Command, ExitStatus, Output, Stdio};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// The `rustc` executable, honouring the `RUSTC` variable Cargo sets.
+pub fn rustc() -> OsString {
+    env::var_os("RUSTC").unwrap_or_else(|| "rustc".into())
+}
+
+/// A temporary directory removed again on drop.
+#[derive(Debug)]
+pub struct Scratch {
+    path: PathBuf,
+}
+
+impl Scratch {
+    pub fn new(label: &str) -> io::Result<Scratch> {
+        static COUNTER: AtomicUsize = AtomicUsize::new(0);
+        let path = env::temp_dir().join(format!(
+            "rustprog-{label}-{}-{}",
+            std::process::id(),
+            COUNTER.fetch_add(1, Ordering::Relaxed)
+        ));
+        fs::create_dir_all(&path)?;
+        Ok(Scratch { path })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl Drop for Scratch {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.path);
+    }
+}
+
+/// Result of compiling a program.
+#[derive(Debug)]
+pub struct Build {
+    /// The executable, when compilation succeeded.
+    pub binary: Option<PathBuf>,
+    /// Compiler diagnostics.
+    pub stderr: String,
+}
+
+impl Build {
+    pub fn succeeded(&self) -> bool {
+        self.binary.is_some()
+    }
+
+    /// Error codes (`E0384`, ...) reported by the compiler, in order.
+    pub fn error_codes(&self) -> Vec<String> {
+        self.stderr
+            .lines()
+            .filter_map(|line| line.strip_prefix("error["))
+            .filter_map(|rest| rest.split_once(']'))
+            .map(|(code, _)| code.to_string())
+            .collect()
+    }
+}
+
+/// Compiles the file at `source` into `scratch`.
+pub fn compile_file(source: &Path, scratch: &Scratch) -> io::Result<Build> {
+    compile(source, scratch.path(), &["--crate-type", "bin"])
+}
+
+/// Compiles the file at `source` into the directory `out`, which is kept.
+pub fn compile_into(source: &Path, out: &Path) -> io::Result<Build> {
+    fs::create_dir_all(out)?;
+    compile(source, out, &["--crate-type", "bin"])
+}
+
+/// Compiles the file at `source` into `scratch` as a test harness.
+pub fn compile_tests(source: &Path, scratch: &Scratch) -> io::Result<Build> {
+    compile(source, scratch.path(), &["--test"])
+}
+
+fn compile(source: &Path, out: &Path, kind: &[&str]) -> io::Result<Build> {
+    let binary = out.join(format!("program{}", env::consts::EXE_SUFFIX));
+    let output = Command::new(rustc())
+        .args(["--edition", "2021", "-A", "warnings"])
+        .args(kind)
+        .arg("-o")
+        .arg(&binary)
+        .arg(source)
+        .output()?;
+    Ok(Build {
+        binary: output.status.success().then_some(binary),
+        stderr: String::from_utf8_lossy(&output.stderr).into_owned(),
+    })
+}
+
+/// Writes `code` to `main.rs` in `scratch` and compiles it.
+pub fn compile_source(code: &str, scratch: &Scratch) -> io::Result<Build> {
+    let source = scratch.path().join("main.rs");
+    fs::write(&source, code)?;
+    compile_file(&source, scratch)
+}
+
+/// Runs a compiled program with `args`, capturing its output.
+pub fn run(binary: &Path, args: &[&str]) -> io::Result<Output> {
+    Command::new(binary).args(args).output()
+}
+
+/// Like [`run`], but kills the program once `timeout` has passed; `None`
+/// when it had to be killed.
+pub fn run_with_timeout(
+    binary: &Path,
+    args: &[&str],
+    timeout: Duration,
+) -> io::Result<Option<Output>> {
+    let mut child = Command::new(binary)
+        .args(args)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()?;
+    // Drain the pipes while waiting, or a chatty program would block on them.
+    let stdout = drain(child.stdout.take());
+    let stderr = drain(child.stderr.take());
+    let status = wait_until(&mut child, Instant::now() + timeout)?;
+    let stdout = stdout.join().unwrap_or_default();
+    let stderr = stderr.join().unwrap_or_default();
+    Ok(status.map(|status| Output {
+        status,
+        stdout,
+        stderr,
+    }))
+}
+
+/// What a program may use when run with [`run_limited`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Limits {
+    pub timeout: Duration,
+    /// Address space in megabytes, enforced with `ulimit -v` on Unix only.
+    pub memory_mb: u64,
+    /// Processes and threads the user may have at once while the program
+    /// runs, enforced with `ulimit -u` (`-p` in dash) on Unix only.
+    pub processes: u64,
+    /// Bytes of output kept per stream; the rest is read and dropped.
+    pub output_bytes: usize,
+}
+
+/// A program run under [`Limits`].
+#[derive(Debug)]
+pub struct Limited {
+    /// `None` when the program was killed for running out of time.
+    pub status: Option<ExitStatus>,
+    pub stdout: Vec<u8>,
+    pub stderr: Vec<u8>,
+    /// Whether output was dropped past [`Limits::output_bytes`].
+    pub truncated: bool,
+    pub elapsed: Duration,
+}
+
+/// Which output a piece of it came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stream {
+    Stdout,
+    Stderr,
+}
+
+/// How a program run with [`run_streaming`] ended.
+#[derive(Debug, Clone, Copy)]
+pub struct Finished {
+    /// `None` when the program was killed for running out of time.
+    pub status: Option<ExitStatus>,
+    /// Whether output was dropped past [`Limits::output_bytes`].
+    pub truncated: bool,
+    pub elapsed: Duration,
+}
+
+/// Runs a compiled program without input, within `limits`.
+pub fn run_limited(binary: &Path, limits: &Limits) -> io::Result<Limited> {
+    let (mut stdout, mut stderr) = (Vec::new(), Vec::new());
+    let finished = run_streaming(binary, limits, |stream, bytes| match stream {
+        Stream::Stdout => stdout.extend_from_slice(bytes),
+        Stream::Stderr => stderr.extend_from_slice(bytes),
+    })?;
+    Ok(Limited {
+        status: finished.status,
+        stdout,
+        stderr,
+        truncated: finished.truncated,
+        elapsed: finished.elapsed,
+    })
+}
+
+/// Like [`run_limited`], but hands the output to `sink` as it is printed.
+pub fn run_streaming(
+    binary: &Path,
+    limits: &Limits,
+    mut sink: impl FnMut(Stream, &[u8]),
+) -> io::Result<Finished> {
+    // A shell that cannot set a limit says so and does not run the program.
+    let mut command = if cfg!(unix) {
+        let mut command = Command::new("sh");
+        command
+            .arg("-c")
+            .arg(
+                r#"ulimit -v "$1" || exit
+{ ulimit -u "$2" 2>/dev/null || ulimit -p "$2"; } || exit
+shift 2
+exec "$@""#,
+            )
+            .arg("sh")
+            .arg((limits.memory_mb * 1024).to_string())
+            .arg(limits.processes.to_string())
+            .arg(binary);
+        command
+    } else {
+        Command::new(binary)
+    };
+    let start = Instant::now();
+    let deadline = start + limits.timeout;
+    let mut child = command
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()?;
+
+    let (sender, output) = mpsc::sync_channel(16);
+    forward(child.stdout.take(), Stream::Stdout, sender.clone());
+    forward(child.stderr.take(), Stream::Stderr, sender);
+    let mut kept = [0, 0];
+    let mut truncated = false;
+    let mut status = None;
+    loop {
+        let wait = deadline.saturating_duration_since(Instant::now());
+        match output.recv_timeout(wait.min(Duration::from_millis(10))) {
+            Ok((stream, bytes)) => {
+                let kept = &mut kept[stream as usize];
+                let keep = bytes.len().min(limits.output_bytes - *kept);
+                *kept += keep;
+                truncated |= keep < bytes.len();
+                if keep > 0 {
+                    sink(stream, &bytes[..keep]);
+                }
+            }
+            Err(RecvTimeoutError::Timeout) => {
+                if Instant::now() >= deadline {
+                    child.kill()?;
+                    child.wait()?;
+                    break;
+                }
+            }
+            // Both pipes are closed: the program is done, or about to be.
+            Err(RecvTimeoutError::Disconnected) => {
+                status = wait_until(&mut child, deadline)?;
+                break;
+            }
+        }
+    }
+    Ok(Finished {
+        status,
+        truncated,
+        elapsed: start.elapsed(),
+    })
+}
+
+/// Sends what is read from `pipe` to `sender`, piece by piece.
+fn forward(
+    pipe: Option<impl Read + Send + 'static>,
+    stream: Stream,
+    sender: mpsc::SyncSender<(Stream, Vec<u8>)>,
+) {
+    thread::spawn(move || {
+        let Some(mut pipe) = pipe else {
+            return;
+        };
+        let mut buffer = [0; 8192];
+        while let Ok(read @ 1..) = pipe.read(&mut buffer) {
+            if sender.send((stream, buffer[..read].to_vec())).is_err() {
+                break;
+            }
+        }
+    });
+}
+
+/// Waits for `child` to exit, killing it at `deadline`; `None` if it had
+/// to be killed.
+fn wait_until(child: &mut Child, deadline: Instant) -> io::Result<Option<ExitStatus>> {
+    loop {
+        if let Some(status) = child.try_wait()? {
+            return Ok(Some(status));
+        }
+        if Instant::now() >= deadline {
+            child.kill()?;
+            child.wait()?;
+            return Ok(None);
+        }
+        thread::sleep(Duration::from_millis(10));
+    }
+}
+
+fn drain(pipe: Option<impl Read + Send + 'static>) -> thread::JoinHandle<Vec<u8>> {
+    thread::spawn(move || {
+        let mut bytes = Vec::new();
+        if let Some(mut pipe) = pipe {
+            let _ = pipe.read_to_end(&mut bytes);
+        }
+        bytes
+    })
+}
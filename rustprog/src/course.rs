@@ -0,0 +1,248 @@
+//! Discovery of the numbered chapter directories and lesson files.
+//!
+//! The course is laid out as `_0N_<Chapter>/_0M_<Lesson>.rs`. Every chapter
+//! directory is a Cargo package and every lesson one of its `[[bin]]`
+//! targets, named after the file with the number and `Rust_` prefix dropped.
+
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Title of the course in the exported site and book.
+pub const COURSE_TITLE: &str = "Rust Programiz lessons";
+
+/// All chapters found under the course root, in numbering order.
+#[derive(Debug, Clone)]
+pub struct Course {
+    pub root: PathBuf,
+    pub chapters: Vec<Chapter>,
+}
+
+/// A `_0N_<Name>` directory together with its lessons.
+#[derive(Debug, Clone)]
+pub struct Chapter {
+    pub number: u32,
+    pub dir_name: String,
+    /// Cargo package name read from the chapter's `Cargo.toml`.
+    pub package: String,
+    pub lessons: Vec<Lesson>,
+}
+
+/// A single `_0M_<Name>.rs` lesson file.
+#[derive(Debug, Clone)]
+pub struct Lesson {
+    pub chapter: u32,
+    pub number: u32,
+    pub path: PathBuf,
+    pub stem: String,
+}
+
+/// Why a lesson query did not resolve to exactly one lesson.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FindError {
+    NotFound(String),
+    Ambiguous(String, Vec<String>),
+}
+
+impl fmt::Display for FindError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FindError::NotFound(query) => write!(f, "no lesson matches '{query}'"),
+            FindError::Ambiguous(query, candidates) => write!(
+                f,
+                "'{query}' matches several lessons: {}",
+                candidates.join(", ")
+            ),
+        }
+    }
+}
+
+impl std::error::Error for FindError {}
+
+/// Splits a `_07_Rust_type_casting` style name into `(7, "Rust_type_casting")`.
+pub fn split_number(name: &str) -> Option<(u32, &str)> {
+    let rest = name.strip_prefix('_')?;
+    let (digits, rest) = rest.split_once('_')?;
+    if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    Some((digits.parse().ok()?, rest))
+}
+
+/// Turns `Rust_type_casting` into `Rust type casting`.
+fn humanize(name: &str) -> String {
+    name.replace('_', " ")
+}
+
+impl Course {
+    /// Scans `root` for chapter directories and their lessons.
+    pub fn discover(root: impl AsRef<Path>) -> io::Result<Course> {
+        let root = root.as_ref().to_path_buf();
+        let mut chapters = Vec::new();
+
+        for entry in fs::read_dir(&root)? {
+            let entry = entry?;
+            let dir_name = entry.file_name().to_string_lossy().into_owned();
+            let Some((number, _)) = split_number(&dir_name) else {
+                continue;
+            };
+            let manifest = entry.path().join("Cargo.toml");
+            if !entry.file_type()?.is_dir() || !manifest.is_file() {
+                continue;
+            }
+
+            let package = package_name(&fs::read_to_string(&manifest)?)
+                .unwrap_or_else(|| dir_name.to_lowercase());
+            let lessons = discover_lessons(&entry.path(), number)?;
+            chapters.push(Chapter {
+                number,
+                dir_name,
+                package,
+                lessons,
+            });
+        }
+
+        chapters.sort_by_key(|chapter| chapter.number);
+        Ok(Course { root, chapters })
+    }
+
+    /// Every lesson of the course, chapter by chapter.
+    pub fn lessons(&self) -> impl Iterator<Item = &Lesson> {
+        self.chapters.iter().flat_map(|chapter| &chapter.lessons)
+    }
+
+    pub fn chapter(&self, number: u32) -> Option<&Chapter> {
+        self.chapters
+            .iter()
+            .find(|chapter| chapter.number == number)
+    }
+
+    /// Chapter that owns `lesson`.
+    pub fn chapter_of(&self, lesson: &Lesson) -> &Chapter {
+        self.chapter(lesson.chapter)
+            .expect("lessons are only created from a discovered chapter")
+    }
+
+    /// Resolves a lesson by its `chapter.lesson` id (`3.2`) or by name.
+    ///
+    /// Names are matched case-insensitively against the binary name and the
+    /// file stem: an exact binary name wins, then a substring match, then a
+    /// subsequence match (`brkcont` finds `break_and_continue`).
+    pub fn find(&self, query: &str) -> Result<&Lesson, FindError> {
+        if let Some(lesson) = self.lessons().find(|lesson| lesson.id() == query) {
+            return Ok(lesson);
+        }
+
+        let needle = query.to_lowercase();
+        let matchers: [&dyn Fn(&Lesson) -> bool; 3] = [
+            &|lesson| lesson.bin_name() == needle,
+            &|lesson| lesson.stem.to_lowercase().contains(&needle),
+            &|lesson| is_subsequence(&needle, &lesson.bin_name()),
+        ];
+        for matches in matchers {
+            let found: Vec<&Lesson> = self.lessons().filter(|lesson| matches(lesson)).collect();
+            match found.as_slice() {
+                [] => continue,
+                [lesson] => return Ok(lesson),
+                _ => {
+                    return Err(FindError::Ambiguous(
+                        query.to_string(),
+                        found
+                            .iter()
+                            .map(|lesson| format!("{} {}", lesson.id(), lesson.bin_name()))
+                            .collect(),
+                    ))
+                }
+            }
+        }
+        Err(FindError::NotFound(query.to_string()))
+    }
+}
+
+impl Chapter {
+    /// `Rust Intoduction` for `_01_Rust_Intoduction`.
+    pub fn name(&self) -> String {
+        split_number(&self.dir_name)
+            .map_or_else(|| self.dir_name.clone(), |(_, rest)| humanize(rest))
+    }
+
+    pub fn dir(&self, course: &Course) -> PathBuf {
+        course.root.join(&self.dir_name)
+    }
+}
+
+impl Lesson {
+    /// Dotted `chapter.lesson` number, e.g. `3.2`.
+    pub fn id(&self) -> String {
+        format!("{}.{}", self.chapter, self.number)
+    }
+
+    /// Name of the lesson's `[[bin]]` target, e.g. `type_casting`.
+    pub fn bin_name(&self) -> String {
+        let name = split_number(&self.stem).map_or(self.stem.as_str(), |(_, rest)| rest);
+        name.strip_prefix("Rust_").unwrap_or(name).to_lowercase()
+    }
+
+    /// `Rust type casting` for `_07_Rust_type_casting.rs`.
+    pub fn name(&self) -> String {
+        split_number(&self.stem).map_or_else(|| self.stem.clone(), |(_, rest)| humanize(rest))
+    }
+
+    /// Path relative to the course root, e.g. `_03_Rust_Data_Types/_02_Rust_slice.rs`.
+    pub fn relative_path(&self, course: &Course) -> PathBuf {
+        self.path
+            .strip_prefix(&course.root)
+            .unwrap_or(&self.path)
+            .to_path_buf()
+    }
+}
+
+fn discover_lessons(dir: &Path, chapter: u32) -> io::Result<Vec<Lesson>> {
+    let mut lessons = Vec::new();
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.extension().and_then(|ext| ext.to_str()) != Some("rs") {
+            continue;
+        }
+        let Some(stem) = path.file_stem().and_then(|stem| stem.to_str()) else {
+            continue;
+        };
+        let Some((number, _)) = split_number(stem) else {
+            continue;
+        };
+        lessons.push(Lesson {
+            chapter,
+            number,
+            stem: stem.to_string(),
+            path,
+        });
+    }
+    lessons.sort_by_key(|lesson| lesson.number);
+    Ok(lessons)
+}
+
+/// Reads `name = "..."` from the `[package]` table of a manifest.
+fn package_name(manifest: &str) -> Option<String> {
+    let mut in_package = false;
+    for line in manifest.lines().map(str::trim) {
+        if line.starts_with('[') {
+            in_package = line == "[package]";
+            continue;
+        }
+        if !in_package {
+            continue;
+        }
+        if let Some((key, value)) = line.split_once('=') {
+            if key.trim() == "name" {
+                return Some(value.trim().trim_matches('"').to_string());
+            }
+        }
+    }
+    None
+}
+
+fn is_subsequence(needle: &str, haystack: &str) -> bool {
+    let mut haystack = haystack.chars();
+    needle.chars().all(|c| haystack.any(|h| h == c))
+}
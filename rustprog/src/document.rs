@@ -0,0 +1,529 @@
+//! A lesson read as a document, for the exporters.
+//!
+//! The prose is split into headings, paragraphs, lists, tables and text to
+//! show as is (pasted compiler errors, syntax summaries); the code of the
+//! lesson and the snippets quoted in the prose or commented out become code
+//! blocks. Example modules are kept whole, block comments inside them
+//! included. When prose splits the lesson's `fn main`, its opening and
+//! closing lines are left out, so the blocks hold the pieces of its body.
+
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use crate::lesson::{brace_delta, indent_of, is_rule, LessonSource, LineKind, Mode, Origin};
+
+/// A lesson as a sequence of blocks.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Document {
+    /// The first `=` heading, which is not repeated in `blocks`.
+    pub title: Option<String>,
+    pub blocks: Vec<Block>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Block {
+    /// A heading below the title: level 1 for `=`, 2 for `-`.
+    Heading {
+        level: u8,
+        text: String,
+    },
+    Paragraph(String),
+    List(Vec<String>),
+    /// An ASCII table drawn with `-----` rules.
+    Table {
+        header: Vec<String>,
+        rows: Vec<Vec<String>>,
+    },
+    Code(CodeBlock),
+    /// An `Output:` block.
+    Output(Vec<String>),
+    /// Text shown as written: compiler errors, indented summaries.
+    Preformatted(String),
+    Rule,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CodeBlock {
+    pub code: String,
+    /// `rust`, or the language of a fenced block in the prose.
+    pub language: String,
+    /// Part of the lesson program rather than quoted in the prose.
+    pub live: bool,
+    /// How a Rust snippet quoted in the prose is checked; `None` for the
+    /// lesson code and other languages.
+    pub mode: Option<Mode>,
+}
+
+impl Document {
+    pub fn read(path: impl AsRef<Path>) -> io::Result<Document> {
+        Ok(Document::parse(&fs::read_to_string(path)?))
+    }
+
+    pub fn parse(text: &str) -> Document {
+        Builder::new(text).build()
+    }
+
+    /// The headings of the document, for a table of contents.
+    pub fn headings(&self) -> impl Iterator<Item = (u8, &str)> {
+        self.blocks.iter().filter_map(|block| match block {
+            Block::Heading { level, text } => Some((*level, text.as_str())),
+            _ => None,
+        })
+    }
+}
+
+/// Walks the lines of a lesson, turning them into blocks.
+struct Builder<'a> {
+    raw: Vec<&'a str>,
+    source: LessonSource,
+    /// Per 0-based line: heading level, for the text line of a heading.
+    headings: Vec<Option<u8>>,
+    /// Per 0-based line: the output and index of the last line of an
+    /// `Output:` block starting there.
+    outputs: Vec<Option<(Vec<String>, usize)>>,
+    /// Per 0-based line: an unfenced prose snippet starting there, with the
+    /// index of its last line.
+    snippets: Vec<Option<(String, usize)>>,
+    /// Per 0-based line: how the prose snippet starting there is checked.
+    modes: Vec<Option<Mode>>,
+    /// Per 0-based line: code commented out with `//` starting there, with
+    /// the index of its last line and how it is checked.
+    commented: Vec<Option<(String, usize, Mode)>>,
+    /// Per 0-based line: inside one of the example modules.
+    in_example: Vec<bool>,
+    /// Per 0-based line: the first or last line of a `fn main` split by
+    /// prose.
+    wrapper: Vec<bool>,
+    title: Option<String>,
+    blocks: Vec<Block>,
+}
+
+impl<'a> Builder<'a> {
+    fn new(text: &'a str) -> Builder<'a> {
+        let source = LessonSource::parse(text);
+        let len = source.lines.len();
+
+        let mut headings = vec![None; len];
+        for heading in source.headings() {
+            headings[heading.line - 1] = Some(heading.level);
+        }
+        let mut outputs = vec![None; len];
+        for block in source.output_blocks() {
+            if source.line(block.line).kind == LineKind::Prose {
+                outputs[block.line - 1] = Some((block.expected, block.end - 1));
+            }
+        }
+        let mut in_example = vec![false; len];
+        for example in source.examples() {
+            in_example[example.start - 1..example.end].fill(true);
+        }
+        let mut snippets = vec![None; len];
+        let mut modes = vec![None; len];
+        let mut commented = vec![None; len];
+        let all = source.snippets();
+        let diagnostics = source.diagnostics();
+        for snippet in &all {
+            let start = snippet.start - 1;
+            let mode = source.snippet_mode(snippet, &all, &diagnostics);
+            let code = snippet.code.trim_end().to_string();
+            match snippet.origin {
+                // Example modules are kept whole.
+                Origin::Commented if !in_example[start] => {
+                    commented[start] = Some((code, snippet.end - 1, mode));
+                }
+                Origin::Commented => {}
+                Origin::Prose => {
+                    if snippet.mode.is_none() {
+                        snippets[start] = Some((code, snippet.end - 1));
+                    }
+                    modes[start] = Some(mode);
+                }
+            }
+        }
+
+        let wrapper = wrapper_lines(&source);
+
+        Builder {
+            raw: text.lines().collect(),
+            source,
+            headings,
+            outputs,
+            snippets,
+            modes,
+            commented,
+            in_example,
+            wrapper,
+            title: None,
+            blocks: Vec::new(),
+        }
+    }
+
+    fn build(mut self) -> Document {
+        let mut index = 0;
+        while index < self.raw.len() {
+            index = if self.is_code(index) {
+                self.code(index)
+            } else {
+                self.prose(index)
+            };
+        }
+        Document {
+            title: self.title,
+            blocks: self.blocks,
+        }
+    }
+
+    fn is_code(&self, index: usize) -> bool {
+        self.source.lines[index].kind == LineKind::Code || self.in_example[index]
+    }
+
+    fn text(&self, index: usize) -> &str {
+        &self.source.lines[index].text
+    }
+
+    fn is_prose(&self, index: usize) -> bool {
+        index < self.raw.len() && !self.is_code(index)
+    }
+
+    /// A run of lesson code, as written in the file, with the code
+    /// commented out in it shown as snippets.
+    fn code(&mut self, start: usize) -> usize {
+        let mut run = start;
+        let mut index = start;
+        while index < self.raw.len() && self.is_code(index) {
+            if self.wrapper[index] {
+                self.live_code(run, index);
+                index += 1;
+                run = index;
+                continue;
+            }
+            match self.commented[index].take() {
+                Some((code, end, mode)) => {
+                    self.live_code(run, index);
+                    self.blocks.push(Block::Code(CodeBlock {
+                        code,
+                        language: "rust".to_string(),
+                        live: false,
+                        mode: Some(mode),
+                    }));
+                    index = end + 1;
+                    run = index;
+                }
+                None => index += 1,
+            }
+        }
+        self.live_code(run, index);
+        index
+    }
+
+    fn live_code(&mut self, start: usize, end: usize) {
+        let lines: Vec<&str> = self.raw[start..end]
+            .iter()
+            .map(|line| line.trim_end())
+            .collect();
+        if let Some(code) = dedent(&lines) {
+            self.blocks.push(Block::Code(CodeBlock {
+                code,
+                language: "rust".to_string(),
+                live: true,
+                mode: None,
+            }));
+        }
+    }
+
+    /// One prose block starting at `index`; returns the index after it.
+    fn prose(&mut self, index: usize) -> usize {
+        let text = self.text(index).trim().to_string();
+        if text.is_empty() {
+            return index + 1;
+        }
+        if let Some(level) = self.headings[index] {
+            if level == 1 && self.title.is_none() && self.blocks.is_empty() {
+                self.title = Some(text);
+            } else {
+                self.blocks.push(Block::Heading { level, text });
+            }
+            return index + 2;
+        }
+        if let Some((expected, end)) = self.outputs[index].take() {
+            self.blocks.push(Block::Output(expected));
+            return end + 1;
+        }
+        if let Some((code, end)) = self.snippets[index].take() {
+            self.blocks.push(Block::Code(CodeBlock {
+                code,
+                language: "rust".to_string(),
+                live: false,
+                mode: self.modes[index].take(),
+            }));
+            return end + 1;
+        }
+        if let Some(info) = text.strip_prefix("```") {
+            return self.fence(index, info);
+        }
+        if is_rule(&text, '-') {
+            return self.rule(index);
+        }
+        self.text_block(index)
+    }
+
+    /// A fenced code block in the prose.
+    fn fence(&mut self, open: usize, info: &str) -> usize {
+        let language = match info.split(',').next().map(str::trim) {
+            None | Some("") => "rust",
+            Some(first) if is_rust_attribute(first) => "rust",
+            Some(first) => first,
+        }
+        .to_string();
+        let mut close = open + 1;
+        while self.is_prose(close) && self.text(close).trim() != "```" {
+            close += 1;
+        }
+        let lines: Vec<&str> = (open + 1..close).map(|index| self.text(index)).collect();
+        if let Some(code) = dedent(&lines) {
+            // A fenced snippet starts on the line after the fence.
+            let mode = self.modes.get_mut(open + 1).and_then(Option::take);
+            self.blocks.push(Block::Code(CodeBlock {
+                code,
+                language,
+                live: false,
+                mode,
+            }));
+        }
+        close + 1
+    }
+
+    /// A `-----` rule: the top of a table, the start of a pasted compiler
+    /// error running to the next rule, or a plain separator.
+    fn rule(&mut self, index: usize) -> usize {
+        if let Some((table, end)) = self.table(index) {
+            self.blocks.push(table);
+            return end;
+        }
+
+        let first = (index + 1..self.raw.len())
+            .take_while(|&next| self.is_prose(next))
+            .find(|&next| !self.text(next).trim().is_empty());
+        let is_error = first.is_some_and(|first| self.text(first).trim().starts_with("error"));
+        if !is_error {
+            self.blocks.push(Block::Rule);
+            return index + 1;
+        }
+
+        let mut end = index + 1;
+        while self.is_prose(end) && !is_rule(self.text(end).trim(), '-') {
+            end += 1;
+        }
+        let lines: Vec<&str> = (index + 1..end).map(|line| self.text(line)).collect();
+        if let Some(text) = dedent(&lines) {
+            self.blocks.push(Block::Preformatted(text));
+        }
+        end + 1
+    }
+
+    /// A table drawn as a rule, a header row, a rule, then rows optionally
+    /// separated by rules, up to a blank line.
+    fn table(&self, index: usize) -> Option<(Block, usize)> {
+        let header = cells(self.text(index + 1));
+        if !self.is_prose(index + 2)
+            || header.len() < 2
+            || !is_rule(self.text(index + 2).trim(), '-')
+        {
+            return None;
+        }
+        let mut rows = Vec::new();
+        let mut end = index + 3;
+        while self.is_prose(end) && !self.text(end).trim().is_empty() {
+            let text = self.text(end).trim();
+            if !is_rule(text, '-') {
+                let mut row = cells(text);
+                if row.len() > header.len() {
+                    let rest = row.split_off(header.len() - 1).join("   ");
+                    row.push(rest);
+                }
+                row.resize(header.len(), String::new());
+                rows.push(row);
+            }
+            end += 1;
+        }
+        Some((Block::Table { header, rows }, end))
+    }
+
+    /// Paragraphs, lists and indented text up to the next blank line or
+    /// other kind of block.
+    fn text_block(&mut self, start: usize) -> usize {
+        let mut end = start + 1;
+        while self.is_prose(end)
+            && !self.text(end).trim().is_empty()
+            && self.headings[end].is_none()
+            && self.outputs[end].is_none()
+            && self.snippets[end].is_none()
+            && !self.text(end).trim().starts_with("```")
+            && !is_rule(self.text(end).trim(), '-')
+        {
+            end += 1;
+        }
+
+        let lines: Vec<&str> = (start..end).map(|index| self.text(index)).collect();
+        let blocks = text_blocks(&lines, self.prose_indent(start));
+        self.blocks.extend(blocks);
+        end
+    }
+
+    /// Indentation of the comment around `index`, so that text indented
+    /// past it is kept as is even when a blank line sets it apart.
+    fn prose_indent(&self, index: usize) -> usize {
+        let first = (0..index)
+            .rev()
+            .find(|&line| !self.is_prose(line))
+            .map_or(0, |line| line + 1);
+        let end = (index..self.raw.len())
+            .find(|&line| !self.is_prose(line))
+            .unwrap_or(self.raw.len());
+        crate::lesson::common_indent((first..end).map(|line| self.text(line)))
+    }
+}
+
+/// Marks the opening and closing lines of the lesson's `fn main` when
+/// prose comes between them.
+fn wrapper_lines(source: &LessonSource) -> Vec<bool> {
+    let mut wrapper = vec![false; source.lines.len()];
+    let lines = &source.lines;
+    let Some(open) = lines.iter().position(|line| {
+        line.kind == LineKind::Code
+            && matches!(line.text.trim_end(), "fn main() {" | "pub fn main() {")
+    }) else {
+        return wrapper;
+    };
+    let mut depth = 0;
+    let mut split = false;
+    for (index, line) in lines.iter().enumerate().skip(open) {
+        if line.kind != LineKind::Code {
+            split = true;
+            continue;
+        }
+        depth += brace_delta(&line.text);
+        if depth == 0 {
+            if split && line.text.trim() == "}" {
+                wrapper[open] = true;
+                wrapper[index] = true;
+            }
+            break;
+        }
+    }
+    wrapper
+}
+
+/// Splits lines of running text into paragraphs, lists and text indented
+/// past `base`.
+fn text_blocks(lines: &[&str], base: usize) -> Vec<Block> {
+    let mut blocks = Vec::new();
+    let mut rest = lines;
+    while let Some(&first) = rest.first() {
+        let taken = if list_marker(first.trim()).is_some() {
+            let (list, taken) = list(rest);
+            blocks.push(list);
+            taken
+        } else if indent_of(first) > base && !is_sentence(first) {
+            let taken = rest
+                .iter()
+                .take_while(|line| indent_of(line) > base)
+                .count();
+            blocks.push(Block::Preformatted(
+                dedent(&rest[..taken]).unwrap_or_default(),
+            ));
+            taken
+        } else {
+            // Indented lines continue a paragraph, unless it introduces them
+            // with a colon.
+            let mut taken = 1;
+            while let Some(line) = rest.get(taken) {
+                let continues =
+                    indent_of(line) <= base || !rest[taken - 1].trim_end().ends_with(':');
+                if !continues || list_marker(line.trim()).is_some() {
+                    break;
+                }
+                taken += 1;
+            }
+            let words: Vec<&str> = rest[..taken].iter().map(|line| line.trim()).collect();
+            blocks.push(Block::Paragraph(words.join(" ")));
+            taken
+        };
+        rest = &rest[taken..];
+    }
+    blocks
+}
+
+/// Whether an indented line reads as running text rather than as text laid
+/// out by hand: some comments indent their paragraphs a little.
+fn is_sentence(line: &str) -> bool {
+    line.trim().starts_with(char::is_uppercase)
+}
+
+/// A list: items start with a marker, lines indented past the marker
+/// continue the item. Also returns the number of lines taken.
+fn list(lines: &[&str]) -> (Block, usize) {
+    let indent = indent_of(lines[0]);
+    let mut items: Vec<String> = Vec::new();
+    let mut taken = 0;
+    for line in lines {
+        let text = line.trim();
+        match list_marker(text) {
+            Some(marker) if indent_of(line) <= indent => {
+                items.push(text[marker..].trim().to_string());
+            }
+            _ if indent_of(line) > indent => {
+                let item = items.last_mut().expect("the first line is an item");
+                item.push(' ');
+                item.push_str(text);
+            }
+            _ => break,
+        }
+        taken += 1;
+    }
+    (Block::List(items), taken)
+}
+
+/// Length of the list marker at the start of `text`: `- `, `-> `, `* ` or
+/// `1. `.
+fn list_marker(text: &str) -> Option<usize> {
+    for marker in ["- ", "-> ", "* "] {
+        if text.starts_with(marker) {
+            return Some(marker.len());
+        }
+    }
+    let digits = text.bytes().take_while(u8::is_ascii_digit).count();
+    (digits > 0 && text[digits..].starts_with(". ")).then_some(digits + 2)
+}
+
+/// Fence attributes that only say how Rust code is checked.
+fn is_rust_attribute(word: &str) -> bool {
+    matches!(word, "rust" | "ignore" | "no_run" | "compile_fail")
+}
+
+/// Cells of a table row, separated by three spaces or more.
+fn cells(row: &str) -> Vec<String> {
+    row.trim()
+        .split("   ")
+        .map(str::trim)
+        .filter(|cell| !cell.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// `lines` without their common indentation and surrounding blank lines,
+/// or `None` if they are all blank.
+fn dedent(lines: &[&str]) -> Option<String> {
+    let first = lines.iter().position(|line| !line.trim().is_empty())?;
+    let last = lines.iter().rposition(|line| !line.trim().is_empty())?;
+    let lines = &lines[first..=last];
+    let indent = crate::lesson::common_indent(lines.iter().copied());
+    Some(
+        lines
+            .iter()
+            .map(|line| line.get(indent..).unwrap_or("").trim_end())
+            .collect::<Vec<_>>()
+            .join("\n"),
+    )
+}
@@ -0,0 +1,50 @@
+use std::fmt;
+use std::io;
+
+use crate::course::FindError;
+
+/// Everything a `rustprog` command can fail with.
+#[derive(Debug)]
+pub enum Error {
+    /// The command line could not be understood.
+    Usage(String),
+    Io(io::Error),
+    Find(FindError),
+    /// A command ran but did not succeed; the message says what failed.
+    Failed(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Usage(message) => write!(f, "{message}"),
+            Error::Io(err) => write!(f, "{err}"),
+            Error::Find(err) => write!(f, "{err}"),
+            Error::Failed(message) => write!(f, "{message}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io(err) => Some(err),
+            Error::Find(err) => Some(err),
+            Error::Usage(_) | Error::Failed(_) => None,
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(err: io::Error) -> Self {
+        Error::Io(err)
+    }
+}
+
+impl From<FindError> for Error {
+    fn from(err: FindError) -> Self {
+        Error::Find(err)
+    }
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
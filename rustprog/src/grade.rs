@@ -0,0 +1,206 @@
+//! Grading the exercises that go with the lessons.
+//!
+//! `exercises/<chapter>/<lesson>.rs` holds stub functions for the learner to
+//! fill in, and `exercises/.tests/<chapter>/<lesson>.rs` the hidden tests
+//! that grade them: one module per exercise, named after its function. Both
+//! are compiled together into a test harness, and the tests of every
+//! exercise run on their own, so that code which never returns only fails
+//! its own exercise.
+
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use crate::compile::{self, Scratch};
+use crate::course::{Course, Lesson};
+
+/// How long the tests of one exercise may run before they are stopped.
+pub const TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Name of the module the hidden tests are compiled into.
+const TESTS_MODULE: &str = "hidden_tests";
+
+/// The graded exercises of one lesson.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LessonGrade {
+    /// Compiler errors, when the exercises do not build.
+    pub build_error: Option<String>,
+    pub exercises: Vec<ExerciseGrade>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExerciseGrade {
+    /// Name of the exercise's function.
+    pub name: String,
+    pub verdict: Verdict,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    /// Every test passed.
+    Passed { tests: usize },
+    /// A test failed, with its name and panic message.
+    Failed(String),
+    /// The tests were stopped after the timeout.
+    TimedOut(Duration),
+    /// The hidden tests have nothing for this exercise.
+    Untested,
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Passed { tests: 1 } => f.write_str("1 test"),
+            Verdict::Passed { tests } => write!(f, "{tests} tests"),
+            Verdict::Failed(message) => f.write_str(message),
+            Verdict::TimedOut(timeout) => write!(f, "timed out after {}s", timeout.as_secs()),
+            Verdict::Untested => f.write_str("no tests"),
+        }
+    }
+}
+
+impl LessonGrade {
+    pub fn passed(&self) -> usize {
+        self.exercises
+            .iter()
+            .filter(|exercise| matches!(exercise.verdict, Verdict::Passed { .. }))
+            .count()
+    }
+}
+
+/// `exercises/<chapter>/<lesson>.rs` under the course root.
+pub fn exercise_path(course: &Course, lesson: &Lesson) -> PathBuf {
+    course
+        .root
+        .join("exercises")
+        .join(&course.chapter_of(lesson).dir_name)
+        .join(format!("{}.rs", lesson.stem))
+}
+
+/// `exercises/.tests/<chapter>/<lesson>.rs` under the course root.
+pub fn tests_path(course: &Course, lesson: &Lesson) -> PathBuf {
+    course
+        .root
+        .join("exercises")
+        .join(".tests")
+        .join(&course.chapter_of(lesson).dir_name)
+        .join(format!("{}.rs", lesson.stem))
+}
+
+/// Grades the exercises of `lesson`, or `None` when it has none.
+pub fn grade_lesson(
+    course: &Course,
+    lesson: &Lesson,
+    timeout: Duration,
+) -> io::Result<Option<LessonGrade>> {
+    let exercises = exercise_path(course, lesson);
+    if !exercises.is_file() {
+        return Ok(None);
+    }
+    grade(&exercises, &tests_path(course, lesson), timeout).map(Some)
+}
+
+/// Grades the exercises in the file `exercises` with the tests in `tests`.
+pub fn grade(exercises: &Path, tests: &Path, timeout: Duration) -> io::Result<LessonGrade> {
+    let names = exercise_names(&fs::read_to_string(exercises)?);
+    let scratch = Scratch::new("grade")?;
+    // `include!` keeps the compiler's messages pointing at the learner's file.
+    let harness = scratch.path().join("harness.rs");
+    fs::write(
+        &harness,
+        format!(
+            "include!({:?});\n\n#[cfg(test)]\nmod {TESTS_MODULE} {{\n    include!({:?});\n}}\n",
+            fs::canonicalize(exercises)?,
+            fs::canonicalize(tests)?
+        ),
+    )?;
+
+    let build = compile::compile_tests(&harness, &scratch)?;
+    let Some(binary) = build.binary else {
+        return Ok(LessonGrade {
+            build_error: Some(build.stderr),
+            exercises: names
+                .into_iter()
+                .map(|name| ExerciseGrade {
+                    name,
+                    verdict: Verdict::Failed("does not compile".to_string()),
+                })
+                .collect(),
+        });
+    };
+
+    let mut graded = Vec::new();
+    for name in names {
+        let filter = format!("{TESTS_MODULE}::{name}::");
+        // One thread, so that the first failure reported is always the same.
+        let args = [filter.as_str(), "--test-threads=1"];
+        let verdict = match compile::run_with_timeout(&binary, &args, timeout)? {
+            None => Verdict::TimedOut(timeout),
+            Some(output) => verdict(&String::from_utf8_lossy(&output.stdout)),
+        };
+        graded.push(ExerciseGrade { name, verdict });
+    }
+    Ok(LessonGrade {
+        build_error: None,
+        exercises: graded,
+    })
+}
+
+/// The exercises of a stub file: its top-level `pub fn`s, in file order.
+pub fn exercise_names(source: &str) -> Vec<String> {
+    source
+        .lines()
+        .filter_map(|line| line.strip_prefix("pub fn "))
+        .filter_map(|rest| {
+            let end = rest.find(|c: char| !(c.is_alphanumeric() || c == '_'))?;
+            (end > 0).then(|| rest[..end].to_string())
+        })
+        .collect()
+}
+
+/// Reads the verdict off the output of a test harness.
+fn verdict(stdout: &str) -> Verdict {
+    let mut passed = 0;
+    let mut failed = None;
+    for line in stdout.lines() {
+        let Some(rest) = line.strip_prefix("test ") else {
+            continue;
+        };
+        if rest.ends_with(" ... ok") {
+            passed += 1;
+        } else if let Some(test) = rest.strip_suffix(" ... FAILED") {
+            failed.get_or_insert(test);
+        }
+    }
+    match failed {
+        Some(test) => {
+            let name = test.rsplit("::").next().unwrap_or(test);
+            Verdict::Failed(match panic_message(stdout, test) {
+                Some(message) => format!("{name}: {message}"),
+                None => name.to_string(),
+            })
+        }
+        None if passed == 0 => Verdict::Untested,
+        None => Verdict::Passed { tests: passed },
+    }
+}
+
+/// The panic message of the failed `test`, on one line.
+fn panic_message(stdout: &str, test: &str) -> Option<String> {
+    let section = stdout.split(&format!("---- {test} stdout ----")).nth(1)?;
+    let mut lines = section
+        .lines()
+        .skip_while(|line| !line.contains("panicked at"))
+        .skip(1)
+        .take_while(|line| {
+            !line.trim().is_empty()
+                && !line.starts_with("note:")
+                && !line.starts_with("stack backtrace:")
+                && !line.starts_with("----")
+        })
+        .map(str::trim);
+    let first = lines.next()?.to_string();
+    Some(lines.fold(first, |message, line| format!("{message}, {line}")))
+}
@@ -0,0 +1,187 @@
+//! HTML escaping and a small syntax highlighter for Rust code.
+
+/// `text` with the HTML special characters escaped.
+pub fn escape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#39;"),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+const KEYWORDS: [&str; 38] = [
+    "as", "break", "const", "continue", "crate", "else", "enum", "extern", "false", "fn", "for",
+    "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub", "ref", "return",
+    "self", "Self", "static", "struct", "super", "trait", "true", "type", "unsafe", "use", "where",
+    "while", "dyn", "async", "await",
+];
+
+/// Rust code as HTML, with `<span class="...">` around comments (`com`),
+/// strings and characters (`str`), numbers (`num`), keywords (`kw`), macro
+/// calls (`mac`) and type names (`ty`).
+pub fn highlight_rust(code: &str) -> String {
+    let chars: Vec<char> = code.chars().collect();
+    let mut out = String::with_capacity(code.len() * 2);
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        let next = chars.get(i + 1).copied();
+        let start = i;
+        let class = match (c, next) {
+            ('/', Some('/')) => {
+                while i < chars.len() && chars[i] != '\n' {
+                    i += 1;
+                }
+                "com"
+            }
+            ('/', Some('*')) => {
+                i = block_comment_end(&chars, i);
+                "com"
+            }
+            ('"', _) => {
+                i = string_end(&chars, i + 1);
+                "str"
+            }
+            ('r', Some('"' | '#')) if raw_string_end(&chars, i).is_some() => {
+                i = raw_string_end(&chars, i).expect("checked above");
+                "str"
+            }
+            ('\'', _) => match char_literal_end(&chars, i) {
+                Some(end) => {
+                    i = end;
+                    "str"
+                }
+                None => {
+                    // A lifetime or loop label: 'a
+                    i += 1;
+                    while i < chars.len() && is_ident(chars[i]) {
+                        i += 1;
+                    }
+                    "kw"
+                }
+            },
+            _ if c.is_ascii_digit() => {
+                while i < chars.len() && (chars[i].is_ascii_alphanumeric() || chars[i] == '_') {
+                    i += 1;
+                }
+                // A fractional part, but not a range (`1..5`) or a method call.
+                if chars.get(i) == Some(&'.') && chars.get(i + 1).is_some_and(char::is_ascii_digit)
+                {
+                    i += 1;
+                    while i < chars.len() && (chars[i].is_ascii_alphanumeric() || chars[i] == '_') {
+                        i += 1;
+                    }
+                }
+                "num"
+            }
+            _ if is_ident(c) => {
+                while i < chars.len() && is_ident(chars[i]) {
+                    i += 1;
+                }
+                let word: String = chars[start..i].iter().collect();
+                if chars.get(i) == Some(&'!') && chars.get(i + 1) != Some(&'=') {
+                    i += 1;
+                    "mac"
+                } else if KEYWORDS.contains(&word.as_str()) {
+                    "kw"
+                } else if word.starts_with(|c: char| c.is_ascii_uppercase()) {
+                    "ty"
+                } else {
+                    ""
+                }
+            }
+            _ => {
+                i += 1;
+                ""
+            }
+        };
+        let text: String = chars[start..i].iter().collect();
+        if class.is_empty() {
+            out.push_str(&escape(&text));
+        } else {
+            out.push_str(&format!("<span class=\"{class}\">{}</span>", escape(&text)));
+        }
+    }
+    out
+}
+
+fn is_ident(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+/// Index after the (nested) block comment opening at `start`.
+fn block_comment_end(chars: &[char], start: usize) -> usize {
+    let mut depth = 0;
+    let mut i = start;
+    while i < chars.len() {
+        match (chars[i], chars.get(i + 1)) {
+            ('/', Some('*')) => {
+                depth += 1;
+                i += 2;
+            }
+            ('*', Some('/')) => {
+                depth -= 1;
+                i += 2;
+                if depth == 0 {
+                    return i;
+                }
+            }
+            _ => i += 1,
+        }
+    }
+    chars.len()
+}
+
+/// Index after the closing quote of a string whose contents start at `i`.
+fn string_end(chars: &[char], mut i: usize) -> usize {
+    while i < chars.len() {
+        match chars[i] {
+            '\\' => i += 2,
+            '"' => return i + 1,
+            _ => i += 1,
+        }
+    }
+    chars.len()
+}
+
+/// Index after a raw string `r"..."` or `r#"..."#` starting at `start`.
+fn raw_string_end(chars: &[char], start: usize) -> Option<usize> {
+    let hashes = chars[start + 1..].iter().take_while(|&&c| c == '#').count();
+    let open = start + 1 + hashes;
+    if chars.get(open) != Some(&'"') {
+        return None;
+    }
+    let mut i = open + 1;
+    while i < chars.len() {
+        if chars[i] == '"'
+            && chars[i + 1..]
+                .iter()
+                .take(hashes)
+                .filter(|&&c| c == '#')
+                .count()
+                == hashes
+        {
+            return Some(i + 1 + hashes);
+        }
+        i += 1;
+    }
+    Some(chars.len())
+}
+
+/// Index after a character literal starting at `start`, if it is one.
+fn char_literal_end(chars: &[char], start: usize) -> Option<usize> {
+    match chars.get(start + 1)? {
+        '\\' => {
+            let close = chars[start + 2..].iter().position(|&c| c == '\'')?;
+            Some(start + 2 + close + 1)
+        }
+        _ => (chars.get(start + 2) == Some(&'\'')).then_some(start + 3),
+    }
+}
@@ -0,0 +1,680 @@
+//! Translations of the lesson prose.
+//!
+//! The prose of a lesson — its title, headings, paragraphs, list items and
+//! the wordy cells of its tables — is extracted into a gettext catalog,
+//! `i18n/<lang>/<chapter>/<lesson>.po` under the course root. The course
+//! title, the chapter names and the labels the renderers put around the
+//! lessons go in `i18n/<lang>/course.po`. Code, identifiers, `Output:`
+//! blocks and pasted compiler errors are the same in every language and
+//! are never extracted.
+//!
+//! A [`Translation`] swaps the prose of a [`Document`] for its translation
+//! before it is rendered, so the renderers need not know about languages.
+//! Messages not translated yet, or marked `fuzzy`, stay in English.
+//!
+//! Updating a catalog keeps the translations of the messages still in the
+//! lesson. Those of messages that went away are kept as obsolete `#~`
+//! entries, so a reworded paragraph does not lose its old translation.
+
+use std::collections::{HashMap, HashSet};
+use std::fmt::{self, Write as _};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::course::{Chapter, Course, Lesson, COURSE_TITLE};
+use crate::document::{Block, Document};
+
+/// The language the lessons are written in.
+pub const SOURCE_LANGUAGE: &str = "en";
+
+/// Labels the renderers write around the lessons.
+pub const LABELS: &[&str] = &["Contents", "On this page", "Output", "Run", "Run lesson"];
+
+/// Longest line written to a catalog, quotes included.
+const WIDTH: usize = 79;
+
+/// A gettext catalog.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Catalog {
+    /// The `msgstr` of the header entry: `Language: fr` and the like.
+    pub header: String,
+    pub messages: Vec<Message>,
+}
+
+/// One entry of a catalog.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Message {
+    /// `# ` comments, left by translators and kept on update.
+    pub notes: Vec<String>,
+    /// `#. ` comments: where in the lesson the message comes from.
+    pub comments: Vec<String>,
+    /// `#: ` references: the lesson file.
+    pub references: Vec<String>,
+    pub fuzzy: bool,
+    pub id: String,
+    /// The translation; empty until there is one.
+    pub text: String,
+    /// A `#~` entry: no longer in the lesson, kept for its translation.
+    pub obsolete: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub message: String,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// `i18n/<lang>` under the course root.
+pub fn catalog_dir(course: &Course, language: &str) -> PathBuf {
+    course.root.join("i18n").join(language)
+}
+
+/// `i18n/<lang>/course.po`: the course title, chapter names and labels.
+pub fn course_catalog_path(course: &Course, language: &str) -> PathBuf {
+    catalog_dir(course, language).join("course.po")
+}
+
+/// `i18n/<lang>/<chapter>/<lesson>.po`.
+pub fn lesson_catalog_path(course: &Course, language: &str, lesson: &Lesson) -> PathBuf {
+    catalog_dir(course, language)
+        .join(&course.chapter_of(lesson).dir_name)
+        .join(format!("{}.po", lesson.stem))
+}
+
+/// Whether `language` can name a catalog directory: `fr`, `pt-BR`.
+pub fn is_language_code(language: &str) -> bool {
+    let mut parts = language.split(['-', '_']);
+    parts.next().is_some_and(|first| {
+        (2..=3).contains(&first.len()) && first.bytes().all(|b| b.is_ascii_lowercase())
+    }) && parts.all(|part| !part.is_empty() && part.bytes().all(|b| b.is_ascii_alphanumeric()))
+}
+
+impl Catalog {
+    /// An empty catalog for `language`.
+    pub fn new(language: &str) -> Catalog {
+        Catalog {
+            header: format!(
+                "Language: {language}\nMIME-Version: 1.0\n\
+                 Content-Type: text/plain; charset=UTF-8\nContent-Transfer-Encoding: 8bit\n"
+            ),
+            messages: Vec::new(),
+        }
+    }
+
+    /// Reads the catalog at `path`.
+    pub fn read(path: &Path) -> io::Result<Catalog> {
+        Catalog::parse(&fs::read_to_string(path)?).map_err(|err| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("{}: {err}", path.display()),
+            )
+        })
+    }
+
+    /// Parses a `.po` file. Contexts (`msgctxt`) and plurals are not used
+    /// by the course and are rejected.
+    pub fn parse(text: &str) -> Result<Catalog, ParseError> {
+        let mut catalog = Catalog::default();
+        let mut entry = Draft::default();
+        for (index, raw) in text.lines().enumerate() {
+            let number = index + 1;
+            let (line, obsolete) = match raw.strip_prefix("#~") {
+                Some(rest) => (rest.trim(), true),
+                None => (raw.trim(), false),
+            };
+            if line.is_empty() {
+                entry.finish(&mut catalog)?;
+                continue;
+            }
+            // A comment or a `msgid` after a `msgstr` starts the next entry.
+            if entry.text.is_some() && (line.starts_with('#') || line.starts_with("msgid ")) {
+                entry.finish(&mut catalog)?;
+            }
+            entry.message.obsolete |= obsolete;
+
+            if let Some(comment) = line.strip_prefix('#') {
+                let message = &mut entry.message;
+                match comment.split_at(comment.chars().next().map_or(0, char::len_utf8)) {
+                    (".", rest) => message.comments.push(rest.trim().to_string()),
+                    (":", rest) => message
+                        .references
+                        .extend(rest.split_whitespace().map(String::from)),
+                    (",", rest) => {
+                        message.fuzzy |= rest.split(',').any(|flag| flag.trim() == "fuzzy")
+                    }
+                    ("|", _) => {}
+                    _ => message
+                        .notes
+                        .push(comment.strip_prefix(' ').unwrap_or(comment).to_string()),
+                }
+                entry.field = None;
+            } else if let Some(rest) = line.strip_prefix("msgid ") {
+                if entry.id.is_some() {
+                    return Err(ParseError {
+                        line: number,
+                        message: "a msgid without its msgstr".to_string(),
+                    });
+                }
+                entry.id = Some((number, unquote(rest, number)?));
+                entry.field = Some(Field::Id);
+            } else if let Some(rest) = line.strip_prefix("msgstr ") {
+                if entry.id.is_none() || entry.text.is_some() {
+                    return Err(ParseError {
+                        line: number,
+                        message: "a msgstr without its msgid".to_string(),
+                    });
+                }
+                entry.text = Some(unquote(rest, number)?);
+                entry.field = Some(Field::Text);
+            } else if line.starts_with('"') {
+                let more = unquote(line, number)?;
+                match (entry.field, &mut entry.id, &mut entry.text) {
+                    (Some(Field::Id), Some((_, id)), _) => id.push_str(&more),
+                    (Some(Field::Text), _, Some(text)) => text.push_str(&more),
+                    _ => {
+                        return Err(ParseError {
+                            line: number,
+                            message: "a string outside a msgid or msgstr".to_string(),
+                        })
+                    }
+                }
+            } else {
+                let keyword = line.split_whitespace().next().unwrap_or(line);
+                return Err(ParseError {
+                    line: number,
+                    message: format!("expected msgid, msgstr or a comment, not '{keyword}'"),
+                });
+            }
+        }
+        entry.finish(&mut catalog)?;
+        Ok(catalog)
+    }
+
+    /// The language named in the header, if any.
+    pub fn language(&self) -> Option<&str> {
+        self.header
+            .lines()
+            .find_map(|line| line.strip_prefix("Language:"))
+            .map(str::trim)
+    }
+
+    /// The translation of `id`, unless it is missing or fuzzy.
+    pub fn get(&self, id: &str) -> Option<&str> {
+        self.messages
+            .iter()
+            .find(|message| message.id == id && !message.obsolete)
+            .filter(|message| !message.fuzzy && !message.text.is_empty())
+            .map(|message| message.text.as_str())
+    }
+
+    /// Replaces the messages with `extracted`, keeping the translations and
+    /// notes of those already there. Returns how many messages are new and
+    /// how many became obsolete.
+    pub fn update(&mut self, extracted: Vec<Message>) -> (usize, usize) {
+        let mut old: HashMap<String, Message> = HashMap::new();
+        for message in self.messages.drain(..) {
+            // An entry still in use wins over an obsolete one.
+            match old.get(&message.id) {
+                Some(kept) if !kept.obsolete => {}
+                _ => {
+                    old.insert(message.id.clone(), message);
+                }
+            }
+        }
+        let mut added = 0;
+        for mut message in extracted {
+            match old.remove(&message.id) {
+                Some(previous) => {
+                    message.text = previous.text;
+                    message.fuzzy = previous.fuzzy;
+                    message.notes = previous.notes;
+                }
+                None => added += 1,
+            }
+            self.messages.push(message);
+        }
+
+        let mut obsolete = 0;
+        let mut gone: Vec<Message> = old
+            .into_values()
+            .filter(|message| !message.text.is_empty())
+            .collect();
+        gone.sort_by(|a, b| a.id.cmp(&b.id));
+        for mut message in gone {
+            if !message.obsolete {
+                obsolete += 1;
+            }
+            message.obsolete = true;
+            message.comments.clear();
+            message.references.clear();
+            self.messages.push(message);
+        }
+        (added, obsolete)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Field {
+    Id,
+    Text,
+}
+
+/// The entry being parsed.
+#[derive(Debug, Default)]
+struct Draft {
+    message: Message,
+    /// The line of the `msgid` and the text so far.
+    id: Option<(usize, String)>,
+    text: Option<String>,
+    field: Option<Field>,
+}
+
+impl Draft {
+    fn finish(&mut self, catalog: &mut Catalog) -> Result<(), ParseError> {
+        let Draft {
+            mut message,
+            id,
+            text,
+            ..
+        } = std::mem::take(self);
+        match (id, text) {
+            // Comments on their own, at the top of the file say.
+            (None, _) => {}
+            (Some((line, _)), None) => {
+                return Err(ParseError {
+                    line,
+                    message: "a msgid without its msgstr".to_string(),
+                })
+            }
+            (Some((_, id)), Some(text)) if id.is_empty() && !message.obsolete => {
+                catalog.header = text;
+            }
+            (Some((_, id)), Some(text)) => {
+                message.id = id;
+                message.text = text;
+                catalog.messages.push(message);
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The text of a `"..."` string.
+fn unquote(quoted: &str, line: usize) -> Result<String, ParseError> {
+    let error = |message: &str| ParseError {
+        line,
+        message: message.to_string(),
+    };
+    let inner = quoted
+        .trim()
+        .strip_prefix('"')
+        .and_then(|rest| rest.strip_suffix('"'))
+        .ok_or_else(|| error("expected a quoted string"))?;
+    let mut text = String::with_capacity(inner.len());
+    let mut chars = inner.chars();
+    while let Some(c) = chars.next() {
+        if c == '"' {
+            return Err(error("an unescaped quote inside a string"));
+        }
+        if c != '\\' {
+            text.push(c);
+            continue;
+        }
+        text.push(match chars.next() {
+            Some('n') => '\n',
+            Some('t') => '\t',
+            Some('"') => '"',
+            Some('\\') => '\\',
+            _ => return Err(error("unknown escape in a string")),
+        });
+    }
+    Ok(text)
+}
+
+fn quote(text: &str) -> String {
+    let mut quoted = String::from("\"");
+    for c in text.chars() {
+        match c {
+            '\n' => quoted.push_str("\\n"),
+            '\t' => quoted.push_str("\\t"),
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+/// Writes `keyword "text"`, split into lines at newlines and spaces when
+/// it does not fit on one, the way `msgcat` does.
+fn write_string(
+    f: &mut fmt::Formatter<'_>,
+    prefix: &str,
+    keyword: &str,
+    text: &str,
+) -> fmt::Result {
+    let whole = quote(text);
+    let inner_newline = text.trim_end_matches('\n').contains('\n');
+    if prefix.len() + keyword.len() + 1 + whole.chars().count() <= WIDTH && !inner_newline {
+        return writeln!(f, "{prefix}{keyword} {whole}");
+    }
+    writeln!(f, "{prefix}{keyword} \"\"")?;
+    let room = WIDTH - prefix.len() - 2;
+    let mut line = String::new();
+    for piece in text.split_inclusive([' ', '\n']) {
+        let length = |text: &str| quote(text).chars().count() - 2;
+        if !line.is_empty() && length(&line) + length(piece) > room {
+            writeln!(f, "{prefix}{}", quote(&line))?;
+            line.clear();
+        }
+        line.push_str(piece);
+        if piece.ends_with('\n') {
+            writeln!(f, "{prefix}{}", quote(&line))?;
+            line.clear();
+        }
+    }
+    if !line.is_empty() {
+        writeln!(f, "{prefix}{}", quote(&line))?;
+    }
+    Ok(())
+}
+
+impl fmt::Display for Catalog {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "msgid \"\"")?;
+        write_string(f, "", "msgstr", &self.header)?;
+        for message in &self.messages {
+            writeln!(f)?;
+            for note in &message.notes {
+                writeln!(f, "# {note}")?;
+            }
+            for comment in &message.comments {
+                writeln!(f, "#. {comment}")?;
+            }
+            if !message.references.is_empty() {
+                writeln!(f, "#: {}", message.references.join(" "))?;
+            }
+            if message.fuzzy {
+                writeln!(f, "#, fuzzy")?;
+            }
+            let prefix = if message.obsolete { "#~ " } else { "" };
+            write_string(f, prefix, "msgid", &message.id)?;
+            write_string(f, prefix, "msgstr", &message.text)?;
+        }
+        Ok(())
+    }
+}
+
+/// Whether a table cell has words to translate, rather than code such as
+/// `a += b`.
+fn is_wordy(text: &str) -> bool {
+    text.split(|c: char| !c.is_alphabetic())
+        .any(|word| word.chars().count() >= 3)
+}
+
+/// The prose of a document in order, without repeats, each with the
+/// section it is in: the heading above it, or the title before the first.
+pub fn prose(document: &Document) -> Vec<(String, String)> {
+    let mut section = document.title.clone().unwrap_or_default();
+    let mut seen = HashSet::new();
+    let mut prose = Vec::new();
+    let mut add = |section: &str, text: &str| {
+        if !text.trim().is_empty() && seen.insert(text.to_string()) {
+            prose.push((section.to_string(), text.to_string()));
+        }
+    };
+    if let Some(title) = &document.title {
+        add(title, title);
+    }
+    for block in &document.blocks {
+        match block {
+            Block::Heading { text, .. } => {
+                section.clone_from(text);
+                add(&section, text);
+            }
+            Block::Paragraph(text) => add(&section, text),
+            Block::List(items) => items.iter().for_each(|item| add(&section, item)),
+            Block::Table { header, rows } => {
+                for cell in header.iter().chain(rows.iter().flatten()) {
+                    if is_wordy(cell) {
+                        add(&section, cell);
+                    }
+                }
+            }
+            Block::Code(_) | Block::Output(_) | Block::Preformatted(_) | Block::Rule => {}
+        }
+    }
+    prose
+}
+
+/// The messages of a lesson, to update its catalog with.
+pub fn extract(course: &Course, lesson: &Lesson, document: &Document) -> Vec<Message> {
+    let reference = lesson
+        .relative_path(course)
+        .to_string_lossy()
+        .replace('\\', "/");
+    prose(document)
+        .into_iter()
+        .enumerate()
+        .map(|(index, (section, id))| Message {
+            comments: vec![if index == 0 && document.title.is_some() {
+                "the title of the lesson".to_string()
+            } else {
+                format!("section: {section}")
+            }],
+            references: vec![reference.clone()],
+            id,
+            ..Message::default()
+        })
+        .collect()
+}
+
+/// The messages of `course.po`.
+pub fn extract_course(course: &Course) -> Vec<Message> {
+    let message = |comment: String, id: &str| Message {
+        comments: vec![comment],
+        id: id.to_string(),
+        ..Message::default()
+    };
+    let mut messages = vec![message("the title of the course".to_string(), COURSE_TITLE)];
+    for chapter in &course.chapters {
+        messages.push(message(
+            format!("chapter {} ({})", chapter.number, chapter.dir_name),
+            &chapter.name(),
+        ));
+    }
+    for label in LABELS {
+        messages.push(message("a label of the rendered pages".to_string(), label));
+    }
+    messages
+}
+
+/// What updating the catalogs of a language did.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Updated {
+    pub catalogs: usize,
+    pub added: usize,
+    pub obsolete: usize,
+}
+
+/// Creates or updates the catalogs of `lessons` in `language`, and the
+/// course catalog.
+pub fn update(course: &Course, language: &str, lessons: &[&Lesson]) -> io::Result<Updated> {
+    let mut updated = Updated::default();
+    let mut write = |path: &Path, messages: Vec<Message>| -> io::Result<()> {
+        let mut catalog = match Catalog::read(path) {
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Catalog::new(language),
+            read => read?,
+        };
+        let (added, obsolete) = catalog.update(messages);
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(path, catalog.to_string())?;
+        updated.catalogs += 1;
+        updated.added += added;
+        updated.obsolete += obsolete;
+        Ok(())
+    };
+    write(
+        &course_catalog_path(course, language),
+        extract_course(course),
+    )?;
+    for lesson in lessons {
+        let document = Document::read(&lesson.path)?;
+        write(
+            &lesson_catalog_path(course, language, lesson),
+            extract(course, lesson, &document),
+        )?;
+    }
+    Ok(updated)
+}
+
+/// How much of a lesson is translated.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Coverage {
+    pub translated: usize,
+    pub total: usize,
+    /// The sections with prose left to translate, in order, with the
+    /// number of messages left in each.
+    pub untranslated: Vec<(String, usize)>,
+}
+
+impl Coverage {
+    pub fn is_complete(&self) -> bool {
+        self.translated == self.total
+    }
+}
+
+/// The lessons and course in one language, ready to render.
+#[derive(Debug, Clone)]
+pub struct Translation {
+    language: String,
+    course: Catalog,
+    lessons: HashMap<PathBuf, Catalog>,
+}
+
+impl Translation {
+    /// The lessons as they are written.
+    pub fn source() -> Translation {
+        Translation {
+            language: SOURCE_LANGUAGE.to_string(),
+            course: Catalog::default(),
+            lessons: HashMap::new(),
+        }
+    }
+
+    /// Reads the catalogs of `language`. Lessons without a catalog yet stay
+    /// untranslated; a language without `i18n/<lang>` is `NotFound`.
+    pub fn load(course: &Course, language: &str) -> io::Result<Translation> {
+        if language == SOURCE_LANGUAGE {
+            return Ok(Translation::source());
+        }
+        let dir = catalog_dir(course, language);
+        if !dir.is_dir() {
+            return Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("no catalogs in {}", dir.display()),
+            ));
+        }
+        let read = |path: &Path| match Catalog::read(path) {
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Catalog::new(language)),
+            read => read,
+        };
+        let mut lessons = HashMap::new();
+        for lesson in course.lessons() {
+            let catalog = read(&lesson_catalog_path(course, language, lesson))?;
+            lessons.insert(lesson.path.clone(), catalog);
+        }
+        Ok(Translation {
+            language: language.to_string(),
+            course: read(&course_catalog_path(course, language))?,
+            lessons,
+        })
+    }
+
+    pub fn language(&self) -> &str {
+        &self.language
+    }
+
+    /// A label, or a name from `course.po`, in this language.
+    pub fn text<'a>(&'a self, text: &'a str) -> &'a str {
+        self.course.get(text).unwrap_or(text)
+    }
+
+    pub fn course_title(&self) -> &str {
+        self.text(COURSE_TITLE)
+    }
+
+    pub fn chapter_name(&self, chapter: &Chapter) -> String {
+        let name = chapter.name();
+        self.text(&name).to_string()
+    }
+
+    /// `document`, the text of `lesson`, with its prose translated.
+    pub fn document(&self, lesson: &Lesson, document: &Document) -> Document {
+        let Some(catalog) = self.lessons.get(&lesson.path) else {
+            return document.clone();
+        };
+        let translate = |text: &mut String| {
+            if let Some(translated) = catalog.get(text) {
+                *text = translated.to_string();
+            }
+        };
+        let mut document = document.clone();
+        if let Some(title) = &mut document.title {
+            translate(title);
+        }
+        for block in &mut document.blocks {
+            match block {
+                Block::Heading { text, .. } | Block::Paragraph(text) => translate(text),
+                Block::List(items) => items.iter_mut().for_each(translate),
+                Block::Table { header, rows } => header
+                    .iter_mut()
+                    .chain(rows.iter_mut().flatten())
+                    .for_each(translate),
+                Block::Code(_) | Block::Output(_) | Block::Preformatted(_) | Block::Rule => {}
+            }
+        }
+        document
+    }
+
+    /// How much of `document`, the text of `lesson`, is translated.
+    pub fn coverage(&self, lesson: &Lesson, document: &Document) -> Coverage {
+        let catalog = self.lessons.get(&lesson.path);
+        let mut coverage = Coverage::default();
+        for (section, text) in prose(document) {
+            coverage.total += 1;
+            if self.language == SOURCE_LANGUAGE || catalog.is_some_and(|c| c.get(&text).is_some()) {
+                coverage.translated += 1;
+                continue;
+            }
+            match coverage.untranslated.last_mut() {
+                Some((last, count)) if *last == section => *count += 1,
+                _ => coverage.untranslated.push((section, 1)),
+            }
+        }
+        coverage
+    }
+}
+
+/// `12/40 (30%)`.
+impl fmt::Display for Coverage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut out = format!("{}/{}", self.translated, self.total);
+        if let Some(percent) = (self.translated * 100).checked_div(self.total) {
+            let _ = write!(out, " ({percent}%)");
+        }
+        f.pad(&out)
+    }
+}
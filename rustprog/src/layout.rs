@@ -0,0 +1,577 @@
+//! How the types of the lessons are laid out in memory.
+//!
+//! Sizes, alignments and field offsets are not worked out here: they are
+//! printed by a program compiled with `rustc`, with `size_of`, `align_of`
+//! and `offset_of!`, so they are what the compiler really does, reordered
+//! fields included. Structs are copied from the lesson that defines them.
+//! What is left between the fields is padding.
+
+use std::fmt;
+use std::fmt::Write as _;
+use std::io;
+
+use crate::compile::{self, Limits, Scratch};
+use crate::course::{Course, Lesson};
+use crate::lesson::{is_live_code, split_line_comment, LessonSource, LineKind};
+use crate::text;
+
+/// Largest type drawn byte by byte.
+pub const MAX_DRAWN: usize = 512;
+
+/// Bytes on a row of the diagram.
+const ROW: usize = 16;
+
+/// Array elements listed before the rest are left out.
+const MAX_ELEMENTS: usize = 4;
+
+/// A struct defined in a lesson.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Definition {
+    pub name: String,
+    /// The lesson defining it, as `3.4`, and the line of the definition.
+    pub lesson: String,
+    pub line: usize,
+    /// The definition as written, without comments.
+    pub source: String,
+    /// The fields and their types; tuple structs have fields `0`, `1`...
+    pub fields: Vec<(String, String)>,
+}
+
+/// What a type is made of, as far as its layout goes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Shape {
+    /// A struct or tuple, with its fields and their types.
+    Fields(Vec<(String, String)>),
+    /// An array of `len` elements.
+    Array { element: String, len: String },
+    /// Anything else: a primitive, a reference, a library type.
+    Opaque,
+}
+
+/// The layout of a type, as measured.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Layout {
+    pub ty: String,
+    pub size: usize,
+    pub align: usize,
+    /// The fields or elements by offset. A type without fields is one
+    /// field named after it.
+    pub fields: Vec<Field>,
+    /// Whether the type has fields, and whether the compiler stored them
+    /// in another order than they are written in.
+    pub has_fields: bool,
+    pub reordered: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Field {
+    pub name: String,
+    pub ty: String,
+    pub offset: usize,
+    pub size: usize,
+    pub align: usize,
+}
+
+/// The structs defined in the lessons, in course order.
+pub fn definitions(course: &Course) -> io::Result<Vec<Definition>> {
+    let mut definitions = Vec::new();
+    for lesson in course.lessons() {
+        definitions.extend(lesson_definitions(
+            lesson,
+            &LessonSource::read(&lesson.path)?,
+        ));
+    }
+    Ok(definitions)
+}
+
+/// The structs `source` defines; generic ones are left out.
+pub fn lesson_definitions(lesson: &Lesson, source: &LessonSource) -> Vec<Definition> {
+    let mut definitions = Vec::new();
+    let code: Vec<&str> = source
+        .lines
+        .iter()
+        .map(|line| {
+            if is_live_code(line) {
+                code_of(&line.text).trim()
+            } else {
+                ""
+            }
+        })
+        .collect();
+    for (index, line) in code.iter().enumerate() {
+        let Some(rest) = line
+            .strip_prefix("pub ")
+            .unwrap_or(line)
+            .strip_prefix("struct ")
+        else {
+            continue;
+        };
+        let name: String = rest
+            .chars()
+            .take_while(|c| c.is_alphanumeric() || *c == '_')
+            .collect();
+        let after = rest[name.len()..].trim_start();
+        let (body, end) = if after.starts_with('(') {
+            let body = after.trim_end_matches(';').trim_end();
+            (body[1..body.len().saturating_sub(1)].to_string(), index)
+        } else if after.starts_with('{') {
+            let Some(end) = (index..code.len()).find(|end| code[*end].ends_with('}')) else {
+                continue;
+            };
+            let body: Vec<&str> = code[index..=end].to_vec();
+            let body = body.join(" ");
+            let body = body[body.find('{').unwrap_or(0) + 1..].trim_end_matches('}');
+            (body.to_string(), end)
+        } else if after == ";" {
+            (String::new(), index)
+        } else {
+            // Generic, or not a definition after all.
+            continue;
+        };
+        let fields = split_top_level(&body, ',')
+            .into_iter()
+            .enumerate()
+            .map(|(position, field)| {
+                let field = field.strip_prefix("pub ").unwrap_or(field);
+                match field.split_once(':') {
+                    Some((name, ty)) if after.starts_with('{') => {
+                        (name.trim().to_string(), ty.trim().to_string())
+                    }
+                    _ => (position.to_string(), field.trim().to_string()),
+                }
+            })
+            .collect();
+        let indent = |line: &str| line.len() - line.trim_start().len();
+        let lines = &source.lines[index..=end];
+        let base = indent(&lines[0].text);
+        let source: Vec<&str> = lines
+            .iter()
+            .map(|line| code_of(&line.text).trim_end())
+            .map(|line| &line[base.min(indent(line))..])
+            .collect();
+        definitions.push(Definition {
+            name,
+            lesson: lesson.id(),
+            line: index + 1,
+            source: source.join("\n"),
+            fields,
+        });
+    }
+    definitions
+}
+
+/// The types a lesson uses: the structs it defines, the types its `let`
+/// statements spell out and those it passes to `size_of`, in its program
+/// or in examples commented out of it.
+pub fn lesson_types(lesson: &Lesson, source: &LessonSource) -> Vec<String> {
+    let mut types: Vec<String> = lesson_definitions(lesson, source)
+        .into_iter()
+        .map(|definition| definition.name)
+        .collect();
+    for line in source
+        .lines
+        .iter()
+        .filter(|line| line.kind == LineKind::Code)
+    {
+        let text = line.text.trim_start();
+        let code = code_of(text.strip_prefix("//").unwrap_or(text)).trim();
+        if let Some(rest) = code.strip_prefix("let ") {
+            let pattern = split_top_level(rest, ':').first().copied().unwrap_or("");
+            let ty = rest[pattern.len()..]
+                .strip_prefix(':')
+                .filter(|ty| !pattern.contains('=') && !ty.starts_with(':'));
+            if let Some(ty) = ty {
+                let end = ty.find(" =").or_else(|| ty.find(';')).unwrap_or(ty.len());
+                types.push(ty[..end].trim().to_string());
+            }
+        }
+        let mut rest = code;
+        while let Some(at) = rest.find("size_of::<") {
+            rest = &rest[at + "size_of::<".len()..];
+            let mut depth = 1;
+            let end = rest.char_indices().find_map(|(at, c)| {
+                match c {
+                    '<' => depth += 1,
+                    '>' => depth -= 1,
+                    _ => {}
+                }
+                (depth == 0).then_some(at)
+            });
+            if let Some(end) = end {
+                types.push(rest[..end].trim().to_string());
+            }
+        }
+    }
+    let mut seen = Vec::new();
+    types.retain(|ty| {
+        !ty.is_empty() && !seen.contains(ty) && {
+            seen.push(ty.clone());
+            true
+        }
+    });
+    types
+}
+
+/// What `ty` is made of; `definitions` are looked up by name, the first
+/// one found wins.
+pub fn shape(ty: &str, definitions: &[Definition]) -> Shape {
+    let ty = ty.trim();
+    if let Some(inner) = ty.strip_prefix('(').and_then(|ty| ty.strip_suffix(')')) {
+        let parts = split_top_level(inner, ',');
+        // `(T)` is T; `(T,)` is a tuple.
+        if parts.len() == 1 && !inner.trim_end().ends_with(',') {
+            return shape(parts[0], definitions);
+        }
+        let fields = parts
+            .into_iter()
+            .enumerate()
+            .map(|(index, part)| (index.to_string(), part.to_string()))
+            .collect();
+        return Shape::Fields(fields);
+    }
+    if let Some(inner) = ty.strip_prefix('[').and_then(|ty| ty.strip_suffix(']')) {
+        if let [element, len] = split_top_level(inner, ';')[..] {
+            return Shape::Array {
+                element: element.to_string(),
+                len: len.to_string(),
+            };
+        }
+    }
+    match definitions.iter().find(|definition| definition.name == ty) {
+        Some(definition) => Shape::Fields(definition.fields.clone()),
+        None => Shape::Opaque,
+    }
+}
+
+/// A program printing the layout of each of `types`, with the definitions
+/// they need.
+pub fn program(types: &[String], definitions: &[Definition]) -> String {
+    let mut needed: Vec<&Definition> = Vec::new();
+    let mut queue: Vec<String> = types.to_vec();
+    while let Some(ty) = queue.pop() {
+        for word in ty.split(|c: char| !(c.is_alphanumeric() || c == '_')) {
+            let found = definitions
+                .iter()
+                .find(|definition| definition.name == word);
+            if let Some(definition) = found {
+                if !needed.iter().any(|needed| needed.name == word) {
+                    needed.push(definition);
+                    queue.extend(definition.fields.iter().map(|(_, ty)| ty.clone()));
+                }
+            }
+        }
+    }
+    needed.sort_by_key(|definition| (definition.lesson.clone(), definition.line));
+
+    let mut program = String::from("use std::mem::{align_of, offset_of, size_of};\n\n");
+    for definition in needed {
+        let _ = writeln!(program, "#[allow(dead_code)]\n{}\n", definition.source);
+    }
+    program.push_str("fn main() {\n");
+    for (index, ty) in types.iter().enumerate() {
+        let _ = writeln!(
+            program,
+            "    println!(\"type\\t{index}\\t{{}}\\t{{}}\", size_of::<{ty}>(), align_of::<{ty}>());"
+        );
+        match shape(ty, definitions) {
+            Shape::Fields(fields) => {
+                for (field, (name, field_ty)) in fields.iter().enumerate() {
+                    let _ = writeln!(
+                        program,
+                        "    println!(\"field\\t{index}\\t{field}\\t{{}}\\t{{}}\\t{{}}\", \
+                         offset_of!({ty}, {name}), size_of::<{field_ty}>(), align_of::<{field_ty}>());"
+                    );
+                }
+            }
+            Shape::Array { element, len } => {
+                let _ = writeln!(
+                    program,
+                    "    println!(\"array\\t{index}\\t{{}}\\t{{}}\\t{{}}\", \
+                     {len}, size_of::<{element}>(), align_of::<{element}>());"
+                );
+            }
+            Shape::Opaque => {}
+        }
+    }
+    program.push_str("}\n");
+    program
+}
+
+/// Measures `types`; `Err` holds the compiler errors if they do not all
+/// name types the program can see.
+pub fn measure(
+    types: &[String],
+    definitions: &[Definition],
+    limits: &Limits,
+) -> io::Result<Result<Vec<Layout>, String>> {
+    let scratch = Scratch::new("layout")?;
+    let build = compile::compile_source(&program(types, definitions), &scratch)?;
+    let Some(binary) = build.binary else {
+        return Ok(Err(build.stderr));
+    };
+    let run = compile::run_limited(&binary, limits)?;
+    if !run.status.is_some_and(|status| status.success()) {
+        return Ok(Err(String::from_utf8_lossy(&run.stderr).into_owned()));
+    }
+    Ok(Ok(parse(
+        types,
+        definitions,
+        &String::from_utf8_lossy(&run.stdout),
+    )))
+}
+
+/// The layouts in the output of [`program`].
+pub fn parse(types: &[String], definitions: &[Definition], output: &str) -> Vec<Layout> {
+    let mut layouts: Vec<Layout> = types
+        .iter()
+        .map(|ty| Layout {
+            ty: ty.clone(),
+            size: 0,
+            align: 1,
+            fields: Vec::new(),
+            has_fields: shape(ty, definitions) != Shape::Opaque,
+            reordered: false,
+        })
+        .collect();
+    for line in output.lines() {
+        let fields: Vec<&str> = line.split('\t').collect();
+        let number = |index: usize| fields.get(index).and_then(|field| field.parse().ok());
+        let Some(layout) = number(1).and_then(|index: usize| layouts.get_mut(index)) else {
+            continue;
+        };
+        match (fields[0], number(2), number(3), number(4)) {
+            ("type", Some(size), Some(align), _) => {
+                layout.size = size;
+                layout.align = align;
+            }
+            ("field", Some(field), Some(offset), Some(size)) => {
+                if let Shape::Fields(declared) = shape(&layout.ty, definitions) {
+                    let (name, ty) = declared[field].clone();
+                    layout.fields.push(Field {
+                        name,
+                        ty,
+                        offset,
+                        size,
+                        align: number(5).unwrap_or(1),
+                    });
+                }
+            }
+            ("array", Some(len), Some(size), Some(align)) => {
+                if let Shape::Array { element, .. } = shape(&layout.ty, definitions) {
+                    layout.fields = (0..len)
+                        .map(|index| Field {
+                            name: format!("[{index}]"),
+                            ty: element.clone(),
+                            offset: index * size,
+                            size,
+                            align,
+                        })
+                        .collect();
+                }
+            }
+            _ => {}
+        }
+    }
+    for layout in &mut layouts {
+        if !layout.has_fields {
+            layout.fields = vec![Field {
+                name: layout.ty.clone(),
+                ty: layout.ty.clone(),
+                offset: 0,
+                size: layout.size,
+                align: layout.align,
+            }];
+        }
+        let declared = layout.fields.clone();
+        layout
+            .fields
+            .sort_by_key(|field| (field.offset, field.size));
+        layout.reordered = layout.fields != declared;
+    }
+    layouts
+}
+
+impl Layout {
+    /// The gaps between the fields and after the last, as offset and size.
+    pub fn padding(&self) -> Vec<(usize, usize)> {
+        let mut padding = Vec::new();
+        let mut end = 0;
+        for field in &self.fields {
+            if field.offset > end {
+                padding.push((end, field.offset - end));
+            }
+            end = end.max(field.offset + field.size);
+        }
+        if self.size > end {
+            padding.push((end, self.size - end));
+        }
+        padding
+    }
+
+    /// The fields and padding in offset order, as offset, size and the
+    /// index of the field.
+    fn parts(&self) -> Vec<(usize, usize, Option<usize>)> {
+        let mut parts: Vec<(usize, usize, Option<usize>)> = self
+            .fields
+            .iter()
+            .enumerate()
+            .map(|(index, field)| (field.offset, field.size, Some(index)))
+            .collect();
+        parts.extend(
+            self.padding()
+                .into_iter()
+                .map(|(offset, size)| (offset, size, None)),
+        );
+        parts.sort_by_key(|(offset, size, _)| (*offset, *size));
+        parts
+    }
+}
+
+fn letter(index: usize) -> char {
+    const LETTERS: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+    LETTERS[index % LETTERS.len()] as char
+}
+
+fn bytes(count: usize) -> String {
+    if count == 1 {
+        "1 byte".to_string()
+    } else {
+        format!("{count} bytes")
+    }
+}
+
+impl fmt::Display for Layout {
+    /// A table of the fields and padding, then a diagram of the bytes,
+    /// one letter per field and `.` for padding.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "{}: {}, aligned to {}",
+            self.ty,
+            bytes(self.size),
+            self.align
+        )?;
+        let parts = self.parts();
+        let is_array =
+            self.has_fields && self.fields.iter().all(|field| field.name.starts_with('['));
+        let shown =
+            |index: usize| !is_array || index < MAX_ELEMENTS - 1 || index + 1 == self.fields.len();
+        if self.has_fields {
+            let header = ["offset", "size", "align", "field"].map(String::from);
+            let mut rows = Vec::new();
+            for (offset, size, part) in &parts {
+                let row = match part {
+                    Some(index) => {
+                        // Long arrays keep their first and last elements.
+                        if !shown(*index) {
+                            if rows.last().is_some_and(|row: &Vec<String>| row[3] != "…") {
+                                rows.push(vec![
+                                    String::new(),
+                                    String::new(),
+                                    String::new(),
+                                    "…".to_string(),
+                                ]);
+                            }
+                            continue;
+                        }
+                        let field = &self.fields[*index];
+                        let name = if is_array {
+                            field.name.clone()
+                        } else {
+                            format!("{}: {}", field.name, field.ty)
+                        };
+                        vec![
+                            offset.to_string(),
+                            size.to_string(),
+                            field.align.to_string(),
+                            name,
+                        ]
+                    }
+                    None => vec![
+                        offset.to_string(),
+                        size.to_string(),
+                        String::new(),
+                        "padding".to_string(),
+                    ],
+                };
+                rows.push(row);
+            }
+            let mut out = String::new();
+            text::table(&mut out, &header, &rows);
+            write!(f, "\n{out}")?;
+        }
+
+        writeln!(f)?;
+        if self.size > MAX_DRAWN {
+            return writeln!(f, "({} are too many to draw)", bytes(self.size));
+        }
+        let mut map = vec!['.'; self.size];
+        for (offset, size, part) in &parts {
+            if let Some(index) = part {
+                for byte in &mut map[*offset..(*offset + *size).min(self.size)] {
+                    *byte = letter(*index);
+                }
+            }
+        }
+        for (row, chunk) in map.chunks(ROW).enumerate() {
+            let chunk: String = chunk.iter().collect();
+            writeln!(f, "{:>6}  {chunk}", row * ROW)?;
+        }
+        let mut legend: Vec<String> = Vec::new();
+        for (index, field) in self.fields.iter().enumerate() {
+            if shown(index) {
+                legend.push(format!("{} {}", letter(index), field.name));
+            } else if legend.last().is_some_and(|last| last != "…") {
+                legend.push("…".to_string());
+            }
+        }
+        if !self.padding().is_empty() {
+            legend.push(". padding".to_string());
+        }
+        if self.size > 0 {
+            writeln!(f, "{}", legend.join("   "))?;
+        }
+        if self.reordered {
+            writeln!(
+                f,
+                "The fields are not stored in the order they are written in; \
+                 #[repr(C)] would keep them in order."
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// A line of code without its `//` comment.
+fn code_of(line: &str) -> &str {
+    split_line_comment(line).map_or(line, |(code, _)| code)
+}
+
+/// Splits `text` at the `separator`s outside brackets, trimming the parts
+/// and dropping empty ones.
+fn split_top_level(text: &str, separator: char) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut depth = 0i32;
+    let mut start = 0;
+    let mut previous = ' ';
+    for (index, c) in text.char_indices() {
+        match c {
+            '(' | '[' | '<' | '{' => depth += 1,
+            // The `>` of `->` closes nothing.
+            '>' if previous == '-' => {}
+            ')' | ']' | '>' | '}' => depth -= 1,
+            _ if c == separator && depth == 0 => {
+                parts.push(&text[start..index]);
+                start = index + c.len_utf8();
+            }
+            _ => {}
+        }
+        previous = c;
+    }
+    parts.push(&text[start..]);
+    parts
+        .into_iter()
+        .map(str::trim)
+        .filter(|part| !part.is_empty())
+        .collect()
+}
@@ -0,0 +1,874 @@
+//! Parsing of a lesson file into prose and code.
+//!
+//! Lessons interleave Rust code with `/* ... */` block comments that carry
+//! the text of the lesson. Each physical line is classified as prose (inside
+//! a block comment) or code, and the lesson conventions are read from that:
+//! `=` / `-` underlined headings, `Output:` blocks, pasted compiler errors
+//! and the example snippets quoted in the prose or commented out with `//`.
+//!
+//! Snippets in the prose may be fenced with ```` ```rust,ignore ```` and the
+//! other rustdoc attributes to say how they are checked.
+
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// One physical line of a lesson.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Line {
+    /// 1-based line number in the file.
+    pub number: usize,
+    pub kind: LineKind,
+    /// For prose, the line without the enclosing `/*` and `*/`; for code,
+    /// the line as written.
+    pub text: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LineKind {
+    Prose,
+    Code,
+}
+
+/// A heading underlined with `=` (level 1) or `-` (level 2).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Heading {
+    pub line: usize,
+    pub level: u8,
+    pub text: String,
+}
+
+/// An `Output:` block documenting what a piece of code prints.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OutputBlock {
+    /// Line of the `Output:` header.
+    pub line: usize,
+    /// Last line of the block: the header itself for inline output.
+    pub end: usize,
+    /// `false` when the code before the block is a snippet that is not
+    /// run (a commented-out infinite loop, for instance).
+    pub checked: bool,
+    pub expected: Vec<String>,
+}
+
+/// Where an example snippet was found.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Origin {
+    /// Quoted inside a block comment.
+    Prose,
+    /// Commented out line by line with `//`.
+    Commented,
+}
+
+/// Example code that is not part of the lesson's own program: a complete
+/// `fn main() { ... }`, a few items, or a run of statements.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Snippet {
+    pub start: usize,
+    pub end: usize,
+    pub origin: Origin,
+    pub code: String,
+    /// How the snippet is checked, when its ```` ``` ```` fence says so.
+    pub mode: Option<Mode>,
+}
+
+/// How a snippet is checked, after rustdoc's code block attributes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Mode {
+    /// Compiled and run, which must succeed.
+    Run,
+    /// Compiled only (`no_run`).
+    CompileOnly,
+    /// Must fail to compile (`compile_fail`), with the given error code.
+    CompileFail(Option<String>),
+    /// Not checked (`ignore`): templates and fragments.
+    Ignore,
+}
+
+/// What an `Output:` block documents.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputSource<'a> {
+    /// The lesson file itself, run as a program.
+    Lesson,
+    /// An example program quoted in the prose or commented out.
+    Program(&'a Snippet),
+    /// One of the lesson's example modules, run on its own.
+    Example(&'a Example),
+}
+
+/// A `mod <name> { pub fn main() { ... } }` module of the lesson code.
+///
+/// Lessons showing several complete programs keep each one in its own
+/// module, and their `main` runs the examples one after the other. An
+/// example is run alone by compiling its module as a program of its own.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Example {
+    pub name: String,
+    /// Lines of the `mod` header and of its closing brace.
+    pub start: usize,
+    pub end: usize,
+}
+
+/// A compiler error pasted into the prose, e.g.
+/// `error[E0384]: cannot assign twice to immutable variable `x``.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    pub line: usize,
+    /// The error code, `E0384`.
+    pub code: String,
+    pub message: String,
+}
+
+impl fmt::Display for Mode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Mode::Run => f.write_str("run"),
+            Mode::CompileOnly => f.write_str("compile-only"),
+            Mode::CompileFail(None) => f.write_str("compile-fail"),
+            Mode::CompileFail(Some(code)) => write!(f, "compile-fail {code}"),
+            Mode::Ignore => f.write_str("ignore"),
+        }
+    }
+}
+
+impl Snippet {
+    /// Whether the snippet is a whole program with its own `fn main`.
+    pub fn is_program(&self) -> bool {
+        self.code.contains("fn main(")
+    }
+
+    /// The snippet as a compilable program, see [`as_program`].
+    pub fn program(&self) -> String {
+        as_program(&self.code)
+    }
+}
+
+/// `code` as a compilable program: items get an empty `main`, statements
+/// are wrapped in one, and whole programs are left as they are.
+pub fn as_program(code: &str) -> String {
+    const ITEMS: [&str; 8] = [
+        "fn ", "struct ", "enum ", "impl ", "trait ", "use ", "mod ", "#[",
+    ];
+    if code.contains("fn main(") {
+        return code.to_string();
+    }
+    let first = code
+        .lines()
+        .map(str::trim)
+        .find(|line| !line.is_empty() && !line.starts_with("//"))
+        .unwrap_or("");
+    if ITEMS.iter().any(|item| first.starts_with(item)) {
+        return format!("{code}\nfn main() {{}}\n");
+    }
+    let body: String = code
+        .lines()
+        .map(|line| match line {
+            "" => "\n".to_string(),
+            line => format!("    {line}\n"),
+        })
+        .collect();
+    format!("fn main() {{\n{body}}}\n")
+}
+
+/// A `print!`/`println!` call with a trailing `// ...` comment showing what
+/// it prints, e.g. `println!("x = {x}"); // 4`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PrintComment {
+    pub line: usize,
+    /// The code before the comment.
+    pub code: String,
+    pub comment: String,
+}
+
+/// A parsed lesson file.
+#[derive(Debug, Clone)]
+pub struct LessonSource {
+    pub lines: Vec<Line>,
+}
+
+impl LessonSource {
+    pub fn read(path: impl AsRef<Path>) -> io::Result<LessonSource> {
+        Ok(LessonSource::parse(&fs::read_to_string(path)?))
+    }
+
+    pub fn parse(source: &str) -> LessonSource {
+        let mut scanner = Scanner::default();
+        let lines = source
+            .lines()
+            .enumerate()
+            .map(|(index, raw)| scanner.line(index + 1, raw))
+            .collect();
+        LessonSource { lines }
+    }
+
+    /// Line `number` (1-based).
+    pub fn line(&self, number: usize) -> &Line {
+        &self.lines[number - 1]
+    }
+
+    /// All `=` and `-` underlined headings, in file order.
+    pub fn headings(&self) -> Vec<Heading> {
+        let mut headings = Vec::new();
+        for pair in self.lines.windows(2) {
+            let (text, underline) = (&pair[0], &pair[1]);
+            if text.kind != LineKind::Prose || underline.kind != LineKind::Prose {
+                continue;
+            }
+            if let Some(level) = heading_level(text.text.trim(), underline.text.trim()) {
+                headings.push(Heading {
+                    line: text.number,
+                    level,
+                    text: text.text.trim().to_string(),
+                });
+            }
+        }
+        headings
+    }
+
+    /// The lesson title: its first `=` underlined heading.
+    pub fn title(&self) -> Option<String> {
+        self.headings()
+            .into_iter()
+            .find(|heading| heading.level == 1)
+            .map(|heading| heading.text)
+    }
+
+    /// Every `Output:` block, in file order.
+    pub fn output_blocks(&self) -> Vec<OutputBlock> {
+        let snippets = self.snippets();
+        let diagnostics = self.diagnostics();
+        let mut blocks = Vec::new();
+        for (index, line) in self.lines.iter().enumerate() {
+            let Some(inline) = output_header(line) else {
+                continue;
+            };
+            let (expected, end) = match inline {
+                Some(inline) => (vec![inline], line.number),
+                None => self.block_after(index + 1),
+            };
+            blocks.push(OutputBlock {
+                line: line.number,
+                end,
+                checked: !self.follows_ignored_snippet(index, &snippets, &diagnostics),
+                expected,
+            });
+        }
+        blocks
+    }
+
+    /// Whether the code right before the line at `index` is a snippet that
+    /// is not run, so that there is nothing to check its output against.
+    fn follows_ignored_snippet(
+        &self,
+        index: usize,
+        snippets: &[Snippet],
+        diagnostics: &[Diagnostic],
+    ) -> bool {
+        let line = self.lines[index].number;
+        let code = self.lines[..index]
+            .iter()
+            .rev()
+            .find(|line| is_live_code(line))
+            .map_or(0, |line| line.number);
+        snippets
+            .iter()
+            .rev()
+            .find(|snippet| snippet.end < line)
+            .is_some_and(|snippet| {
+                snippet.start > code
+                    && self.snippet_mode(snippet, snippets, diagnostics) == Mode::Ignore
+            })
+    }
+
+    /// Lines following an `Output:` header: an optional `-----` underline
+    /// and blank lines are skipped, then everything up to the next blank
+    /// line is the expected output. Also returns the number of the last
+    /// line taken.
+    fn block_after(&self, mut index: usize) -> (Vec<String>, usize) {
+        let prose = |index: usize| {
+            self.lines
+                .get(index)
+                .filter(|line| line.kind == LineKind::Prose)
+        };
+        if prose(index).is_some_and(|line| is_rule(line.text.trim(), '-')) {
+            index += 1;
+        }
+        while prose(index).is_some_and(|line| line.text.trim().is_empty()) {
+            index += 1;
+        }
+
+        let mut block = Vec::new();
+        while let Some(line) = prose(index) {
+            if line.text.trim().is_empty() {
+                break;
+            }
+            block.push(line.text.trim_end().to_string());
+            index += 1;
+        }
+        let indent = common_indent(block.iter().map(String::as_str));
+        let block = block
+            .iter()
+            .map(|line| line[indent..].to_string())
+            .collect();
+        // `index` is the 0-based index of the line after the block, which is
+        // the 1-based number of its last line.
+        (block, index)
+    }
+
+    /// Example code quoted in the prose or commented out with `//`, in
+    /// file order.
+    pub fn snippets(&self) -> Vec<Snippet> {
+        let mut snippets = Vec::new();
+        let mut index = 0;
+        while index < self.lines.len() {
+            if let Some((mode, close)) = self.fence_at(index) {
+                // Code blocks in another language are skipped entirely.
+                if let Some(mode) = mode {
+                    snippets.extend(self.fenced_snippet(index, close, mode));
+                }
+                index = close + 1;
+                continue;
+            }
+            match self.snippet_at(index) {
+                Some(snippet) => {
+                    index = snippet.end;
+                    snippets.push(snippet);
+                }
+                None => index += 1,
+            }
+        }
+        snippets
+    }
+
+    /// The snippets that are complete `fn main() { ... }` programs.
+    pub fn programs(&self) -> Vec<Snippet> {
+        self.snippets()
+            .into_iter()
+            .filter(Snippet::is_program)
+            .collect()
+    }
+
+    /// How `snippet` is checked: as marked by its fence, else against the
+    /// compiler error pasted after it, by running it when it is a program
+    /// and by compiling other prose code. Other commented-out code was
+    /// disabled on purpose and is left alone.
+    pub fn snippet_mode(
+        &self,
+        snippet: &Snippet,
+        snippets: &[Snippet],
+        diagnostics: &[Diagnostic],
+    ) -> Mode {
+        if let Some(mode) = &snippet.mode {
+            return mode.clone();
+        }
+        let pasted = diagnostics
+            .iter()
+            .find(|diagnostic| self.diagnostic_source(diagnostic, snippets) == Some(snippet));
+        match (pasted, snippet.origin) {
+            (Some(diagnostic), _) => Mode::CompileFail(Some(diagnostic.code.clone())),
+            _ if snippet.is_program() => Mode::Run,
+            (None, Origin::Prose) => Mode::CompileOnly,
+            (None, Origin::Commented) => Mode::Ignore,
+        }
+    }
+
+    /// A ```` ``` ```` fence opening at `index` in the prose: what its info
+    /// string says (see [`fence_mode`]) and the index of the closing fence.
+    fn fence_at(&self, index: usize) -> Option<(Option<Option<Mode>>, usize)> {
+        let line = &self.lines[index];
+        let info = line.text.trim().strip_prefix("```")?;
+        if line.kind != LineKind::Prose {
+            return None;
+        }
+        let close = self.lines[index + 1..]
+            .iter()
+            .take_while(|line| line.kind == LineKind::Prose)
+            .position(|line| line.text.trim() == "```")?;
+        Some((fence_mode(info), index + 1 + close))
+    }
+
+    fn fenced_snippet(&self, open: usize, close: usize, mode: Option<Mode>) -> Option<Snippet> {
+        let body = &self.lines[open + 1..close];
+        let indent = common_indent(body.iter().map(|line| line.text.as_str()));
+        let code: String = body
+            .iter()
+            .map(|line| format!("{}\n", line.text.get(indent..).unwrap_or("").trim_end()))
+            .collect();
+        (!code.trim().is_empty()).then(|| Snippet {
+            start: self.lines[open + 1].number,
+            end: self.lines[close - 1].number,
+            origin: Origin::Prose,
+            code,
+            mode,
+        })
+    }
+
+    fn snippet_at(&self, index: usize) -> Option<Snippet> {
+        match self.lines[index].kind {
+            LineKind::Prose => self.prose_snippet_at(index),
+            LineKind::Code => self.commented_snippet_at(index),
+        }
+    }
+
+    /// Code quoted in a block comment: a line opening an item or statement,
+    /// followed by the lines up to its closing brace, or by more code lines.
+    fn prose_snippet_at(&self, index: usize) -> Option<Snippet> {
+        const OPENINGS: [&str; 14] = [
+            "fn ",
+            "let ",
+            "const ",
+            "struct ",
+            "enum ",
+            "impl ",
+            "#[",
+            "if ",
+            "while ",
+            "for ",
+            "loop ",
+            "match ",
+            "print!(",
+            "println!(",
+        ];
+        let first = &self.lines[index];
+        let opening = first.text.trim();
+        let head = split_line_comment(opening).map_or(opening, |(code, _)| code);
+        let head = head.trim_end();
+        if !OPENINGS.iter().any(|word| opening.starts_with(word))
+            || !(head.ends_with('{') || head.ends_with(';'))
+        {
+            return None;
+        }
+
+        let mut depth = brace_delta(&first.text);
+        let mut end = index;
+        for line in &self.lines[index + 1..] {
+            if line.kind != LineKind::Prose || (depth <= 0 && !is_code_like(&line.text)) {
+                break;
+            }
+            depth += brace_delta(&line.text);
+            end = line.number - 1;
+        }
+        if depth != 0 {
+            return None;
+        }
+
+        let body = &self.lines[index..=end];
+        let indent = common_indent(body.iter().map(|line| line.text.as_str()));
+        let code = body
+            .iter()
+            .map(|line| line.text.get(indent..).unwrap_or("").trim_end())
+            .collect::<Vec<_>>()
+            .join("\n");
+        Some(Snippet {
+            start: first.number,
+            end: self.lines[end].number,
+            origin: Origin::Prose,
+            code: code + "\n",
+            mode: None,
+        })
+    }
+
+    /// Code commented out with `//`: a run of `//` and blank lines, without
+    /// the plain comments before and after the code.
+    fn commented_snippet_at(&self, index: usize) -> Option<Snippet> {
+        let run: Vec<(usize, String)> = self.lines[index..]
+            .iter()
+            .map_while(|line| match example_text(line, Origin::Commented) {
+                Some(text) => Some((line.number, text)),
+                None if line.kind == LineKind::Code && line.text.trim().is_empty() => {
+                    Some((line.number, String::new()))
+                }
+                None => None,
+            })
+            .collect();
+        let first = run.iter().position(|(_, text)| is_code_like(text))?;
+        let last = run.iter().rposition(|(_, text)| is_code_like(text))?;
+        let body = &run[first..=last];
+
+        let indent = common_indent(body.iter().map(|(_, text)| text.as_str()));
+        let code = body
+            .iter()
+            .map(|(_, text)| text.get(indent..).unwrap_or("").trim_end())
+            .collect::<Vec<_>>()
+            .join("\n");
+        Some(Snippet {
+            start: body[0].0,
+            end: body[body.len() - 1].0,
+            origin: Origin::Commented,
+            code: code + "\n",
+            mode: None,
+        })
+    }
+
+    /// The example modules of the lesson code, in file order.
+    pub fn examples(&self) -> Vec<Example> {
+        let mut examples = Vec::new();
+        let mut open: Option<(String, usize, bool)> = None;
+        for line in self.lines.iter().filter(|line| line.kind == LineKind::Code) {
+            if let Some((name, start, has_main)) = &mut open {
+                *has_main |= line.text.trim_start().starts_with("pub fn main()");
+                if line.text.trim_end() == "}" {
+                    if *has_main {
+                        examples.push(Example {
+                            name: name.clone(),
+                            start: *start,
+                            end: line.number,
+                        });
+                    }
+                    open = None;
+                }
+                continue;
+            }
+            let name = line
+                .text
+                .strip_prefix("mod ")
+                .and_then(|rest| rest.trim_end().strip_suffix('{'))
+                .map(str::trim);
+            if let Some(name) = name {
+                open = Some((name.to_string(), line.number, false));
+            }
+        }
+        examples
+    }
+
+    /// Decides what the `Output:` block at `block` documents: the closest
+    /// example program before it, unless lesson code comes in between. Lesson
+    /// code inside an example module documents that example alone.
+    pub fn output_source<'a>(
+        &self,
+        block: &OutputBlock,
+        programs: &'a [Snippet],
+        examples: &'a [Example],
+    ) -> Option<OutputSource<'a>> {
+        let program = programs
+            .iter()
+            .rev()
+            .find(|program| program.end < block.line);
+        let code = self.lines[..block.line - 1]
+            .iter()
+            .rev()
+            .find(|line| is_live_code(line))
+            .map(|line| line.number);
+
+        let lesson = |code: usize| {
+            examples
+                .iter()
+                .find(|example| (example.start..=example.end).contains(&code))
+                .map_or(OutputSource::Lesson, OutputSource::Example)
+        };
+        match (program, code) {
+            (Some(program), Some(code)) if program.end < code => Some(lesson(code)),
+            (Some(program), _) => Some(OutputSource::Program(program)),
+            (None, Some(code)) => Some(lesson(code)),
+            (None, None) => None,
+        }
+    }
+
+    /// `print!`/`println!` calls in the lesson code followed by a comment
+    /// with the text they print.
+    pub fn print_comments(&self) -> Vec<PrintComment> {
+        self.lines
+            .iter()
+            .filter(|line| is_live_code(line))
+            .filter_map(|line| {
+                let (code, comment) = split_line_comment(&line.text)?;
+                let prints = code.contains("print!(") || code.contains("println!(");
+                let comment = comment.trim();
+                (prints && !comment.is_empty()).then(|| PrintComment {
+                    line: line.number,
+                    code: code.trim().to_string(),
+                    comment: comment.to_string(),
+                })
+            })
+            .collect()
+    }
+
+    /// Compiler errors pasted into the prose, in file order.
+    pub fn diagnostics(&self) -> Vec<Diagnostic> {
+        self.lines
+            .iter()
+            .filter(|line| line.kind == LineKind::Prose)
+            .filter_map(|line| {
+                let rest = line.text.trim().strip_prefix("error[")?;
+                let (code, message) = rest.split_once("]:")?;
+                Some(Diagnostic {
+                    line: line.number,
+                    code: code.to_string(),
+                    message: message.trim().to_string(),
+                })
+            })
+            .collect()
+    }
+
+    /// The snippet a pasted compiler error was produced from: the closest
+    /// one before it.
+    pub fn diagnostic_source<'a>(
+        &self,
+        diagnostic: &Diagnostic,
+        snippets: &'a [Snippet],
+    ) -> Option<&'a Snippet> {
+        snippets
+            .iter()
+            .rev()
+            .find(|snippet| snippet.end < diagnostic.line)
+    }
+}
+
+/// Tracks block comment nesting and string literals across lines.
+#[derive(Debug, Default)]
+struct Scanner {
+    depth: usize,
+}
+
+impl Scanner {
+    fn line(&mut self, number: usize, raw: &str) -> Line {
+        let started_in_comment = self.depth > 0;
+        let mut prose = String::new();
+        let mut has_code = false;
+        let mut touched_comment = started_in_comment;
+        let mut in_string = false;
+        let chars: Vec<char> = raw.chars().collect();
+        let mut i = 0;
+
+        while i < chars.len() {
+            let c = chars[i];
+            let next = chars.get(i + 1).copied();
+            if self.depth > 0 {
+                match (c, next) {
+                    ('/', Some('*')) => {
+                        self.depth += 1;
+                        prose.push_str("/*");
+                        i += 2;
+                    }
+                    ('*', Some('/')) => {
+                        self.depth -= 1;
+                        if self.depth > 0 {
+                            prose.push_str("*/");
+                        }
+                        i += 2;
+                    }
+                    _ => {
+                        prose.push(c);
+                        i += 1;
+                    }
+                }
+                continue;
+            }
+
+            if in_string {
+                match c {
+                    '\\' => i += 2,
+                    '"' => {
+                        in_string = false;
+                        i += 1;
+                    }
+                    _ => i += 1,
+                }
+                continue;
+            }
+
+            match (c, next) {
+                ('/', Some('/')) => {
+                    has_code = true;
+                    break;
+                }
+                ('/', Some('*')) => {
+                    self.depth = 1;
+                    touched_comment = true;
+                    // Keep the column so indentation of the prose survives.
+                    prose.extend(std::iter::repeat_n(' ', i + 2));
+                    i += 2;
+                }
+                ('"', _) => {
+                    has_code = true;
+                    in_string = true;
+                    i += 1;
+                }
+                ('\'', _) if chars.get(i + 2) == Some(&'\'') => {
+                    has_code = true;
+                    i += 3;
+                }
+                _ => {
+                    has_code |= !c.is_whitespace();
+                    i += 1;
+                }
+            }
+        }
+
+        if touched_comment && !has_code {
+            Line {
+                number,
+                kind: LineKind::Prose,
+                text: prose.trim_end().to_string(),
+            }
+        } else {
+            Line {
+                number,
+                kind: LineKind::Code,
+                text: raw.to_string(),
+            }
+        }
+    }
+}
+
+/// Code that is part of the lesson program: not blank, not a `//` comment.
+pub fn is_live_code(line: &Line) -> bool {
+    let text = line.text.trim();
+    line.kind == LineKind::Code && !text.is_empty() && !text.starts_with("//")
+}
+
+/// The text of `line` as part of an example with the given origin: prose
+/// lines as they are, commented-out lines without their leading `//`.
+fn example_text(line: &Line, origin: Origin) -> Option<String> {
+    match (origin, line.kind) {
+        (Origin::Prose, LineKind::Prose) => Some(line.text.clone()),
+        (Origin::Commented, LineKind::Code) => {
+            let indent = indent_of(&line.text);
+            let rest = line.text[indent..].strip_prefix("//")?;
+            let rest = rest.strip_prefix(' ').unwrap_or(rest);
+            Some(format!("{}{rest}", &line.text[..indent]))
+        }
+        _ => None,
+    }
+}
+
+/// Reads the info string of a code fence, `rust,ignore` or
+/// `compile_fail,E0425` for instance: `None` when the block is not Rust,
+/// otherwise the mode its attributes ask for, if any.
+fn fence_mode(info: &str) -> Option<Option<Mode>> {
+    let mut mode = None;
+    let mut code = None;
+    for attribute in info.split(',').map(str::trim) {
+        match attribute {
+            "" | "rust" => {}
+            "ignore" => mode = Some(Mode::Ignore),
+            "no_run" => mode = Some(Mode::CompileOnly),
+            "compile_fail" => mode = Some(Mode::CompileFail(None)),
+            _ if is_error_code(attribute) => code = Some(attribute.to_string()),
+            _ => return None,
+        }
+    }
+    Some(match mode {
+        Some(Mode::CompileFail(_)) => Some(Mode::CompileFail(code)),
+        mode => mode,
+    })
+}
+
+/// `E0425` and the like.
+fn is_error_code(text: &str) -> bool {
+    text.len() == 5 && text.starts_with('E') && text[1..].bytes().all(|b| b.is_ascii_digit())
+}
+
+/// A line that reads as a complete piece of code: a statement or a line
+/// opening or closing a block, rather than a remark.
+fn is_code_like(text: &str) -> bool {
+    let code = split_line_comment(text)
+        .map_or(text, |(code, _)| code)
+        .trim();
+    !code.is_empty() && (code.ends_with(';') || code.ends_with('{') || code.ends_with('}'))
+}
+
+/// Opened minus closed braces on `line`, outside strings and comments.
+pub fn brace_delta(line: &str) -> i32 {
+    let code = split_line_comment(line).map_or(line, |(code, _)| code);
+    let mut depth = 0;
+    let mut in_string = false;
+    let mut escaped = false;
+    for c in code.chars() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' if in_string => escaped = true,
+            '"' => in_string = !in_string,
+            '{' if !in_string => depth += 1,
+            '}' if !in_string => depth -= 1,
+            _ => {}
+        }
+    }
+    depth
+}
+
+/// Splits `code // comment` at the first `//` outside a string literal.
+pub fn split_line_comment(line: &str) -> Option<(&str, &str)> {
+    let mut in_string = false;
+    let mut escaped = false;
+    for (index, c) in line.char_indices() {
+        if in_string {
+            match c {
+                _ if escaped => escaped = false,
+                '\\' => escaped = true,
+                '"' => in_string = false,
+                _ => {}
+            }
+        } else if c == '"' {
+            in_string = true;
+        } else if line[index..].starts_with("//") {
+            return Some((&line[..index], &line[index + 2..]));
+        }
+    }
+    None
+}
+
+/// Recognises `Output:`, `Output` and `Output: <text>` headers, returning
+/// any output written on the header line itself.
+fn output_header(line: &Line) -> Option<Option<String>> {
+    let text = match line.kind {
+        LineKind::Prose => line.text.trim(),
+        LineKind::Code => line.text.trim().strip_prefix("//")?.trim(),
+    };
+    let rest = text.strip_prefix("Output")?.trim();
+    if rest.is_empty() || rest == ":" {
+        return Some(None);
+    }
+    let inline = rest.strip_prefix(':')?.trim();
+    Some(Some(inline.to_string()))
+}
+
+/// `Some(1)` for a `===` underline and `Some(2)` for `---`, when the
+/// underline roughly spans the text above it.
+fn heading_level(text: &str, underline: &str) -> Option<u8> {
+    let level = if is_rule(underline, '=') {
+        1
+    } else if is_rule(underline, '-') {
+        2
+    } else {
+        return None;
+    };
+    // Table rows use runs of spaces between columns, sentences end with a
+    // full stop and `Output:` blocks are underlined too; none is a heading.
+    if text.is_empty()
+        || text.contains("  ")
+        || text.ends_with('.')
+        || is_rule(text, '-')
+        || output_header(&Line {
+            number: 0,
+            kind: LineKind::Prose,
+            text: text.to_string(),
+        })
+        .is_some()
+    {
+        return None;
+    }
+    let (text_len, rule_len) = (text.chars().count(), underline.chars().count());
+    (rule_len + 4 >= text_len && rule_len <= text_len + 12).then_some(level)
+}
+
+/// A line made only of `c`, at least three long.
+pub fn is_rule(text: &str, c: char) -> bool {
+    text.len() >= 3 && text.chars().all(|x| x == c)
+}
+
+/// Number of leading whitespace bytes of `text`.
+pub fn indent_of(text: &str) -> usize {
+    text.len() - text.trim_start().len()
+}
+
+/// Smallest indentation among the non-blank `lines`.
+pub fn common_indent<'a>(lines: impl IntoIterator<Item = &'a str>) -> usize {
+    lines
+        .into_iter()
+        .filter(|line| !line.trim().is_empty())
+        .map(indent_of)
+        .min()
+        .unwrap_or(0)
+}
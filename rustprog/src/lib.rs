@@ -0,0 +1,33 @@
+//! Tooling around the Rust_Programiz.com lessons: discovering the numbered
+//! chapter and lesson files, running them through Cargo and checking that
+//! what the lessons document matches what their code does.
+
+pub mod args;
+pub mod cli;
+pub mod compile;
+pub mod course;
+pub mod document;
+pub mod error;
+pub mod grade;
+pub mod html;
+pub mod i18n;
+pub mod json;
+pub mod layout;
+pub mod lesson;
+pub mod lint;
+pub mod manifest;
+pub mod mdbook;
+pub mod notebook;
+pub mod playground;
+pub mod progress;
+pub mod quiz;
+pub mod runner;
+pub mod search;
+pub mod serve;
+pub mod site;
+pub mod text;
+pub mod trace;
+pub mod verify;
+
+pub use course::{Chapter, Course, Lesson};
+pub use error::{Error, Result};
@@ -0,0 +1,417 @@
+//! Checks that the lessons follow the course's conventions.
+//!
+//! A lesson opens with its title underlined with `=`, has sections
+//! underlined with `=` or `-`, keeps its code in `fn main` and documents
+//! what the code prints in `Output:` blocks. Chapters and lessons are
+//! numbered from 1 without gaps, and so are numbered headings (`1. Performance`,
+//! `2. Memory Safety`, ...).
+//!
+//! Errors break what the other commands rely on; warnings are naming and
+//! layout inconsistencies.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::course::{split_number, Chapter, Course};
+use crate::lesson::{Heading, LessonSource, LineKind};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+    Warning,
+    Error,
+}
+
+/// One problem found by the linter.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Finding {
+    /// File or directory, relative to the course root.
+    pub path: PathBuf,
+    pub line: Option<usize>,
+    pub severity: Severity,
+    /// Short name of the rule, e.g. `title`.
+    pub rule: &'static str,
+    pub message: String,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Severity::Warning => "warning",
+            Severity::Error => "error",
+        })
+    }
+}
+
+/// `path:line: severity: message [rule]`, the way compilers report.
+impl fmt::Display for Finding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.path.display())?;
+        if let Some(line) = self.line {
+            write!(f, ":{line}")?;
+        }
+        write!(f, ": {}: {} [{}]", self.severity, self.message, self.rule)
+    }
+}
+
+/// Collects the findings of one file.
+struct Findings<'a> {
+    path: &'a Path,
+    found: Vec<Finding>,
+}
+
+impl Findings<'_> {
+    fn add(
+        &mut self,
+        line: Option<usize>,
+        severity: Severity,
+        rule: &'static str,
+        message: String,
+    ) {
+        self.found.push(Finding {
+            path: self.path.to_path_buf(),
+            line,
+            severity,
+            rule,
+            message,
+        });
+    }
+}
+
+/// Lints the numbering of the chapters.
+pub fn lint_course(course: &Course) -> Vec<Finding> {
+    let mut findings = Findings {
+        path: Path::new("."),
+        found: Vec::new(),
+    };
+    let numbers: Vec<u32> = course
+        .chapters
+        .iter()
+        .map(|chapter| chapter.number)
+        .collect();
+    for message in numbering_gaps(&numbers, "chapter") {
+        findings.add(None, Severity::Error, "numbering", message);
+    }
+    findings.found
+}
+
+/// Lints the names and numbering of a chapter's directory and lesson files,
+/// but not the lessons themselves.
+pub fn lint_chapter(course: &Course, chapter: &Chapter) -> Vec<Finding> {
+    let path = PathBuf::from(&chapter.dir_name);
+    let mut findings = Findings {
+        path: &path,
+        found: Vec::new(),
+    };
+
+    // The package name was written by hand and spells what the chapter is.
+    let words = |name: &str| -> Vec<String> {
+        name.split('_')
+            .filter(|word| !word.is_empty() && !word.eq_ignore_ascii_case("rust"))
+            .filter(|word| {
+                !(word.starts_with("ch") && word[2..].bytes().all(|b| b.is_ascii_digit()))
+            })
+            .map(str::to_lowercase)
+            .collect()
+    };
+    let dir_words = split_number(&chapter.dir_name).map_or_else(Vec::new, |(_, name)| words(name));
+    if dir_words != words(&chapter.package) {
+        findings.add(
+            None,
+            Severity::Warning,
+            "chapter-name",
+            format!(
+                "directory name '{}' does not match its package name '{}'",
+                chapter.dir_name, chapter.package
+            ),
+        );
+    }
+
+    let numbers: Vec<u32> = chapter.lessons.iter().map(|lesson| lesson.number).collect();
+    for message in numbering_gaps(&numbers, "lesson") {
+        findings.add(None, Severity::Error, "numbering", message);
+    }
+    for lesson in &chapter.lessons {
+        let name = split_number(&lesson.stem).map_or("", |(_, name)| name);
+        if !name.starts_with("Rust_") {
+            findings.found.push(Finding {
+                path: lesson.relative_path(course),
+                line: None,
+                severity: Severity::Warning,
+                rule: "file-name",
+                message: format!(
+                    "lesson file '{}.rs' is not named _{:02}_Rust_<topic>.rs",
+                    lesson.stem, lesson.number
+                ),
+            });
+        }
+    }
+    findings.found
+}
+
+/// Lints the lesson file at `path` in the course at `root`.
+pub fn lint_file(root: &Path, path: &Path) -> io::Result<Vec<Finding>> {
+    let source = LessonSource::read(path)?;
+    Ok(lint_lesson(
+        path.strip_prefix(root).unwrap_or(path),
+        &source,
+    ))
+}
+
+/// Lints a parsed lesson, reporting its findings against `path`.
+pub fn lint_lesson(path: &Path, source: &LessonSource) -> Vec<Finding> {
+    let mut findings = Findings {
+        path,
+        found: Vec::new(),
+    };
+    let headings = source.headings();
+    check_title(&mut findings, source, &headings);
+    check_headings(&mut findings, source, &headings);
+    check_numbered_headings(&mut findings, &headings);
+    check_main(&mut findings, source);
+    check_output_blocks(&mut findings, source);
+    findings.found.sort_by_key(|finding| finding.line);
+    findings.found
+}
+
+/// The first thing in the file is the title, underlined with `=`, and it
+/// names the lesson the way the file name does.
+fn check_title(findings: &mut Findings, source: &LessonSource, headings: &[Heading]) {
+    let first = source
+        .lines
+        .iter()
+        .find(|line| !line.text.trim().is_empty());
+    let Some(first) = first else {
+        findings.add(
+            None,
+            Severity::Error,
+            "title",
+            "the lesson is empty".to_string(),
+        );
+        return;
+    };
+    let title = headings
+        .first()
+        .filter(|heading| heading.line == first.number);
+    let Some(title) = title else {
+        findings.add(
+            Some(first.number),
+            Severity::Error,
+            "title",
+            "the lesson does not start with a title underlined with '='".to_string(),
+        );
+        return;
+    };
+    if title.level != 1 {
+        findings.add(
+            Some(title.line),
+            Severity::Error,
+            "title",
+            format!(
+                "the title '{}' is underlined with '-' instead of '='",
+                title.text
+            ),
+        );
+    }
+
+    let stem = findings
+        .path
+        .file_stem()
+        .and_then(|stem| stem.to_str())
+        .unwrap_or("");
+    let name = split_number(stem).map_or(stem, |(_, name)| name);
+    let title_words: Vec<String> = title
+        .text
+        .split(|c: char| !c.is_alphanumeric())
+        .map(str::to_lowercase)
+        .collect();
+    let missing: Vec<&str> = name
+        .split('_')
+        .filter(|word| !word.is_empty() && !title_words.contains(&word.to_lowercase()))
+        .collect();
+    if !missing.is_empty() {
+        findings.add(
+            Some(title.line),
+            Severity::Warning,
+            "title",
+            format!(
+                "the title '{}' does not name the lesson like its file ('{}' is missing)",
+                title.text,
+                missing.join(" ")
+            ),
+        );
+    }
+}
+
+/// Underlines span their heading, sections are not empty and no two
+/// headings read the same.
+fn check_headings(findings: &mut Findings, source: &LessonSource, headings: &[Heading]) {
+    let mut seen: BTreeMap<String, usize> = BTreeMap::new();
+    for (index, heading) in headings.iter().enumerate() {
+        let underline = source.line(heading.line + 1).text.trim();
+        let (text_len, rule_len) = (heading.text.chars().count(), underline.chars().count());
+        if rule_len < text_len {
+            findings.add(
+                Some(heading.line + 1),
+                Severity::Warning,
+                "heading",
+                format!(
+                    "the underline of '{}' is {} characters short",
+                    heading.text,
+                    text_len - rule_len
+                ),
+            );
+        }
+
+        if let Some(first) = seen.insert(heading.text.to_lowercase(), heading.line) {
+            findings.add(
+                Some(heading.line),
+                Severity::Warning,
+                "heading",
+                format!(
+                    "the heading '{}' repeats the one on line {first}",
+                    heading.text
+                ),
+            );
+        }
+
+        // A section followed straight away by a section of the same or a
+        // higher level has nothing in it; the title may lead straight into
+        // the first section.
+        let next = headings.get(index + 1).filter(|_| index > 0);
+        if let Some(next) = next.filter(|next| next.level <= heading.level) {
+            let empty = source.lines[heading.line + 1..next.line - 1]
+                .iter()
+                .all(|line| line.text.trim().is_empty());
+            if empty {
+                findings.add(
+                    Some(heading.line),
+                    Severity::Warning,
+                    "heading",
+                    format!("the section '{}' is empty", heading.text),
+                );
+            }
+        }
+    }
+}
+
+/// Numbered headings count up from 1 under the same parent section.
+fn check_numbered_headings(findings: &mut Findings, headings: &[Heading]) {
+    // The number expected next at each level, once a numbered run started.
+    let mut expected: [Option<u32>; 2] = [None, None];
+    let mut previous: Option<(&Heading, u32)> = None;
+    for heading in headings {
+        let level = usize::from(heading.level) - 1;
+        // A heading ends the runs below it.
+        expected[level + 1..].fill(None);
+        let Some(number) = heading_number(&heading.text) else {
+            expected[level] = None;
+            continue;
+        };
+        // `2.` right after `1.` continues its run, whatever the underline.
+        if let Some((before, _)) =
+            previous.filter(|(before, at)| before.level != heading.level && at + 1 == number)
+        {
+            findings.add(
+                Some(heading.line),
+                Severity::Error,
+                "numbering",
+                format!(
+                    "'{}' is underlined with '{}' but '{}' on line {} with '{}'",
+                    heading.text,
+                    underline_char(heading.level),
+                    before.text,
+                    before.line,
+                    underline_char(before.level)
+                ),
+            );
+            expected[level] = Some(number + 1);
+            previous = Some((heading, number));
+            continue;
+        }
+        previous = Some((heading, number));
+        let want = expected[level].unwrap_or(1);
+        if number != want {
+            findings.add(
+                Some(heading.line),
+                Severity::Error,
+                "numbering",
+                format!("heading number {number} should be {want}"),
+            );
+        }
+        expected[level] = Some(number + 1);
+    }
+}
+
+fn underline_char(level: u8) -> char {
+    if level == 1 {
+        '='
+    } else {
+        '-'
+    }
+}
+
+/// `2` for `2. Memory Safety`.
+fn heading_number(text: &str) -> Option<u32> {
+    let (digits, rest) = text.split_once('.')?;
+    let number = digits.parse().ok()?;
+    rest.starts_with(' ').then_some(number)
+}
+
+/// Every lesson is a program with a top-level `main`.
+fn check_main(findings: &mut Findings, source: &LessonSource) {
+    let has_main = source.lines.iter().any(|line| {
+        line.kind == LineKind::Code
+            && (line.text.starts_with("fn main(") || line.text.starts_with("pub fn main("))
+    });
+    if !has_main {
+        findings.add(
+            None,
+            Severity::Error,
+            "main",
+            "the lesson has no top-level fn main".to_string(),
+        );
+    }
+}
+
+/// `Output:` blocks follow the code they document and are not empty.
+fn check_output_blocks(findings: &mut Findings, source: &LessonSource) {
+    let programs = source.programs();
+    let examples = source.examples();
+    for block in source.output_blocks() {
+        if block.expected.iter().all(|line| line.trim().is_empty()) {
+            findings.add(
+                Some(block.line),
+                Severity::Error,
+                "output",
+                "the Output block is empty".to_string(),
+            );
+        } else if source.output_source(&block, &programs, &examples).is_none() {
+            findings.add(
+                Some(block.line),
+                Severity::Error,
+                "output",
+                "the Output block follows no code".to_string(),
+            );
+        }
+    }
+}
+
+/// Chapter or lesson numbers that do not run 1, 2, 3, ...
+fn numbering_gaps(numbers: &[u32], what: &str) -> Vec<String> {
+    let mut messages = Vec::new();
+    let mut want = 1;
+    for (index, &number) in numbers.iter().enumerate() {
+        if index > 0 && numbers[index - 1] == number {
+            messages.push(format!("two {what}s are numbered {number}"));
+            continue;
+        }
+        if number > want {
+            let missing: Vec<String> = (want..number).map(|n| n.to_string()).collect();
+            messages.push(format!("{what} numbering skips {}", missing.join(", ")));
+        }
+        want = number + 1;
+    }
+    messages
+}
@@ -0,0 +1,14 @@
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    match rustprog::cli::run(std::env::args().skip(1)) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("rustprog: {err}");
+            if let rustprog::Error::Usage(_) = err {
+                eprintln!("\n{}", rustprog::cli::USAGE);
+            }
+            ExitCode::FAILURE
+        }
+    }
+}
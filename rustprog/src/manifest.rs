@@ -0,0 +1,181 @@
+//! A machine-readable description of the course, `course.json`.
+//!
+//! The manifest lists the chapters and, for every lesson, what the other
+//! tools otherwise find by scanning the tree: its title and sections, its
+//! code, its `Output:` blocks, the compiler errors it quotes and where its
+//! exercises and quiz live. [`SCHEMA`] describes the format as a JSON
+//! Schema, written next to the manifest as `course.schema.json`.
+
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use crate::course::{Course, Lesson, COURSE_TITLE};
+use crate::grade;
+use crate::json::Value;
+use crate::lesson::{is_live_code, LessonSource, Mode, OutputSource};
+use crate::quiz;
+
+/// Version of the manifest format; bumped when a field changes meaning.
+pub const VERSION: u64 = 1;
+
+/// The JSON Schema of `course.json`.
+pub const SCHEMA: &str = include_str!("course.schema.json");
+
+/// File name the schema is written under, as referenced by `$schema`.
+pub const SCHEMA_FILE: &str = "course.schema.json";
+
+/// Writes `course.json` and its schema into `out`, returning the number of
+/// lessons listed.
+pub fn write(course: &Course, out: &Path) -> io::Result<usize> {
+    fs::create_dir_all(out)?;
+    let manifest = manifest(course)?;
+    fs::write(out.join("course.json"), manifest.pretty())?;
+    fs::write(out.join(SCHEMA_FILE), SCHEMA)?;
+    Ok(course.lessons().count())
+}
+
+/// The manifest of `course`.
+pub fn manifest(course: &Course) -> io::Result<Value> {
+    let chapters = course
+        .chapters
+        .iter()
+        .map(|chapter| {
+            object(vec![
+                ("number", chapter.number.into()),
+                ("title", chapter.name().into()),
+                ("directory", chapter.dir_name.as_str().into()),
+                ("package", chapter.package.as_str().into()),
+                (
+                    "lessons",
+                    Value::from(chapter.lessons.iter().map(Lesson::id).collect::<Vec<_>>()),
+                ),
+            ])
+        })
+        .collect();
+    let lessons = course
+        .lessons()
+        .map(|lesson| lesson_entry(course, lesson))
+        .collect::<io::Result<Vec<_>>>()?;
+    Ok(object(vec![
+        ("$schema", SCHEMA_FILE.into()),
+        ("version", VERSION.into()),
+        ("title", COURSE_TITLE.into()),
+        ("chapters", Value::Array(chapters)),
+        ("lessons", Value::Array(lessons)),
+    ]))
+}
+
+fn lesson_entry(course: &Course, lesson: &Lesson) -> io::Result<Value> {
+    let source = LessonSource::read(&lesson.path)?;
+    let headings = source.headings();
+    let title = source.title();
+    // The title is the first heading; the others are the sections.
+    let sections = headings
+        .iter()
+        .skip(usize::from(title.is_some()))
+        .map(|heading| {
+            object(vec![
+                ("line", heading.line.into()),
+                ("level", u32::from(heading.level).into()),
+                ("title", heading.text.as_str().into()),
+            ])
+        })
+        .collect();
+
+    let snippets = source.snippets();
+    let programs = source.programs();
+    let examples = source.examples();
+    let outputs = source
+        .output_blocks()
+        .iter()
+        .map(|block| {
+            let documents = match source.output_source(block, &programs, &examples) {
+                Some(OutputSource::Lesson) => "lesson".into(),
+                Some(OutputSource::Program(program)) => format!("line {}", program.start).into(),
+                Some(OutputSource::Example(example)) => format!("example {}", example.name).into(),
+                None => Value::Null,
+            };
+            object(vec![
+                ("line", block.line.into()),
+                ("checked", block.checked.into()),
+                ("documents", documents),
+                ("expected", block.expected.clone().into()),
+            ])
+        })
+        .collect();
+
+    let mut error_codes: Vec<String> = Vec::new();
+    let quoted = source
+        .diagnostics()
+        .into_iter()
+        .map(|diagnostic| diagnostic.code);
+    let expected = snippets.iter().filter_map(|snippet| match &snippet.mode {
+        Some(Mode::CompileFail(Some(code))) => Some(code.clone()),
+        _ => None,
+    });
+    for code in quoted.chain(expected) {
+        if !error_codes.contains(&code) {
+            error_codes.push(code);
+        }
+    }
+
+    let relative = |path: &Path| -> Value {
+        if path.is_file() {
+            path.strip_prefix(&course.root)
+                .unwrap_or(path)
+                .to_string_lossy()
+                .replace('\\', "/")
+                .into()
+        } else {
+            Value::Null
+        }
+    };
+    let code = object(vec![
+        (
+            "lines",
+            source
+                .lines
+                .iter()
+                .filter(|line| is_live_code(line))
+                .count()
+                .into(),
+        ),
+        (
+            "examples",
+            Value::from(
+                examples
+                    .iter()
+                    .map(|example| example.name.as_str())
+                    .collect::<Vec<_>>(),
+            ),
+        ),
+        ("snippets", snippets.len().into()),
+        // The lesson program, or each of its examples, plus every snippet.
+        ("units", (examples.len().max(1) + snippets.len()).into()),
+    ]);
+
+    Ok(object(vec![
+        ("id", lesson.id().into()),
+        ("chapter", lesson.chapter.into()),
+        ("order", lesson.number.into()),
+        ("path", relative(&lesson.path)),
+        ("bin", lesson.bin_name().into()),
+        ("title", title.unwrap_or_else(|| lesson.name()).into()),
+        ("sections", Value::Array(sections)),
+        ("code", code),
+        ("outputs", Value::Array(outputs)),
+        ("error_codes", error_codes.into()),
+        ("exercises", relative(&grade::exercise_path(course, lesson))),
+        ("quiz", relative(&quiz::quiz_path(course, lesson))),
+    ]))
+}
+
+fn object(members: Vec<(&str, Value)>) -> Value {
+    Value::Object(
+        members
+            .into_iter()
+            .map(|(key, value)| (key.to_string(), value))
+            .collect(),
+    )
+}
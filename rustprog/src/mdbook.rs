@@ -0,0 +1,351 @@
+//! Exports the course as the source of an mdBook.
+//!
+//! The book has the layout mdBook expects: `book.toml` at the top and the
+//! pages under `src/`, with `SUMMARY.md` listing the chapters and their
+//! lessons in numbering order. Each chapter directory gets a `README.md`
+//! and every lesson `_0M_<Name>.rs` becomes `_0M_<Name>.md` next to it.
+//!
+//! Code blocks carry rustdoc attributes, so `mdbook test` checks the quoted
+//! snippets the way `rustprog verify` does. Example modules are written out
+//! as the `fn main` programs they wrap, ready for the playground. The pieces
+//! of a lesson's `main` split up by prose come without its `fn main` line,
+//! and rustdoc wraps each of them in a `main` of its own: a piece runs
+//! unless it uses a name declared by an earlier piece or leaves a block
+//! open, in which case it is marked `ignore`.
+
+use std::collections::HashSet;
+use std::fmt::Write as _;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use crate::course::Course;
+use crate::document::{Block, CodeBlock, Document};
+use crate::i18n::Translation;
+use crate::lesson::{brace_delta, common_indent, Mode};
+use crate::trace;
+
+/// Writes the book for `course` into `out`, returning the number of pages.
+pub fn export(course: &Course, out: &Path) -> io::Result<usize> {
+    export_translated(course, &Translation::source(), out)
+}
+
+/// Writes the book in the language of `translation`.
+pub fn export_translated(
+    course: &Course,
+    translation: &Translation,
+    out: &Path,
+) -> io::Result<usize> {
+    let src = out.join("src");
+    fs::create_dir_all(&src)?;
+    let course_title = translation.course_title();
+    fs::write(
+        out.join("book.toml"),
+        format!(
+            "[book]\ntitle = \"{}\"\nlanguage = \"{}\"\nsrc = \"src\"\n\n\
+             [rust]\nedition = \"2021\"\n",
+            course_title.replace('\\', "\\\\").replace('"', "\\\""),
+            translation.language()
+        ),
+    )?;
+
+    let mut summary = format!("# Summary\n\n[{}](README.md)\n\n", escape(course_title));
+    let mut contents = format!("# {}\n\n", escape(course_title));
+    let mut pages = 1;
+    for chapter in &course.chapters {
+        let dir = src.join(&chapter.dir_name);
+        fs::create_dir_all(&dir)?;
+        let title = translation.chapter_name(chapter);
+        let _ = writeln!(
+            summary,
+            "- [{}]({}/README.md)",
+            escape(&title),
+            chapter.dir_name
+        );
+        let _ = writeln!(
+            contents,
+            "{}. [{}]({}/README.md)",
+            chapter.number,
+            escape(&title),
+            chapter.dir_name
+        );
+
+        let mut lessons = String::new();
+        for lesson in &chapter.lessons {
+            let document = translation.document(lesson, &Document::read(&lesson.path)?);
+            let title = document.title.clone().unwrap_or_else(|| lesson.name());
+            let _ = writeln!(
+                summary,
+                "    - [{}]({}/{}.md)",
+                escape(&title),
+                chapter.dir_name,
+                lesson.stem
+            );
+            let _ = writeln!(
+                lessons,
+                "{}. [{}]({}.md)",
+                lesson.number,
+                escape(&title),
+                lesson.stem
+            );
+            fs::write(
+                dir.join(format!("{}.md", lesson.stem)),
+                lesson_page(&title, &document, translation),
+            )?;
+            pages += 1;
+        }
+        fs::write(dir.join("README.md"), chapter_page(&title, &lessons))?;
+        pages += 1;
+    }
+
+    fs::write(src.join("SUMMARY.md"), summary)?;
+    fs::write(src.join("README.md"), contents)?;
+    Ok(pages)
+}
+
+/// mdBook numbers the chapters and lessons itself, so titles go without.
+fn chapter_page(title: &str, lessons: &str) -> String {
+    format!("# {}\n\n{lessons}", escape(title))
+}
+
+fn lesson_page(title: &str, document: &Document, translation: &Translation) -> String {
+    let has_examples = document.blocks.iter().any(|block| {
+        matches!(block, Block::Code(code) if code.live && example_program(&code.code).is_some())
+    });
+    let mut page = format!("# {}\n", escape(title));
+    let mut pieces = Pieces::new(has_examples);
+    for block in &document.blocks {
+        page.push('\n');
+        render_block(&mut page, block, &mut pieces, translation);
+    }
+    page
+}
+
+pub(crate) fn render_block(
+    out: &mut String,
+    block: &Block,
+    pieces: &mut Pieces,
+    translation: &Translation,
+) {
+    match block {
+        Block::Heading { level, text } => {
+            let _ = writeln!(
+                out,
+                "{} {}",
+                "#".repeat(usize::from(*level) + 1),
+                escape(text)
+            );
+        }
+        Block::Paragraph(text) => {
+            let _ = writeln!(out, "{}", escape_line_start(&escape(text)));
+        }
+        Block::List(items) => {
+            for item in items {
+                let _ = writeln!(out, "- {}", escape(item));
+            }
+        }
+        Block::Table { header, rows } => {
+            let _ = writeln!(out, "{}", table_row(header));
+            let _ = writeln!(out, "|{}", "---|".repeat(header.len()));
+            for row in rows {
+                let _ = writeln!(out, "{}", table_row(row));
+            }
+        }
+        Block::Code(block) => {
+            let (info, code) = code_block(block, pieces);
+            fenced(out, &info, &code);
+        }
+        Block::Output(lines) => {
+            let _ = writeln!(out, "**{}**\n", escape(translation.text("Output")));
+            fenced(out, "text", &lines.join("\n"));
+        }
+        Block::Preformatted(text) => fenced(out, "text", text),
+        Block::Rule => out.push_str("---\n"),
+    }
+}
+
+/// The info string and code of a code block: rustdoc attributes for Rust,
+/// and example modules unwrapped into their `fn main` program.
+fn code_block(block: &CodeBlock, pieces: &mut Pieces) -> (String, String) {
+    if block.language != "rust" {
+        return (block.language.clone(), block.code.clone());
+    }
+    if !block.live {
+        let info = match &block.mode {
+            None | Some(Mode::Run) => "rust".to_string(),
+            Some(Mode::CompileOnly) => "rust,no_run".to_string(),
+            Some(Mode::CompileFail(None)) => "rust,compile_fail".to_string(),
+            Some(Mode::CompileFail(Some(code))) => format!("rust,compile_fail,{code}"),
+            Some(Mode::Ignore) => "rust,ignore".to_string(),
+        };
+        return (info, block.code.clone());
+    }
+    if let Some(program) = example_program(&block.code) {
+        return ("rust".to_string(), program);
+    }
+    // With example modules, the lesson's own `main` only runs them.
+    let runs = if is_program(&block.code) {
+        !pieces.has_examples
+    } else {
+        pieces.stands_alone(&block.code) && !pieces.has_examples
+    };
+    let info = if runs { "rust" } else { "rust,ignore" };
+    (info.to_string(), block.code.clone())
+}
+
+/// The pieces of a lesson's `main` met so far, to tell the ones that run
+/// on their own from the ones that need the code before them.
+pub(crate) struct Pieces {
+    has_examples: bool,
+    /// Names declared by the earlier pieces.
+    declared: HashSet<String>,
+}
+
+impl Pieces {
+    pub(crate) fn new(has_examples: bool) -> Pieces {
+        Pieces {
+            has_examples,
+            declared: HashSet::new(),
+        }
+    }
+
+    /// Whether `code` closes every block it opens and only uses names it
+    /// declares itself or that the earlier pieces do not declare. The
+    /// names `code` declares are then remembered for the next pieces.
+    fn stands_alone(&mut self, code: &str) -> bool {
+        let mut own = HashSet::new();
+        let mut depth = 0;
+        let mut alone = true;
+        for line in code.lines() {
+            depth += brace_delta(line);
+            alone &= depth >= 0;
+            let (used, declared) = names(trace::code_of(line).trim());
+            alone &= used
+                .iter()
+                .all(|name| own.contains(name) || !self.declared.contains(name));
+            own.extend(declared);
+        }
+        self.declared.extend(own);
+        alone && depth == 0
+    }
+}
+
+/// The names a line of code uses and the names it declares, as far as a
+/// `let`, a `for` loop or an item goes.
+pub(crate) fn names(code: &str) -> (Vec<String>, Vec<String>) {
+    if let Some(rest) = code.strip_prefix("let ") {
+        let end = rest.find(['=', ':', ';']).unwrap_or(rest.len());
+        return (
+            trace::identifiers(&rest[end..]),
+            trace::declared_pattern(&rest[..end]),
+        );
+    }
+    if let Some(pattern) = trace::for_pattern(code) {
+        let iterator = &code["for ".len() + pattern.len()..];
+        return (
+            trace::identifiers(iterator),
+            trace::declared_pattern(pattern),
+        );
+    }
+    if code.starts_with("use ") {
+        return (Vec::new(), trace::identifiers(code));
+    }
+    let item = code.strip_prefix("pub ").unwrap_or(code);
+    let mut words = item.splitn(2, ' ');
+    let keyword = words.next().unwrap_or("");
+    let rest = words.next().unwrap_or("");
+    let is_item = matches!(
+        keyword,
+        "fn" | "struct" | "enum" | "trait" | "type" | "const" | "static" | "mod" | "macro_rules!"
+    );
+    let name = trace::identifiers(rest).into_iter().next();
+    let Some(name) = name.filter(|_| is_item) else {
+        return (trace::identifiers(code), Vec::new());
+    };
+    if keyword != "fn" {
+        return (trace::identifiers(&rest[name.len()..]), vec![name]);
+    }
+    // The parameters of a function are its own.
+    let (params, returns) = rest.split_once(')').unwrap_or((rest, ""));
+    let mut declared = trace::declared_pattern(params);
+    declared.push(name);
+    (trace::identifiers(returns), declared)
+}
+
+/// The program wrapped by an example module `mod <name> { pub fn main() {
+/// ... } }`: its body, dedented, with a private `fn main`.
+pub(crate) fn example_program(code: &str) -> Option<String> {
+    let lines: Vec<&str> = code.lines().collect();
+    let (first, rest) = lines.split_first()?;
+    let (last, body) = rest.split_last()?;
+    first.strip_prefix("mod ")?.trim_end().strip_suffix('{')?;
+    if *last != "}" {
+        return None;
+    }
+    let indent = common_indent(body.iter().copied());
+    let mut has_main = false;
+    let program: Vec<String> = body
+        .iter()
+        .map(|line| {
+            let line = line.get(indent..).unwrap_or("");
+            match line.strip_prefix("pub fn main()") {
+                Some(rest) => {
+                    has_main = true;
+                    format!("fn main(){rest}")
+                }
+                None => line.to_string(),
+            }
+        })
+        .collect();
+    has_main.then(|| program.join("\n"))
+}
+
+/// Whether `code` is a whole program: a top-level `main` and balanced braces.
+pub(crate) fn is_program(code: &str) -> bool {
+    let has_main = code
+        .lines()
+        .any(|line| line.starts_with("fn main(") || line.starts_with("pub fn main("));
+    has_main && code.lines().map(brace_delta).sum::<i32>() == 0
+}
+
+/// Writes `code` in a fence long enough not to be closed by the code itself.
+pub(crate) fn fenced(out: &mut String, info: &str, code: &str) {
+    let longest = code.split(|c| c != '`').map(str::len).max().unwrap_or(0);
+    let fence = "`".repeat(longest.max(2) + 1);
+    let _ = writeln!(out, "{fence}{info}\n{code}\n{fence}");
+}
+
+fn table_row(cells: &[String]) -> String {
+    let cells: Vec<String> = cells.iter().map(|cell| escape(cell)).collect();
+    format!("| {} |", cells.join(" | "))
+}
+
+/// Escapes the characters of `text` that Markdown would take for markup.
+pub(crate) fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    let mut previous = ' ';
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        let next = chars.peek().copied().unwrap_or(' ');
+        let markup = match c {
+            '\\' | '`' | '*' | '[' | ']' | '<' | '>' | '|' => true,
+            // Underscores inside words are never emphasis.
+            '_' => !previous.is_alphanumeric() || !next.is_alphanumeric(),
+            _ => false,
+        };
+        if markup {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+        previous = c;
+    }
+    escaped
+}
+
+/// Keeps a paragraph from being read as a heading or a list.
+fn escape_line_start(text: &str) -> String {
+    match text.chars().next() {
+        Some('#' | '+' | '-' | '=') => format!("\\{text}"),
+        _ => text.to_string(),
+    }
+}
@@ -0,0 +1,421 @@
+//! Exports the lessons as Jupyter notebooks for the evcxr Rust kernel.
+//!
+//! The prose becomes Markdown cells, rendered the way the mdBook export
+//! renders it. The code of a lesson's `fn main` becomes code cells of
+//! top-level statements, which evcxr runs one after the other in a single
+//! session, so a cell sees the variables of the ones before it. Functions
+//! declared inside `main` are declared at the top level of their cell.
+//!
+//! Example modules and the snippets in the prose that run on their own
+//! get a cell each, with their `main` unwrapped the same way. Snippets
+//! that are not meant to run (they do not compile, or never stop) stay in
+//! the Markdown as code blocks, and so do programs whose `main` returns a
+//! value, since their `return` and `?` only make sense inside `main`. A
+//! piece of `main` that prose splits in the middle of a block is kept in
+//! one cell, with the prose after it.
+//!
+//! evcxr keeps variables between cells only when they own their data;
+//! a cell that borrows a variable of an earlier cell may need to be run
+//! together with it. A closure cannot be kept at all, as its type has no
+//! name, so the code that defines one and the code that uses it go in
+//! the same cell, with the prose between them after it.
+
+use std::collections::HashSet;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::course::{Course, Lesson};
+use crate::document::{Block, CodeBlock, Document};
+use crate::i18n::Translation;
+use crate::json::Value;
+use crate::lesson::{brace_delta, common_indent, Mode};
+use crate::mdbook;
+use crate::trace;
+
+/// Where the notebook of `lesson` is written under `out`:
+/// `<chapter dir>/<stem>.ipynb`.
+pub fn notebook_path(course: &Course, lesson: &Lesson, out: &Path) -> PathBuf {
+    out.join(&course.chapter_of(lesson).dir_name)
+        .join(format!("{}.ipynb", lesson.stem))
+}
+
+/// Writes the notebooks of `lessons` into `out`.
+pub fn export(course: &Course, lessons: &[&Lesson], out: &Path) -> io::Result<()> {
+    for lesson in lessons {
+        let document = Document::read(&lesson.path)?;
+        let title = document.title.clone().unwrap_or_else(|| lesson.name());
+        let path = notebook_path(course, lesson, out);
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(path, notebook(&title, &document).pretty())?;
+    }
+    Ok(())
+}
+
+/// A cell of a notebook.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Cell {
+    Markdown(String),
+    Code(String),
+}
+
+/// The notebook of a lesson, in nbformat 4.
+pub fn notebook(title: &str, document: &Document) -> Value {
+    let cells = cells(title, document)
+        .iter()
+        .enumerate()
+        .map(|(index, cell)| {
+            let (kind, text) = match cell {
+                Cell::Markdown(text) => ("markdown", text),
+                Cell::Code(code) => ("code", code),
+            };
+            let mut members = vec![
+                ("cell_type", kind.into()),
+                ("id", format!("cell-{}", index + 1).into()),
+                ("metadata", object(Vec::new())),
+                ("source", source_lines(text)),
+            ];
+            if let Cell::Code(_) = cell {
+                members.push(("execution_count", Value::Null));
+                members.push(("outputs", Value::Array(Vec::new())));
+            }
+            object(members)
+        })
+        .collect();
+    object(vec![
+        ("cells", Value::Array(cells)),
+        (
+            "metadata",
+            object(vec![
+                (
+                    "kernelspec",
+                    object(vec![
+                        ("display_name", "Rust".into()),
+                        ("language", "rust".into()),
+                        ("name", "rust".into()),
+                    ]),
+                ),
+                (
+                    "language_info",
+                    object(vec![
+                        ("codemirror_mode", "rust".into()),
+                        ("file_extension", ".rs".into()),
+                        ("mimetype", "text/rust".into()),
+                        ("name", "Rust".into()),
+                        ("pygment_lexer", "rust".into()),
+                        ("version", "".into()),
+                    ]),
+                ),
+            ]),
+        ),
+        ("nbformat", 4u32.into()),
+        ("nbformat_minor", 5u32.into()),
+    ])
+}
+
+/// The cells of a lesson, starting with its title.
+pub fn cells(title: &str, document: &Document) -> Vec<Cell> {
+    let has_examples = document.blocks.iter().any(|block| {
+        matches!(block, Block::Code(code) if code.live && mdbook::example_program(&code.code).is_some())
+    });
+    let mut cells = Cells {
+        cells: Vec::new(),
+        markdown: format!("# {}\n", mdbook::escape(title)),
+        code: Vec::new(),
+        deferred: String::new(),
+        closures: Vec::new(),
+        depth: 0,
+        main: false,
+        // With example modules, the lesson's own `main` only runs them.
+        skip_main: has_examples,
+    };
+    for block in &document.blocks {
+        match block {
+            Block::Code(code) if code.live => match mdbook::example_program(&code.code) {
+                Some(program) if !cells.is_open() => cells.push_code(&program_body(&program)),
+                _ => cells.live_code(&code.code),
+            },
+            Block::Code(code) => match runnable(code) {
+                Some(body) if !cells.is_open() => cells.push_code(&body),
+                _ => {
+                    let mut text = String::new();
+                    mdbook::fenced(&mut text, &code.language, &code.code);
+                    cells.markdown(&text);
+                }
+            },
+            block => {
+                let mut text = String::new();
+                mdbook::render_block(
+                    &mut text,
+                    block,
+                    &mut mdbook::Pieces::new(has_examples),
+                    &Translation::source(),
+                );
+                cells.markdown(&text);
+            }
+        }
+    }
+    cells.finish()
+}
+
+/// Cells under construction.
+struct Cells {
+    cells: Vec<Cell>,
+    /// Markdown to go in the next Markdown cell.
+    markdown: String,
+    /// Lines of the lesson code in the cell being built.
+    code: Vec<String>,
+    /// Markdown met while that code had a block open, to go after it.
+    deferred: String,
+    /// Closures bound by the lesson code, with the cell that binds them.
+    closures: Vec<(String, usize)>,
+    /// Brace depth of the lesson code so far.
+    depth: i32,
+    /// Inside the lesson's top-level `main`.
+    main: bool,
+    /// Drop the body of the lesson's `main`.
+    skip_main: bool,
+}
+
+impl Cells {
+    /// Whether lesson code has a block open that the next piece of code
+    /// closes.
+    fn is_open(&self) -> bool {
+        self.depth != i32::from(self.main)
+    }
+
+    fn markdown(&mut self, text: &str) {
+        let markdown = if self.is_open() {
+            &mut self.deferred
+        } else {
+            &mut self.markdown
+        };
+        if !markdown.is_empty() {
+            markdown.push('\n');
+        }
+        markdown.push_str(text);
+    }
+
+    fn flush_markdown(&mut self) {
+        let text = std::mem::take(&mut self.markdown);
+        if !text.trim().is_empty() {
+            self.cells.push(Cell::Markdown(text.trim_end().to_string()));
+        }
+    }
+
+    /// A cell of code that stands on its own.
+    fn push_code(&mut self, code: &str) {
+        if code.trim().is_empty() {
+            return;
+        }
+        self.flush_markdown();
+        self.cells.push(Cell::Code(code.to_string()));
+    }
+
+    /// A piece of the lesson program: `fn main` is unwrapped, and a cell
+    /// is complete once its blocks are closed again.
+    fn live_code(&mut self, code: &str) {
+        for line in code.lines() {
+            let opens_main = line.starts_with("fn main(") || line.starts_with("pub fn main(");
+            if !self.main && self.depth == 0 && opens_main {
+                self.main = true;
+                self.depth += brace_delta(line);
+                continue;
+            }
+            self.depth += brace_delta(line);
+            if self.main && self.depth == 0 {
+                self.main = false;
+                continue;
+            }
+            if self.main && self.skip_main {
+                continue;
+            }
+            if self.code.is_empty() && line.trim().is_empty() {
+                continue;
+            }
+            self.code.push(line.to_string());
+        }
+        if !self.is_open() {
+            let lines: Vec<&str> = self.code.iter().map(String::as_str).collect();
+            let code = dedent(&lines);
+            self.code.clear();
+            self.push_lesson_code(&code);
+            let deferred = std::mem::take(&mut self.deferred);
+            if !deferred.is_empty() {
+                self.markdown(&deferred);
+            }
+        }
+    }
+
+    /// A cell of the lesson program. Code that uses a closure of an
+    /// earlier cell joins that cell, and the prose between them follows.
+    fn push_lesson_code(&mut self, code: &str) {
+        if code.trim().is_empty() {
+            return;
+        }
+        let (used, declared, closures) = closure_names(code);
+        let first = self
+            .closures
+            .iter()
+            .filter(|(name, _)| used.contains(name))
+            .map(|&(_, cell)| cell)
+            .min();
+        if let Some(first) = first {
+            let mut joined = Vec::new();
+            let mut markdown = Vec::new();
+            for cell in self.cells.drain(first..) {
+                match cell {
+                    Cell::Code(code) => joined.push(code),
+                    Cell::Markdown(text) => markdown.push(text),
+                }
+            }
+            joined.push(code.to_string());
+            let pending = std::mem::take(&mut self.markdown);
+            if !pending.trim().is_empty() {
+                markdown.push(pending);
+            }
+            self.cells.push(Cell::Code(joined.join("\n\n")));
+            self.markdown = markdown.join("\n\n");
+            for (_, cell) in &mut self.closures {
+                *cell = (*cell).min(first);
+            }
+        } else {
+            self.push_code(code);
+        }
+        let cell = self.cells.len() - 1;
+        self.closures.retain(|(name, _)| !declared.contains(name));
+        self.closures
+            .extend(closures.into_iter().map(|name| (name, cell)));
+    }
+
+    fn finish(mut self) -> Vec<Cell> {
+        if !self.code.is_empty() {
+            let lines: Vec<&str> = self.code.iter().map(String::as_str).collect();
+            let code = dedent(&lines);
+            self.code.clear();
+            self.push_lesson_code(&code);
+        }
+        let deferred = std::mem::take(&mut self.deferred);
+        self.markdown.push_str(&deferred);
+        self.flush_markdown();
+        self.cells
+    }
+}
+
+/// The names `code` uses without declaring them first, the names it
+/// declares, and the ones of those bound to a closure.
+fn closure_names(code: &str) -> (HashSet<String>, HashSet<String>, Vec<String>) {
+    let mut used = HashSet::new();
+    let mut declared = HashSet::new();
+    let mut closures = Vec::new();
+    for line in code.lines() {
+        let line = trace::code_of(line).trim();
+        let (uses, declares) = mdbook::names(line);
+        used.extend(uses.into_iter().filter(|name| !declared.contains(name)));
+        declared.extend(declares);
+        closures.extend(closure_binding(line));
+    }
+    (used, declared, closures)
+}
+
+/// The name of `let <name> = |...| ...`, a closure bound to a variable.
+fn closure_binding(line: &str) -> Option<String> {
+    let (pattern, value) = line.strip_prefix("let ")?.split_once('=')?;
+    let value = value.trim_start();
+    let value = value.strip_prefix("move").unwrap_or(value).trim_start();
+    if !value.starts_with('|') {
+        return None;
+    }
+    let pattern = pattern.split(':').next().unwrap_or("");
+    let mut names = trace::declared_pattern(pattern);
+    (names.len() == 1).then(|| names.remove(0))
+}
+
+/// The code of a snippet quoted in the prose, ready for a cell, if it is
+/// meant to run.
+fn runnable(block: &CodeBlock) -> Option<String> {
+    if block.language != "rust" || block.mode != Some(Mode::Run) {
+        return None;
+    }
+    if main_returns_value(&block.code) {
+        return None;
+    }
+    Some(if mdbook::is_program(&block.code) {
+        program_body(&block.code)
+    } else {
+        block.code.clone()
+    })
+}
+
+/// Whether the program declares `fn main() -> T`, such as a `main` that
+/// returns a `Result`.
+fn main_returns_value(code: &str) -> bool {
+    code.lines().any(|line| {
+        let opens_main = line.starts_with("fn main(") || line.starts_with("pub fn main(");
+        opens_main && line.contains("->")
+    })
+}
+
+/// A program as top-level statements: the body of its `fn main`, after
+/// whatever else the program declares.
+fn program_body(program: &str) -> String {
+    let mut items = Vec::new();
+    let mut body = Vec::new();
+    let mut depth = 0;
+    let mut in_main = false;
+    for line in program.lines() {
+        let opens_main = line.starts_with("fn main(") || line.starts_with("pub fn main(");
+        let before = depth;
+        depth += brace_delta(line);
+        if before == 0 && opens_main {
+            in_main = true;
+        } else if in_main && depth == 0 {
+            in_main = false;
+        } else if in_main {
+            body.push(line);
+        } else {
+            items.push(line);
+        }
+    }
+    let mut code = dedent(&items);
+    let body = dedent(&body);
+    if !code.is_empty() && !body.is_empty() {
+        code.push_str("\n\n");
+    }
+    code.push_str(&body);
+    code
+}
+
+/// `lines` without their common indentation and surrounding blank lines.
+fn dedent(lines: &[&str]) -> String {
+    let first = lines.iter().position(|line| !line.trim().is_empty());
+    let last = lines.iter().rposition(|line| !line.trim().is_empty());
+    let (Some(first), Some(last)) = (first, last) else {
+        return String::new();
+    };
+    let lines = &lines[first..=last];
+    let indent = common_indent(lines.iter().copied());
+    lines
+        .iter()
+        .map(|line| line.get(indent..).unwrap_or("").trim_end())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Cell source as nbformat stores it: one string per line, each but the
+/// last ending with a newline.
+fn source_lines(text: &str) -> Value {
+    let lines: Vec<String> = text.split_inclusive('\n').map(str::to_string).collect();
+    lines.into()
+}
+
+fn object(members: Vec<(&str, Value)>) -> Value {
+    Value::Object(
+        members
+            .into_iter()
+            .map(|(key, value)| (key.to_string(), value))
+            .collect(),
+    )
+}
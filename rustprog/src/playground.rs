@@ -0,0 +1,400 @@
+//! A scratch crate to edit and run a piece of a lesson in.
+//!
+//! The piece is copied into `target/playground/<lesson>[-<piece>]/src/main.rs`
+//! as a program of its own: the whole lesson, one of its example modules,
+//! or a snippet. A snippet quoted in the prose, or a whole program
+//! commented out, is wrapped the way `verify` compiles it; other code
+//! commented out in the lesson program is uncommented in place, so the
+//! rest of the lesson runs around it.
+//!
+//! Programs are compiled with `rustc` and run under [`Limits`], so an
+//! endless `loop` is stopped instead of hanging the terminal. Compiler
+//! errors are followed by the lesson lines they point at: the edited
+//! program is lined up with the lesson by content, so the lines the
+//! learner did not touch still find where they came from.
+
+use std::env;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::{Command, ExitStatus};
+use std::time::{Duration, SystemTime};
+
+use crate::compile::{self, Limited, Limits};
+use crate::course::{Course, Lesson};
+use crate::lesson::{LessonSource, Origin};
+use crate::mdbook;
+
+/// How long a playground program may run, what it may allocate and how
+/// much of its output is shown.
+pub const LIMITS: Limits = Limits {
+    timeout: Duration::from_secs(5),
+    memory_mb: 512,
+    processes: 256,
+    output_bytes: 16 * 1024,
+};
+
+/// The part of a lesson to play with.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Piece {
+    /// The whole lesson program.
+    Lesson,
+    /// One of its example modules, by name.
+    Example(String),
+    /// The snippet on a line.
+    Snippet(usize),
+}
+
+/// A piece of a lesson copied out as a program.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Excerpt {
+    pub code: String,
+    /// First and last lesson lines the code comes from.
+    pub lines: (usize, usize),
+    /// Short name for the scratch crate: `lesson`, the example name or
+    /// `line-<n>`.
+    pub name: String,
+}
+
+/// Copies `piece` out of a lesson, or `None` if the lesson has no such
+/// example or no snippet on that line.
+pub fn excerpt(text: &str, piece: &Piece) -> Option<Excerpt> {
+    let source = LessonSource::parse(text);
+    let raw: Vec<&str> = text.lines().collect();
+    match piece {
+        Piece::Lesson => Some(Excerpt {
+            code: text.to_string(),
+            lines: (1, raw.len()),
+            name: "lesson".to_string(),
+        }),
+        Piece::Example(name) => {
+            let example = source
+                .examples()
+                .into_iter()
+                .find(|example| &example.name == name)?;
+            let module = raw[example.start - 1..example.end].join("\n");
+            Some(Excerpt {
+                code: format!("{}\n", mdbook::example_program(&module)?),
+                lines: (example.start, example.end),
+                name: name.clone(),
+            })
+        }
+        Piece::Snippet(line) => {
+            // A fenced snippet may be picked by its opening fence.
+            let snippet = source
+                .snippets()
+                .into_iter()
+                .find(|snippet| (snippet.start.saturating_sub(1)..=snippet.end).contains(line))?;
+            let name = format!("line-{}", snippet.start);
+            if snippet.origin == Origin::Prose || snippet.is_program() {
+                return Some(Excerpt {
+                    code: snippet.program(),
+                    lines: (snippet.start, snippet.end),
+                    name,
+                });
+            }
+            let mut lines: Vec<String> = raw.iter().map(|line| line.to_string()).collect();
+            for line in &mut lines[snippet.start - 1..snippet.end] {
+                *line = uncomment(line);
+            }
+            Some(Excerpt {
+                code: lines.join("\n") + "\n",
+                lines: (1, raw.len()),
+                name,
+            })
+        }
+    }
+}
+
+/// `line` without its leading `//`.
+fn uncomment(line: &str) -> String {
+    let indent = line.len() - line.trim_start().len();
+    match line[indent..].strip_prefix("//") {
+        Some(rest) => {
+            let rest = rest.strip_prefix(' ').unwrap_or(rest);
+            format!("{}{rest}", &line[..indent])
+        }
+        None => line.to_string(),
+    }
+}
+
+/// The scratch crate of a piece of a lesson.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Playground {
+    pub dir: PathBuf,
+    /// The lesson file, and its path relative to the course root.
+    pub lesson: PathBuf,
+    pub lesson_name: String,
+    /// First and last lesson lines the code was copied from.
+    pub lines: (usize, usize),
+}
+
+impl Playground {
+    /// Sets up the crate for `excerpt` of `lesson` under `target/playground`.
+    /// Code edited in an earlier session is kept unless `reset`.
+    pub fn create(
+        course: &Course,
+        lesson: &Lesson,
+        excerpt: &Excerpt,
+        reset: bool,
+    ) -> io::Result<Playground> {
+        let dir = course
+            .root
+            .join("target")
+            .join("playground")
+            .join(format!("{}-{}", lesson.stem, excerpt.name));
+        let playground = Playground {
+            dir,
+            lesson: lesson.path.clone(),
+            lesson_name: lesson
+                .relative_path(course)
+                .to_string_lossy()
+                .replace('\\', "/"),
+            lines: excerpt.lines,
+        };
+        fs::create_dir_all(playground.dir.join("src"))?;
+        fs::write(
+            playground.dir.join("Cargo.toml"),
+            "[package]\nname = \"playground\"\nversion = \"0.1.0\"\nedition = \"2021\"\n\n\
+             # Not a member of the course workspace.\n[workspace]\n",
+        )?;
+        let main = playground.main_path();
+        if reset || !main.is_file() {
+            fs::write(&main, &excerpt.code)?;
+        }
+        Ok(playground)
+    }
+
+    /// `src/main.rs`, the file to edit.
+    pub fn main_path(&self) -> PathBuf {
+        self.dir.join("src").join("main.rs")
+    }
+
+    /// Compiles the code as it is now and runs it within `limits`.
+    pub fn run(&self, limits: &Limits) -> io::Result<Report> {
+        let build = compile::compile_into(&self.main_path(), &self.dir.join("target"))?;
+        let Some(binary) = build.binary else {
+            let program = fs::read_to_string(self.main_path())?;
+            let lesson = fs::read_to_string(&self.lesson)?;
+            return Ok(Report::DoesNotCompile(self.annotate(
+                &build.stderr,
+                &program,
+                &lesson,
+            )));
+        };
+        Ok(Report::Ran(compile::run_limited(&binary, limits)?))
+    }
+
+    /// Compiler errors, each followed by the lesson lines it points at.
+    pub fn annotate(&self, stderr: &str, program: &str, lesson: &str) -> String {
+        let lesson: Vec<&str> = lesson.lines().collect();
+        let (first, last) = self.lines;
+        let window: Vec<(usize, &str)> = (first..=last.min(lesson.len()))
+            .map(|number| (number, lesson[number - 1]))
+            .collect();
+        let program: Vec<&str> = program.lines().collect();
+        let origins = align(&program, &window);
+
+        let mut out = String::new();
+        for block in diagnostics(stderr) {
+            out.push_str(block.trim_end());
+            out.push('\n');
+            let location = block
+                .lines()
+                .find_map(|line| line.trim_start().strip_prefix("--> "))
+                .and_then(|location| {
+                    let mut parts = location.rsplitn(3, ':');
+                    let _column = parts.next()?;
+                    parts.next()?.parse::<usize>().ok()
+                });
+            let Some(line) = location.filter(|&line| line >= 1) else {
+                out.push('\n');
+                continue;
+            };
+            // A line the learner wrote themselves is shown by the lesson
+            // line above it.
+            let origin = origins[..line.min(origins.len())]
+                .iter()
+                .rev()
+                .enumerate()
+                .find_map(|(back, origin)| origin.map(|origin| (origin, back == 0)));
+            let Some((origin, exact)) = origin else {
+                out.push('\n');
+                continue;
+            };
+            out.push_str(&format!(
+                "  = {} lesson {}:{origin}\n",
+                if exact { "from" } else { "near" },
+                self.lesson_name
+            ));
+            let width = (origin + 1).to_string().len();
+            for number in origin.saturating_sub(1).max(1)..=(origin + 1).min(lesson.len()) {
+                let marker = if number == origin { '>' } else { ' ' };
+                out.push_str(&format!(
+                    "  {marker} {number:>width$} | {}\n",
+                    lesson[number - 1]
+                ));
+            }
+            out.push('\n');
+        }
+        out.trim_end().to_string()
+    }
+}
+
+/// The blocks of rustc's output, one per error.
+fn diagnostics(stderr: &str) -> Vec<&str> {
+    let mut starts: Vec<usize> = Vec::new();
+    let mut offset = 0;
+    for line in stderr.split_inclusive('\n') {
+        if line.starts_with("error") || line.starts_with("warning") {
+            starts.push(offset);
+        }
+        offset += line.len();
+    }
+    if starts.first() != Some(&0) {
+        starts.insert(0, 0);
+    }
+    starts.push(stderr.len());
+    starts
+        .windows(2)
+        .map(|pair| &stderr[pair[0]..pair[1]])
+        .filter(|block| !block.trim().is_empty())
+        .collect()
+}
+
+/// For each line of `program`, the lesson line in `window` it was copied
+/// from: the longest common run of lines, compared without indentation or
+/// comment markers. Blank lines match nothing.
+pub fn align(program: &[&str], window: &[(usize, &str)]) -> Vec<Option<usize>> {
+    fn key(line: &str) -> &str {
+        let line = line.trim();
+        line.strip_prefix("//").map_or(line, str::trim_start)
+    }
+    let same = |a: &str, b: &str| !key(a).is_empty() && key(a) == key(b);
+
+    let (n, m) = (program.len(), window.len());
+    // `common[i][j]`: length of the longest common run of program[i..]
+    // and window[j..].
+    let mut common = vec![vec![0u32; m + 1]; n + 1];
+    for i in (0..n).rev() {
+        for j in (0..m).rev() {
+            common[i][j] = if same(program[i], window[j].1) {
+                common[i + 1][j + 1] + 1
+            } else {
+                common[i + 1][j].max(common[i][j + 1])
+            };
+        }
+    }
+    let mut origins = vec![None; n];
+    let (mut i, mut j) = (0, 0);
+    while i < n && j < m {
+        if same(program[i], window[j].1) {
+            origins[i] = Some(window[j].0);
+            i += 1;
+            j += 1;
+        } else if common[i + 1][j] >= common[i][j + 1] {
+            i += 1;
+        } else {
+            j += 1;
+        }
+    }
+    origins
+}
+
+/// What came of compiling and running the playground.
+#[derive(Debug)]
+pub enum Report {
+    /// rustc's errors, with the lesson lines they point at.
+    DoesNotCompile(String),
+    Ran(Limited),
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let run = match self {
+            Report::DoesNotCompile(errors) => return write!(f, "{errors}"),
+            Report::Ran(run) => run,
+        };
+        for bytes in [&run.stdout, &run.stderr] {
+            let mut text = String::from_utf8_lossy(bytes).into_owned();
+            // Output cut off mid-line ends with the last whole line.
+            if run.truncated && !text.ends_with('\n') {
+                text.truncate(text.rfind('\n').map_or(0, |end| end + 1));
+            }
+            write!(f, "{}", condense(&text))?;
+        }
+        if run.truncated {
+            writeln!(f, "[more output was cut off]")?;
+        }
+        write!(f, "-- {}", ending(run.status, run.elapsed))
+    }
+}
+
+/// How a run ended, e.g. `finished in 0.01s`; `status` is `None` when the
+/// program was stopped for running too long.
+pub fn ending(status: Option<ExitStatus>, elapsed: Duration) -> String {
+    let elapsed = elapsed.as_secs_f64();
+    match status {
+        Some(status) if status.success() => format!("finished in {elapsed:.2}s"),
+        Some(status) => format!("{} after {elapsed:.2}s", describe(status)),
+        None => {
+            format!("stopped after {elapsed:.0}s: the program was still running (an endless loop?)")
+        }
+    }
+}
+
+/// `text` with lines repeated more than three times in a row shown three
+/// times and counted, as an endless loop prints.
+fn condense(text: &str) -> String {
+    let mut out = String::new();
+    let mut lines = text.lines().peekable();
+    while let Some(line) = lines.next() {
+        let mut repeats = 1;
+        while lines.peek() == Some(&line) {
+            lines.next();
+            repeats += 1;
+        }
+        for _ in 0..repeats.min(3) {
+            out.push_str(line);
+            out.push('\n');
+        }
+        if repeats > 3 {
+            out.push_str(&format!("[the line above, {} more times]\n", repeats - 3));
+        }
+    }
+    out
+}
+
+fn describe(status: ExitStatus) -> String {
+    match status.code() {
+        Some(code) => format!("exited with status {code}"),
+        // Killed by a signal: on Unix, running out of memory aborts.
+        None => "crashed (out of memory?)".to_string(),
+    }
+}
+
+/// The editor to open: `$VISUAL`, then `$EDITOR`, then `vi`.
+pub fn editor() -> String {
+    ["VISUAL", "EDITOR"]
+        .iter()
+        .filter_map(env::var_os)
+        .map(|editor| editor.to_string_lossy().into_owned())
+        .find(|editor| !editor.trim().is_empty())
+        .unwrap_or_else(|| "vi".to_string())
+}
+
+/// Opens `path` in `editor`, which may carry arguments (`code --wait`),
+/// and waits for it to close.
+pub fn edit(editor: &str, path: &Path) -> io::Result<ExitStatus> {
+    let mut words = editor.split_whitespace();
+    let program = words
+        .next()
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "no editor"))?;
+    Command::new(program).args(words).arg(path).status()
+}
+
+/// When `path` was last saved.
+pub fn modified(path: &Path) -> io::Result<SystemTime> {
+    fs::metadata(path)?.modified()
+}
@@ -0,0 +1,235 @@
+//! What the learner has done so far, kept in a JSON file.
+//!
+//! Running a lesson marks it as viewed, grading records every exercise that
+//! passes and taking the quiz its best score, each with the time it
+//! happened. Lessons are keyed by their `<chapter>.<lesson>` id, `3.3` for
+//! `_03_Rust_Data_Types/_03_Rust_tuple.rs`, and keep their path for whoever
+//! reads the file.
+//!
+//! The file lives in the user's data directory,
+//! `~/.local/share/rustprog/progress.json` on Linux, unless
+//! `$RUSTPROG_PROGRESS` names another one.
+
+use std::collections::BTreeMap;
+use std::env;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::course::{Course, Lesson};
+use crate::json::Value;
+
+/// Version of the file format, written into the file.
+const VERSION: u64 = 1;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Progress {
+    /// Per lesson id, in id order.
+    pub lessons: BTreeMap<String, LessonProgress>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LessonProgress {
+    /// The lesson file relative to the course root.
+    pub path: String,
+    /// When the lesson was last run, in seconds since the Unix epoch.
+    pub viewed: Option<u64>,
+    /// When each exercise first passed.
+    pub passed: BTreeMap<String, u64>,
+    /// The best score of the lesson's quiz.
+    pub quiz: Option<QuizScore>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QuizScore {
+    pub correct: u64,
+    pub total: u64,
+    /// When the score was reached.
+    pub at: u64,
+}
+
+/// The progress file: `$RUSTPROG_PROGRESS`, else `rustprog/progress.json` in
+/// the user's data directory.
+pub fn default_path() -> Option<PathBuf> {
+    if let Some(path) = env::var_os("RUSTPROG_PROGRESS") {
+        return Some(PathBuf::from(path));
+    }
+    data_dir().map(|dir| dir.join("rustprog").join("progress.json"))
+}
+
+/// `$XDG_DATA_HOME` or its default on Unix, the platform's own elsewhere.
+fn data_dir() -> Option<PathBuf> {
+    let home = || env::var_os("HOME").map(PathBuf::from);
+    if cfg!(windows) {
+        env::var_os("APPDATA").map(PathBuf::from)
+    } else if cfg!(target_os = "macos") {
+        home().map(|home| home.join("Library/Application Support"))
+    } else {
+        env::var_os("XDG_DATA_HOME")
+            .map(PathBuf::from)
+            .filter(|dir| dir.is_absolute())
+            .or_else(|| home().map(|home| home.join(".local/share")))
+    }
+}
+
+/// Seconds since the Unix epoch.
+pub fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs())
+}
+
+/// `2024-03-01` for a time in seconds since the Unix epoch, in UTC.
+pub fn date(seconds: u64) -> String {
+    // Howard Hinnant's days-to-civil algorithm.
+    let days = (seconds / 86_400) as i64 + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+    let month = if shifted_month < 10 {
+        shifted_month + 3
+    } else {
+        shifted_month - 9
+    };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    format!("{year:04}-{month:02}-{day:02}")
+}
+
+impl Progress {
+    /// Reads the progress file; a missing file is no progress yet.
+    pub fn load(path: &Path) -> io::Result<Progress> {
+        let text = match fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Progress::default()),
+            Err(err) => return Err(err),
+        };
+        let invalid = |message: String| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("{}: {message}", path.display()),
+            )
+        };
+        let value = Value::parse(&text).map_err(|err| invalid(err.to_string()))?;
+        Progress::from_json(&value).ok_or_else(|| invalid("not a progress file".to_string()))
+    }
+
+    /// Writes the progress file, creating its directory if needed.
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        // Write aside and rename, so that an interrupted save loses nothing.
+        let partial = path.with_extension("json.partial");
+        fs::write(&partial, self.to_json().pretty())?;
+        fs::rename(&partial, path)
+    }
+
+    pub fn lesson(&self, lesson: &Lesson) -> Option<&LessonProgress> {
+        self.lessons.get(&lesson.id())
+    }
+
+    fn entry(&mut self, course: &Course, lesson: &Lesson) -> &mut LessonProgress {
+        let entry = self.lessons.entry(lesson.id()).or_default();
+        entry.path = lesson
+            .relative_path(course)
+            .to_string_lossy()
+            .replace('\\', "/");
+        entry
+    }
+
+    pub fn mark_viewed(&mut self, course: &Course, lesson: &Lesson, at: u64) {
+        self.entry(course, lesson).viewed = Some(at);
+    }
+
+    /// Records that `exercise` passed, unless it already had.
+    pub fn mark_passed(&mut self, course: &Course, lesson: &Lesson, exercise: &str, at: u64) {
+        self.entry(course, lesson)
+            .passed
+            .entry(exercise.to_string())
+            .or_insert(at);
+    }
+
+    /// Records a quiz score, unless an earlier one was as good.
+    pub fn mark_quiz(&mut self, course: &Course, lesson: &Lesson, score: QuizScore) {
+        let entry = self.entry(course, lesson);
+        let better = entry.quiz.is_none_or(|best| {
+            score.correct * best.total.max(1) > best.correct * score.total.max(1)
+        });
+        if better {
+            entry.quiz = Some(score);
+        }
+    }
+
+    fn to_json(&self) -> Value {
+        let lessons = self
+            .lessons
+            .iter()
+            .map(|(id, lesson)| {
+                let mut members = vec![("path".to_string(), Value::from(lesson.path.as_str()))];
+                if let Some(viewed) = lesson.viewed {
+                    members.push(("viewed".to_string(), Value::from(viewed)));
+                }
+                let passed = lesson
+                    .passed
+                    .iter()
+                    .map(|(name, at)| (name.clone(), Value::from(*at)))
+                    .collect();
+                members.push(("passed".to_string(), Value::Object(passed)));
+                if let Some(quiz) = lesson.quiz {
+                    let quiz = vec![
+                        ("correct".to_string(), Value::from(quiz.correct)),
+                        ("total".to_string(), Value::from(quiz.total)),
+                        ("at".to_string(), Value::from(quiz.at)),
+                    ];
+                    members.push(("quiz".to_string(), Value::Object(quiz)));
+                }
+                (id.clone(), Value::Object(members))
+            })
+            .collect();
+        Value::Object(vec![
+            ("version".to_string(), Value::from(VERSION)),
+            ("lessons".to_string(), Value::Object(lessons)),
+        ])
+    }
+
+    fn from_json(value: &Value) -> Option<Progress> {
+        if value.get("version")?.as_u64()? != VERSION {
+            return None;
+        }
+        let mut lessons = BTreeMap::new();
+        for (id, lesson) in value.get("lessons")?.as_object()? {
+            let viewed = match lesson.get("viewed") {
+                None => None,
+                Some(viewed) => Some(viewed.as_u64()?),
+            };
+            let mut passed = BTreeMap::new();
+            for (name, at) in lesson.get("passed")?.as_object()? {
+                passed.insert(name.clone(), at.as_u64()?);
+            }
+            let quiz = match lesson.get("quiz") {
+                None => None,
+                Some(quiz) => Some(QuizScore {
+                    correct: quiz.get("correct")?.as_u64()?,
+                    total: quiz.get("total")?.as_u64()?,
+                    at: quiz.get("at")?.as_u64()?,
+                }),
+            };
+            let path = lesson.get("path")?.as_str()?.to_string();
+            lessons.insert(
+                id.clone(),
+                LessonProgress {
+                    path,
+                    viewed,
+                    passed,
+                    quiz,
+                },
+            );
+        }
+        Some(Progress { lessons })
+    }
+}
@@ -0,0 +1,501 @@
+//! Quizzes on the lessons, asked in the terminal.
+//!
+//! `quizzes/<chapter>/<lesson>.md` holds the questions of a lesson. Each
+//! question starts with a `## <kind>` heading, followed by its prompt, an
+//! optional ```` ```rust ```` block and, for `choice`, the options:
+//!
+//! ````text
+//! ## choice
+//! Which keyword converts between primitive types?
+//!
+//! - [ ] `into`
+//! - [x] `as`
+//!
+//! ## output
+//! What does this program print?
+//!
+//! ```rust
+//! fn main() {
+//!     println!("{}", 54.321_f64 as u16);
+//! }
+//! ```
+//!
+//! > Casting a float to an integer drops the fraction.
+//! ````
+//!
+//! The kinds are `choice` (one or more options marked `[x]`), `output`
+//! (predict what the program prints) and `compiles` (does the program
+//! build?). `> ` lines explain the answer once it is given. Only the
+//! options of a `choice` are written down: the answers to the other two
+//! kinds come from compiling and running their program.
+
+use std::collections::BTreeSet;
+use std::fmt;
+use std::fs;
+use std::io::{self, BufRead, Write};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use crate::compile::{self, Scratch};
+use crate::course::{Course, Lesson};
+
+/// How long the program of an `output` question may run.
+pub const TIMEOUT: Duration = Duration::from_secs(10);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Quiz {
+    pub questions: Vec<Question>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Question {
+    /// Line of the `## <kind>` heading in the question file.
+    pub line: usize,
+    pub prompt: String,
+    /// The program shown with the question, if any.
+    pub code: Option<String>,
+    pub kind: Kind,
+    pub explanation: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Kind {
+    /// Pick the right options; `correct` holds their indices.
+    Choice {
+        options: Vec<String>,
+        correct: Vec<usize>,
+    },
+    /// Predict what the program prints.
+    Output,
+    /// Tell whether the program compiles.
+    Compiles,
+}
+
+/// The answer to a question, worked out when the question is checked.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    /// Indices of the correct options, out of `options`.
+    Choice { correct: Vec<usize>, options: usize },
+    /// What the program printed.
+    Output(String),
+    /// Whether the program compiled, and the first error when it did not.
+    Compiles(Result<(), String>),
+}
+
+/// Why a question file could not be read.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub message: String,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// How many questions were answered right.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Score {
+    pub correct: usize,
+    pub answered: usize,
+    pub total: usize,
+}
+
+/// `quizzes/<chapter>/<lesson>.md` under the course root.
+pub fn quiz_path(course: &Course, lesson: &Lesson) -> PathBuf {
+    course
+        .root
+        .join("quizzes")
+        .join(&course.chapter_of(lesson).dir_name)
+        .join(format!("{}.md", lesson.stem))
+}
+
+impl Quiz {
+    /// Reads the question file at `path`.
+    pub fn read(path: &Path) -> io::Result<Quiz> {
+        Quiz::parse(&fs::read_to_string(path)?).map_err(|err| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("{}: {err}", path.display()),
+            )
+        })
+    }
+
+    /// Parses a question file; anything before the first question is ignored.
+    pub fn parse(text: &str) -> Result<Quiz, ParseError> {
+        let mut questions = Vec::new();
+        let mut current: Option<Draft> = None;
+        let mut fence: Option<(usize, Vec<&str>)> = None;
+
+        for (index, line) in text.lines().enumerate() {
+            let number = index + 1;
+            if let Some((_, code)) = &mut fence {
+                if line.trim_end() == "```" {
+                    let (start, code) = fence.take().unwrap_or_default();
+                    // Code blocks only open inside a question.
+                    let Some(draft) = current.as_mut() else {
+                        continue;
+                    };
+                    if draft.code.is_some() {
+                        return Err(ParseError {
+                            line: start,
+                            message: "a question has one program at most".to_string(),
+                        });
+                    }
+                    draft.code = Some(code.join("\n"));
+                } else {
+                    code.push(line);
+                }
+                continue;
+            }
+
+            if let Some(kind) = line.strip_prefix("## ") {
+                if let Some(draft) = current.take() {
+                    questions.push(draft.finish()?);
+                }
+                current = Some(Draft::new(number, kind.trim())?);
+                continue;
+            }
+            let Some(draft) = current.as_mut() else {
+                continue;
+            };
+            if let Some(info) = line.strip_prefix("```") {
+                if !matches!(info.trim(), "" | "rust") {
+                    return Err(ParseError {
+                        line: number,
+                        message: format!("expected a rust program, not '{}'", info.trim()),
+                    });
+                }
+                fence = Some((number, Vec::new()));
+            } else if let Some(option) = line.strip_prefix("- [") {
+                let (mark, text) = option.split_once("] ").ok_or(ParseError {
+                    line: number,
+                    message: "expected '- [ ] option' or '- [x] option'".to_string(),
+                })?;
+                draft
+                    .options
+                    .push((mark.trim() == "x", text.trim().to_string()));
+            } else if let Some(text) = line.strip_prefix('>') {
+                draft.explanation.push(text.trim().to_string());
+            } else if !line.trim().is_empty() {
+                if draft.code.is_some() || !draft.options.is_empty() {
+                    return Err(ParseError {
+                        line: number,
+                        message: "the prompt goes before the program and the options".to_string(),
+                    });
+                }
+                draft.prompt.push(line.trim().to_string());
+            }
+        }
+
+        if let Some((start, _)) = fence {
+            return Err(ParseError {
+                line: start,
+                message: "unclosed code block".to_string(),
+            });
+        }
+        if let Some(draft) = current {
+            questions.push(draft.finish()?);
+        }
+        Ok(Quiz { questions })
+    }
+}
+
+/// A question as it is being read.
+struct Draft {
+    line: usize,
+    kind: String,
+    prompt: Vec<String>,
+    code: Option<String>,
+    options: Vec<(bool, String)>,
+    explanation: Vec<String>,
+}
+
+impl Draft {
+    fn new(line: usize, kind: &str) -> Result<Draft, ParseError> {
+        if !matches!(kind, "choice" | "output" | "compiles") {
+            return Err(ParseError {
+                line,
+                message: format!(
+                    "unknown kind of question '{kind}' (expected choice, output or compiles)"
+                ),
+            });
+        }
+        Ok(Draft {
+            line,
+            kind: kind.to_string(),
+            prompt: Vec::new(),
+            code: None,
+            options: Vec::new(),
+            explanation: Vec::new(),
+        })
+    }
+
+    fn finish(self) -> Result<Question, ParseError> {
+        let error = |message: &str| ParseError {
+            line: self.line,
+            message: message.to_string(),
+        };
+        if self.prompt.is_empty() {
+            return Err(error("the question has no prompt"));
+        }
+        let kind = match self.kind.as_str() {
+            "choice" => {
+                if self.options.len() < 2 {
+                    return Err(error("a choice needs two options or more"));
+                }
+                let correct: Vec<usize> = (0..self.options.len())
+                    .filter(|&index| self.options[index].0)
+                    .collect();
+                if correct.is_empty() {
+                    return Err(error("no option is marked [x]"));
+                }
+                Kind::Choice {
+                    options: self.options.into_iter().map(|(_, text)| text).collect(),
+                    correct,
+                }
+            }
+            kind => {
+                if self.code.is_none() {
+                    return Err(error("the question has no program"));
+                }
+                if !self.options.is_empty() {
+                    return Err(error("only a choice has options"));
+                }
+                if kind == "output" {
+                    Kind::Output
+                } else {
+                    Kind::Compiles
+                }
+            }
+        };
+        Ok(Question {
+            line: self.line,
+            prompt: self.prompt.join(" "),
+            code: self.code,
+            kind,
+            explanation: (!self.explanation.is_empty()).then(|| self.explanation.join(" ")),
+        })
+    }
+}
+
+impl Question {
+    /// Works out the answer, compiling and running the program if need be.
+    /// A program that should print something but does not build, or does
+    /// not stop, makes the question itself wrong.
+    pub fn answer(&self, timeout: Duration) -> io::Result<Answer> {
+        let broken = |message: String| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("question on line {}: {message}", self.line),
+            )
+        };
+        let code = match (&self.kind, &self.code) {
+            (Kind::Choice { options, correct }, _) => {
+                return Ok(Answer::Choice {
+                    correct: correct.clone(),
+                    options: options.len(),
+                })
+            }
+            (_, Some(code)) => code,
+            (_, None) => return Err(broken("no program".to_string())),
+        };
+        let scratch = Scratch::new("quiz")?;
+        let build = compile::compile_source(code, &scratch)?;
+        match (&self.kind, build.binary) {
+            (Kind::Compiles, Some(_)) => Ok(Answer::Compiles(Ok(()))),
+            (Kind::Compiles, None) => Ok(Answer::Compiles(Err(first_error(&build.stderr)))),
+            (_, None) => Err(broken(format!(
+                "the program does not compile\n{}",
+                build.stderr.trim_end()
+            ))),
+            (_, Some(binary)) => match compile::run_with_timeout(&binary, &[], timeout)? {
+                Some(output) => Ok(Answer::Output(
+                    String::from_utf8_lossy(&output.stdout).into_owned(),
+                )),
+                None => Err(broken(format!(
+                    "the program did not finish within {}s",
+                    timeout.as_secs()
+                ))),
+            },
+        }
+    }
+}
+
+/// The first `error...` line of compiler output.
+fn first_error(stderr: &str) -> String {
+    stderr
+        .lines()
+        .find(|line| line.starts_with("error"))
+        .unwrap_or("error")
+        .to_string()
+}
+
+impl Answer {
+    /// Whether `reply` gives this answer, or `None` when `reply` is not a
+    /// reply to the question at all.
+    pub fn accepts(&self, reply: &str) -> Option<bool> {
+        match self {
+            Answer::Choice { correct, options } => {
+                let mut picked = BTreeSet::new();
+                for item in reply
+                    .split(|c: char| c == ',' || c.is_whitespace())
+                    .filter(|item| !item.is_empty())
+                {
+                    let number: usize = item.parse().ok()?;
+                    if !(1..=*options).contains(&number) {
+                        return None;
+                    }
+                    picked.insert(number - 1);
+                }
+                if picked.is_empty() {
+                    return None;
+                }
+                Some(picked.into_iter().eq(correct.iter().copied()))
+            }
+            Answer::Output(printed) => Some(normalize(reply) == normalize(printed)),
+            Answer::Compiles(result) => match reply.trim().to_lowercase().as_str() {
+                "y" | "yes" => Some(result.is_ok()),
+                "n" | "no" => Some(result.is_err()),
+                _ => None,
+            },
+        }
+    }
+}
+
+/// Output lines without trailing spaces or blank lines, which nobody types
+/// the same way twice.
+fn normalize(text: &str) -> Vec<&str> {
+    text.lines()
+        .map(str::trim_end)
+        .filter(|line| !line.is_empty())
+        .collect()
+}
+
+/// Asks the questions of `quiz` one after the other, reading replies from
+/// `input` until it runs out.
+pub fn take(
+    quiz: &Quiz,
+    input: &mut impl BufRead,
+    out: &mut impl Write,
+    timeout: Duration,
+) -> io::Result<Score> {
+    let mut score = Score {
+        total: quiz.questions.len(),
+        ..Score::default()
+    };
+    for (index, question) in quiz.questions.iter().enumerate() {
+        writeln!(
+            out,
+            "Question {} of {} ({})",
+            index + 1,
+            quiz.questions.len(),
+            match question.kind {
+                Kind::Choice { .. } => "multiple choice",
+                Kind::Output => "predict the output",
+                Kind::Compiles => "does it compile?",
+            }
+        )?;
+        writeln!(out, "{}", question.prompt)?;
+        if let Some(code) = &question.code {
+            writeln!(out)?;
+            for line in code.lines() {
+                writeln!(out, "    {line}")?;
+            }
+        }
+        if let Kind::Choice { options, .. } = &question.kind {
+            writeln!(out)?;
+            for (number, option) in options.iter().enumerate() {
+                writeln!(out, "  {}. {option}", number + 1)?;
+            }
+        }
+        writeln!(out)?;
+
+        // The answer is worked out before asking, so a broken question
+        // fails before the learner has typed anything.
+        let answer = question.answer(timeout)?;
+        let prompt = match &answer {
+            Answer::Choice { correct, .. } if correct.len() > 1 => {
+                "Your answer (every number that applies, e.g. 1,3): "
+            }
+            Answer::Choice { .. } => "Your answer (a number): ",
+            Answer::Output(_) => "Type the output, then an empty line:\n",
+            Answer::Compiles(_) => "Your answer (y/n): ",
+        };
+        let correct = loop {
+            write!(out, "{prompt}")?;
+            out.flush()?;
+            let Some(reply) = read_reply(input, matches!(answer, Answer::Output(_)))? else {
+                writeln!(out)?;
+                return Ok(score);
+            };
+            match answer.accepts(&reply) {
+                Some(correct) => break correct,
+                None => writeln!(out, "Please answer with {}.", expected_form(&answer))?,
+            }
+        };
+
+        score.answered += 1;
+        if correct {
+            score.correct += 1;
+            writeln!(out, "Correct!")?;
+        } else {
+            match (&question.kind, &answer) {
+                (Kind::Choice { options, .. }, Answer::Choice { correct, .. }) => {
+                    writeln!(out, "Not quite, the answer is:")?;
+                    for &index in correct {
+                        writeln!(out, "  {}. {}", index + 1, options[index])?;
+                    }
+                }
+                (_, Answer::Output(printed)) => {
+                    writeln!(out, "Not quite, the program prints:")?;
+                    for line in printed.lines() {
+                        writeln!(out, "    {line}")?;
+                    }
+                }
+                (_, Answer::Compiles(Ok(()))) => writeln!(out, "Not quite, it compiles.")?,
+                (_, Answer::Compiles(Err(error))) => {
+                    writeln!(out, "Not quite, it does not compile:")?;
+                    writeln!(out, "    {error}")?;
+                }
+                _ => {}
+            }
+        }
+        if let Some(explanation) = &question.explanation {
+            writeln!(out, "{explanation}")?;
+        }
+        writeln!(out)?;
+    }
+    Ok(score)
+}
+
+/// One reply, or for `output` questions every line up to an empty one;
+/// `None` at the end of the input.
+fn read_reply(input: &mut impl BufRead, multiline: bool) -> io::Result<Option<String>> {
+    let mut reply = String::new();
+    loop {
+        let mut line = String::new();
+        if input.read_line(&mut line)? == 0 {
+            return Ok((!reply.is_empty()).then_some(reply));
+        }
+        if !multiline {
+            return Ok(Some(line));
+        }
+        if line.trim().is_empty() {
+            return Ok(Some(reply));
+        }
+        reply.push_str(&line);
+    }
+}
+
+fn expected_form(answer: &Answer) -> &'static str {
+    match answer {
+        Answer::Choice { .. } => "the numbers of options",
+        Answer::Output(_) => "the lines the program prints",
+        Answer::Compiles(_) => "y or n",
+    }
+}
@@ -0,0 +1,45 @@
+//! Running lessons through Cargo.
+
+use std::env;
+use std::ffi::OsString;
+use std::fs;
+use std::io::{self, Write};
+use std::process::{Command, ExitStatus};
+
+use crate::compile::{self, Scratch};
+use crate::course::{Course, Lesson};
+use crate::playground::{self, Piece};
+
+/// The `cargo` executable, honouring the `CARGO` variable Cargo itself sets.
+pub fn cargo() -> OsString {
+    env::var_os("CARGO").unwrap_or_else(|| "cargo".into())
+}
+
+/// `cargo run` for a lesson's binary, with stdio inherited from us.
+pub fn run_lesson(course: &Course, lesson: &Lesson, args: &[String]) -> io::Result<ExitStatus> {
+    let chapter = course.chapter_of(lesson);
+    Command::new(cargo())
+        .current_dir(&course.root)
+        .args(["run", "--quiet", "--package", &chapter.package])
+        .args(["--bin", &lesson.bin_name()])
+        .arg("--")
+        .args(args)
+        .status()
+}
+
+/// Compiles the example module `name` of `lesson` as a program of its own
+/// and runs it, with stdio inherited from us. `None` when the lesson has no
+/// such example, or when it does not compile; the errors go to stderr.
+pub fn run_example(lesson: &Lesson, name: &str) -> io::Result<Option<ExitStatus>> {
+    let text = fs::read_to_string(&lesson.path)?;
+    let Some(excerpt) = playground::excerpt(&text, &Piece::Example(name.to_string())) else {
+        return Ok(None);
+    };
+    let scratch = Scratch::new("example")?;
+    let build = compile::compile_source(&excerpt.code, &scratch)?;
+    let Some(binary) = build.binary else {
+        io::stderr().write_all(build.stderr.as_bytes())?;
+        return Ok(None);
+    };
+    Command::new(binary).status().map(Some)
+}
@@ -0,0 +1,515 @@
+//! Full-text search across the lessons, from an index kept on disk.
+//!
+//! Every line of prose and code is split into words, and the words are
+//! cut down to a rough stem so that `shadowing` also finds `shadows` and
+//! `shadowed`. Headings weigh more than the text under them. A few groups
+//! of related words (`CONCEPTS`) let a search for one find the others with
+//! a lower score, and without the weight of a heading: `shadowing` turns
+//! up the lesson on declaring variables too.
+//!
+//! Hits carry the section heading they fall under and, in code, the
+//! function around them. The index is written to
+//! `target/rustprog/search-index.json` and rebuilt when a lesson changes.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::UNIX_EPOCH;
+
+use crate::course::Course;
+use crate::json::Value;
+use crate::lesson::{brace_delta, LessonSource, LineKind};
+
+/// Version of the index format; an index of another version is rebuilt.
+const VERSION: u64 = 1;
+
+/// Words that stand for the same idea in the lessons.
+const CONCEPTS: &[&[&str]] = &[
+    &["shadowing", "redeclare", "declare", "declaration", "freeze"],
+    &["cast", "casting", "as", "conversion", "convert"],
+    &[
+        "loop",
+        "while",
+        "for",
+        "iteration",
+        "iterate",
+        "break",
+        "continue",
+    ],
+    &["closure", "lambda", "anonymous", "capture"],
+    &["function", "fn", "parameter", "argument", "return"],
+    &["print", "println", "output", "format"],
+    &["array", "slice", "index", "element"],
+    &["tuple", "destructuring", "destructure"],
+    &["struct", "field", "instance"],
+    &["condition", "if", "else", "boolean"],
+    &["scope", "block", "inner", "outer"],
+    &["constant", "const"],
+];
+
+/// How much a hit on a related word counts, against one on the word itself.
+const RELATED_WEIGHT: f64 = 0.3;
+
+/// At most this many hits are shown per lesson, so that other lessons get
+/// a place in the results.
+pub const HITS_PER_LESSON: usize = 3;
+
+/// What a line of a lesson is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LineRole {
+    Title,
+    Heading,
+    Prose,
+    Code,
+}
+
+impl LineRole {
+    fn weight(self) -> f64 {
+        match self {
+            LineRole::Title => 8.0,
+            LineRole::Heading => 5.0,
+            LineRole::Prose | LineRole::Code => 1.0,
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            LineRole::Title => "title",
+            LineRole::Heading => "heading",
+            LineRole::Prose => "prose",
+            LineRole::Code => "code",
+        }
+    }
+
+    fn from_name(name: &str) -> Option<LineRole> {
+        Some(match name {
+            "title" => LineRole::Title,
+            "heading" => LineRole::Heading,
+            "prose" => LineRole::Prose,
+            "code" => LineRole::Code,
+            _ => return None,
+        })
+    }
+}
+
+/// One indexed line.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Entry {
+    /// Index into [`Index::lessons`].
+    pub lesson: usize,
+    pub line: usize,
+    pub role: LineRole,
+    /// The heading the line falls under.
+    pub section: Option<String>,
+    /// The function the line is in, e.g. `test3()`, other than `main`.
+    pub function: Option<String>,
+    pub text: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct IndexedLesson {
+    pub id: String,
+    /// Relative to the course root.
+    pub path: String,
+    /// Size and modification time of the file when it was indexed.
+    pub stamp: String,
+}
+
+/// The search index of a course.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Index {
+    pub lessons: Vec<IndexedLesson>,
+    pub entries: Vec<Entry>,
+    /// Stemmed word to the entries holding it, once per occurrence.
+    terms: BTreeMap<String, Vec<usize>>,
+}
+
+/// A line matching a search.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Hit<'a> {
+    pub lesson: &'a IndexedLesson,
+    /// The best matching line of its section or function.
+    pub entry: &'a Entry,
+    pub score: f64,
+    /// Score of `entry` alone.
+    best: f64,
+}
+
+/// `target/rustprog/search-index.json` under the course root.
+pub fn index_path(course: &Course) -> PathBuf {
+    course
+        .root
+        .join("target")
+        .join("rustprog")
+        .join("search-index.json")
+}
+
+/// The words of `text`, lowercased: identifiers whole and, when they hold
+/// underscores, in parts too.
+pub fn words(text: &str) -> Vec<String> {
+    let mut words = Vec::new();
+    for word in text
+        .split(|c: char| !(c.is_alphanumeric() || c == '_'))
+        .map(|word| word.trim_matches('_'))
+        .filter(|word| !word.is_empty())
+    {
+        let word = word.to_lowercase();
+        if word.contains('_') {
+            words.extend(
+                word.split('_')
+                    .filter(|part| !part.is_empty())
+                    .map(str::to_string),
+            );
+        }
+        words.push(word);
+    }
+    words
+}
+
+/// A rough stem of a lowercase word: `shadowing`, `shadows` and `shadowed`
+/// all become `shadow`.
+pub fn stem(word: &str) -> String {
+    if let Some(base) = word.strip_suffix("ies").filter(|base| base.len() >= 3) {
+        return format!("{base}y");
+    }
+    for suffix in ["ing", "ed"] {
+        if let Some(base) = word.strip_suffix(suffix).filter(|base| base.len() >= 4) {
+            return base.to_string();
+        }
+    }
+    match word.strip_suffix('s') {
+        Some(base) if base.len() >= 3 && !base.ends_with('s') => base.to_string(),
+        _ => word.to_string(),
+    }
+}
+
+/// A file's size and modification time, to tell when it changed.
+fn stamp(path: &Path) -> io::Result<String> {
+    let metadata = fs::metadata(path)?;
+    let modified = metadata
+        .modified()?
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_nanos());
+    Ok(format!("{}:{modified}", metadata.len()))
+}
+
+impl Index {
+    /// Indexes every lesson of `course`.
+    pub fn build(course: &Course) -> io::Result<Index> {
+        let mut index = Index {
+            lessons: Vec::new(),
+            entries: Vec::new(),
+            terms: BTreeMap::new(),
+        };
+        for lesson in course.lessons() {
+            let source = LessonSource::read(&lesson.path)?;
+            index.lessons.push(IndexedLesson {
+                id: lesson.id(),
+                path: lesson
+                    .relative_path(course)
+                    .to_string_lossy()
+                    .replace('\\', "/"),
+                stamp: stamp(&lesson.path)?,
+            });
+            index.add_lesson(index.lessons.len() - 1, &source);
+        }
+        Ok(index)
+    }
+
+    fn add_lesson(&mut self, lesson: usize, source: &LessonSource) {
+        let headings = source.headings();
+        let underlines: Vec<usize> = headings.iter().map(|heading| heading.line + 1).collect();
+        let mut section: Option<String> = None;
+        // Functions and modules the code is in, with the brace depth at
+        // which each one was opened.
+        let mut open: Vec<(String, i32)> = Vec::new();
+        let mut depth = 0;
+
+        for line in &source.lines {
+            let heading = headings
+                .iter()
+                .position(|heading| heading.line == line.number);
+            let role = match heading {
+                Some(0) => LineRole::Title,
+                Some(_) => LineRole::Heading,
+                None if line.kind == LineKind::Code => LineRole::Code,
+                None => LineRole::Prose,
+            };
+            if let Some(heading) = heading {
+                section = Some(headings[heading].text.clone());
+            }
+            if line.kind == LineKind::Code {
+                let code = line.text.trim();
+                let item = code
+                    .strip_prefix("pub ")
+                    .unwrap_or(code)
+                    .strip_prefix("fn ")
+                    .map(|rest| (rest, "()"))
+                    .or_else(|| code.strip_prefix("mod ").map(|rest| (rest, "::")));
+                if let Some((rest, suffix)) = item {
+                    let end = rest
+                        .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+                        .unwrap_or(rest.len());
+                    open.push((format!("{}{suffix}", &rest[..end]), depth));
+                }
+            }
+            // The line belongs to the items open on it, even the ones its
+            // own closing brace ends.
+            let function = function_name(&open);
+            if line.kind == LineKind::Code {
+                depth += brace_delta(&line.text);
+                if line.text.contains('}') {
+                    while open.last().is_some_and(|(_, opened)| depth <= *opened) {
+                        open.pop();
+                    }
+                }
+            }
+
+            if underlines.contains(&line.number) || line.text.trim().is_empty() {
+                continue;
+            }
+            let words = words(&line.text);
+            if words.is_empty() {
+                continue;
+            }
+            let entry = self.entries.len();
+            for word in words {
+                self.terms.entry(stem(&word)).or_default().push(entry);
+            }
+            self.entries.push(Entry {
+                lesson,
+                line: line.number,
+                role,
+                section: section.clone(),
+                function,
+                text: line.text.trim().to_string(),
+            });
+        }
+    }
+
+    /// Whether the index still matches the lessons of `course`.
+    pub fn is_current(&self, course: &Course) -> bool {
+        let lessons: Vec<_> = course.lessons().collect();
+        lessons.len() == self.lessons.len()
+            && lessons.iter().zip(&self.lessons).all(|(lesson, indexed)| {
+                lesson.id() == indexed.id
+                    && stamp(&lesson.path).is_ok_and(|stamp| stamp == indexed.stamp)
+            })
+    }
+
+    /// The index at `path`, or `None` when there is none or it cannot be
+    /// read, so that it gets rebuilt.
+    pub fn load(path: &Path) -> Option<Index> {
+        let text = fs::read_to_string(path).ok()?;
+        Index::from_json(&Value::parse(&text).ok()?)
+    }
+
+    /// The stored index of `course`, rebuilt and saved first if a lesson
+    /// changed since. Also says whether it was rebuilt.
+    pub fn open(course: &Course) -> io::Result<(Index, bool)> {
+        let path = index_path(course);
+        if let Some(index) = Index::load(&path).filter(|index| index.is_current(course)) {
+            return Ok((index, false));
+        }
+        let index = Index::build(course)?;
+        index.save(&path)?;
+        Ok((index, true))
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(path, self.to_json().to_string())
+    }
+
+    /// The lines matching `query`, best first, with at most
+    /// [`HITS_PER_LESSON`] per lesson.
+    pub fn search(&self, query: &str) -> Vec<Hit<'_>> {
+        let mut weights: BTreeMap<String, f64> = BTreeMap::new();
+        for word in words(query) {
+            let word = stem(&word);
+            for group in CONCEPTS {
+                let stems: Vec<String> = group.iter().map(|word| stem(word)).collect();
+                if stems.contains(&word) {
+                    for related in stems {
+                        let weight = weights.entry(related).or_insert(0.0);
+                        *weight = weight.max(RELATED_WEIGHT);
+                    }
+                }
+            }
+            weights.insert(word, 1.0);
+        }
+
+        let mut scores: BTreeMap<usize, f64> = BTreeMap::new();
+        for (term, weight) in &weights {
+            let Some(postings) = self.terms.get(term) else {
+                continue;
+            };
+            // Rare words say more than common ones.
+            let mut lines = postings.clone();
+            lines.dedup();
+            let rarity = (self.entries.len() as f64 / lines.len() as f64).ln() + 1.0;
+            for &entry in postings {
+                // A related word counts the same wherever it is, so that a
+                // heading holding one does not outrank the word itself.
+                let role = if *weight < 1.0 {
+                    1.0
+                } else {
+                    self.entries[entry].role.weight()
+                };
+                *scores.entry(entry).or_insert(0.0) += weight * rarity * role;
+            }
+        }
+
+        // Lines are grouped by the section and function they are in; a
+        // group scores its best line plus half of the others, and shows the
+        // best one.
+        let mut groups: Vec<Hit> = Vec::new();
+        for (entry, score) in scores {
+            let entry = &self.entries[entry];
+            let group = groups.iter_mut().find(|hit| {
+                hit.entry.lesson == entry.lesson
+                    && hit.entry.section == entry.section
+                    && hit.entry.function == entry.function
+            });
+            match group {
+                Some(hit) if score > hit.best => {
+                    hit.score += hit.best / 2.0 + score / 2.0;
+                    hit.best = score;
+                    hit.entry = entry;
+                }
+                Some(hit) => hit.score += score / 2.0,
+                None => groups.push(Hit {
+                    lesson: &self.lessons[entry.lesson],
+                    entry,
+                    score,
+                    best: score,
+                }),
+            }
+        }
+        let mut hits = groups;
+        hits.sort_by(|a, b| {
+            b.score
+                .total_cmp(&a.score)
+                .then(a.entry.lesson.cmp(&b.entry.lesson))
+                .then(a.entry.line.cmp(&b.entry.line))
+        });
+
+        let mut per_lesson: BTreeMap<usize, usize> = BTreeMap::new();
+        hits.retain(|hit| {
+            let count = per_lesson.entry(hit.entry.lesson).or_insert(0);
+            *count += 1;
+            *count <= HITS_PER_LESSON
+        });
+        hits
+    }
+
+    fn to_json(&self) -> Value {
+        let lessons = self
+            .lessons
+            .iter()
+            .map(|lesson| {
+                Value::from(vec![
+                    lesson.id.as_str(),
+                    lesson.path.as_str(),
+                    lesson.stamp.as_str(),
+                ])
+            })
+            .collect();
+        let entries = self
+            .entries
+            .iter()
+            .map(|entry| {
+                let text = |text: &Option<String>| text.as_deref().map_or(Value::Null, Value::from);
+                Value::Array(vec![
+                    entry.lesson.into(),
+                    entry.line.into(),
+                    entry.role.name().into(),
+                    text(&entry.section),
+                    text(&entry.function),
+                    entry.text.as_str().into(),
+                ])
+            })
+            .collect();
+        let terms = self
+            .terms
+            .iter()
+            .map(|(term, postings)| (term.clone(), Value::from(postings.clone())))
+            .collect();
+        Value::Object(vec![
+            ("version".to_string(), VERSION.into()),
+            ("lessons".to_string(), Value::Array(lessons)),
+            ("entries".to_string(), Value::Array(entries)),
+            ("terms".to_string(), Value::Object(terms)),
+        ])
+    }
+
+    fn from_json(value: &Value) -> Option<Index> {
+        if value.get("version")?.as_u64()? != VERSION {
+            return None;
+        }
+        let mut lessons = Vec::new();
+        for lesson in value.get("lessons")?.as_array()? {
+            let [id, path, stamp] = lesson.as_array()? else {
+                return None;
+            };
+            lessons.push(IndexedLesson {
+                id: id.as_str()?.to_string(),
+                path: path.as_str()?.to_string(),
+                stamp: stamp.as_str()?.to_string(),
+            });
+        }
+        let mut entries = Vec::new();
+        for entry in value.get("entries")?.as_array()? {
+            let [lesson, line, role, section, function, text] = entry.as_array()? else {
+                return None;
+            };
+            let text_or_null = |value: &Value| match value {
+                Value::Null => Some(None),
+                value => value.as_str().map(|text| Some(text.to_string())),
+            };
+            entries.push(Entry {
+                lesson: usize::try_from(lesson.as_u64()?)
+                    .ok()
+                    .filter(|&lesson| lesson < lessons.len())?,
+                line: usize::try_from(line.as_u64()?).ok()?,
+                role: LineRole::from_name(role.as_str()?)?,
+                section: text_or_null(section)?,
+                function: text_or_null(function)?,
+                text: text.as_str()?.to_string(),
+            });
+        }
+        let mut terms = BTreeMap::new();
+        for (term, postings) in value.get("terms")?.as_object()? {
+            let postings = postings
+                .as_array()?
+                .iter()
+                .map(|entry| {
+                    usize::try_from(entry.as_u64()?)
+                        .ok()
+                        .filter(|&entry| entry < entries.len())
+                })
+                .collect::<Option<Vec<_>>>()?;
+            terms.insert(term.clone(), postings);
+        }
+        Some(Index {
+            lessons,
+            entries,
+            terms,
+        })
+    }
+}
+
+/// `test3()` or `if_else::main()` for the items the code is in; nothing
+/// for the lesson's own `main`.
+fn function_name(open: &[(String, i32)]) -> Option<String> {
+    let names: Vec<&str> = open.iter().map(|(name, _)| name.as_str()).collect();
+    let names = match names.as_slice() {
+        ["main()", rest @ ..] => rest,
+        names => names,
+    };
+    let (last, modules) = names.split_last()?;
+    Some(format!("{}{last}", modules.concat()))
+}
@@ -0,0 +1,436 @@
+//! A small HTTP server for the rendered course, with somewhere to run code.
+//!
+//! `GET` serves the files of the site. `POST /run` takes the source of a
+//! snippet as a JSON body, `{"source": "..."}`, compiles it with the local
+//! `rustc` in a scratch directory and runs it under the playground's
+//! [`Limits`]. What it prints comes back as it is printed: a chunked
+//! response of JSON lines, `{"stdout": "..."}` and `{"stderr": "..."}`,
+//! ending with `{"exit": "finished in 0.01s"}`. Compiler errors come as
+//! `stderr`.
+//!
+//! Nothing leaves the machine: the server only listens on the address it
+//! is given, `127.0.0.1` from the command line, and needs no network. Other
+//! web pages must not run code through it either. Requests must name the
+//! server itself in `Host`, and in `Origin` if they have one, which stops
+//! DNS rebinding; `/run` only takes `application/json`, which a browser
+//! does not send to another site without asking the server first.
+
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::net::{Shutdown, SocketAddr, TcpListener, TcpStream, ToSocketAddrs};
+use std::path::{Path, PathBuf};
+use std::str;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+use crate::compile::{self, Limits, Scratch, Stream};
+use crate::json::Value;
+use crate::lesson::as_program;
+use crate::mdbook;
+use crate::playground;
+
+/// Largest source accepted by `/run`.
+pub const MAX_SOURCE: usize = 64 * 1024;
+
+/// Largest request line and headers accepted, together.
+pub const MAX_HEAD: u64 = 16 * 1024;
+
+/// Programs compiled or running at the same time; more are turned away.
+pub const MAX_RUNS: usize = 4;
+
+/// The server, bound and ready to serve.
+#[derive(Debug)]
+pub struct Server {
+    listener: TcpListener,
+    shared: Arc<Shared>,
+}
+
+#[derive(Debug)]
+struct Shared {
+    site: PathBuf,
+    /// The `Host` values that name the server, such as `localhost:8000`.
+    hosts: Vec<String>,
+    limits: Limits,
+    running: AtomicUsize,
+}
+
+impl Server {
+    /// Listens on `address`, serving the site in `site`.
+    pub fn bind(address: impl ToSocketAddrs, site: &Path, limits: Limits) -> io::Result<Server> {
+        let listener = TcpListener::bind(address)?;
+        let bound = listener.local_addr()?;
+        Ok(Server {
+            listener,
+            shared: Arc::new(Shared {
+                site: site.to_path_buf(),
+                hosts: vec![bound.to_string(), format!("localhost:{}", bound.port())],
+                limits,
+                running: AtomicUsize::new(0),
+            }),
+        })
+    }
+
+    pub fn local_addr(&self) -> io::Result<SocketAddr> {
+        self.listener.local_addr()
+    }
+
+    /// Answers requests, each on a thread of its own, until the process
+    /// is stopped.
+    pub fn serve(self) -> io::Result<()> {
+        for stream in self.listener.incoming() {
+            let Ok(stream) = stream else {
+                continue;
+            };
+            let shared = Arc::clone(&self.shared);
+            thread::spawn(move || {
+                // A client that goes away mid-answer is no concern of ours.
+                let _ = handle(stream, &shared);
+            });
+        }
+        Ok(())
+    }
+}
+
+/// A request: its method, path, the headers we look at and its body.
+struct Request {
+    method: String,
+    path: String,
+    host: Option<String>,
+    origin: Option<String>,
+    content_type: Option<String>,
+    body: Vec<u8>,
+}
+
+impl Shared {
+    /// Whether the request names this server, and comes from one of its
+    /// pages if it comes from a page at all.
+    fn allows(&self, request: &Request) -> bool {
+        let is_ours = |host: &str| self.hosts.iter().any(|ours| ours == host);
+        let host = request.host.as_deref().is_some_and(is_ours);
+        let origin = request
+            .origin
+            .as_deref()
+            .is_none_or(|origin| origin.strip_prefix("http://").is_some_and(is_ours));
+        host && origin
+    }
+}
+
+fn handle(stream: TcpStream, shared: &Shared) -> io::Result<()> {
+    stream.set_read_timeout(Some(Duration::from_secs(10)))?;
+    let mut out = stream.try_clone()?;
+    let mut reader = BufReader::new(stream);
+    let request = match read_request(&mut reader) {
+        Ok(request) => request,
+        Err(status) => {
+            respond(&mut out, status, "text/plain", status.as_bytes())?;
+            // Closing with unread input would reset the connection, and
+            // the client might lose the answer.
+            out.shutdown(Shutdown::Write)?;
+            io::copy(&mut reader.take(MAX_SOURCE as u64), &mut io::sink())?;
+            return Ok(());
+        }
+    };
+    if !shared.allows(&request) {
+        return respond(
+            &mut out,
+            "403 Forbidden",
+            "text/plain",
+            b"only pages of this server may use it",
+        );
+    }
+    match (request.method.as_str(), request.path.as_str()) {
+        ("POST", "/run") => run_request(&mut out, shared, &request),
+        ("GET" | "HEAD", path) => match site_file(&shared.site, path) {
+            Some(file) => {
+                let body = std::fs::read(&file)?;
+                let body = if request.method == "HEAD" {
+                    &[][..]
+                } else {
+                    &body
+                };
+                respond(&mut out, "200 OK", content_type(&file), body)
+            }
+            None => respond(&mut out, "404 Not Found", "text/plain", b"not found"),
+        },
+        _ => respond(
+            &mut out,
+            "405 Method Not Allowed",
+            "text/plain",
+            b"method not allowed",
+        ),
+    }
+}
+
+/// Reads a request, or says which error status to answer with.
+fn read_request(reader: &mut impl BufRead) -> Result<Request, &'static str> {
+    const BAD: &str = "400 Bad Request";
+    let mut left = MAX_HEAD;
+    let mut line = String::new();
+    read_head_line(reader, &mut line, &mut left)?;
+    let mut words = line.split_whitespace();
+    let (Some(method), Some(target)) = (words.next(), words.next()) else {
+        return Err(BAD);
+    };
+    let (method, target) = (method.to_string(), target.to_string());
+
+    let mut length = 0;
+    let (mut host, mut origin, mut content_type) = (None, None, None);
+    loop {
+        line.clear();
+        read_head_line(reader, &mut line, &mut left)?;
+        let header = line.trim_end();
+        if header.is_empty() {
+            break;
+        }
+        if let Some((name, value)) = header.split_once(':') {
+            let value = value.trim();
+            if name.eq_ignore_ascii_case("content-length") {
+                length = value.parse().map_err(|_| BAD)?;
+            } else if name.eq_ignore_ascii_case("host") {
+                host = Some(value.to_string());
+            } else if name.eq_ignore_ascii_case("origin") {
+                origin = Some(value.to_string());
+            } else if name.eq_ignore_ascii_case("content-type") {
+                content_type = Some(value.to_string());
+            }
+        }
+    }
+    if length > MAX_SOURCE {
+        return Err("413 Payload Too Large");
+    }
+    let mut body = vec![0; length];
+    reader.read_exact(&mut body).map_err(|_| BAD)?;
+    let path = target.split(['?', '#']).next().unwrap_or("/");
+    Ok(Request {
+        method,
+        path: percent_decode(path).ok_or(BAD)?,
+        host,
+        origin,
+        content_type,
+        body,
+    })
+}
+
+/// Reads a line of the request head into `line`, out of the `left` bytes
+/// the head may still take.
+fn read_head_line(
+    reader: &mut impl BufRead,
+    line: &mut String,
+    left: &mut u64,
+) -> Result<(), &'static str> {
+    let read = reader
+        .by_ref()
+        .take(*left)
+        .read_line(line)
+        .map_err(|_| "400 Bad Request")?;
+    if !line.ends_with('\n') {
+        return Err(if read as u64 == *left {
+            "431 Request Header Fields Too Large"
+        } else {
+            "400 Bad Request"
+        });
+    }
+    *left -= read as u64;
+    Ok(())
+}
+
+/// `%20` and the like decoded, or `None` if that is not UTF-8.
+fn percent_decode(path: &str) -> Option<String> {
+    let bytes = path.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut index = 0;
+    while index < bytes.len() {
+        let escaped = (bytes[index] == b'%')
+            .then(|| path.get(index + 1..index + 3))
+            .flatten()
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+        match escaped {
+            Some(byte) => {
+                decoded.push(byte);
+                index += 3;
+            }
+            None => {
+                decoded.push(bytes[index]);
+                index += 1;
+            }
+        }
+    }
+    String::from_utf8(decoded).ok()
+}
+
+/// The file of the site at a request path, if there is one; `..` and
+/// hidden files are never served.
+fn site_file(site: &Path, path: &str) -> Option<PathBuf> {
+    let mut file = site.to_path_buf();
+    for part in path.split('/').filter(|part| !part.is_empty()) {
+        if part.starts_with('.') || part.contains('\\') {
+            return None;
+        }
+        file.push(part);
+    }
+    if file.is_dir() {
+        file.push("index.html");
+    }
+    file.is_file().then_some(file)
+}
+
+fn content_type(file: &Path) -> &'static str {
+    match file.extension().and_then(|extension| extension.to_str()) {
+        Some("html") => "text/html; charset=utf-8",
+        Some("css") => "text/css; charset=utf-8",
+        Some("js") => "text/javascript; charset=utf-8",
+        Some("json" | "ipynb") => "application/json",
+        Some("svg") => "image/svg+xml",
+        Some("png") => "image/png",
+        _ => "text/plain; charset=utf-8",
+    }
+}
+
+fn respond(out: &mut impl Write, status: &str, content_type: &str, body: &[u8]) -> io::Result<()> {
+    write!(
+        out,
+        "HTTP/1.1 {status}\r\nContent-Type: {content_type}\r\nContent-Length: {}\r\n\
+         Connection: close\r\n\r\n",
+        body.len()
+    )?;
+    out.write_all(body)?;
+    out.flush()
+}
+
+/// The program to build for the source of a code block: example modules
+/// are unwrapped and snippets wrapped, as `verify` does.
+pub fn program(source: &str) -> String {
+    mdbook::example_program(source.trim()).unwrap_or_else(|| as_program(source))
+}
+
+fn run_request(out: &mut TcpStream, shared: &Shared, request: &Request) -> io::Result<()> {
+    let is_json = request.content_type.as_deref().is_some_and(|content_type| {
+        content_type
+            .split(';')
+            .next()
+            .is_some_and(|kind| kind.trim().eq_ignore_ascii_case("application/json"))
+    });
+    if !is_json {
+        return respond(
+            out,
+            "415 Unsupported Media Type",
+            "text/plain",
+            b"send the source as application/json",
+        );
+    }
+    let body = str::from_utf8(&request.body).ok();
+    let json = body.and_then(|body| Value::parse(body).ok());
+    let Some(source) = json.as_ref().and_then(|json| json.get("source")?.as_str()) else {
+        return respond(
+            out,
+            "400 Bad Request",
+            "text/plain",
+            b"expected {\"source\": \"...\"}",
+        );
+    };
+    let taken = shared.running.fetch_add(1, Ordering::SeqCst);
+    let _slot = Slot(&shared.running);
+    if taken >= MAX_RUNS {
+        return respond(
+            out,
+            "503 Service Unavailable",
+            "text/plain",
+            b"too many programs running, try again",
+        );
+    }
+    write!(
+        out,
+        "HTTP/1.1 200 OK\r\nContent-Type: application/x-ndjson\r\n\
+         Transfer-Encoding: chunked\r\nCache-Control: no-store\r\nConnection: close\r\n\r\n"
+    )?;
+    run(source, &shared.limits, &mut Chunked(out))?;
+    out.write_all(b"0\r\n\r\n")?;
+    out.flush()
+}
+
+/// A place among the [`MAX_RUNS`] programs, given back on drop.
+struct Slot<'a>(&'a AtomicUsize);
+
+impl Drop for Slot<'_> {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+/// Compiles and runs `source`, writing what happens to `out` as JSON lines.
+pub fn run(source: &str, limits: &Limits, out: &mut impl Write) -> io::Result<()> {
+    let scratch = Scratch::new("serve")?;
+    let build = compile::compile_source(&program(source), &scratch)?;
+    let Some(binary) = build.binary else {
+        event(out, "stderr", &build.stderr)?;
+        return event(out, "exit", "does not compile");
+    };
+
+    let mut text = [Utf8::default(), Utf8::default()];
+    let mut written = Ok(());
+    let finished = compile::run_streaming(&binary, limits, |stream, bytes| {
+        let (kind, text) = match stream {
+            Stream::Stdout => ("stdout", &mut text[0]),
+            Stream::Stderr => ("stderr", &mut text[1]),
+        };
+        let text = text.push(bytes);
+        if written.is_ok() && !text.is_empty() {
+            written = event(out, kind, &text);
+        }
+    })?;
+    written?;
+    if finished.truncated {
+        event(out, "stderr", "[more output was cut off]\n")?;
+    }
+    event(
+        out,
+        "exit",
+        &playground::ending(finished.status, finished.elapsed),
+    )
+}
+
+/// Writes `{"<kind>": "<text>"}` and a newline, and sends it on.
+fn event(out: &mut impl Write, kind: &str, text: &str) -> io::Result<()> {
+    let line = Value::Object(vec![(kind.to_string(), text.into())]);
+    out.write_all(format!("{line}\n").as_bytes())?;
+    out.flush()
+}
+
+/// Writes each write as a chunk of a chunked HTTP response.
+struct Chunked<'a>(&'a mut TcpStream);
+
+impl Write for Chunked<'_> {
+    fn write(&mut self, bytes: &[u8]) -> io::Result<usize> {
+        if !bytes.is_empty() {
+            write!(self.0, "{:x}\r\n", bytes.len())?;
+            self.0.write_all(bytes)?;
+            self.0.write_all(b"\r\n")?;
+        }
+        Ok(bytes.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.0.flush()
+    }
+}
+
+/// Turns output into text as it arrives, holding back a character split
+/// between two reads.
+#[derive(Debug, Default)]
+struct Utf8 {
+    pending: Vec<u8>,
+}
+
+impl Utf8 {
+    fn push(&mut self, bytes: &[u8]) -> String {
+        self.pending.extend_from_slice(bytes);
+        let complete = match str::from_utf8(&self.pending) {
+            Err(err) if err.error_len().is_none() => err.valid_up_to(),
+            _ => self.pending.len(),
+        };
+        let text = String::from_utf8_lossy(&self.pending[..complete]).into_owned();
+        self.pending.drain(..complete);
+        text
+    }
+}
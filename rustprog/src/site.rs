@@ -0,0 +1,536 @@
+//! Renders the course as a static HTML site.
+//!
+//! The site mirrors the course layout: `index.html` is the table of
+//! contents, every chapter directory gets an `index.html` listing its
+//! lessons, and every lesson `_0M_<Name>.rs` becomes `_0M_<Name>.html` next
+//! to it. Pages link to each other relatively, so the site can be opened
+//! straight from disk.
+//!
+//! Every Rust code block has a Run button, shown by `run.js` when the site
+//! is served by `rustprog serve` (see [`crate::serve`]). Example modules and
+//! the snippets of the prose run on their own; a piece of a lesson's `main`
+//! runs the whole lesson, whose source each page carries for that.
+
+use std::collections::HashMap;
+use std::fmt::Write as _;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use crate::course::{Chapter, Course, Lesson};
+use crate::document::{Block, CodeBlock, Document};
+use crate::html::{escape, highlight_rust};
+use crate::i18n::Translation;
+use crate::mdbook;
+
+/// Shows the Run buttons when the page comes from `rustprog serve`, and
+/// streams what the code prints under it.
+const SCRIPT: &str = r#"if (location.protocol.startsWith("http")) {
+  for (const button of document.querySelectorAll("button.run")) {
+    button.hidden = false;
+    button.addEventListener("click", () => run(button));
+  }
+}
+
+async function run(button) {
+  const code = button.dataset.run === "lesson"
+    ? document.getElementById("lesson-program")
+    : button.previousElementSibling;
+  const output = button.nextElementSibling;
+  output.hidden = false;
+  output.textContent = "";
+  button.disabled = true;
+  try {
+    const response = await fetch("/run", {
+      method: "POST",
+      headers: { "Content-Type": "application/json" },
+      body: JSON.stringify({ source: code.textContent }),
+    });
+    const reader = response.body.getReader();
+    const decoder = new TextDecoder();
+    let pending = "";
+    for (;;) {
+      const { done, value } = await reader.read();
+      if (done) break;
+      pending += decoder.decode(value, { stream: true });
+      const lines = pending.split("\n");
+      pending = lines.pop();
+      for (const line of lines) {
+        if (line) show(output, JSON.parse(line));
+      }
+    }
+  } catch (error) {
+    append(output, "stderr", String(error) + "\n");
+  }
+  button.disabled = false;
+}
+
+function show(output, event) {
+  for (const [kind, text] of Object.entries(event)) {
+    append(output, kind, kind === "exit" ? "-- " + text + "\n" : text);
+  }
+}
+
+function append(output, kind, text) {
+  const span = document.createElement("span");
+  span.className = kind;
+  span.textContent = text;
+  output.append(span);
+}
+"#;
+
+const STYLE: &str = "\
+body { margin: 0; font: 16px/1.6 system-ui, sans-serif; color: #222; display: flex; }
+a { color: #b7410e; }
+nav.course { width: 17rem; flex-shrink: 0; padding: 1rem; background: #f6f2ee; min-height: 100vh; font-size: 14px; }
+nav.course ol { padding-left: 1.2rem; }
+nav.course .current { font-weight: bold; }
+main { max-width: 48rem; padding: 1rem 2rem 3rem; }
+.crumbs { font-size: 14px; color: #666; }
+nav.toc { border-left: 3px solid #e0d6cc; padding-left: 1rem; font-size: 14px; }
+pre { background: #f8f8f8; border: 1px solid #e4e4e4; padding: .75rem; overflow-x: auto; font-size: 14px; line-height: 1.4; }
+pre.example { border-left: 3px solid #b7410e; }
+.output pre { background: #222; color: #eee; }
+.output .label { margin-bottom: 0; font-size: 13px; color: #666; }
+table { border-collapse: collapse; }
+th, td { border: 1px solid #ccc; padding: .3rem .8rem; text-align: left; font-family: monospace; }
+.kw { color: #8959a8; } .str { color: #718c00; } .num { color: #f5871f; }
+.com { color: #8e908c; font-style: italic; } .mac { color: #3e999f; } .ty { color: #4271ae; }
+button.run { margin: -.5rem 0 1rem; font-size: 13px; }
+pre.run-output { background: #222; color: #eee; margin-top: 0; }
+pre.run-output .stderr { color: #f99; } pre.run-output .exit { color: #999; }
+nav.pager { display: flex; justify-content: space-between; margin-top: 3rem; border-top: 1px solid #ddd; padding-top: 1rem; }
+";
+
+/// Writes the site for `course` into `out`, returning the number of pages.
+pub fn build(course: &Course, out: &Path) -> io::Result<usize> {
+    build_translated(course, &Translation::source(), out)
+}
+
+/// Writes the site in the language of `translation`.
+pub fn build_translated(
+    course: &Course,
+    translation: &Translation,
+    out: &Path,
+) -> io::Result<usize> {
+    let lessons: Vec<&Lesson> = course.lessons().collect();
+    let sources = lessons
+        .iter()
+        .map(|lesson| fs::read_to_string(&lesson.path))
+        .collect::<io::Result<Vec<_>>>()?;
+    let documents: Vec<Document> = sources
+        .iter()
+        .zip(&lessons)
+        .map(|(source, lesson)| translation.document(lesson, &Document::parse(source)))
+        .collect();
+    let site = Site {
+        course,
+        translation,
+        titles: lessons
+            .iter()
+            .zip(&documents)
+            .map(|(lesson, document)| (lesson.path.as_path(), lesson_title(lesson, document)))
+            .collect(),
+    };
+
+    fs::create_dir_all(out)?;
+    fs::write(out.join("style.css"), STYLE)?;
+    fs::write(out.join("run.js"), SCRIPT)?;
+    fs::write(out.join("index.html"), site.contents_page())?;
+    let mut pages = 1;
+
+    for (index, chapter) in course.chapters.iter().enumerate() {
+        let dir = out.join(&chapter.dir_name);
+        fs::create_dir_all(&dir)?;
+        let neighbours = (
+            index.checked_sub(1).map(|index| &course.chapters[index]),
+            course.chapters.get(index + 1),
+        );
+        fs::write(
+            dir.join("index.html"),
+            site.chapter_page(chapter, neighbours),
+        )?;
+        pages += 1;
+    }
+    for (index, ((lesson, document), source)) in
+        lessons.iter().zip(&documents).zip(&sources).enumerate()
+    {
+        let neighbours = (
+            index.checked_sub(1).map(|index| lessons[index]),
+            lessons.get(index + 1).copied(),
+        );
+        let path = out
+            .join(&course.chapter_of(lesson).dir_name)
+            .join(format!("{}.html", lesson.stem));
+        fs::write(path, site.lesson_page(lesson, document, source, neighbours))?;
+        pages += 1;
+    }
+    Ok(pages)
+}
+
+/// The course with the titles of its lessons.
+struct Site<'a> {
+    course: &'a Course,
+    translation: &'a Translation,
+    titles: HashMap<&'a Path, String>,
+}
+
+/// Link to `lesson` from a page inside a chapter directory.
+fn lesson_href(course: &Course, lesson: &Lesson) -> String {
+    format!(
+        "../{}/{}.html",
+        course.chapter_of(lesson).dir_name,
+        lesson.stem
+    )
+}
+
+/// The lesson's title: its first heading, or the name of its file.
+fn lesson_title(lesson: &Lesson, document: &Document) -> String {
+    document.title.clone().unwrap_or_else(|| lesson.name())
+}
+
+impl Site<'_> {
+    fn page(&self, title: &str, root: &str, nav: &str, body: &str) -> String {
+        format!(
+            "<!DOCTYPE html>\n<html lang=\"{}\">\n<head>\n<meta charset=\"utf-8\">\n\
+             <meta name=\"viewport\" content=\"width=device-width, initial-scale=1\">\n\
+             <title>{} · {}</title>\n<link rel=\"stylesheet\" href=\"{root}style.css\">\n\
+             <script src=\"{root}run.js\" defer></script>\n</head>\n<body>\n{nav}<main>\n{body}</main>\n</body>\n</html>\n",
+            self.translation.language(),
+            escape(title),
+            escape(self.translation.course_title())
+        )
+    }
+
+    fn title(&self, lesson: &Lesson) -> &str {
+        &self.titles[lesson.path.as_path()]
+    }
+
+    /// The chapter navigation shown on every page inside a chapter.
+    fn course_nav(&self, current: Option<&Lesson>) -> String {
+        let course = self.course;
+        let mut nav = String::from("<nav class=\"course\">\n");
+        let _ = writeln!(
+            nav,
+            "<p><a href=\"../index.html\">{}</a></p>",
+            escape(self.translation.course_title())
+        );
+        for chapter in &course.chapters {
+            let _ = writeln!(
+                nav,
+                "<p><a href=\"../{}/index.html\">{}. {}</a></p>\n<ol>",
+                chapter.dir_name,
+                chapter.number,
+                escape(&self.translation.chapter_name(chapter))
+            );
+            for lesson in &chapter.lessons {
+                let is_current = current.is_some_and(|current| current.path == lesson.path);
+                let attributes = if is_current {
+                    " class=\"current\" aria-current=\"page\""
+                } else {
+                    ""
+                };
+                let _ = writeln!(
+                    nav,
+                    "<li value=\"{}\"><a href=\"{}\"{attributes}>{}</a></li>",
+                    lesson.number,
+                    lesson_href(course, lesson),
+                    escape(self.title(lesson))
+                );
+            }
+            nav.push_str("</ol>\n");
+        }
+        nav.push_str("</nav>\n");
+        nav
+    }
+
+    fn contents_page(&self) -> String {
+        let course = self.course;
+        let mut body = format!(
+            "<h1>{}</h1>\n<nav class=\"contents\">\n",
+            escape(self.translation.course_title())
+        );
+        for chapter in &course.chapters {
+            let _ = writeln!(
+                body,
+                "<h2><a href=\"{}/index.html\">{}. {}</a></h2>\n<ol>",
+                chapter.dir_name,
+                chapter.number,
+                escape(&self.translation.chapter_name(chapter))
+            );
+            for lesson in &chapter.lessons {
+                let title = self.title(lesson);
+                let _ = writeln!(
+                    body,
+                    "<li value=\"{}\"><a href=\"{}/{}.html\">{} {}</a></li>",
+                    lesson.number,
+                    chapter.dir_name,
+                    lesson.stem,
+                    lesson.id(),
+                    escape(title)
+                );
+            }
+            body.push_str("</ol>\n");
+        }
+        body.push_str("</nav>\n");
+        self.page(self.translation.text("Contents"), "", "", &body)
+    }
+
+    fn chapter_page(
+        &self,
+        chapter: &Chapter,
+        (previous, next): (Option<&Chapter>, Option<&Chapter>),
+    ) -> String {
+        let mut body = format!(
+            "<p class=\"crumbs\"><a href=\"../index.html\">{}</a></p>\n<h1>{}. {}</h1>\n<ol>\n",
+            escape(self.translation.text("Contents")),
+            chapter.number,
+            escape(&self.translation.chapter_name(chapter))
+        );
+        for lesson in &chapter.lessons {
+            let title = self.title(lesson);
+            let _ = writeln!(
+                body,
+                "<li value=\"{}\"><a href=\"{}.html\">{} {}</a></li>",
+                lesson.number,
+                lesson.stem,
+                lesson.id(),
+                escape(title)
+            );
+        }
+        body.push_str("</ol>\n");
+        body += &pager(
+            previous.map(|chapter| {
+                (
+                    format!("../{}/index.html", chapter.dir_name),
+                    format!(
+                        "{}. {}",
+                        chapter.number,
+                        self.translation.chapter_name(chapter)
+                    ),
+                )
+            }),
+            next.map(|chapter| {
+                (
+                    format!("../{}/index.html", chapter.dir_name),
+                    format!(
+                        "{}. {}",
+                        chapter.number,
+                        self.translation.chapter_name(chapter)
+                    ),
+                )
+            }),
+        );
+        self.page(
+            &self.translation.chapter_name(chapter),
+            "../",
+            &self.course_nav(None),
+            &body,
+        )
+    }
+
+    fn lesson_page(
+        &self,
+        lesson: &Lesson,
+        document: &Document,
+        source: &str,
+        (previous, next): (Option<&Lesson>, Option<&Lesson>),
+    ) -> String {
+        let course = self.course;
+        let chapter = course.chapter_of(lesson);
+        let title = lesson_title(lesson, document);
+        let mut ids = Ids::default();
+        let headings: Vec<(u8, String, String)> = document
+            .headings()
+            .map(|(level, text)| (level, text.to_string(), ids.next(text)))
+            .collect();
+
+        let mut body = format!(
+            "<p class=\"crumbs\"><a href=\"../index.html\">{}</a> › \
+             <a href=\"index.html\">{}. {}</a></p>\n<h1>{} {}</h1>\n",
+            escape(self.translation.text("Contents")),
+            chapter.number,
+            escape(&self.translation.chapter_name(chapter)),
+            lesson.id(),
+            escape(&title)
+        );
+        if !headings.is_empty() {
+            let _ = write!(
+                body,
+                "<nav class=\"toc\">\n<p>{}</p>\n<ul>\n",
+                escape(self.translation.text("On this page"))
+            );
+            for (level, text, id) in &headings {
+                let indent = if *level > 1 {
+                    " style=\"margin-left: 1rem\""
+                } else {
+                    ""
+                };
+                let _ = writeln!(
+                    body,
+                    "<li{indent}><a href=\"#{id}\">{}</a></li>",
+                    escape(text)
+                );
+            }
+            body.push_str("</ul>\n</nav>\n");
+        }
+
+        body.push_str("<article>\n");
+        let mut ids = headings.iter().map(|(_, _, id)| id);
+        for block in &document.blocks {
+            render_block(&mut body, block, &mut ids, self.translation);
+        }
+        body.push_str("</article>\n");
+        let _ = writeln!(
+            body,
+            "<pre id=\"lesson-program\" hidden>{}</pre>",
+            escape(source)
+        );
+
+        let link = |lesson: &Lesson| {
+            (
+                lesson_href(course, lesson),
+                format!("{} {}", lesson.id(), self.title(lesson)),
+            )
+        };
+        body += &pager(previous.map(link), next.map(link));
+        self.page(&title, "../", &self.course_nav(Some(lesson)), &body)
+    }
+}
+
+fn render_block<'a>(
+    out: &mut String,
+    block: &Block,
+    ids: &mut impl Iterator<Item = &'a String>,
+    translation: &Translation,
+) {
+    match block {
+        Block::Heading { level, text } => {
+            let tag = if *level == 1 { "h2" } else { "h3" };
+            let id = ids.next().map_or("", String::as_str);
+            let _ = writeln!(out, "<{tag} id=\"{id}\">{}</{tag}>", escape(text));
+        }
+        Block::Paragraph(text) => {
+            let _ = writeln!(out, "<p>{}</p>", escape(text));
+        }
+        Block::List(items) => {
+            out.push_str("<ul>\n");
+            for item in items {
+                let _ = writeln!(out, "<li>{}</li>", escape(item));
+            }
+            out.push_str("</ul>\n");
+        }
+        Block::Table { header, rows } => {
+            out.push_str("<table>\n<thead><tr>");
+            for cell in header {
+                let _ = write!(out, "<th>{}</th>", escape(cell));
+            }
+            out.push_str("</tr></thead>\n<tbody>\n");
+            for row in rows {
+                out.push_str("<tr>");
+                for cell in row {
+                    let _ = write!(out, "<td>{}</td>", escape(cell));
+                }
+                out.push_str("</tr>\n");
+            }
+            out.push_str("</tbody>\n</table>\n");
+        }
+        Block::Code(CodeBlock {
+            code,
+            language,
+            live,
+            ..
+        }) => {
+            let class = if *live { "lesson" } else { "example" };
+            // A piece of the lesson's `main` cannot run alone.
+            let runs_alone = !*live || mdbook::example_program(code).is_some();
+            let code = if language == "rust" {
+                highlight_rust(code)
+            } else {
+                escape(code)
+            };
+            let _ = writeln!(
+                out,
+                "<pre class=\"{class}\"><code class=\"language-{}\">{code}</code></pre>",
+                escape(language)
+            );
+            if language == "rust" {
+                let (run, label) = if runs_alone {
+                    ("block", translation.text("Run"))
+                } else {
+                    ("lesson", translation.text("Run lesson"))
+                };
+                let _ = writeln!(
+                    out,
+                    "<button type=\"button\" class=\"run\" data-run=\"{run}\" hidden>{}</button>\n\
+                     <pre class=\"run-output\" hidden></pre>",
+                    escape(label)
+                );
+            }
+        }
+        Block::Output(lines) => {
+            let _ = writeln!(
+                out,
+                "<div class=\"output\">\n<p class=\"label\">{}</p>\n<pre>{}</pre>\n</div>",
+                escape(translation.text("Output")),
+                escape(&lines.join("\n"))
+            );
+        }
+        Block::Preformatted(text) => {
+            let _ = writeln!(out, "<pre class=\"text\">{}</pre>", escape(text));
+        }
+        Block::Rule => out.push_str("<hr>\n"),
+    }
+}
+
+/// Previous and next links, as `(href, label)`.
+fn pager(previous: Option<(String, String)>, next: Option<(String, String)>) -> String {
+    let mut out = String::from("<nav class=\"pager\">\n");
+    match previous {
+        Some((href, label)) => {
+            let _ = writeln!(
+                out,
+                "<a rel=\"prev\" href=\"{href}\">← {}</a>",
+                escape(&label)
+            );
+        }
+        None => out.push_str("<span></span>\n"),
+    }
+    if let Some((href, label)) = next {
+        let _ = writeln!(
+            out,
+            "<a rel=\"next\" href=\"{href}\">{} →</a>",
+            escape(&label)
+        );
+    }
+    out.push_str("</nav>\n");
+    out
+}
+
+/// Unique anchors for the headings of a page.
+#[derive(Default)]
+struct Ids {
+    used: HashMap<String, usize>,
+}
+
+impl Ids {
+    /// `working-of-variable-scope-in-rust` for `Working of Variable Scope in Rust`.
+    fn next(&mut self, text: &str) -> String {
+        let mut slug = String::new();
+        for c in text.chars() {
+            if c.is_alphanumeric() {
+                slug.extend(c.to_lowercase());
+            } else if !slug.is_empty() && !slug.ends_with('-') {
+                slug.push('-');
+            }
+        }
+        let slug = slug.trim_end_matches('-').to_string();
+        let count = self.used.entry(slug.clone()).or_insert(0);
+        *count += 1;
+        if *count == 1 {
+            slug
+        } else {
+            format!("{slug}-{count}")
+        }
+    }
+}
@@ -0,0 +1,112 @@
+//! Renders a lesson as plain text, to read in the terminal.
+//!
+//! Headings are underlined the way the lessons underline them, prose is
+//! wrapped to [`WIDTH`] columns and code is indented by four spaces, so
+//! the page reads like the lesson file without the `//` of its comments.
+
+use std::fmt::Write as _;
+
+use crate::document::{Block, Document};
+use crate::i18n::Translation;
+
+/// Columns the prose is wrapped to.
+pub const WIDTH: usize = 78;
+
+/// The page for a lesson titled `title`.
+pub fn render(title: &str, document: &Document, translation: &Translation) -> String {
+    let mut out = String::new();
+    underline(&mut out, title, '=');
+    for block in &document.blocks {
+        out.push('\n');
+        render_block(&mut out, block, translation);
+    }
+    out
+}
+
+fn render_block(out: &mut String, block: &Block, translation: &Translation) {
+    match block {
+        Block::Heading { level, text } => underline(out, text, if *level == 1 { '=' } else { '-' }),
+        Block::Paragraph(text) => wrap(out, text, "", ""),
+        Block::List(items) => {
+            for item in items {
+                wrap(out, item, "  - ", "    ");
+            }
+        }
+        Block::Table { header, rows } => table(out, header, rows),
+        Block::Code(code) => indented(out, &code.code),
+        Block::Output(lines) => {
+            let _ = writeln!(out, "{}:", translation.text("Output"));
+            indented(out, &lines.join("\n"));
+        }
+        Block::Preformatted(text) => indented(out, text),
+        Block::Rule => {
+            let _ = writeln!(out, "{}", "-".repeat(WIDTH));
+        }
+    }
+}
+
+fn width(text: &str) -> usize {
+    text.chars().count()
+}
+
+fn underline(out: &mut String, text: &str, mark: char) {
+    let _ = writeln!(out, "{text}\n{}", mark.to_string().repeat(width(text)));
+}
+
+/// Writes `text` wrapped at word boundaries, starting with `first` and
+/// continuing with `rest`.
+fn wrap(out: &mut String, text: &str, first: &str, rest: &str) {
+    let mut line = first.to_string();
+    let mut empty = true;
+    for word in text.split_whitespace() {
+        if !empty && width(&line) + 1 + width(word) > WIDTH {
+            let _ = writeln!(out, "{line}");
+            line = rest.to_string();
+            empty = true;
+        }
+        if !empty {
+            line.push(' ');
+        }
+        line.push_str(word);
+        empty = false;
+    }
+    let _ = writeln!(out, "{}", line.trim_end());
+}
+
+fn indented(out: &mut String, text: &str) {
+    for line in text.lines() {
+        if line.is_empty() {
+            out.push('\n');
+        } else {
+            let _ = writeln!(out, "    {line}");
+        }
+    }
+}
+
+/// Writes a table with its columns lined up and a rule under the header.
+pub(crate) fn table(out: &mut String, header: &[String], rows: &[Vec<String>]) {
+    let mut widths: Vec<usize> = header.iter().map(|cell| width(cell)).collect();
+    for row in rows {
+        for (index, cell) in row.iter().enumerate() {
+            match widths.get_mut(index) {
+                Some(column) => *column = (*column).max(width(cell)),
+                None => widths.push(width(cell)),
+            }
+        }
+    }
+    table_row(out, header, &widths);
+    let rule: Vec<String> = widths.iter().map(|width| "-".repeat(*width)).collect();
+    table_row(out, &rule, &widths);
+    for row in rows {
+        table_row(out, row, &widths);
+    }
+}
+
+fn table_row(out: &mut String, cells: &[String], widths: &[usize]) {
+    let mut line = String::new();
+    for (index, width) in widths.iter().enumerate() {
+        let cell = cells.get(index).map_or("", String::as_str);
+        let _ = write!(line, "{cell:<width$}  ");
+    }
+    let _ = writeln!(out, "{}", line.trim_end());
+}
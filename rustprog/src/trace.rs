@@ -0,0 +1,763 @@
+//! Step-through traces of the loops and branches of a lesson.
+//!
+//! The lesson is instrumented where it stands, line for line, so what the
+//! tracer reports lines up with the lesson file:
+//!
+//! - the condition of every `if`, `else if` and `while` is wrapped in a
+//!   call that records whether it held;
+//! - `else` blocks, and each iteration of a `loop`, `for` or `while let`,
+//!   record that they were entered;
+//! - `break` and `continue` record that they were reached, and the tracer
+//!   knows from the braces where each one jumps to.
+//!
+//! Every record carries the values of the variables in scope that the
+//! statement uses, `i` and `j` in a nested loop say, printed with `{:?}`.
+//! If that does not compile, for a variable that is not `Debug` or was
+//! moved, the lesson is traced again without the values.
+//!
+//! Records go to stdout with the program's own output, marked with an
+//! ASCII record separator, so what the program prints is kept in order
+//! between the steps. A trace stops after a number of steps, so endless
+//! loops end too.
+
+use std::collections::HashSet;
+use std::fmt::Write as _;
+use std::io;
+
+use crate::compile::{self, Limits, Scratch};
+use crate::lesson::{is_live_code, split_line_comment, LessonSource};
+use crate::text;
+
+/// Steps recorded before the program is stopped.
+pub const STEPS: usize = 500;
+
+/// Limits for a traced run; the records make the output much longer than
+/// the program's own.
+pub const LIMITS: Limits = Limits {
+    timeout: std::time::Duration::from_secs(10),
+    memory_mb: 512,
+    processes: 256,
+    output_bytes: 4 << 20,
+};
+
+/// Starts a record in the output of an instrumented program.
+const RECORD: char = '\u{1e}';
+/// Separates the fields of a record.
+const FIELD: char = '\u{1f}';
+
+/// Something the tracer records, on a line of the lesson.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Point {
+    /// The condition of an `if`, `else if` or `while`.
+    Condition { keyword: String, condition: String },
+    /// An `else` block, or the body of an `if let`, was entered.
+    Entered(String),
+    /// An iteration of a `loop`, `for` or `while let` starts.
+    Iteration(String),
+    /// A `break` or `continue`, with the line it carries on at: the line
+    /// after the loop, or its header.
+    Jump { keyword: String, to: usize },
+    /// The statement is over and the function carries on; its row is not
+    /// shown, but what is printed after it is not part of the statement.
+    End,
+}
+
+/// A control-flow statement of a function body, traced as one table.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Statement {
+    /// First and last line of the statement.
+    pub lines: (usize, usize),
+    /// The heading of the lesson above it.
+    pub heading: Option<String>,
+}
+
+/// One step of a trace.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Step {
+    /// Index into [`Trace::points`].
+    pub point: usize,
+    /// For a condition, whether it held.
+    pub held: Option<bool>,
+    /// The variables in scope the statement uses, and their values.
+    pub values: Vec<(String, String)>,
+    /// What the program printed after this step, up to the next.
+    pub output: String,
+}
+
+/// A traced run of a lesson.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Trace {
+    /// The points, with their line and the statement they belong to.
+    pub points: Vec<(usize, usize, Point)>,
+    pub statements: Vec<Statement>,
+    pub steps: Vec<Step>,
+    /// Whether the steps carry the values of the variables.
+    pub has_values: bool,
+    /// Whether the program was stopped after [`STEPS`] steps, or by the
+    /// limits.
+    pub stopped: bool,
+}
+
+/// The lesson with its points found and instrumented.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Instrumented {
+    pub points: Vec<(usize, usize, Point)>,
+    pub statements: Vec<Statement>,
+    /// The program recording the values of the variables.
+    pub with_values: String,
+    /// The same without the values.
+    pub without_values: String,
+}
+
+/// Traces the lesson `text`, stopping after `steps` steps. `Err` holds the
+/// compiler errors if the lesson does not compile as it is.
+pub fn trace(text: &str, limits: &Limits, steps: usize) -> io::Result<Result<Trace, String>> {
+    let instrumented = instrument(text, steps);
+    let scratch = Scratch::new("trace")?;
+    let mut has_values = true;
+    let mut build = compile::compile_source(&instrumented.with_values, &scratch)?;
+    if build.binary.is_none() {
+        has_values = false;
+        build = compile::compile_source(&instrumented.without_values, &scratch)?;
+    }
+    let Some(binary) = build.binary else {
+        // Report the errors of the lesson itself, not of the instrumented copy.
+        return Ok(Err(compile::compile_source(text, &scratch)?.stderr));
+    };
+    let run = compile::run_limited(&binary, limits)?;
+    let (steps, stopped) = parse_steps(&String::from_utf8_lossy(&run.stdout));
+    Ok(Ok(Trace {
+        points: instrumented.points,
+        statements: instrumented.statements,
+        steps,
+        has_values,
+        stopped: stopped || run.truncated || run.status.is_none(),
+    }))
+}
+
+/// What kind of block a `{` opens.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    Function,
+    Loop(usize),
+    Other,
+}
+
+/// A block open at some point of the lesson.
+#[derive(Debug, Clone)]
+struct Open {
+    kind: Kind,
+    /// Variables declared in it so far.
+    names: Vec<String>,
+}
+
+/// A loop of the lesson, for resolving where its jumps go.
+#[derive(Debug, Clone)]
+struct Loop {
+    label: Option<String>,
+    header: usize,
+    end: usize,
+}
+
+/// Finds the points of `text` and writes the instrumented programs.
+pub fn instrument(text: &str, steps: usize) -> Instrumented {
+    let source = LessonSource::parse(text);
+    let raw: Vec<&str> = text.lines().collect();
+    let live: Vec<bool> = (1..=raw.len())
+        .map(|number| is_live_code(source.line(number)))
+        .collect();
+    let headings = source.headings();
+
+    let mut stack: Vec<Open> = Vec::new();
+    let mut loops: Vec<Loop> = Vec::new();
+    let mut statements: Vec<Statement> = Vec::new();
+    let mut current: Option<usize> = None;
+    let mut ended: Option<usize> = None;
+    // The points with the names in scope there, and the jumps still to
+    // resolve: the point and the loop it leaves or repeats.
+    let mut points: Vec<(usize, usize, Point, Vec<String>)> = Vec::new();
+    let mut jumps: Vec<(usize, usize)> = Vec::new();
+    // Names of a `let` whose value is a block, with the depth they are
+    // declared at; they are in scope once the block is closed.
+    let mut pending: Vec<(usize, String)> = Vec::new();
+
+    for (index, line) in raw.iter().enumerate() {
+        let number = index + 1;
+        if !live[index] {
+            continue;
+        }
+        let code = code_of(line).trim();
+        let braces = braces(code);
+        if let Some(statement) = ended.take() {
+            let carries_on = ["}", ")", "]", ".", "else"]
+                .iter()
+                .any(|start| code.starts_with(start));
+            if !carries_on {
+                points.push((number, statement, Point::End, Vec::new()));
+            }
+        }
+        let in_function = stack.last().is_some_and(|open| open.kind == Kind::Function);
+        if in_function && current.is_none() && braces.first() == Some(&'{') {
+            statements.push(Statement {
+                lines: (number, number),
+                heading: headings
+                    .iter()
+                    .rev()
+                    .find(|heading| heading.line < number)
+                    .map(|heading| heading.text.clone()),
+            });
+            current = Some(statements.len() - 1);
+        }
+
+        let opens = code.ends_with('{');
+        let (label, header) = split_label(code.trim_end_matches('{').trim_end());
+        let before = in_scope(&stack);
+        if let Some(statement) = current {
+            if let Some((keyword, condition)) = condition(header).filter(|_| opens) {
+                let point = Point::Condition {
+                    keyword: keyword.to_string(),
+                    condition: condition.to_string(),
+                };
+                points.push((number, statement, point, before.clone()));
+            } else if let Some(keyword) = ["break", "continue"]
+                .into_iter()
+                .find(|keyword| starts_with_word(code, keyword))
+            {
+                let label = code[keyword.len()..]
+                    .trim_start()
+                    .strip_prefix('\'')
+                    .map(|rest| rest.split(|c: char| !is_ident(c)).next().unwrap_or(""));
+                let mut open_loops = stack.iter().rev().filter_map(|open| match open.kind {
+                    Kind::Loop(index) => Some(index),
+                    _ => None,
+                });
+                let target = match label {
+                    Some(label) => {
+                        open_loops.find(|index| loops[*index].label.as_deref() == Some(label))
+                    }
+                    None => open_loops.next(),
+                };
+                if let Some(target) = target {
+                    jumps.push((points.len(), target));
+                    let point = Point::Jump {
+                        keyword: keyword.to_string(),
+                        to: 0,
+                    };
+                    points.push((number, statement, point, before.clone()));
+                }
+            }
+        }
+
+        let is_loop = opens && is_loop_header(header);
+        let is_function = code.starts_with("fn ") || code.starts_with("pub fn ");
+        for brace in &braces {
+            if *brace == '{' {
+                let kind = if is_function {
+                    Kind::Function
+                } else if is_loop {
+                    loops.push(Loop {
+                        label: label.map(str::to_string),
+                        header: number,
+                        end: number,
+                    });
+                    Kind::Loop(loops.len() - 1)
+                } else {
+                    Kind::Other
+                };
+                stack.push(Open {
+                    kind,
+                    names: Vec::new(),
+                });
+            } else if let Some(Open {
+                kind: Kind::Loop(index),
+                ..
+            }) = stack.pop()
+            {
+                loops[index].end = number;
+            }
+        }
+        let depth = stack.len();
+        pending.retain(|(at, name)| {
+            if *at < depth {
+                return true;
+            }
+            if let Some(open) = stack.last_mut() {
+                open.names.push(name.clone());
+            }
+            false
+        });
+
+        // Names declared on the line: the pattern of a `for` inside its
+        // body, a `let` after the line or once its block is closed.
+        let opened = braces.iter().filter(|brace| **brace == '{').count()
+            > braces.iter().filter(|brace| **brace == '}').count();
+        let declared = declared(code);
+        if let Some(pattern) = for_pattern(header).filter(|_| opened) {
+            if let Some(open) = stack.last_mut() {
+                open.names.extend(declared_pattern(pattern));
+            }
+        } else if opened {
+            pending.extend(declared.into_iter().map(|name| (depth - 1, name)));
+        } else if let Some(open) = stack.last_mut() {
+            open.names.extend(declared);
+        }
+
+        let Some(statement) = current else {
+            continue;
+        };
+        let after = in_scope(&stack);
+        if opens && (header == "} else" || header == "else") {
+            points.push((number, statement, Point::Entered("else".to_string()), after));
+        } else if opens && (header.starts_with("if let ") || header.starts_with("} else if let ")) {
+            let header = header.trim_start_matches("} else ").to_string();
+            points.push((number, statement, Point::Entered(header), after));
+        } else if is_loop && condition(header).is_none() {
+            points.push((
+                number,
+                statement,
+                Point::Iteration(header.to_string()),
+                after,
+            ));
+        }
+        statements[statement].lines.1 = number;
+        if stack.last().is_some_and(|open| open.kind == Kind::Function) {
+            ended = current.take();
+        }
+    }
+
+    for (point, target) in jumps {
+        let Point::Jump { keyword, to } = &mut points[point].2 else {
+            continue;
+        };
+        let Loop { header, end, .. } = loops[target];
+        *to = if keyword == "continue" {
+            header
+        } else {
+            (end + 1..=raw.len())
+                .find(|number| live[number - 1])
+                .unwrap_or(end)
+        };
+    }
+
+    // Statements without a point are not traced; in the others only the
+    // names the statement uses are recorded.
+    let mut kept: Vec<Option<usize>> = vec![None; statements.len()];
+    let mut traced = Vec::new();
+    for (index, statement) in statements.into_iter().enumerate() {
+        if points
+            .iter()
+            .any(|(_, of, point, _)| *of == index && *point != Point::End)
+        {
+            kept[index] = Some(traced.len());
+            traced.push(statement);
+        }
+    }
+    let used: Vec<HashSet<String>> = traced
+        .iter()
+        .map(|statement| {
+            (statement.lines.0..=statement.lines.1)
+                .filter(|number| live[number - 1])
+                .flat_map(|number| identifiers(code_of(raw[number - 1])))
+                .collect()
+        })
+        .collect();
+    let points: Vec<(usize, usize, Point, Vec<String>)> = points
+        .into_iter()
+        .filter_map(|(line, statement, point, names)| {
+            let statement = kept[statement]?;
+            let names = names
+                .into_iter()
+                .filter(|name| used[statement].contains(name))
+                .collect();
+            Some((line, statement, point, names))
+        })
+        .collect();
+
+    let write = |with_values: bool| {
+        let mut lines: Vec<String> = raw.iter().map(|line| line.to_string()).collect();
+        for (index, (number, _, point, names)) in points.iter().enumerate() {
+            let values: Vec<String> = names
+                .iter()
+                .filter(|_| with_values)
+                .map(|name| format!("(\"{name}\", format!(\"{{:?}}\", {name}))"))
+                .collect();
+            let values = format!("&[{}]", values.join(", "));
+            let line = &mut lines[number - 1];
+            let code = code_of(line);
+            let comment = &line[code.len()..];
+            let indent = &code[..code.len() - code.trim_start().len()];
+            let code = code.trim();
+            let rewritten = match point {
+                Point::Condition { condition, .. } => {
+                    let head = code.trim_end_matches('{').trim_end();
+                    let head = &head[..head.len() - condition.len()];
+                    format!("{head}__cond({index}, {condition}, {values}) {{")
+                }
+                Point::Entered(_) | Point::Iteration(_) => {
+                    format!("{code} __step({index}, {values});")
+                }
+                Point::Jump { .. } | Point::End => {
+                    format!("__step({index}, {values}); {code}")
+                }
+            };
+            let space = if comment.is_empty() { "" } else { " " };
+            *line = format!("{indent}{rewritten}{space}{}", comment.trim_start());
+        }
+        let mut program = lines.join("\n");
+        let _ = write!(program, "\n\n{}", helpers(steps));
+        program
+    };
+
+    Instrumented {
+        with_values: write(true),
+        without_values: write(false),
+        points: points
+            .into_iter()
+            .map(|(line, statement, point, _)| (line, statement, point))
+            .collect(),
+        statements: traced,
+    }
+}
+
+/// A line of code without its `//` comment.
+pub(crate) fn code_of(line: &str) -> &str {
+    split_line_comment(line).map_or(line, |(code, _)| code)
+}
+
+/// The functions the instrumented program calls.
+fn helpers(steps: usize) -> String {
+    format!(
+        "#[allow(dead_code)]
+fn __record(point: usize, held: &str, values: &[(&str, String)]) {{
+    use std::sync::atomic::{{AtomicUsize, Ordering}};
+    static STEPS: AtomicUsize = AtomicUsize::new(0);
+    if STEPS.fetch_add(1, Ordering::Relaxed) >= {steps} {{
+        println!(\"{RECORD}stop\");
+        std::process::exit(0);
+    }}
+    let mut record = format!(\"{RECORD}{{point}}{FIELD}{{held}}\");
+    for (name, value) in values {{
+        record.push_str(&format!(\"{FIELD}{{name}}={{value}}\"));
+    }}
+    println!(\"{{record}}\");
+}}
+
+#[allow(dead_code)]
+fn __cond(point: usize, held: bool, values: &[(&str, String)]) -> bool {{
+    __record(point, if held {{ \"true\" }} else {{ \"false\" }}, values);
+    held
+}}
+
+#[allow(dead_code)]
+fn __step(point: usize, values: &[(&str, String)]) {{
+    __record(point, \"\", values);
+}}
+"
+    )
+}
+
+/// The steps recorded in `stdout`, and whether the program was stopped.
+pub fn parse_steps(stdout: &str) -> (Vec<Step>, bool) {
+    let mut steps: Vec<Step> = Vec::new();
+    let mut stopped = false;
+    let mut pieces = stdout.split(RECORD);
+    // What the program printed before its first step is not part of a trace.
+    pieces.next();
+    for piece in pieces {
+        let (record, output) = piece.split_once('\n').unwrap_or((piece, ""));
+        let mut fields = record.split(FIELD);
+        let point = fields.next().and_then(|point| point.parse().ok());
+        let Some(point) = point else {
+            stopped |= record == "stop";
+            continue;
+        };
+        let held = match fields.next() {
+            Some("true") => Some(true),
+            Some("false") => Some(false),
+            _ => None,
+        };
+        let values = fields
+            .filter_map(|field| field.split_once('='))
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+            .collect();
+        steps.push(Step {
+            point,
+            held,
+            values,
+            output: output.to_string(),
+        });
+    }
+    (steps, stopped)
+}
+
+/// The braces of a line of code, outside strings and character literals.
+fn braces(code: &str) -> Vec<char> {
+    let mut braces = Vec::new();
+    let mut in_string = false;
+    let mut escaped = false;
+    let mut chars = code.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' if in_string => escaped = true,
+            '"' => in_string = !in_string,
+            // '{' and '}' as characters.
+            '\'' if !in_string => {
+                let mut ahead = chars.clone();
+                if matches!(ahead.next(), Some('{' | '}')) && ahead.next() == Some('\'') {
+                    chars.next();
+                    chars.next();
+                }
+            }
+            '{' | '}' if !in_string => braces.push(c),
+            _ => {}
+        }
+    }
+    braces
+}
+
+fn is_ident(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+/// Whether `code` starts with `word` as a whole word.
+fn starts_with_word(code: &str, word: &str) -> bool {
+    code.strip_prefix(word)
+        .is_some_and(|rest| !rest.starts_with(is_ident))
+}
+
+/// `'outer: loop` into `Some("outer")` and `loop`.
+fn split_label(header: &str) -> (Option<&str>, &str) {
+    if let Some(rest) = header.strip_prefix('\'') {
+        if let Some((label, rest)) = rest.split_once(':') {
+            if !label.is_empty() && label.chars().all(is_ident) {
+                return (Some(label), rest.trim_start());
+            }
+        }
+    }
+    (None, header)
+}
+
+/// The keyword and condition of `if <c>`, `} else if <c>` or `while <c>`,
+/// unless it is a `let` pattern.
+fn condition(header: &str) -> Option<(&'static str, &str)> {
+    let header = header.strip_prefix("} ").unwrap_or(header);
+    let (keyword, condition) = if let Some(rest) = header.strip_prefix("else if ") {
+        ("else if", rest)
+    } else if let Some(rest) = header.strip_prefix("if ") {
+        ("if", rest)
+    } else if let Some(rest) = header.strip_prefix("while ") {
+        ("while", rest)
+    } else {
+        return None;
+    };
+    let condition = condition.trim();
+    (!condition.is_empty() && !starts_with_word(condition, "let")).then_some((keyword, condition))
+}
+
+fn is_loop_header(header: &str) -> bool {
+    ["loop", "while", "for"]
+        .iter()
+        .any(|keyword| starts_with_word(header, keyword))
+}
+
+/// The pattern of `for <pattern> in <iterator>`.
+pub(crate) fn for_pattern(header: &str) -> Option<&str> {
+    let rest = header.strip_prefix("for ")?;
+    rest.split_once(" in ").map(|(pattern, _)| pattern)
+}
+
+/// The names bound by `let`, as far as a simple pattern goes.
+fn declared(code: &str) -> Vec<String> {
+    let Some(rest) = code.strip_prefix("let ") else {
+        return Vec::new();
+    };
+    let pattern = rest.split(['=', ':', ';']).next().unwrap_or("");
+    declared_pattern(pattern)
+}
+
+pub(crate) fn declared_pattern(pattern: &str) -> Vec<String> {
+    identifiers(pattern)
+        .into_iter()
+        .filter(|name| !matches!(name.as_str(), "mut" | "ref" | "_"))
+        .filter(|name| name.starts_with(|c: char| c.is_lowercase() || c == '_'))
+        .collect()
+}
+
+/// The identifiers of a piece of code, outside strings.
+pub(crate) fn identifiers(code: &str) -> Vec<String> {
+    let mut names = Vec::new();
+    let mut in_string = false;
+    let mut escaped = false;
+    let mut word = String::new();
+    for c in code.chars().chain([' ']) {
+        if in_string {
+            match c {
+                _ if escaped => escaped = false,
+                '\\' => escaped = true,
+                '"' => in_string = false,
+                // Names captured by a format string: `{i}`.
+                _ if is_ident(c) => {
+                    word.push(c);
+                    continue;
+                }
+                _ => {}
+            }
+            if !word.is_empty() {
+                names.push(std::mem::take(&mut word));
+            }
+            continue;
+        }
+        if is_ident(c) {
+            word.push(c);
+            continue;
+        }
+        if !word.is_empty() && !word.starts_with(|c: char| c.is_ascii_digit()) {
+            names.push(word.clone());
+        }
+        word.clear();
+        if c == '"' {
+            in_string = true;
+        }
+    }
+    names
+}
+
+/// The variables in scope, innermost last, each once.
+fn in_scope(stack: &[Open]) -> Vec<String> {
+    let mut seen = HashSet::new();
+    let mut names: Vec<String> = stack
+        .iter()
+        .rev()
+        .flat_map(|open| open.names.iter().rev())
+        .filter(|name| seen.insert(name.as_str()))
+        .cloned()
+        .collect();
+    names.reverse();
+    names
+}
+
+impl Trace {
+    /// The statement around `line`, if one was traced.
+    pub fn statement_at(&self, line: usize) -> Option<usize> {
+        self.statements
+            .iter()
+            .position(|statement| (statement.lines.0..=statement.lines.1).contains(&line))
+    }
+
+    /// The steps of a statement.
+    pub fn steps_of(&self, statement: usize) -> impl Iterator<Item = &Step> {
+        self.steps.iter().filter(move |step| {
+            self.points
+                .get(step.point)
+                .is_some_and(|(_, of, _)| *of == statement)
+        })
+    }
+
+    /// What a step did, `while i <= 5: true` or `break → line 70`.
+    pub fn describe(&self, step: &Step) -> String {
+        let (_, _, point) = &self.points[step.point];
+        match point {
+            Point::Condition { keyword, condition } => {
+                let held = step
+                    .held
+                    .map_or("?", |held| if held { "true" } else { "false" });
+                format!("{keyword} {condition}: {held}")
+            }
+            Point::Entered(header) | Point::Iteration(header) => header.clone(),
+            Point::Jump { keyword, to } => format!("{keyword} → line {to}"),
+            Point::End => "end".to_string(),
+        }
+    }
+
+    /// The steps of a statement as a table: one row per step, one column
+    /// per variable, and what the program printed.
+    pub fn table(&self, statement: usize) -> String {
+        let steps: Vec<&Step> = self
+            .steps_of(statement)
+            .filter(|step| self.points[step.point].2 != Point::End)
+            .collect();
+        let mut names: Vec<&str> = Vec::new();
+        for step in &steps {
+            for (name, _) in &step.values {
+                if !names.contains(&name.as_str()) {
+                    names.push(name);
+                }
+            }
+        }
+        let mut header: Vec<String> = ["step", "line", "what happens"].map(String::from).to_vec();
+        header.extend(names.iter().map(|name| name.to_string()));
+        header.push("output".to_string());
+        let rows: Vec<Vec<String>> = steps
+            .iter()
+            .enumerate()
+            .map(|(index, step)| {
+                let mut row = vec![
+                    (index + 1).to_string(),
+                    self.points[step.point].0.to_string(),
+                    self.describe(step),
+                ];
+                row.extend(names.iter().map(|name| {
+                    step.values
+                        .iter()
+                        .find(|(named, _)| named == name)
+                        .map_or_else(String::new, |(_, value)| value.clone())
+                }));
+                row.push(shown_output(&step.output));
+                row
+            })
+            .collect();
+        let mut out = String::new();
+        text::table(&mut out, &header, &rows);
+        out
+    }
+
+    /// The frames of an animation of a statement: its code with the line
+    /// of each step marked, the values and the output so far.
+    pub fn frames(&self, statement: usize, lesson: &str) -> Vec<String> {
+        let lines: Vec<&str> = lesson.lines().collect();
+        let (first, last) = self.statements[statement].lines;
+        let mut printed = String::new();
+        let mut frames = Vec::new();
+        for step in self.steps_of(statement) {
+            let (line, _, point) = &self.points[step.point];
+            let target = match point {
+                Point::Jump { to, .. } => Some(*to),
+                Point::End => continue,
+                _ => None,
+            };
+            let mut frame = String::new();
+            for number in first..=last.max(target.unwrap_or(0)) {
+                let marker = if number == *line {
+                    "▶"
+                } else if Some(number) == target {
+                    "↳"
+                } else {
+                    " "
+                };
+                let _ = writeln!(frame, "{marker} {number:>4} | {}", lines[number - 1]);
+            }
+            let _ = writeln!(frame, "\n{}", self.describe(step));
+            if !step.values.is_empty() {
+                let values: Vec<String> = step
+                    .values
+                    .iter()
+                    .map(|(name, value)| format!("{name} = {value}"))
+                    .collect();
+                let _ = writeln!(frame, "{}", values.join("   "));
+            }
+            let _ = write!(frame, "\noutput:\n{printed}");
+            printed.push_str(&step.output);
+            frames.push(frame);
+        }
+        frames
+    }
+}
+
+/// Output for a table cell: on one line, with `⏎` for the line breaks.
+fn shown_output(output: &str) -> String {
+    output
+        .strip_suffix('\n')
+        .unwrap_or(output)
+        .replace('\n', "⏎")
+}
@@ -0,0 +1,439 @@
+//! Checks the documented output of a lesson against what its code prints.
+//!
+//! Four kinds of documentation are checked:
+//!
+//! - `Output:` blocks. A block after an example program quoted in the prose
+//!   (or commented out) must match that program's output exactly, and so
+//!   must a block after one of the lesson's example modules, run on its
+//!   own. A block after other lesson code must appear, in order, in the
+//!   output of the lesson. A block after a snippet that is not run, such
+//!   as a commented-out infinite loop, is skipped.
+//! - `println!(...); // text` comments on lesson code, whose text must
+//!   be a later line of the lesson output, or the part of it after the
+//!   literal text the format string starts with (ignoring whitespace).
+//! - Pasted compiler errors. The example before an `error[E0384]: ...`
+//!   excerpt must still fail to compile with that error code.
+//! - Code snippets in the prose. Each one is run, compiled, expected to
+//!   fail or ignored, as its fence or its surroundings say.
+
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use crate::compile::{self, Build, Scratch};
+use crate::lesson::{
+    Diagnostic, Example, LessonSource, Mode, OutputBlock, OutputSource, PrintComment, Snippet,
+};
+use crate::playground::{self, Piece};
+
+/// A piece of documented output that does not match the real one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mismatch {
+    /// Line of the lesson holding the documented output.
+    pub line: usize,
+    pub message: String,
+    /// Unified-style diff or compiler output explaining the mismatch.
+    pub details: String,
+}
+
+/// Outcome of verifying one lesson file.
+#[derive(Debug, Clone, Default)]
+pub struct Report {
+    pub checked: usize,
+    pub skipped: usize,
+    pub mismatches: Vec<Mismatch>,
+}
+
+impl Report {
+    pub fn passed(&self) -> bool {
+        self.mismatches.is_empty()
+    }
+
+    /// Adds the checks of `other` to this report.
+    pub fn merge(&mut self, other: Report) {
+        self.checked += other.checked;
+        self.skipped += other.skipped;
+        self.mismatches.extend(other.mismatches);
+    }
+}
+
+impl fmt::Display for Mismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "line {}: {}", self.line, self.message)?;
+        for line in self.details.lines() {
+            writeln!(f, "    {line}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Verifies every `Output:` block and print comment of the lesson at `path`.
+pub fn verify_lesson(path: &Path) -> io::Result<Report> {
+    let source = LessonSource::read(path)?;
+    let programs = source.programs();
+    let examples = source.examples();
+    let mut lesson = LessonOutput::new(path);
+    let mut report = Report::default();
+
+    for block in source.output_blocks() {
+        if !block.checked {
+            report.skipped += 1;
+            continue;
+        }
+        report.checked += 1;
+        let mismatch = match source.output_source(&block, &programs, &examples) {
+            None => Some(Mismatch {
+                line: block.line,
+                message: "output block has no code before it".to_string(),
+                details: String::new(),
+            }),
+            Some(OutputSource::Lesson) => lesson.expect_block(&block)?,
+            Some(OutputSource::Example(example)) => lesson.expect_example(&block, example)?,
+            Some(OutputSource::Program(program)) => {
+                let scratch = Scratch::new("example")?;
+                let build = compile::compile_source(&program.program(), &scratch)?;
+                match &build.binary {
+                    None => Some(Mismatch {
+                        line: block.line,
+                        message: format!("example at line {} does not compile", program.start),
+                        details: build.stderr,
+                    }),
+                    Some(binary) => {
+                        let output = compile::run(binary, &[])?;
+                        let actual = output_lines(&output.stdout);
+                        (actual != block.expected).then(|| Mismatch {
+                            line: block.line,
+                            message: format!(
+                                "output of the example at line {} differs",
+                                program.start
+                            ),
+                            details: diff(&block.expected, &actual),
+                        })
+                    }
+                }
+            }
+        };
+        report.mismatches.extend(mismatch);
+    }
+
+    for comment in source.print_comments() {
+        report.checked += 1;
+        report.mismatches.extend(lesson.expect_comment(&comment)?);
+    }
+    Ok(report)
+}
+
+/// Verifies that every compiler error pasted into the lesson at `path` is
+/// still produced by the example before it.
+pub fn verify_errors(path: &Path) -> io::Result<Report> {
+    let source = LessonSource::read(path)?;
+    let snippets = source.snippets();
+    let mut report = Report::default();
+    for diagnostic in source.diagnostics() {
+        report.checked += 1;
+        let snippet = source.diagnostic_source(&diagnostic, &snippets);
+        report
+            .mismatches
+            .extend(expect_error(&diagnostic, snippet)?);
+    }
+    Ok(report)
+}
+
+/// Checks that `snippet` still fails to compile with the error pasted
+/// after it.
+fn expect_error(
+    diagnostic: &Diagnostic,
+    snippet: Option<&Snippet>,
+) -> io::Result<Option<Mismatch>> {
+    let Some(snippet) = snippet else {
+        return Ok(Some(Mismatch {
+            line: diagnostic.line,
+            message: format!("error {} has no example before it", diagnostic.code),
+            details: String::new(),
+        }));
+    };
+    let scratch = Scratch::new("compile-fail")?;
+    let build = compile::compile_source(&snippet.program(), &scratch)?;
+    Ok(
+        unexpected_build(&build, Some(&diagnostic.code)).map(|message| Mismatch {
+            line: diagnostic.line,
+            message: format!("example at line {} {message}", snippet.start),
+            details: build.stderr,
+        }),
+    )
+}
+
+/// Why `build` is not the compile failure expected, with `code` if given.
+fn unexpected_build(build: &Build, code: Option<&str>) -> Option<String> {
+    let codes = build.error_codes();
+    let expected = code.map_or("an error".to_string(), |code| format!("error {code}"));
+    if build.succeeded() {
+        return Some(format!("compiles, expected {expected}"));
+    }
+    let code = code?;
+    if codes.iter().any(|found| found == code) {
+        return None;
+    }
+    let found = if codes.is_empty() {
+        "no error code".to_string()
+    } else {
+        codes.join(", ")
+    };
+    Some(format!("fails with {found} instead of {code}"))
+}
+
+/// Compiles every code snippet of the lesson at `path` and checks it the
+/// way its mode asks, see [`LessonSource::snippet_mode`].
+pub fn verify_snippets(path: &Path) -> io::Result<Report> {
+    let source = LessonSource::read(path)?;
+    let snippets = source.snippets();
+    let diagnostics = source.diagnostics();
+    let mut report = Report::default();
+    for snippet in &snippets {
+        let mode = source.snippet_mode(snippet, &snippets, &diagnostics);
+        if mode == Mode::Ignore {
+            report.skipped += 1;
+            continue;
+        }
+        report.checked += 1;
+        report.mismatches.extend(check_snippet(snippet, &mode)?);
+    }
+    for diagnostic in &diagnostics {
+        if source.diagnostic_source(diagnostic, &snippets).is_none() {
+            report.checked += 1;
+            report.mismatches.extend(expect_error(diagnostic, None)?);
+        }
+    }
+    Ok(report)
+}
+
+fn check_snippet(snippet: &Snippet, mode: &Mode) -> io::Result<Option<Mismatch>> {
+    let scratch = Scratch::new("snippet")?;
+    let build = compile::compile_source(&snippet.program(), &scratch)?;
+    let message = match (mode, &build.binary) {
+        (Mode::CompileFail(code), _) => unexpected_build(&build, code.as_deref()),
+        (Mode::Run | Mode::CompileOnly, None) => Some("does not compile".to_string()),
+        (Mode::Run, Some(binary)) => {
+            let output = compile::run(binary, &[])?;
+            return Ok((!output.status.success()).then(|| Mismatch {
+                line: snippet.start,
+                message: format!("snippet exits with {}", output.status),
+                details: String::from_utf8_lossy(&output.stderr).into_owned(),
+            }));
+        }
+        (Mode::CompileOnly, Some(_)) | (Mode::Ignore, _) => None,
+    };
+    Ok(message.map(|message| Mismatch {
+        line: snippet.start,
+        message: format!("snippet {message}"),
+        details: build.stderr,
+    }))
+}
+
+/// The lesson compiled and run once, with cursors for in-order matching.
+struct LessonOutput<'a> {
+    path: &'a Path,
+    build: Option<(Scratch, Build)>,
+    lines: Option<Result<Vec<String>, String>>,
+    block_cursor: usize,
+    comment_cursor: usize,
+}
+
+impl<'a> LessonOutput<'a> {
+    fn new(path: &'a Path) -> LessonOutput<'a> {
+        LessonOutput {
+            path,
+            build: None,
+            lines: None,
+            block_cursor: 0,
+            comment_cursor: 0,
+        }
+    }
+
+    /// The lesson compiled once, kept for every run.
+    fn build(&mut self) -> io::Result<&Build> {
+        if self.build.is_none() {
+            let scratch = Scratch::new("lesson")?;
+            let build = compile::compile_file(self.path, &scratch)?;
+            self.build = Some((scratch, build));
+        }
+        Ok(&self.build.as_ref().expect("set above").1)
+    }
+
+    /// Output lines of the whole lesson, or the compiler errors.
+    fn lines(&mut self) -> io::Result<&Result<Vec<String>, String>> {
+        if self.lines.is_none() {
+            let build = self.build()?;
+            let lines = match &build.binary {
+                Some(binary) => Ok(output_lines(&compile::run(binary, &[])?.stdout)),
+                None => Err(build.stderr.clone()),
+            };
+            self.lines = Some(lines);
+        }
+        Ok(self.lines.as_ref().expect("set above"))
+    }
+
+    fn expect_example(
+        &mut self,
+        block: &OutputBlock,
+        example: &Example,
+    ) -> io::Result<Option<Mismatch>> {
+        // The example is compiled on its own, as `rustprog run` does.
+        let text = fs::read_to_string(self.path)?;
+        let piece = Piece::Example(example.name.clone());
+        let program =
+            playground::excerpt(&text, &piece).map_or(String::new(), |excerpt| excerpt.code);
+        let scratch = Scratch::new("example")?;
+        let build = compile::compile_source(&program, &scratch)?;
+        let actual = match &build.binary {
+            Some(binary) => output_lines(&compile::run(binary, &[])?.stdout),
+            None => return Ok(Some(does_not_compile(block.line, &build.stderr))),
+        };
+        Ok((actual != block.expected).then(|| Mismatch {
+            line: block.line,
+            message: format!("output of the example '{}' differs", example.name),
+            details: diff(&block.expected, &actual),
+        }))
+    }
+
+    fn expect_block(&mut self, block: &OutputBlock) -> io::Result<Option<Mismatch>> {
+        let cursor = self.block_cursor;
+        let actual = match self.lines()? {
+            Ok(lines) => lines,
+            Err(stderr) => return Ok(Some(does_not_compile(block.line, stderr))),
+        };
+
+        let len = block.expected.len();
+        let found = (cursor..=actual.len().saturating_sub(len))
+            .find(|&start| actual[start..start + len] == block.expected[..]);
+        if let Some(start) = found {
+            self.block_cursor = start + len;
+            return Ok(None);
+        }
+
+        // Show the stretch of output where the block was most likely meant
+        // to be: from the first expected line if it appears, else the cursor.
+        let start = block
+            .expected
+            .first()
+            .and_then(|first| actual[cursor..].iter().position(|line| line == first))
+            .map_or(cursor, |offset| cursor + offset);
+        let end = (start + len).min(actual.len());
+        Ok(Some(Mismatch {
+            line: block.line,
+            message: "documented output does not appear in the lesson output".to_string(),
+            details: diff(&block.expected, &actual[start..end]),
+        }))
+    }
+
+    fn expect_comment(&mut self, comment: &PrintComment) -> io::Result<Option<Mismatch>> {
+        let cursor = self.comment_cursor;
+        let actual = match self.lines()? {
+            Ok(lines) => lines,
+            Err(stderr) => return Ok(Some(does_not_compile(comment.line, stderr))),
+        };
+
+        // The comment is the whole line, or what follows the literal
+        // text the format string starts with.
+        let wanted = squash(&comment.comment);
+        let literal = squash(&format_prefix(&comment.code));
+        let matches = |line: &String| {
+            let line = squash(line);
+            line == wanted || line.strip_prefix(&literal) == Some(wanted.as_str())
+        };
+        match actual[cursor.min(actual.len())..].iter().position(matches) {
+            Some(offset) => {
+                self.comment_cursor = cursor + offset + 1;
+                Ok(None)
+            }
+            None => {
+                // The line printed by this call most likely starts with the
+                // literal text of its format string.
+                let prefix = format_prefix(&comment.code);
+                let nearby = actual[cursor.min(actual.len())..]
+                    .iter()
+                    .find(|line| !prefix.is_empty() && line.starts_with(&prefix))
+                    .or(actual.get(cursor))
+                    .cloned()
+                    .unwrap_or_default();
+                Ok(Some(Mismatch {
+                    line: comment.line,
+                    message: format!("comment '// {}' does not match the output", comment.comment),
+                    details: diff(std::slice::from_ref(&comment.comment), &[nearby]),
+                }))
+            }
+        }
+    }
+}
+
+fn does_not_compile(line: usize, stderr: &str) -> Mismatch {
+    Mismatch {
+        line,
+        message: "the lesson does not compile".to_string(),
+        details: stderr.to_string(),
+    }
+}
+
+/// Program output split into lines, without trailing whitespace or
+/// trailing blank lines.
+pub fn output_lines(stdout: &[u8]) -> Vec<String> {
+    let mut lines: Vec<String> = String::from_utf8_lossy(stdout)
+        .lines()
+        .map(|line| line.trim_end().to_string())
+        .collect();
+    while lines.last().is_some_and(|line| line.is_empty()) {
+        lines.pop();
+    }
+    lines
+}
+
+/// Literal text at the start of the first string in `code`:
+/// `Number of byte(s) for u8: ` for `println!("Number of byte(s) for u8: {}", ..)`.
+fn format_prefix(code: &str) -> String {
+    let Some((_, rest)) = code.split_once('"') else {
+        return String::new();
+    };
+    rest.chars()
+        .take_while(|c| !matches!(c, '{' | '"' | '\\'))
+        .collect()
+}
+
+/// `text` without any whitespace, for loose comparisons.
+fn squash(text: &str) -> String {
+    text.chars().filter(|c| !c.is_whitespace()).collect()
+}
+
+/// A line diff of `expected` against `actual`: `-` lines are documented but
+/// not printed, `+` lines printed but not documented.
+pub fn diff(expected: &[String], actual: &[String]) -> String {
+    // Longest common subsequence table, filled from the end.
+    let (n, m) = (expected.len(), actual.len());
+    let mut lcs = vec![vec![0usize; m + 1]; n + 1];
+    for i in (0..n).rev() {
+        for j in (0..m).rev() {
+            lcs[i][j] = if expected[i] == actual[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut out = String::from("--- documented\n+++ actual\n");
+    let (mut i, mut j) = (0, 0);
+    while i < n || j < m {
+        if i < n && j < m && expected[i] == actual[j] {
+            out += &format!("  {}\n", expected[i]);
+            i += 1;
+            j += 1;
+        } else if j < m && (i == n || lcs[i][j + 1] >= lcs[i + 1][j]) {
+            out += &format!("+ {}\n", actual[j]);
+            j += 1;
+        } else {
+            out += &format!("- {}\n", expected[i]);
+            i += 1;
+        }
+    }
+    out
+}
@@ -0,0 +1,92 @@
+//! Compiler errors pasted into the lessons (`error[E0384]: ...`) must still
+//! be what rustc reports for the example they follow.
+
+use std::fs;
+use std::path::Path;
+
+use rustprog::compile::Scratch;
+use rustprog::lesson::LessonSource;
+use rustprog::verify;
+use rustprog::Course;
+
+#[test]
+fn pasted_errors_are_still_reported() {
+    let course = Course::discover(Path::new(env!("CARGO_MANIFEST_DIR")).join("..")).unwrap();
+    let mut checked = 0;
+    let mut failures = String::new();
+    for lesson in course.lessons() {
+        let report = verify::verify_errors(&lesson.path).unwrap();
+        checked += report.checked;
+        for mismatch in &report.mismatches {
+            failures += &format!("{}:{mismatch}\n", lesson.relative_path(&course).display());
+        }
+    }
+    assert!(checked >= 8, "only {checked} pasted errors found");
+    assert!(failures.is_empty(), "pasted errors drifted:\n\n{failures}");
+}
+
+#[test]
+fn errors_follow_the_closest_example() {
+    let course = Course::discover(Path::new(env!("CARGO_MANIFEST_DIR")).join("..")).unwrap();
+    let lesson = course.find("variable_scope").unwrap();
+    let source = LessonSource::read(&lesson.path).unwrap();
+    let snippets = source.snippets();
+    let sources: Vec<(String, String)> = source
+        .diagnostics()
+        .iter()
+        .map(|diagnostic| {
+            let snippet = source.diagnostic_source(diagnostic, &snippets).unwrap();
+            let first = snippet.code.lines().next().unwrap_or("").to_string();
+            (diagnostic.code.clone(), first)
+        })
+        .collect();
+    assert_eq!(
+        sources,
+        [
+            ("E0425".to_string(), "fn test2() {".to_string()),
+            ("E0384".to_string(), "fn test4() {".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn a_different_error_code_is_reported() {
+    let scratch = Scratch::new("compile-fail-test").unwrap();
+    let path = scratch.path().join("lesson.rs");
+    fs::write(
+        &path,
+        "fn main() {}\n\n// let x: u8 = 1;\n// x = 2;\n\n/*\nerror[E0308]: mismatched types\n*/\n",
+    )
+    .unwrap();
+    let report = verify::verify_errors(&path).unwrap();
+    assert_eq!(report.checked, 1);
+    assert_eq!(report.mismatches.len(), 1);
+    assert!(
+        report.mismatches[0]
+            .message
+            .contains("fails with E0384 instead of E0308"),
+        "{}",
+        report.mismatches[0]
+    );
+}
+
+#[test]
+fn an_example_that_compiles_is_reported() {
+    let scratch = Scratch::new("compile-fail-test").unwrap();
+    let path = scratch.path().join("lesson.rs");
+    fs::write(
+        &path,
+        "fn main() {}\n\n// let x: u8 = 1;\n\n/*\nerror[E0308]: mismatched types\n*/\n",
+    )
+    .unwrap();
+    let report = verify::verify_errors(&path).unwrap();
+    assert_eq!(report.checked, 1);
+    assert_eq!(report.mismatches.len(), 1);
+    assert!(
+        report.mismatches[0]
+            .message
+            .contains("compiles, expected error E0308"),
+        "{}",
+        report.mismatches[0]
+    );
+}
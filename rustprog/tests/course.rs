@@ -0,0 +1,61 @@
+use std::fs;
+use std::path::Path;
+
+use rustprog::course::FindError;
+use rustprog::Course;
+
+fn course() -> Course {
+    Course::discover(Path::new(env!("CARGO_MANIFEST_DIR")).join("..")).unwrap()
+}
+
+#[test]
+fn lessons_are_numbered_by_chapter() {
+    let course = course();
+    let ids: Vec<String> = course.chapters.iter().map(|c| c.dir_name.clone()).collect();
+    assert_eq!(
+        &ids[..4],
+        [
+            "_01_Rust_Intoduction",
+            "_02_Rust_Control_Flow",
+            "_03_Rust_Data_Types",
+            "_04_Rust_Functions"
+        ]
+    );
+
+    let slice = course.find("3.2").unwrap();
+    assert_eq!(slice.stem, "_02_Rust_slice");
+    assert_eq!(slice.bin_name(), "slice");
+}
+
+#[test]
+fn lessons_are_found_by_name() {
+    let course = course();
+    assert_eq!(course.find("type_casting").unwrap().id(), "1.7");
+    assert_eq!(course.find("Closure").unwrap().id(), "4.3");
+    assert_eq!(course.find("brkcont").unwrap().id(), "2.5");
+    // `loop` is both a lesson name and part of `while_loop` and `for_loop`.
+    assert_eq!(course.find("loop").unwrap().id(), "2.2");
+    assert!(matches!(
+        course.find("_rust_"),
+        Err(FindError::Ambiguous(..))
+    ));
+    assert!(matches!(course.find("9.9"), Err(FindError::NotFound(_))));
+}
+
+#[test]
+fn every_lesson_has_a_bin_target() {
+    let course = course();
+    for chapter in &course.chapters {
+        let manifest = fs::read_to_string(chapter.dir(&course).join("Cargo.toml")).unwrap();
+        for lesson in &chapter.lessons {
+            let file = lesson.path.file_name().unwrap().to_str().unwrap();
+            let entry = format!("name = \"{}\"\npath = \"{file}\"", lesson.bin_name());
+            assert!(
+                manifest.contains(&entry),
+                "{} does not declare [[bin]] {}",
+                chapter.dir_name,
+                lesson.bin_name()
+            );
+        }
+    }
+}
@@ -0,0 +1,72 @@
+//! Lessons with several complete programs keep each in an example module
+//! that can be compiled and run as a program of its own.
+
+use std::fs;
+use std::path::Path;
+
+use rustprog::compile::{self, Scratch};
+use rustprog::lesson::LessonSource;
+use rustprog::playground::{self, Piece};
+use rustprog::Course;
+
+fn course() -> Course {
+    Course::discover(Path::new(env!("CARGO_MANIFEST_DIR")).join("..")).unwrap()
+}
+
+fn example_names(course: &Course, query: &str) -> Vec<String> {
+    let lesson = course.find(query).unwrap();
+    LessonSource::read(&lesson.path)
+        .unwrap()
+        .examples()
+        .into_iter()
+        .map(|example| example.name)
+        .collect()
+}
+
+#[test]
+fn multi_program_lessons_are_split_into_examples() {
+    let course = course();
+    assert_eq!(example_names(&course, "comments").len(), 7);
+    assert_eq!(
+        example_names(&course, "print_output"),
+        [
+            "hello_world",
+            "print_macro",
+            "println_macro",
+            "print_variables",
+            "placeholder_text",
+            "multiple_variables",
+            "numbered_placeholders",
+            "named_placeholders",
+            "newline",
+        ]
+    );
+    assert!(example_names(&course, "slice").is_empty());
+}
+
+#[test]
+fn every_example_runs_on_its_own() {
+    let course = course();
+    for lesson in course.lessons() {
+        let text = fs::read_to_string(&lesson.path).unwrap();
+        for example in LessonSource::parse(&text).examples() {
+            let piece = Piece::Example(example.name.clone());
+            let excerpt = playground::excerpt(&text, &piece).unwrap();
+            let scratch = Scratch::new("examples-test").unwrap();
+            let build = compile::compile_source(&excerpt.code, &scratch).unwrap();
+            let binary = build
+                .binary
+                .as_ref()
+                .unwrap_or_else(|| panic!("{}", build.stderr));
+
+            let output = compile::run(binary, &[]).unwrap();
+            assert!(output.status.success(), "{} {}", lesson.id(), example.name);
+            assert!(
+                !output.stdout.is_empty(),
+                "{} {}",
+                lesson.id(),
+                example.name
+            );
+        }
+    }
+}
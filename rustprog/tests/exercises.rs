@@ -0,0 +1,166 @@
+//! Grading the exercises against their hidden tests.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use rustprog::compile::Scratch;
+use rustprog::grade::{self, ExerciseGrade, Verdict};
+use rustprog::{Course, Lesson};
+
+fn course() -> Course {
+    Course::discover(Path::new(env!("CARGO_MANIFEST_DIR")).join("..")).unwrap()
+}
+
+fn with_exercises(course: &Course) -> Vec<&Lesson> {
+    course
+        .lessons()
+        .filter(|lesson| grade::exercise_path(course, lesson).is_file())
+        .collect()
+}
+
+fn solution_path(course: &Course, lesson: &Lesson) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/solutions")
+        .join(&course.chapter_of(lesson).dir_name)
+        .join(format!("{}.rs", lesson.stem))
+}
+
+#[test]
+fn the_stubs_compile_and_fail() {
+    let course = course();
+    let lessons = with_exercises(&course);
+    assert!(
+        lessons.len() >= 15,
+        "{} lessons have exercises",
+        lessons.len()
+    );
+    let for_loop = course.find("2.4").unwrap();
+    assert!(lessons.iter().any(|lesson| lesson.path == for_loop.path));
+
+    for lesson in lessons {
+        let graded = grade::grade_lesson(&course, lesson, grade::TIMEOUT)
+            .unwrap()
+            .unwrap();
+        assert_eq!(graded.build_error, None, "{}", lesson.id());
+        assert!(!graded.exercises.is_empty());
+        for exercise in &graded.exercises {
+            assert!(
+                matches!(&exercise.verdict, Verdict::Failed(message) if message.ends_with("not yet implemented")),
+                "{} {}: {}",
+                lesson.id(),
+                exercise.name,
+                exercise.verdict
+            );
+        }
+    }
+}
+
+#[test]
+fn the_reference_solutions_pass() {
+    let course = course();
+    for lesson in with_exercises(&course) {
+        let stubs = fs::read_to_string(grade::exercise_path(&course, lesson)).unwrap();
+        let solution = solution_path(&course, lesson);
+        let solved = fs::read_to_string(&solution).unwrap();
+        assert_eq!(
+            grade::exercise_names(&solved),
+            grade::exercise_names(&stubs),
+            "{}",
+            lesson.id()
+        );
+
+        let tests = grade::tests_path(&course, lesson);
+        let graded = grade::grade(&solution, &tests, grade::TIMEOUT).unwrap();
+        for exercise in &graded.exercises {
+            assert!(
+                matches!(exercise.verdict, Verdict::Passed { .. }),
+                "{} {}: {}",
+                lesson.id(),
+                exercise.name,
+                exercise.verdict
+            );
+        }
+    }
+}
+
+#[test]
+fn the_exercises_crate_has_every_lesson() {
+    let course = course();
+    let lib = fs::read_to_string(course.root.join("exercises/lib.rs")).unwrap();
+    for lesson in with_exercises(&course) {
+        let path = format!(
+            "#[path = \"{}/{}.rs\"]",
+            course.chapter_of(lesson).dir_name,
+            lesson.stem
+        );
+        assert!(lib.contains(&path), "{path} missing from exercises/lib.rs");
+    }
+}
+
+#[test]
+fn every_exercise_gets_its_own_verdict() {
+    let scratch = Scratch::new("grade-test").unwrap();
+    let exercises = scratch.path().join("exercises.rs");
+    let tests = scratch.path().join("tests.rs");
+    fs::write(
+        &exercises,
+        "pub fn double(x: i32) -> i32 {\n    x * 3\n}\n\n\
+         pub fn forever() -> u32 {\n    loop {}\n}\n\n\
+         pub fn square(x: i32) -> i32 {\n    x * x\n}\n\n\
+         pub fn untested() {}\n",
+    )
+    .unwrap();
+    fs::write(
+        &tests,
+        "mod double {\n    use crate::*;\n\n    #[test]\n    fn two() {\n        \
+         assert_eq!(double(2), 4, \"double(2)\");\n    }\n}\n\n\
+         mod forever {\n    use crate::*;\n\n    #[test]\n    fn returns() {\n        \
+         assert_eq!(forever(), 1);\n    }\n}\n\n\
+         mod square {\n    use crate::*;\n\n    #[test]\n    fn three() {\n        \
+         assert_eq!(square(3), 9);\n    }\n\n    #[test]\n    fn negative() {\n        \
+         assert_eq!(square(-2), 4);\n    }\n}\n",
+    )
+    .unwrap();
+
+    let graded = grade::grade(&exercises, &tests, Duration::from_secs(1)).unwrap();
+    let verdicts: Vec<(&str, &Verdict)> = graded
+        .exercises
+        .iter()
+        .map(|ExerciseGrade { name, verdict }| (name.as_str(), verdict))
+        .collect();
+    assert_eq!(
+        verdicts,
+        [
+            (
+                "double",
+                &Verdict::Failed(
+                    "two: assertion `left == right` failed: double(2), left: 6, right: 4"
+                        .to_string()
+                )
+            ),
+            ("forever", &Verdict::TimedOut(Duration::from_secs(1))),
+            ("square", &Verdict::Passed { tests: 2 }),
+            ("untested", &Verdict::Untested),
+        ]
+    );
+    assert_eq!(graded.passed(), 1);
+}
+
+#[test]
+fn answers_that_do_not_compile_fail_every_exercise() {
+    let scratch = Scratch::new("grade-test").unwrap();
+    let exercises = scratch.path().join("exercises.rs");
+    let tests = scratch.path().join("tests.rs");
+    fs::write(&exercises, "pub fn one() -> u32 {\n    \"one\"\n}\n").unwrap();
+    fs::write(&tests, "").unwrap();
+
+    let graded = grade::grade(&exercises, &tests, grade::TIMEOUT).unwrap();
+    let errors = graded.build_error.unwrap();
+    assert!(errors.contains("error[E0308]"), "{errors}");
+    assert!(errors.contains("exercises.rs:2:5"), "{errors}");
+    assert_eq!(
+        graded.exercises[0].verdict,
+        Verdict::Failed("does not compile".to_string())
+    );
+}
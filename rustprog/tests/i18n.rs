@@ -0,0 +1,188 @@
+//! The prose of the lessons translates through gettext catalogs.
+
+use std::fs;
+use std::path::Path;
+
+use rustprog::compile::Scratch;
+use rustprog::document::{Block, Document};
+use rustprog::i18n::{self, Catalog, Message, Translation};
+use rustprog::{mdbook, site, text, Course};
+
+fn course() -> Course {
+    Course::discover(Path::new(env!("CARGO_MANIFEST_DIR")).join("..")).unwrap()
+}
+
+fn message(id: &str, text: &str) -> Message {
+    Message {
+        id: id.to_string(),
+        text: text.to_string(),
+        ..Message::default()
+    }
+}
+
+#[test]
+fn catalogs_read_back_what_they_write() {
+    let mut catalog = Catalog::new("fr");
+    catalog.messages = vec![
+        Message {
+            notes: vec!["checked by Camille".to_string()],
+            comments: vec!["section: Rust \"Hello, World!\" Program".to_string()],
+            references: vec!["_01_Rust_Intoduction/_02_Rust_hello_world.rs".to_string()],
+            ..message(
+                "A \"Hello, World!\" program prints the text Hello, World! to the screen. \
+                 Since it's a very simple program, it's often used as an ice breaker.",
+                "Un programme « Hello, World! » affiche le texte Hello, World! à l'écran.",
+            )
+        },
+        Message {
+            fuzzy: true,
+            ..message("Tabs\tand\nnewlines \\ too", "")
+        },
+        Message {
+            obsolete: true,
+            ..message("An old paragraph", "Un vieux paragraphe")
+        },
+    ];
+    let written = catalog.to_string();
+    assert!(written.contains("msgid \"\"\n\"A \\\"Hello, World!\\\" program prints"));
+    assert!(written.contains("#, fuzzy\nmsgid \"\"\n\"Tabs\\tand\\n\"\n\"newlines \\\\ too\"\n"));
+    assert!(written.contains("#~ msgid \"An old paragraph\"\n#~ msgstr \"Un vieux paragraphe\"\n"));
+    assert!(written.lines().all(|line| line.chars().count() <= 79));
+
+    let read = Catalog::parse(&written).unwrap();
+    assert_eq!(read, catalog);
+    assert_eq!(read.language(), Some("fr"));
+    // Fuzzy and obsolete messages are not used.
+    assert_eq!(read.get("Tabs\tand\nnewlines \\ too"), None);
+    assert_eq!(read.get("An old paragraph"), None);
+
+    let error = Catalog::parse("msgid \"a\"\nmsgctxt \"b\"\n").unwrap_err();
+    assert_eq!(error.line, 2);
+    assert_eq!(Catalog::parse("msgid \"a\"\n").unwrap_err().line, 1);
+}
+
+#[test]
+fn updates_keep_the_translations() {
+    let mut catalog = Catalog::new("fr");
+    catalog.messages = vec![
+        message("Kept", "Gardé"),
+        message("Reworded", "Reformulé"),
+        message("Never translated", ""),
+    ];
+    let (added, obsolete) = catalog.update(vec![message("New", ""), message("Kept", "")]);
+    assert_eq!((added, obsolete), (1, 1));
+    let ids: Vec<(&str, &str, bool)> = catalog
+        .messages
+        .iter()
+        .map(|message| (message.id.as_str(), message.text.as_str(), message.obsolete))
+        .collect();
+    assert_eq!(
+        ids,
+        [
+            ("New", "", false),
+            ("Kept", "Gardé", false),
+            ("Reworded", "Reformulé", true)
+        ]
+    );
+
+    // A paragraph reworded back picks its translation up again.
+    let (added, obsolete) = catalog.update(vec![message("Reworded", "")]);
+    assert_eq!((added, obsolete), (0, 1));
+    assert_eq!(catalog.get("Reworded"), Some("Reformulé"));
+}
+
+#[test]
+fn only_the_prose_is_extracted() {
+    let course = course();
+    let document = Document::read(&course.find("operators").unwrap().path).unwrap();
+    let prose: Vec<String> = i18n::prose(&document)
+        .into_iter()
+        .map(|(_, text)| text)
+        .collect();
+    assert_eq!(prose[0], "Rust Operators");
+    assert!(prose.contains(&"+= (Addition  assignment)".to_string()));
+    // Cells that are code stay shared.
+    assert!(!prose.contains(&"a += b".to_string()));
+    for block in &document.blocks {
+        if let Block::Code(code) = block {
+            assert!(!prose.contains(&code.code));
+        }
+    }
+}
+
+#[test]
+fn lessons_render_in_french() {
+    let course = course();
+    let translation = Translation::load(&course, "fr").unwrap();
+    let lesson = course.find("hello_world").unwrap();
+    let english = Document::read(&lesson.path).unwrap();
+    let french = translation.document(lesson, &english);
+    assert_eq!(
+        french.title.as_deref(),
+        Some("Le programme Hello World en Rust")
+    );
+    assert!(translation.coverage(lesson, &english).is_complete());
+    // The code and its output are shared.
+    let shared = |document: &Document| {
+        document
+            .blocks
+            .iter()
+            .filter(|block| matches!(block, Block::Code(_) | Block::Output(_)))
+            .cloned()
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(shared(&french), shared(&english));
+
+    let page = text::render("Hello", &french, &translation);
+    assert!(page.contains("\nSortie:\n    Hello, World!\n"), "{page}");
+    assert!(page.lines().all(|line| line.chars().count() <= text::WIDTH));
+
+    let scratch = Scratch::new("i18n-test").unwrap();
+    site::build_translated(&course, &translation, &scratch.path().join("site")).unwrap();
+    let html = fs::read_to_string(
+        scratch
+            .path()
+            .join("site/_01_Rust_Intoduction/_02_Rust_hello_world.html"),
+    )
+    .unwrap();
+    assert!(html.contains("<html lang=\"fr\">"));
+    assert!(html.contains("<a href=\"../index.html\">Sommaire</a>"));
+    assert!(html.contains("<h1>1.2 Le programme Hello World en Rust</h1>"));
+    assert!(html.contains("<p class=\"label\">Sortie</p>"));
+
+    mdbook::export_translated(&course, &translation, &scratch.path().join("book")).unwrap();
+    let book = fs::read_to_string(scratch.path().join("book/book.toml")).unwrap();
+    assert!(book.contains("language = \"fr\""));
+    let summary = fs::read_to_string(scratch.path().join("book/src/SUMMARY.md")).unwrap();
+    assert!(summary.contains("- [Introduction à Rust](_01_Rust_Intoduction/README.md)"));
+}
+
+#[test]
+fn untranslated_sections_are_reported() {
+    let course = course();
+    let lesson = course.find("hello_world").unwrap();
+    let document = Document::read(&lesson.path).unwrap();
+    let scratch = Scratch::new("i18n-test").unwrap();
+    let mut catalog = Catalog::new("fr");
+    catalog.update(i18n::extract(&course, lesson, &document));
+    for message in &mut catalog.messages[..3] {
+        message.text = "traduit".to_string();
+    }
+    // A copy of the course whose only catalog is the one above.
+    let copy = Course {
+        root: scratch.path().to_path_buf(),
+        ..course.clone()
+    };
+    let path = i18n::lesson_catalog_path(&copy, "fr", lesson);
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(&path, catalog.to_string()).unwrap();
+
+    let translation = Translation::load(&copy, "fr").unwrap();
+    let coverage = translation.coverage(lesson, &document);
+    assert_eq!((coverage.translated, coverage.total), (3, 13));
+    assert_eq!(
+        coverage.untranslated[0],
+        ("Rust \"Hello, World!\" Program".to_string(), 2)
+    );
+    assert_eq!(coverage.to_string(), "3/13 (23%)");
+}
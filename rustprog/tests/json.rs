@@ -0,0 +1,67 @@
+use rustprog::json::Value;
+
+#[test]
+fn values_round_trip() {
+    let text =
+        r#"{"name": "tuple", "id": 3.3, "tags": ["data", "types"], "done": false, "note": null}"#;
+    let value = Value::parse(text).unwrap();
+    assert_eq!(value.get("name").and_then(Value::as_str), Some("tuple"));
+    assert_eq!(value.get("id").and_then(Value::as_f64), Some(3.3));
+    assert_eq!(value.get("done").and_then(Value::as_bool), Some(false));
+    assert_eq!(
+        value.get("tags").and_then(Value::as_array).map(<[_]>::len),
+        Some(2)
+    );
+    assert_eq!(
+        value.to_string(),
+        r#"{"name":"tuple","id":3.3,"tags":["data","types"],"done":false,"note":null}"#
+    );
+    assert_eq!(Value::parse(&value.pretty()).unwrap(), value);
+}
+
+#[test]
+fn pretty_output_is_indented_and_keeps_key_order() {
+    let value = Value::Object(vec![
+        ("b".to_string(), Value::from(1u64)),
+        ("a".to_string(), Value::from(vec!["x", "y"])),
+        ("empty".to_string(), Value::Object(Vec::new())),
+    ]);
+    assert_eq!(
+        value.pretty(),
+        "{\n  \"b\": 1,\n  \"a\": [\n    \"x\",\n    \"y\"\n  ],\n  \"empty\": {}\n}\n"
+    );
+}
+
+#[test]
+fn strings_are_escaped() {
+    let text = "quote \" backslash \\ newline \n tab \t bell \u{7} é 🦀";
+    let written = Value::from(text).to_string();
+    assert_eq!(
+        written,
+        "\"quote \\\" backslash \\\\ newline \\n tab \\t bell \\u0007 é 🦀\""
+    );
+    assert_eq!(Value::parse(&written).unwrap(), Value::from(text));
+    assert_eq!(Value::parse(r#""é🦀\/""#).unwrap(), Value::from("é🦀/"));
+}
+
+#[test]
+fn numbers() {
+    assert_eq!(
+        Value::parse("1792321720").unwrap().as_u64(),
+        Some(1_792_321_720)
+    );
+    assert_eq!(Value::parse("-2.5e3").unwrap().as_f64(), Some(-2500.0));
+    assert_eq!(Value::parse("1.5").unwrap().as_u64(), None);
+    assert_eq!(Value::from(1_792_321_720u64).to_string(), "1792321720");
+    assert_eq!(Value::from(0.1).to_string(), "0.1");
+}
+
+#[test]
+fn errors_point_at_the_problem() {
+    let err = Value::parse("{\n  \"a\": [1, 2,]\n}").unwrap_err();
+    assert_eq!((err.line, err.column), (2, 14));
+    assert_eq!(err.to_string(), "2:14: expected a value");
+    assert!(Value::parse("\"open").is_err());
+    assert!(Value::parse("[1] 2").is_err());
+    assert!(Value::parse(r#""\ud83e""#).is_err());
+}
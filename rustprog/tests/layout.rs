@@ -0,0 +1,90 @@
+//! Layouts are measured by compiled code and drawn byte by byte.
+
+use std::mem::{align_of, offset_of, size_of};
+use std::path::Path;
+
+use rustprog::layout::{self, Definition};
+use rustprog::lesson::LessonSource;
+use rustprog::{playground, Course};
+
+fn course() -> Course {
+    Course::discover(Path::new(env!("CARGO_MANIFEST_DIR")).join("..")).unwrap()
+}
+
+#[test]
+fn lesson_types_are_measured() {
+    let course = course();
+    let tuple = course.find("tuple").unwrap();
+    let types = layout::lesson_types(tuple, &LessonSource::read(&tuple.path).unwrap());
+    assert!(types.contains(&"(&str, u8, f32)".to_string()), "{types:?}");
+    let structs = course.find("struct").unwrap();
+    let types = layout::lesson_types(structs, &LessonSource::read(&structs.path).unwrap());
+    assert!(types.contains(&"Person3".to_string()), "{types:?}");
+
+    let definitions = layout::definitions(&course).unwrap();
+    let person = definitions
+        .iter()
+        .find(|definition| definition.name == "Person3")
+        .unwrap();
+    assert_eq!(person.lesson, "3.4");
+    assert_eq!(person.fields[1], ("age".to_string(), "u8".to_string()));
+
+    let types = ["(&str, u8, f32)", "Person3", "[i32; 5]"].map(String::from);
+    let layouts = layout::measure(&types, &definitions, &playground::LIMITS)
+        .unwrap()
+        .unwrap();
+    let tuple = &layouts[0];
+    assert_eq!(tuple.size, size_of::<(&str, u8, f32)>());
+    assert_eq!(tuple.align, align_of::<(&str, u8, f32)>());
+    let offsets: Vec<(String, usize)> = tuple
+        .fields
+        .iter()
+        .map(|field| (field.name.clone(), field.offset))
+        .collect();
+    assert!(offsets.contains(&("2".to_string(), offset_of!((&str, u8, f32), 2))));
+
+    for layout in &layouts {
+        let padding: usize = layout.padding().iter().map(|(_, size)| size).sum();
+        let fields: usize = layout.fields.iter().map(|field| field.size).sum();
+        assert_eq!(fields + padding, layout.size, "{layout}");
+    }
+    assert_eq!(layouts[2].fields.len(), 5);
+    assert_eq!(layouts[2].fields[4].offset, 16);
+}
+
+#[test]
+fn padding_and_reordering_are_shown() {
+    let definitions = vec![Definition {
+        name: "Loose".to_string(),
+        lesson: "0.0".to_string(),
+        line: 1,
+        source: "struct Loose {\n    a: u8,\n    b: u32,\n    c: u8,\n}".to_string(),
+        fields: vec![
+            ("a".to_string(), "u8".to_string()),
+            ("b".to_string(), "u32".to_string()),
+            ("c".to_string(), "u8".to_string()),
+        ],
+    }];
+    let types = ["Loose", "u16"].map(String::from);
+    let layouts = layout::measure(&types, &definitions, &playground::LIMITS)
+        .unwrap()
+        .unwrap();
+    let loose = &layouts[0];
+    assert_eq!((loose.size, loose.align), (8, 4));
+    assert!(loose.reordered);
+    assert_eq!(
+        loose.padding().iter().map(|(_, size)| size).sum::<usize>(),
+        2
+    );
+    let drawn = loose.to_string();
+    assert!(drawn.contains("#[repr(C)]"), "{drawn}");
+    assert!(drawn.contains("padding"), "{drawn}");
+
+    let drawn = layouts[1].to_string();
+    assert_eq!(drawn, "u16: 2 bytes, aligned to 2\n\n     0  AA\nA u16\n");
+
+    let error = layout::measure(&["Nope".to_string()], &[], &playground::LIMITS)
+        .unwrap()
+        .unwrap_err();
+    assert!(error.contains("Nope"), "{error}");
+}
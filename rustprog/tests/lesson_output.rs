@@ -0,0 +1,52 @@
+//! Every `Output:` block and `println!(...); // output` comment in the
+//! lessons must match what the code really prints.
+
+use std::fs;
+use std::path::Path;
+
+use rustprog::compile::Scratch;
+use rustprog::lesson::LessonSource;
+use rustprog::verify;
+use rustprog::Course;
+
+#[test]
+fn documented_output_matches_the_code() {
+    let course = Course::discover(Path::new(env!("CARGO_MANIFEST_DIR")).join("..")).unwrap();
+    let mut failures = String::new();
+    for lesson in course.lessons() {
+        let report = verify::verify_lesson(&lesson.path).unwrap();
+        for mismatch in &report.mismatches {
+            failures += &format!("{}:{mismatch}\n", lesson.relative_path(&course).display());
+        }
+    }
+    assert!(
+        failures.is_empty(),
+        "documented output drifted:\n\n{failures}"
+    );
+}
+
+#[test]
+fn a_comment_must_match_a_whole_value() {
+    let scratch = Scratch::new("lesson-output-test").unwrap();
+    let path = scratch.path().join("lesson.rs");
+    fs::write(
+        &path,
+        "fn main() {\n    println!(\"{}\", 10); // 1\n    println!(\"x = {}\", 8); // 8\n    \
+         println!(\"x = {}\", 8); // x = 8\n}\n",
+    )
+    .unwrap();
+    let report = verify::verify_lesson(&path).unwrap();
+    assert_eq!(report.mismatches.len(), 1, "{:?}", report.mismatches);
+    assert_eq!(report.mismatches[0].line, 2);
+}
+
+#[test]
+fn output_of_a_snippet_that_is_not_run_is_skipped() {
+    let course = Course::discover(Path::new(env!("CARGO_MANIFEST_DIR")).join("..")).unwrap();
+    let lesson = course.find("2.2").unwrap();
+    let blocks = LessonSource::read(&lesson.path).unwrap().output_blocks();
+    // The commented-out `loop` that never stops.
+    assert!(!blocks[0].checked);
+    assert_eq!(blocks[0].expected[0], "Loop forever!");
+    assert!(blocks[1..].iter().all(|block| block.checked));
+}
@@ -0,0 +1,129 @@
+//! The lesson linter.
+
+use std::path::Path;
+
+use rustprog::lesson::LessonSource;
+use rustprog::lint::{self, Finding, Severity};
+use rustprog::Course;
+
+fn course() -> Course {
+    Course::discover(Path::new(env!("CARGO_MANIFEST_DIR")).join("..")).unwrap()
+}
+
+fn lint(path: &str, source: &str) -> Vec<String> {
+    lint::lint_lesson(Path::new(path), &LessonSource::parse(source))
+        .iter()
+        .map(Finding::to_string)
+        .collect()
+}
+
+#[test]
+fn the_lessons_have_no_errors() {
+    let course = course();
+    let mut findings = lint::lint_course(&course);
+    for chapter in &course.chapters {
+        findings.extend(lint::lint_chapter(&course, chapter));
+        for lesson in &chapter.lessons {
+            findings.extend(lint::lint_file(&course.root, &lesson.path).unwrap());
+        }
+    }
+    let errors: Vec<String> = findings
+        .iter()
+        .filter(|finding| finding.severity == Severity::Error)
+        .map(Finding::to_string)
+        .collect();
+    assert_eq!(errors, Vec::<String>::new());
+
+    // Known inconsistencies are kept as warnings.
+    let warnings: Vec<String> = findings.iter().map(Finding::to_string).collect();
+    assert!(warnings.contains(
+        &"_01_Rust_Intoduction: warning: directory name '_01_Rust_Intoduction' does not \
+          match its package name 'ch01_introduction' [chapter-name]"
+            .to_string()
+    ));
+}
+
+#[test]
+fn a_well_formed_lesson_is_clean() {
+    let source = "\
+/*
+Rust Tuple
+==========
+
+1. Creating
+===========
+*/
+
+pub fn main() {
+    println!(\"{}\", 1);
+}
+
+/*
+Output:
+1
+
+2. Reading
+==========
+*/
+";
+    assert_eq!(lint("_03_Rust_tuple.rs", source), Vec::<String>::new());
+}
+
+#[test]
+fn structure_problems_are_reported_by_line() {
+    let source = "\
+// Tuples
+/*
+Output:
+1
+
+2. Creating
+===========
+
+Reading
+-------
+
+Reading
+-------
+Use .0
+*/
+
+fn helper() {}
+";
+    assert_eq!(
+        lint("_03_Rust_tuple.rs", source),
+        [
+            "_03_Rust_tuple.rs: error: the lesson has no top-level fn main [main]",
+            "_03_Rust_tuple.rs:1: error: the lesson does not start with a title underlined \
+             with '=' [title]",
+            "_03_Rust_tuple.rs:3: error: the Output block follows no code [output]",
+            "_03_Rust_tuple.rs:6: error: heading number 2 should be 1 [numbering]",
+            "_03_Rust_tuple.rs:9: warning: the section 'Reading' is empty [heading]",
+            "_03_Rust_tuple.rs:12: warning: the heading 'Reading' repeats the one on line 9 \
+             [heading]",
+        ]
+    );
+}
+
+#[test]
+fn titles_and_underlines() {
+    let source = "\
+/*
+Rust Tuples
+--------
+*/
+
+fn main() {}
+";
+    assert_eq!(
+        lint("_03_Rust_tuple.rs", source),
+        [
+            "_03_Rust_tuple.rs:2: error: the title 'Rust Tuples' is underlined with '-' \
+             instead of '=' [title]",
+            "_03_Rust_tuple.rs:2: warning: the title 'Rust Tuples' does not name the lesson \
+             like its file ('tuple' is missing) [title]",
+            "_03_Rust_tuple.rs:3: warning: the underline of 'Rust Tuples' is 3 characters \
+             short [heading]",
+        ]
+    );
+}
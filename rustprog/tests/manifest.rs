@@ -0,0 +1,178 @@
+//! `course.json` and its schema.
+
+use std::path::Path;
+
+use rustprog::json::Value;
+use rustprog::manifest;
+use rustprog::Course;
+
+fn course() -> Course {
+    Course::discover(Path::new(env!("CARGO_MANIFEST_DIR")).join("..")).unwrap()
+}
+
+/// Checks `value` against the part of JSON Schema that `course.schema.json`
+/// uses, except `pattern`, collecting the paths that do not match.
+fn validate(schema: &Value, root: &Value, value: &Value, path: &str, errors: &mut Vec<String>) {
+    if let Some(reference) = schema.get("$ref").and_then(Value::as_str) {
+        let name = reference.strip_prefix("#/$defs/").unwrap();
+        let target = root.get("$defs").and_then(|defs| defs.get(name)).unwrap();
+        return validate(target, root, value, path, errors);
+    }
+    if let Some(options) = schema.get("oneOf").and_then(Value::as_array) {
+        let matching = options
+            .iter()
+            .filter(|option| {
+                let mut found = Vec::new();
+                validate(option, root, value, path, &mut found);
+                found.is_empty()
+            })
+            .count();
+        if matching != 1 {
+            errors.push(format!("{path}: matches {matching} of oneOf"));
+        }
+    }
+    if let Some(expected) = schema.get("const") {
+        if expected != value {
+            errors.push(format!("{path}: is not {expected}"));
+        }
+    }
+    if let Some(choices) = schema.get("enum").and_then(Value::as_array) {
+        if !choices.contains(value) {
+            errors.push(format!("{path}: {value} is not one of the enum"));
+        }
+    }
+    if let Some(types) = schema.get("type") {
+        let types: Vec<&str> = match types {
+            Value::String(name) => vec![name],
+            types => types
+                .as_array()
+                .unwrap()
+                .iter()
+                .filter_map(Value::as_str)
+                .collect(),
+        };
+        let is = |name: &str| match (name, value) {
+            ("null", Value::Null)
+            | ("boolean", Value::Bool(_))
+            | ("string", Value::String(_))
+            | ("array", Value::Array(_))
+            | ("object", Value::Object(_))
+            | ("number", Value::Number(_)) => true,
+            ("integer", Value::Number(number)) => number.fract() == 0.0,
+            _ => false,
+        };
+        if !types.iter().any(|name| is(name)) {
+            errors.push(format!("{path}: {value} is not of type {types:?}"));
+            return;
+        }
+    }
+    if let (Some(minimum), Some(number)) = (
+        schema.get("minimum").and_then(Value::as_f64),
+        value.as_f64(),
+    ) {
+        if number < minimum {
+            errors.push(format!("{path}: {number} is below {minimum}"));
+        }
+    }
+    if let (Some(items), Some(values)) = (schema.get("items"), value.as_array()) {
+        for (index, item) in values.iter().enumerate() {
+            validate(items, root, item, &format!("{path}[{index}]"), errors);
+        }
+    }
+    if let Some(members) = value.as_object() {
+        let properties = schema.get("properties");
+        for required in schema
+            .get("required")
+            .and_then(Value::as_array)
+            .unwrap_or_default()
+        {
+            let name = required.as_str().unwrap();
+            if value.get(name).is_none() {
+                errors.push(format!("{path}: misses {name}"));
+            }
+        }
+        for (name, member) in members {
+            match properties.and_then(|properties| properties.get(name)) {
+                Some(property) => {
+                    validate(property, root, member, &format!("{path}.{name}"), errors)
+                }
+                None if schema.get("additionalProperties") == Some(&Value::Bool(false)) => {
+                    errors.push(format!("{path}: unexpected {name}"))
+                }
+                None => {}
+            }
+        }
+    }
+}
+
+#[test]
+fn the_manifest_matches_its_schema() {
+    let schema = Value::parse(manifest::SCHEMA).unwrap();
+    let manifest = manifest::manifest(&course()).unwrap();
+    let mut errors = Vec::new();
+    validate(&schema, &schema, &manifest, "$", &mut errors);
+    assert_eq!(errors, Vec::<String>::new());
+
+    // The checker does catch mistakes.
+    let broken =
+        Value::parse(r#"{"version": 2, "title": 1, "chapters": [], "extra": null}"#).unwrap();
+    validate(&schema, &schema, &broken, "$", &mut errors);
+    assert_eq!(
+        errors,
+        [
+            "$: misses lessons",
+            "$.version: is not 1",
+            "$.title: 1 is not of type [\"string\"]",
+            "$: unexpected extra",
+        ]
+    );
+}
+
+#[test]
+fn lessons_are_described() {
+    let manifest = manifest::manifest(&course()).unwrap();
+    let lessons = manifest.get("lessons").and_then(Value::as_array).unwrap();
+    assert_eq!(lessons.len(), course().lessons().count());
+    let lesson = |id: &str| {
+        lessons
+            .iter()
+            .find(|lesson| lesson.get("id").and_then(Value::as_str) == Some(id))
+            .unwrap()
+    };
+
+    let casting = lesson("1.7");
+    assert_eq!(
+        casting.get("title").and_then(Value::as_str),
+        Some("Rust Type Casting")
+    );
+    assert_eq!(casting.get("chapter").and_then(Value::as_u64), Some(1));
+    assert_eq!(casting.get("order").and_then(Value::as_u64), Some(7));
+    assert_eq!(
+        casting.get("error_codes").unwrap().to_string(),
+        r#"["E0604","E0308"]"#
+    );
+    let sections = casting.get("sections").and_then(Value::as_array).unwrap();
+    assert_eq!(
+        sections[0].to_string(),
+        r#"{"line":44,"level":1,"title":"Type Conversion: Character to Integer in Rust"}"#
+    );
+    assert_eq!(
+        casting.get("quiz").and_then(Value::as_str),
+        Some("quizzes/_01_Rust_Intoduction/_07_Rust_type_casting.md")
+    );
+
+    // Lessons without exercises say so.
+    assert_eq!(lesson("1.1").get("exercises"), Some(&Value::Null));
+
+    let outputs: usize = lessons
+        .iter()
+        .map(|lesson| {
+            lesson
+                .get("outputs")
+                .and_then(Value::as_array)
+                .unwrap()
+                .len()
+        })
+        .sum();
+    assert!(outputs > 10, "{outputs} Output blocks");
+}
@@ -0,0 +1,105 @@
+//! The course exports as an mdBook source tree.
+
+use std::fs;
+use std::path::Path;
+
+use rustprog::compile::Scratch;
+use rustprog::{mdbook, Course};
+
+fn course() -> Course {
+    Course::discover(Path::new(env!("CARGO_MANIFEST_DIR")).join("..")).unwrap()
+}
+
+#[test]
+fn the_summary_follows_the_numbering() {
+    let course = course();
+    let scratch = Scratch::new("mdbook-test").unwrap();
+    let pages = mdbook::export(&course, scratch.path()).unwrap();
+    assert_eq!(pages, 1 + course.chapters.len() + course.lessons().count());
+    assert!(scratch.path().join("book.toml").is_file());
+
+    let src = scratch.path().join("src");
+    let summary = fs::read_to_string(src.join("SUMMARY.md")).unwrap();
+    assert!(summary.contains("- [Rust Data Types](_03_Rust_Data_Types/README.md)\n"));
+    assert!(summary
+        .contains("    - [Rust Type Casting](_01_Rust_Intoduction/_07_Rust_type_casting.md)\n"));
+
+    let mut last = 0;
+    for lesson in course.lessons() {
+        let link = format!("{}/{}.md)", course.chapter_of(lesson).dir_name, lesson.stem);
+        let at = summary
+            .find(&link)
+            .unwrap_or_else(|| panic!("{link} missing"));
+        assert!(at > last, "{link} is out of order");
+        assert!(src.join(&link[..link.len() - 1]).is_file());
+        last = at;
+    }
+}
+
+#[test]
+fn lessons_become_markdown_pages() {
+    let course = course();
+    let scratch = Scratch::new("mdbook-test").unwrap();
+    mdbook::export(&course, scratch.path()).unwrap();
+    let page = |chapter: &str, stem: &str| {
+        fs::read_to_string(
+            scratch
+                .path()
+                .join("src")
+                .join(chapter)
+                .join(format!("{stem}.md")),
+        )
+        .unwrap()
+    };
+
+    let casting = page("_01_Rust_Intoduction", "_07_Rust_type_casting");
+    assert!(casting.starts_with("# Rust Type Casting\n"));
+    assert!(casting.contains("\n## Type Conversion: Character to Integer in Rust\n"));
+    assert!(casting.contains("```rust,compile_fail,E0604\nlet decimal: f32 = 65.321;"));
+
+    let structs = page("_03_Rust_Data_Types", "_04_Rust_struct");
+    assert!(structs.contains("\n## Defining a Struct in Rust\n"));
+
+    // Example modules and commented-out programs keep their `fn main`.
+    let print = page("_01_Rust_Intoduction", "_04_Rust_print_output");
+    assert!(print.contains("```rust\nfn main() {\n    print!(\"Hello, World!\");\n}\n```"));
+    let variables = page("_01_Rust_Intoduction", "_05_Rust_variables_and_mutability");
+    assert!(variables.contains("```rust\nfn main() {\n    // variable to store integer value\n"));
+    assert!(variables.contains("```rust,compile_fail,E0384\nfn main() {"));
+
+    let operators = page("_01_Rust_Intoduction", "_08_Rust_operators");
+    assert!(operators.contains("| Operator | Example | Equivalent |\n|---|---|---|\n"));
+    assert!(operators.contains("| \\|\\| (Logical OR) |"));
+}
+
+#[test]
+fn pieces_marked_runnable_compile_alone() {
+    let course = course();
+    let scratch = Scratch::new("mdbook-test").unwrap();
+    mdbook::export(&course, scratch.path()).unwrap();
+
+    // rustdoc wraps a block without `fn main` in one; a function per piece
+    // lets a single build check them all.
+    let mut program = String::from("#![allow(unused)]\nfn main() {}\n");
+    let mut pieces = 0;
+    for lesson in course.lessons() {
+        let page = scratch
+            .path()
+            .join("src")
+            .join(&course.chapter_of(lesson).dir_name)
+            .join(format!("{}.md", lesson.stem));
+        let page = fs::read_to_string(page).unwrap();
+        assert!(!page.contains("```rust,ignore\nfn main() {\n```"), "{page}");
+        for block in page.split("\n```rust\n").skip(1) {
+            let code = &block[..block.find("\n```\n").unwrap()];
+            if code.lines().any(|line| line.starts_with("fn main(")) {
+                continue;
+            }
+            pieces += 1;
+            program += &format!("\n// {}\nfn piece_{pieces}() {{\n{code}\n}}\n", lesson.stem);
+        }
+    }
+    assert!(pieces >= 100, "only {pieces} runnable pieces");
+    let build = rustprog::compile::compile_source(&program, &scratch).unwrap();
+    assert!(build.succeeded(), "{}", build.stderr);
+}
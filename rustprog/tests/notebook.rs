@@ -0,0 +1,143 @@
+//! Lessons export as Jupyter notebooks for evcxr.
+
+use std::fs;
+use std::path::Path;
+
+use rustprog::compile::{self, Scratch};
+use rustprog::document::Document;
+use rustprog::json::Value;
+use rustprog::notebook::{self, Cell};
+use rustprog::Course;
+
+fn course() -> Course {
+    Course::discover(Path::new(env!("CARGO_MANIFEST_DIR")).join("..")).unwrap()
+}
+
+fn cells(course: &Course, query: &str) -> Vec<Cell> {
+    let lesson = course.find(query).unwrap();
+    let document = Document::read(&lesson.path).unwrap();
+    notebook::cells(&document.title.clone().unwrap(), &document)
+}
+
+fn code_cells(cells: &[Cell]) -> Vec<&str> {
+    cells
+        .iter()
+        .filter_map(|cell| match cell {
+            Cell::Code(code) => Some(code.as_str()),
+            Cell::Markdown(_) => None,
+        })
+        .collect()
+}
+
+#[test]
+fn notebooks_are_written_in_nbformat_4() {
+    let course = course();
+    let scratch = Scratch::new("notebook-test").unwrap();
+    let lesson = course.find("tuple").unwrap();
+    notebook::export(&course, &[lesson], scratch.path()).unwrap();
+
+    let path = notebook::notebook_path(&course, lesson, scratch.path());
+    assert!(path.ends_with("_03_Rust_Data_Types/_03_Rust_tuple.ipynb"));
+    let notebook = Value::parse(&fs::read_to_string(path).unwrap()).unwrap();
+    assert_eq!(notebook.get("nbformat").and_then(Value::as_u64), Some(4));
+    let kernel = notebook
+        .get("metadata")
+        .and_then(|meta| meta.get("kernelspec"));
+    assert_eq!(
+        kernel
+            .and_then(|kernel| kernel.get("name"))
+            .and_then(Value::as_str),
+        Some("rust")
+    );
+
+    let cells = notebook.get("cells").and_then(Value::as_array).unwrap();
+    let first = &cells[0];
+    assert_eq!(
+        first.get("cell_type").and_then(Value::as_str),
+        Some("markdown")
+    );
+    let source = first.get("source").and_then(Value::as_array).unwrap();
+    assert_eq!(source[0].as_str(), Some("# Rust tuple\n"));
+    let code = cells
+        .iter()
+        .find(|cell| cell.get("cell_type").and_then(Value::as_str) == Some("code"))
+        .unwrap();
+    assert_eq!(code.get("execution_count"), Some(&Value::Null));
+    assert_eq!(code.get("outputs"), Some(&Value::Array(Vec::new())));
+}
+
+#[test]
+fn main_is_unwrapped_into_cells() {
+    let course = course();
+    let closure = cells(&course, "closure");
+    let code = code_cells(&closure);
+    assert!(code[0].starts_with(
+        "// define a closure to print a text\nlet print_text = || println!(\"Defining Closure\");"
+    ));
+    assert!(code.iter().all(|cell| !cell.contains("fn main(")));
+    // The capture examples quoted in the prose run as cells of their own.
+    assert!(code
+        .iter()
+        .any(|cell| cell.starts_with("let mut word = String::from(\"Hello\");")));
+    // Code that is not meant to run stays in the Markdown.
+    assert!(closure.iter().any(|cell| matches!(
+        cell,
+        Cell::Markdown(text) if text.contains("```rust\nfn add_numbers(a: i32, b: i32) {")
+    )));
+
+    // Example modules become their own cells; the main running them is gone.
+    let print = code_cells(&cells(&course, "print_output")).join("\n");
+    assert!(print.starts_with("print!(\"Hello, World!\");"));
+    assert!(!print.contains("hello_world::main"));
+
+    // A main that returns a Result cannot run as top-level statements.
+    let question = cells(&course, "question_mark_operator");
+    assert!(code_cells(&question)
+        .iter()
+        .all(|cell| !cell.contains("return Ok(());")));
+    assert!(question.iter().any(|cell| matches!(
+        cell,
+        Cell::Markdown(text) if text.contains("fn main() -> Result<(), std::num::ParseIntError> {")
+    )));
+}
+
+#[test]
+fn closures_are_defined_in_the_cell_that_uses_them() {
+    let course = course();
+    let closure = cells(&course, "closure");
+    let code = code_cells(&closure);
+    // evcxr cannot keep a closure for a later cell.
+    assert_eq!(
+        code[0],
+        "// define a closure to print a text\n\
+         let print_text = || println!(\"Defining Closure\");\n\
+         \n\
+         // call the closure\n\
+         print_text();"
+    );
+    let add_one = code
+        .iter()
+        .find(|cell| cell.contains("let add_one"))
+        .unwrap();
+    assert!(add_one.contains("add_one(2);"));
+    // The prose in between follows the cell.
+    let calls = "Here, print_text() calls the closure.";
+    let prose = closure
+        .iter()
+        .position(|cell| matches!(cell, Cell::Markdown(text) if text.contains(calls)))
+        .unwrap();
+    assert_eq!(closure[prose - 1], Cell::Code(code[0].to_string()));
+}
+
+#[test]
+fn the_code_cells_compile_in_order() {
+    let course = course();
+    for lesson in course.lessons() {
+        let cells = cells(&course, &lesson.id());
+        // evcxr runs the cells in one session, as if they were one body.
+        let program = format!("fn main() {{\n{}\n}}\n", code_cells(&cells).join("\n"));
+        let scratch = Scratch::new("notebook-test").unwrap();
+        let build = compile::compile_source(&program, &scratch).unwrap();
+        assert!(build.binary.is_some(), "{}: {}", lesson.id(), build.stderr);
+    }
+}
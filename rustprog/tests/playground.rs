@@ -0,0 +1,130 @@
+//! The playground: copying pieces of lessons out and running them safely.
+
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
+
+use rustprog::compile::{self, Limits, Scratch};
+use rustprog::playground::{self, Piece, Playground, Report};
+use rustprog::Course;
+
+fn course() -> Course {
+    Course::discover(Path::new(env!("CARGO_MANIFEST_DIR")).join("..")).unwrap()
+}
+
+const QUICK: Limits = Limits {
+    timeout: Duration::from_secs(1),
+    memory_mb: 256,
+    processes: 256,
+    output_bytes: 4096,
+};
+
+#[test]
+fn pieces_are_copied_as_programs() {
+    let course = course();
+    let text = |query: &str| fs::read_to_string(&course.find(query).unwrap().path).unwrap();
+
+    // Commented-out code is uncommented where it stands.
+    let endless = playground::excerpt(&text("loop"), &Piece::Snippet(34)).unwrap();
+    assert_eq!(endless.name, "line-34");
+    assert!(endless
+        .code
+        .contains("    loop {\n        println!(\"Loop forever!\");\n    }\n"));
+
+    // Whole programs quoted in the prose run on their own.
+    let mutability = text("variables_and_mutability");
+    let program = playground::excerpt(&mutability, &Piece::Snippet(69)).unwrap();
+    assert!(program.code.starts_with("fn main() {\n"));
+    assert_eq!(program.lines, (69, 77));
+
+    let example = playground::excerpt(&text("print_output"), &Piece::Example("newline".into()));
+    assert!(example.unwrap().code.starts_with("fn main() {"));
+    assert_eq!(
+        playground::excerpt(&mutability, &Piece::Example("nope".into())),
+        None
+    );
+    assert_eq!(playground::excerpt(&mutability, &Piece::Snippet(2)), None);
+}
+
+#[test]
+fn endless_loops_are_stopped() {
+    let course = course();
+    for (query, line) in [("loop", 34), ("while_loop", 68)] {
+        let lesson = course.find(query).unwrap();
+        let text = fs::read_to_string(&lesson.path).unwrap();
+        let excerpt = playground::excerpt(&text, &Piece::Snippet(line)).unwrap();
+        let playground = Playground::create(&course, lesson, &excerpt, true).unwrap();
+        let report = playground.run(&QUICK).unwrap();
+        let Report::Ran(run) = &report else {
+            panic!("{query}: {report}");
+        };
+        assert_eq!(run.status, None, "{query}");
+        assert!(run.stdout.len() <= QUICK.output_bytes);
+        let shown = report.to_string();
+        assert!(
+            shown.contains("Loop forever!\n[the line above, "),
+            "{shown}"
+        );
+        assert!(
+            shown.ends_with("still running (an endless loop?)"),
+            "{shown}"
+        );
+    }
+}
+
+#[test]
+fn compiler_errors_point_at_the_lesson() {
+    let course = course();
+    let lesson = course.find("variables_and_mutability").unwrap();
+    let text = fs::read_to_string(&lesson.path).unwrap();
+    let excerpt = playground::excerpt(&text, &Piece::Snippet(69)).unwrap();
+    let playground = Playground::create(&course, lesson, &excerpt, true).unwrap();
+
+    // A line added by the learner moves the code down.
+    let edited = excerpt
+        .code
+        .replacen("fn main() {", "fn main() {\n    let y = 0;", 1);
+    fs::write(playground.main_path(), &edited).unwrap();
+    let Report::DoesNotCompile(errors) = playground.run(&QUICK).unwrap() else {
+        panic!("the snippet compiled");
+    };
+    assert!(errors.contains("error[E0384]"), "{errors}");
+    assert!(
+        errors.contains(
+            "  = from lesson _01_Rust_Intoduction/_05_Rust_variables_and_mutability.rs:75\n"
+        ),
+        "{errors}"
+    );
+    assert!(errors.contains("  > 75 | //     x = 2;\n"), "{errors}");
+}
+
+#[test]
+fn edited_lines_are_lined_up_with_the_lesson() {
+    let lesson = [
+        (10, "    let x = 1;"),
+        (11, ""),
+        (12, "    // x = 2;"),
+        (13, "}"),
+    ];
+    let program = ["let x = 1;", "let y = 3;", "", "x = 2;", "}"];
+    assert_eq!(
+        playground::align(&program, &lesson),
+        [Some(10), None, None, Some(12), Some(13)]
+    );
+}
+
+#[test]
+fn memory_is_limited() {
+    if !cfg!(unix) {
+        return;
+    }
+    let scratch = Scratch::new("playground-test").unwrap();
+    let build = compile::compile_source(
+        "fn main() {\n    let big = vec![1u8; 1 << 30];\n    println!(\"{}\", big[12345]);\n}\n",
+        &scratch,
+    )
+    .unwrap();
+    let run = compile::run_limited(&build.binary.unwrap(), &QUICK).unwrap();
+    assert!(!run.status.unwrap().success());
+    assert!(run.stdout.is_empty());
+}
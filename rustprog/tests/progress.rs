@@ -0,0 +1,84 @@
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use rustprog::compile::Scratch;
+use rustprog::progress::{self, Progress, QuizScore};
+use rustprog::Course;
+
+fn course() -> Course {
+    Course::discover(Path::new(env!("CARGO_MANIFEST_DIR")).join("..")).unwrap()
+}
+
+#[test]
+fn progress_is_kept_per_lesson_id() {
+    let course = course();
+    let tuple = course.find("tuple").unwrap();
+    let for_loop = course.find("2.4").unwrap();
+    let scratch = Scratch::new("progress-test").unwrap();
+    let path = scratch.path().join("data/rustprog/progress.json");
+
+    let mut progress = Progress::load(&path).unwrap();
+    assert!(progress.lessons.is_empty());
+    progress.mark_viewed(&course, tuple, 100);
+    progress.mark_viewed(&course, tuple, 200);
+    progress.mark_passed(&course, for_loop, "sum_range", 300);
+    progress.mark_passed(&course, for_loop, "sum_range", 400);
+    progress.save(&path).unwrap();
+
+    let loaded = Progress::load(&path).unwrap();
+    assert_eq!(loaded, progress);
+    let record = loaded.lesson(tuple).unwrap();
+    assert_eq!(record.path, "_03_Rust_Data_Types/_03_Rust_tuple.rs");
+    assert_eq!(record.viewed, Some(200));
+    let record = &loaded.lessons["2.4"];
+    assert_eq!(record.viewed, None);
+    assert_eq!(record.passed["sum_range"], 300);
+
+    let text = fs::read_to_string(&path).unwrap();
+    assert!(text.contains("\"3.3\": {\n      \"path\": \"_03_Rust_Data_Types/_03_Rust_tuple.rs\""));
+}
+
+#[test]
+fn the_best_quiz_score_is_kept() {
+    let course = course();
+    let casting = course.find("type_casting").unwrap();
+    let score = |correct, at| QuizScore {
+        correct,
+        total: 4,
+        at,
+    };
+    let mut progress = Progress::default();
+    progress.mark_quiz(&course, casting, score(2, 100));
+    progress.mark_quiz(&course, casting, score(3, 200));
+    progress.mark_quiz(&course, casting, score(1, 300));
+    progress.mark_quiz(&course, casting, score(3, 400));
+    assert_eq!(progress.lesson(casting).unwrap().quiz, Some(score(3, 200)));
+
+    let scratch = Scratch::new("progress-test").unwrap();
+    let path = scratch.path().join("progress.json");
+    progress.save(&path).unwrap();
+    assert_eq!(Progress::load(&path).unwrap(), progress);
+}
+
+#[test]
+fn a_damaged_file_is_reported() {
+    let scratch = Scratch::new("progress-test").unwrap();
+    let path = scratch.path().join("progress.json");
+    fs::write(&path, "{\"version\": 1, \"lessons\": [").unwrap();
+    let err = Progress::load(&path).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    assert!(err.to_string().contains("progress.json: 1:28"), "{err}");
+
+    fs::write(&path, "{\"version\": 7, \"lessons\": {}}").unwrap();
+    let err = Progress::load(&path).unwrap_err();
+    assert!(err.to_string().ends_with("not a progress file"), "{err}");
+}
+
+#[test]
+fn dates_are_shown_in_utc() {
+    assert_eq!(progress::date(0), "1970-01-01");
+    assert_eq!(progress::date(951_868_799), "2000-02-29");
+    assert_eq!(progress::date(1_709_251_200), "2024-03-01");
+    assert_eq!(progress::date(1_792_321_720), "2026-10-18");
+}
@@ -0,0 +1,142 @@
+//! Question files and the quiz runner.
+
+use std::io::Cursor;
+use std::path::Path;
+
+use rustprog::quiz::{self, Answer, Kind, Quiz};
+use rustprog::Course;
+
+fn course() -> Course {
+    Course::discover(Path::new(env!("CARGO_MANIFEST_DIR")).join("..")).unwrap()
+}
+
+const SAMPLE: &str = r#"# Quiz: sample
+
+## output
+What does this print?
+
+```rust
+fn main() {
+    let decimal: f64 = 54.321;
+    println!("integer = {}", decimal as u16);
+}
+```
+
+> The fraction is dropped.
+
+## compiles
+Does this compile?
+
+```rust
+fn main() {
+    let x = 1;
+    x = 2;
+}
+```
+
+## choice
+Pick the even numbers.
+
+- [ ] one
+- [x] two
+- [ ] three
+- [x] four
+"#;
+
+#[test]
+fn every_lesson_has_a_working_quiz() {
+    let course = course();
+    for lesson in course.lessons() {
+        let path = quiz::quiz_path(&course, lesson);
+        let quiz = Quiz::read(&path).unwrap();
+        assert!(quiz.questions.len() >= 3, "{}", path.display());
+        assert!(
+            quiz.questions
+                .iter()
+                .any(|question| !matches!(question.kind, Kind::Choice { .. })),
+            "{} only has multiple choice questions",
+            path.display()
+        );
+        for question in &quiz.questions {
+            if let Err(err) = question.answer(quiz::TIMEOUT) {
+                panic!("{}: {err}", path.display());
+            }
+        }
+    }
+}
+
+#[test]
+fn code_answers_come_from_running_the_program() {
+    let quiz = Quiz::parse(SAMPLE).unwrap();
+    assert_eq!(quiz.questions.len(), 3);
+    assert_eq!(quiz.questions[0].line, 3);
+    assert_eq!(
+        quiz.questions[0].explanation.as_deref(),
+        Some("The fraction is dropped.")
+    );
+
+    let output = quiz.questions[0].answer(quiz::TIMEOUT).unwrap();
+    assert_eq!(output, Answer::Output("integer = 54\n".to_string()));
+    assert_eq!(output.accepts("integer = 54  \n\n"), Some(true));
+    assert_eq!(output.accepts("integer = 54.321"), Some(false));
+
+    let compiles = quiz.questions[1].answer(quiz::TIMEOUT).unwrap();
+    match &compiles {
+        Answer::Compiles(Err(error)) => assert!(error.starts_with("error[E0384]"), "{error}"),
+        other => panic!("{other:?}"),
+    }
+    assert_eq!(compiles.accepts("No"), Some(true));
+    assert_eq!(compiles.accepts("y"), Some(false));
+    assert_eq!(compiles.accepts("maybe"), None);
+
+    let choice = quiz.questions[2].answer(quiz::TIMEOUT).unwrap();
+    assert_eq!(choice.accepts("4, 2"), Some(true));
+    assert_eq!(choice.accepts("2"), Some(false));
+    assert_eq!(choice.accepts("5"), None);
+    assert_eq!(choice.accepts("two"), None);
+}
+
+#[test]
+fn mistakes_in_question_files_are_reported_by_line() {
+    let error = |text: &str| Quiz::parse(text).unwrap_err().to_string();
+    assert_eq!(
+        error("## riddle\nWhat?\n"),
+        "line 1: unknown kind of question 'riddle' (expected choice, output or compiles)"
+    );
+    assert_eq!(
+        error("## output\nWhat?\n\n```rust\nfn main() {}\n"),
+        "line 4: unclosed code block"
+    );
+    assert_eq!(
+        error("## choice\nWhich?\n\n- [ ] a\n- [ ] b\n"),
+        "line 1: no option is marked [x]"
+    );
+    assert_eq!(
+        error("## compiles\nDoes it?\n"),
+        "line 1: the question has no program"
+    );
+}
+
+#[test]
+fn taking_a_quiz() {
+    let quiz = Quiz::parse(SAMPLE).unwrap();
+    let mut input = Cursor::new("integer = 54\n\nperhaps\nn\n2\n");
+    let mut out = Vec::new();
+    let score = quiz::take(&quiz, &mut input, &mut out, quiz::TIMEOUT).unwrap();
+    assert_eq!(score.answered, 3);
+    assert_eq!(score.correct, 2);
+    let out = String::from_utf8(out).unwrap();
+    assert!(
+        out.contains("Question 1 of 3 (predict the output)"),
+        "{out}"
+    );
+    assert!(out.contains("Please answer with y or n."), "{out}");
+    assert!(
+        out.contains("Not quite, the answer is:\n  2. two\n  4. four\n"),
+        "{out}"
+    );
+
+    let mut input = Cursor::new("integer = 54\n\n");
+    let score = quiz::take(&quiz, &mut input, &mut Vec::new(), quiz::TIMEOUT).unwrap();
+    assert_eq!((score.correct, score.answered, score.total), (1, 1, 3));
+}
@@ -0,0 +1,101 @@
+//! The search index.
+
+use std::path::Path;
+
+use rustprog::compile::Scratch;
+use rustprog::search::{self, Hit, Index};
+use rustprog::Course;
+
+fn course() -> Course {
+    Course::discover(Path::new(env!("CARGO_MANIFEST_DIR")).join("..")).unwrap()
+}
+
+fn places(hits: &[Hit]) -> Vec<String> {
+    hits.iter()
+        .map(|hit| {
+            let function = hit.entry.function.as_deref().unwrap_or("");
+            format!("{} {function}", hit.lesson.path).trim().to_string()
+        })
+        .collect()
+}
+
+#[test]
+fn words_are_split_and_stemmed() {
+    assert_eq!(
+        search::words("let outer_var = x as u8;"),
+        ["let", "outer", "var", "outer_var", "x", "as", "u8"]
+    );
+    for word in ["shadowing", "shadows", "shadowed"] {
+        assert_eq!(search::stem(word), "shadow");
+    }
+    assert_eq!(search::stem("as"), "as");
+    assert_eq!(search::stem("class"), "class");
+    assert_eq!(search::stem("entries"), "entry");
+}
+
+#[test]
+fn shadowing_finds_the_scope_and_mutability_lessons() {
+    let index = Index::build(&course()).unwrap();
+    let hits = index.search("shadowing");
+    let places = places(&hits[..10]);
+    assert_eq!(places[0], "_04_Rust_Functions/_02_Rust_Variable_Scope.rs");
+    assert!(places.contains(&"_04_Rust_Functions/_02_Rust_Variable_Scope.rs test3()".to_string()));
+    assert!(
+        places.contains(&"_01_Rust_Intoduction/_05_Rust_variables_and_mutability.rs".to_string())
+    );
+
+    let test3 = hits
+        .iter()
+        .find(|hit| hit.entry.function.as_deref() == Some("test3()"))
+        .unwrap();
+    assert_eq!(
+        test3.entry.section.as_deref(),
+        Some("Variable Shadowing in Rust")
+    );
+}
+
+#[test]
+fn related_words_rank_below_the_word_itself() {
+    let index = Index::build(&course()).unwrap();
+    let hits = index.search("shadowing");
+    let rank = |line: &str| hits.iter().position(|hit| hit.entry.text == line);
+    let test3 = hits
+        .iter()
+        .position(|hit| hit.entry.function.as_deref() == Some("test3()"))
+        .unwrap();
+    for heading in [
+        "3. No Mutable Reference While Immutable Ones Are in Use",
+        "Rust Variables and Mutability",
+        "Mutability in Rust",
+    ] {
+        assert!(rank(heading).is_none_or(|rank| rank > test3), "{heading}");
+    }
+}
+
+#[test]
+fn as_finds_the_casting_lesson() {
+    let index = Index::build(&course()).unwrap();
+    let hits = index.search("as");
+    assert_eq!(
+        hits[0].lesson.path,
+        "_01_Rust_Intoduction/_07_Rust_type_casting.rs"
+    );
+    assert!(index.search("xyzzy").is_empty());
+}
+
+#[test]
+fn the_index_is_stored_and_goes_stale() {
+    let course = course();
+    let index = Index::build(&course).unwrap();
+    assert!(index.is_current(&course));
+
+    let scratch = Scratch::new("search-test").unwrap();
+    let path = scratch.path().join("search-index.json");
+    index.save(&path).unwrap();
+    assert_eq!(Index::load(&path).as_ref(), Some(&index));
+
+    let mut stale = index.clone();
+    stale.lessons[0].stamp = "0:0".to_string();
+    assert!(!stale.is_current(&course));
+    assert_eq!(Index::load(&scratch.path().join("missing.json")), None);
+}
@@ -0,0 +1,199 @@
+//! The local server serves the site and runs code sent from its pages.
+
+use std::io::{Read, Write};
+use std::net::{SocketAddr, TcpStream};
+use std::path::Path;
+use std::thread;
+use std::time::Duration;
+
+use rustprog::compile::{Limits, Scratch};
+use rustprog::json::{self, Value};
+use rustprog::serve::Server;
+use rustprog::{site, Course};
+
+const QUICK: Limits = Limits {
+    timeout: Duration::from_secs(1),
+    memory_mb: 256,
+    processes: 256,
+    output_bytes: 4096,
+};
+
+/// Builds the site into a scratch directory and serves it on a free port.
+fn start() -> (Scratch, SocketAddr) {
+    let course = Course::discover(Path::new(env!("CARGO_MANIFEST_DIR")).join("..")).unwrap();
+    let scratch = Scratch::new("serve-test").unwrap();
+    site::build(&course, scratch.path()).unwrap();
+    let server = Server::bind("127.0.0.1:0", scratch.path(), QUICK).unwrap();
+    let address = server.local_addr().unwrap();
+    thread::spawn(move || server.serve());
+    (scratch, address)
+}
+
+/// Sends a request as a page of the site would, and returns the status
+/// line and the body, de-chunked.
+fn request(address: SocketAddr, method: &str, path: &str, body: &str) -> (String, String) {
+    send(
+        address,
+        &format!(
+            "{method} {path} HTTP/1.1\r\nHost: {address}\r\nContent-Length: {}\r\n\r\n{body}",
+            body.len()
+        ),
+    )
+}
+
+/// Posts `source` to `/run` the way `run.js` does.
+fn run(address: SocketAddr, source: &str) -> (String, String) {
+    let body = format!("{{\"source\": {}}}", json::quote(source));
+    send(
+        address,
+        &format!(
+            "POST /run HTTP/1.1\r\nHost: {address}\r\nOrigin: http://{address}\r\n\
+             Content-Type: application/json\r\nContent-Length: {}\r\n\r\n{body}",
+            body.len()
+        ),
+    )
+}
+
+/// Sends `request` as it is and reads the answer.
+fn send(address: SocketAddr, request: &str) -> (String, String) {
+    let mut stream = TcpStream::connect(address).unwrap();
+    stream.write_all(request.as_bytes()).unwrap();
+    let mut response = String::new();
+    stream.read_to_string(&mut response).unwrap();
+    let (head, mut rest) = response.split_once("\r\n\r\n").unwrap();
+    let status = head.lines().next().unwrap().to_string();
+    if !head.contains("Transfer-Encoding: chunked") {
+        return (status, rest.to_string());
+    }
+    let mut body = String::new();
+    loop {
+        let (size, after) = rest.split_once("\r\n").unwrap();
+        let size = usize::from_str_radix(size, 16).unwrap();
+        if size == 0 {
+            return (status, body);
+        }
+        body.push_str(&after[..size]);
+        rest = &after[size + 2..];
+    }
+}
+
+/// The events of a `/run` response, as (kind, text) pairs.
+fn parse_events(body: &str) -> Vec<(String, String)> {
+    body.lines()
+        .map(|line| match Value::parse(line).unwrap() {
+            Value::Object(mut fields) => {
+                let (kind, text) = fields.remove(0);
+                (kind, text.as_str().unwrap().to_string())
+            }
+            other => panic!("not an event: {other}"),
+        })
+        .collect()
+}
+
+fn output(events: &[(String, String)], kind: &str) -> String {
+    events
+        .iter()
+        .filter(|(k, _)| k == kind)
+        .map(|(_, text)| text.as_str())
+        .collect()
+}
+
+#[test]
+fn the_site_is_served() {
+    let (_site, address) = start();
+    let (status, body) = request(address, "GET", "/", "");
+    assert_eq!(status, "HTTP/1.1 200 OK");
+    assert!(body.contains("<html"));
+    let (status, _) = request(address, "GET", "/run.js", "");
+    assert_eq!(status, "HTTP/1.1 200 OK");
+
+    for path in ["/../Cargo.toml", "/%2e%2e/Cargo.toml", "/nope.html"] {
+        let (status, _) = request(address, "GET", path, "");
+        assert_eq!(status, "HTTP/1.1 404 Not Found", "{path}");
+    }
+    let (status, _) = request(address, "DELETE", "/", "");
+    assert_eq!(status, "HTTP/1.1 405 Method Not Allowed");
+}
+
+#[test]
+fn snippets_are_run() {
+    let (_site, address) = start();
+    // A bare snippet, as it appears in the prose, is wrapped in a main.
+    let (status, body) = run(
+        address,
+        "let x = 40 + 2;\nprintln!(\"x = {x}\");\neprintln!(\"done\");",
+    );
+    assert_eq!(status, "HTTP/1.1 200 OK");
+    let events = parse_events(&body);
+    assert_eq!(output(&events, "stdout"), "x = 42\n");
+    assert_eq!(output(&events, "stderr"), "done\n");
+    let (kind, exit) = events.last().unwrap();
+    assert_eq!(kind, "exit");
+    assert!(exit.starts_with("finished in "), "{exit}");
+}
+
+#[test]
+fn bad_programs_are_reported() {
+    let (_site, address) = start();
+    let (_, body) = run(address, "fn main() {\n    let x = ;\n}\n");
+    let events = parse_events(&body);
+    assert!(output(&events, "stderr").contains("error"));
+    assert_eq!(events.last().unwrap().1, "does not compile");
+
+    let (_, body) = run(
+        address,
+        "fn main() {\n    loop {\n        println!(\"Loop forever!\");\n    }\n}\n",
+    );
+    let events = parse_events(&body);
+    assert!(output(&events, "stdout").len() <= QUICK.output_bytes);
+    assert!(output(&events, "stderr").contains("cut off"));
+    assert!(events.last().unwrap().1.contains("still running"));
+}
+
+#[test]
+fn other_sites_cannot_run_code() {
+    let (_site, address) = start();
+    let body = r#"{"source": "fn main() {}"}"#;
+    let post = |headers: &str| {
+        send(
+            address,
+            &format!(
+                "POST /run HTTP/1.1\r\n{headers}Content-Length: {}\r\n\r\n{body}",
+                body.len()
+            ),
+        )
+        .0
+    };
+    let json = "Content-Type: application/json\r\n";
+
+    // Another page, or a name of the attacker's that resolves to us.
+    let evil = post(&format!(
+        "Host: {address}\r\nOrigin: https://evil.example\r\n{json}"
+    ));
+    assert_eq!(evil, "HTTP/1.1 403 Forbidden");
+    let port = address.port();
+    let rebound = post(&format!("Host: evil.example:{port}\r\n{json}"));
+    assert_eq!(rebound, "HTTP/1.1 403 Forbidden");
+    assert_eq!(post(json), "HTTP/1.1 403 Forbidden");
+
+    // A form or a plain fetch() sends text, which needs no preflight.
+    let text = post(&format!(
+        "Host: localhost:{port}\r\nContent-Type: text/plain\r\n"
+    ));
+    assert_eq!(text, "HTTP/1.1 415 Unsupported Media Type");
+    let ours = post(&format!(
+        "Host: localhost:{port}\r\nOrigin: http://localhost:{port}\r\n{json}"
+    ));
+    assert_eq!(ours, "HTTP/1.1 200 OK");
+}
+
+#[test]
+fn long_heads_are_refused() {
+    let (_site, address) = start();
+    let long = "a".repeat(20 * 1024);
+    let (status, _) = send(
+        address,
+        &format!("GET / HTTP/1.1\r\nHost: {address}\r\nX-Long: {long}\r\n\r\n"),
+    );
+    assert_eq!(status, "HTTP/1.1 431 Request Header Fields Too Large");
+}
@@ -0,0 +1,156 @@
+//! The lessons render as a static HTML site.
+
+use std::fs;
+use std::path::Path;
+
+use rustprog::compile::Scratch;
+use rustprog::document::{Block, Document};
+use rustprog::html::highlight_rust;
+use rustprog::{site, Course};
+
+fn course() -> Course {
+    Course::discover(Path::new(env!("CARGO_MANIFEST_DIR")).join("..")).unwrap()
+}
+
+#[test]
+fn operator_tables_become_tables() {
+    let course = course();
+    let document = Document::read(&course.find("operators").unwrap().path).unwrap();
+    assert_eq!(document.title.as_deref(), Some("Rust Operators"));
+
+    let tables: Vec<(&Vec<String>, &Vec<Vec<String>>)> = document
+        .blocks
+        .iter()
+        .filter_map(|block| match block {
+            Block::Table { header, rows } => Some((header, rows)),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(tables.len(), 4);
+    let (header, rows) = tables[1];
+    assert_eq!(header, &["Operator", "Example", "Equivalent"]);
+    assert_eq!(
+        rows[0],
+        ["+= (Addition  assignment)", "a += b", "a = a + b"]
+    );
+    assert_eq!(
+        tables[3].1.last().unwrap(),
+        &["! (Logical NOT)", "!expression"]
+    );
+}
+
+#[test]
+fn headings_and_pasted_errors_are_recognised() {
+    let course = course();
+    let document = Document::read(&course.find("variable_scope").unwrap().path).unwrap();
+    let headings: Vec<(u8, &str)> = document.headings().collect();
+    assert_eq!(headings[0], (1, "Working of Variable Scope in Rust"));
+    assert!(document.blocks.iter().any(|block| matches!(
+        block,
+        Block::Preformatted(text) if text.starts_with("error[E0425]")
+    )));
+}
+
+#[test]
+fn rust_code_is_highlighted() {
+    assert_eq!(
+        highlight_rust("let x = 5; // five"),
+        "<span class=\"kw\">let</span> x = <span class=\"num\">5</span>; \
+         <span class=\"com\">// five</span>"
+    );
+    assert_eq!(
+        highlight_rust("println!(\"<{}>\", 'a');"),
+        "<span class=\"mac\">println!</span>(<span class=\"str\">&quot;&lt;{}&gt;&quot;</span>, \
+         <span class=\"str\">&#39;a&#39;</span>);"
+    );
+}
+
+#[test]
+fn the_site_links_every_lesson() {
+    let course = course();
+    let scratch = Scratch::new("site-test").unwrap();
+    let pages = site::build(&course, scratch.path()).unwrap();
+    assert_eq!(pages, 1 + course.chapters.len() + course.lessons().count());
+
+    let contents = fs::read_to_string(scratch.path().join("index.html")).unwrap();
+    for lesson in course.lessons() {
+        let chapter = course.chapter_of(lesson);
+        let href = format!("{}/{}.html", chapter.dir_name, lesson.stem);
+        assert!(contents.contains(&href), "{href} missing from the contents");
+        assert!(scratch.path().join(&href).is_file());
+    }
+
+    let first = fs::read_to_string(
+        scratch
+            .path()
+            .join("_01_Rust_Intoduction/_01_getting_started_with_Rust.html"),
+    )
+    .unwrap();
+    assert!(!first.contains("rel=\"prev\""));
+    assert!(
+        first.contains("rel=\"next\" href=\"../_01_Rust_Intoduction/_02_Rust_hello_world.html\"")
+    );
+
+    let operators = fs::read_to_string(
+        scratch
+            .path()
+            .join("_01_Rust_Intoduction/_08_Rust_operators.html"),
+    )
+    .unwrap();
+    assert!(operators.contains("<th>Equivalent</th>"));
+    assert!(operators.contains("<a href=\"#logical-operators\">Logical Operators</a>"));
+    assert!(operators.contains("<h2 id=\"logical-operators\">Logical Operators</h2>"));
+    assert!(
+        operators.contains("rel=\"next\" href=\"../_02_Rust_Control_Flow/_01_Rust_if_else.html\"")
+    );
+}
+
+#[test]
+fn rust_code_blocks_get_a_run_button() {
+    let course = course();
+    let scratch = Scratch::new("site-test").unwrap();
+    site::build(&course, scratch.path()).unwrap();
+    assert!(scratch.path().join("run.js").is_file());
+
+    let tuple = fs::read_to_string(
+        scratch
+            .path()
+            .join("_03_Rust_Data_Types/_03_Rust_tuple.html"),
+    )
+    .unwrap();
+    assert!(tuple.contains("<pre id=\"lesson-program\" hidden>"));
+    assert!(tuple.contains("<script src=\"../run.js\" defer></script>"));
+    let blocks = tuple.matches("<code class=\"language-rust\">").count();
+    assert!(blocks > 0);
+    assert_eq!(
+        tuple
+            .matches("<button type=\"button\" class=\"run\"")
+            .count(),
+        blocks
+    );
+}
+
+#[test]
+fn the_lesson_main_is_left_out_of_the_code_blocks() {
+    let course = course();
+    for lesson in course.lessons() {
+        let document = Document::read(&lesson.path).unwrap();
+        for block in &document.blocks {
+            if let Block::Code(code) = block {
+                assert!(
+                    code.code != "fn main() {" && code.code != "}",
+                    "{}: a code block holds only `{}`",
+                    lesson.stem,
+                    code.code
+                );
+            }
+        }
+    }
+
+    let document = Document::read(&course.find("struct").unwrap().path).unwrap();
+    let first = document.blocks.iter().find_map(|block| match block {
+        Block::Code(code) if code.live => Some(code.code.as_str()),
+        _ => None,
+    });
+    assert!(first.unwrap().starts_with("let _person_name: String"));
+}
@@ -0,0 +1,102 @@
+//! Code quoted in the prose of the lessons is compiled, and run or expected
+//! to fail, so that the examples cannot rot silently.
+
+use std::fs;
+use std::path::Path;
+
+use rustprog::compile::Scratch;
+use rustprog::lesson::{LessonSource, Mode};
+use rustprog::verify;
+use rustprog::Course;
+
+#[test]
+fn prose_snippets_compile() {
+    let course = Course::discover(Path::new(env!("CARGO_MANIFEST_DIR")).join("..")).unwrap();
+    let mut failures = String::new();
+    for lesson in course.lessons() {
+        let report = verify::verify_snippets(&lesson.path).unwrap();
+        for mismatch in &report.mismatches {
+            failures += &format!("{}:{mismatch}\n", lesson.relative_path(&course).display());
+        }
+    }
+    assert!(failures.is_empty(), "snippets drifted:\n\n{failures}");
+}
+
+const LESSON: &str = r#"fn main() {}
+
+/*
+A function:
+
+fn greet() {
+    println!("Hello");
+}
+
+A template:
+
+```rust,ignore
+let name = value;
+```
+
+Not Rust at all:
+
+```c
+for (i = 0; i < 10; i++) {
+    printf("%d\n", i);
+}
+```
+
+A mistake:
+
+```compile_fail,E0308
+let number: u8 = "one";
+```
+
+A program:
+
+fn main() {
+    println!("Hello");
+}
+*/
+"#;
+
+#[test]
+fn snippets_are_marked_by_fences_or_surroundings() {
+    let source = LessonSource::parse(LESSON);
+    let snippets = source.snippets();
+    let diagnostics = source.diagnostics();
+    let modes: Vec<(usize, Mode)> = snippets
+        .iter()
+        .map(|snippet| {
+            let mode = source.snippet_mode(snippet, &snippets, &diagnostics);
+            (snippet.start, mode)
+        })
+        .collect();
+    assert_eq!(
+        modes,
+        [
+            (6, Mode::CompileOnly),
+            (13, Mode::Ignore),
+            (27, Mode::CompileFail(Some("E0308".to_string()))),
+            (32, Mode::Run),
+        ]
+    );
+}
+
+#[test]
+fn a_compile_fail_snippet_that_compiles_is_reported() {
+    let scratch = Scratch::new("snippets-test").unwrap();
+    let path = scratch.path().join("lesson.rs");
+    fs::write(
+        &path,
+        LESSON.replace(r#"let number: u8 = "one";"#, "let number: u8 = 1;"),
+    )
+    .unwrap();
+    let report = verify::verify_snippets(&path).unwrap();
+    assert_eq!((report.checked, report.skipped), (3, 1));
+    assert_eq!(report.mismatches.len(), 1);
+    assert_eq!(report.mismatches[0].line, 27);
+    assert_eq!(
+        report.mismatches[0].message,
+        "snippet compiles, expected error E0308"
+    );
+}
@@ -0,0 +1,13 @@
+// Reference solutions for the exercises of lesson 1.4, Rust Print Output.
+
+/// Returns `Name = <name>, Age = <age>`, built with the `format!` macro,
+/// which takes the same placeholders as `println!`.
+pub fn introduce(name: &str, age: u32) -> String {
+    format!("Name = {name}, Age = {age}")
+}
+
+/// Returns the two lines `Rust is fun!` and `I love Rust programming.`
+/// as a single string, separated by the `\n` escape sequence.
+pub fn two_lines() -> String {
+    String::from("Rust is fun!\nI love Rust programming.")
+}
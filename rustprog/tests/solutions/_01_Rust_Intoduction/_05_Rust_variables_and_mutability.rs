@@ -0,0 +1,20 @@
+// Reference solutions for the exercises of lesson 1.5, Rust Variables and Mutability.
+
+/// Returns the area of a circle of the given radius, `PI * radius *
+/// radius`. Declare `PI` as a constant with the value `3.14159`.
+pub fn circle_area(radius: f32) -> f32 {
+    const PI: f32 = 3.14159;
+    PI * radius * radius
+}
+
+/// Returns `start` after adding `step` to it `times` times. Keep the
+/// running value in a mutable variable.
+pub fn add_repeatedly(start: i32, step: i32, times: u32) -> i32 {
+    let mut value = start;
+    let mut count = 0;
+    while count < times {
+        value += step;
+        count += 1;
+    }
+    value
+}
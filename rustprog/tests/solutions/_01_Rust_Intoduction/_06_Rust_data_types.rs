@@ -0,0 +1,13 @@
+// Reference solutions for the exercises of lesson 1.6, Rust Data Types.
+
+/// Returns whether `letter` is one of the lowercase vowels `a`, `e`,
+/// `i`, `o` and `u`.
+pub fn is_vowel(letter: char) -> bool {
+    letter == 'a' || letter == 'e' || letter == 'i' || letter == 'o' || letter == 'u'
+}
+
+/// Returns the largest value an `i8` can hold, plus one, as an `i16`:
+/// the number of non-negative values of an `i8`.
+pub fn i8_non_negative_values() -> i16 {
+    i8::MAX as i16 + 1
+}
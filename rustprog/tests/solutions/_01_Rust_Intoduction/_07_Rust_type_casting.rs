@@ -0,0 +1,14 @@
+// Reference solutions for the exercises of lesson 1.7, Rust Type Casting.
+
+/// Returns the uppercase letter at `index` in the alphabet, counting
+/// from 0: `letter_at(0)` is `'A'`, `letter_at(25)` is `'Z'`. Convert with
+/// `as`, starting from the `u8` code of `'A'`.
+pub fn letter_at(index: u8) -> char {
+    (b'A' + index) as char
+}
+
+/// Returns the whole part of `value`, dropping the decimals, as an
+/// `i32`.
+pub fn whole_part(value: f64) -> i32 {
+    value as i32
+}
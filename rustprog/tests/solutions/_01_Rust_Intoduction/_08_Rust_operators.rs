@@ -0,0 +1,13 @@
+// Reference solutions for the exercises of lesson 1.8, Rust Operators.
+
+/// Returns whether `number` is even, with the remainder operator `%`.
+pub fn is_even(number: i32) -> bool {
+    number % 2 == 0
+}
+
+/// Returns whether `year` is a leap year: divisible by 4 but not by 100,
+/// unless it is also divisible by 400. Combine the conditions with the
+/// logical operators.
+pub fn is_leap_year(year: u32) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
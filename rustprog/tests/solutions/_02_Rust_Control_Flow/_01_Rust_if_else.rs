@@ -0,0 +1,24 @@
+// Reference solutions for the exercises of lesson 2.1, Rust If else.
+
+/// Returns `"positive"`, `"negative"` or `"zero"` depending on `number`,
+/// with an `if..else if..else` expression.
+pub fn sign(number: i32) -> &'static str {
+    if number > 0 {
+        "positive"
+    } else if number < 0 {
+        "negative"
+    } else {
+        "zero"
+    }
+}
+
+/// Returns the largest of the three numbers.
+pub fn largest_of_three(a: i32, b: i32, c: i32) -> i32 {
+    if a >= b && a >= c {
+        a
+    } else if b >= c {
+        b
+    } else {
+        c
+    }
+}
@@ -0,0 +1,14 @@
+// Reference solutions for the exercises of lesson 2.2, Rust loop.
+
+/// Returns the first power of `base` (1, `base`, `base * base`, ...)
+/// that is greater than `limit`. Use `loop` and leave it with `break`;
+/// `base` is at least 2.
+pub fn first_power_above(base: u32, limit: u32) -> u32 {
+    let mut power = 1;
+    loop {
+        if power > limit {
+            break power;
+        }
+        power *= base;
+    }
+}
@@ -0,0 +1,24 @@
+// Reference solutions for the exercises of lesson 2.3, Rust while loop.
+
+/// Returns the multiplication table of `number`: `number * 1` up to
+/// `number * 10`, built with a `while` loop.
+pub fn multiplication_table(number: u32) -> Vec<u32> {
+    let mut table = Vec::new();
+    let mut factor = 1;
+    while factor <= 10 {
+        table.push(number * factor);
+        factor += 1;
+    }
+    table
+}
+
+/// Returns how many decimal digits `number` has; 0 has one digit.
+pub fn count_digits(number: u32) -> u32 {
+    let mut number = number / 10;
+    let mut digits = 1;
+    while number > 0 {
+        number /= 10;
+        digits += 1;
+    }
+    digits
+}
@@ -0,0 +1,20 @@
+// Reference solutions for the exercises of lesson 2.4, Rust for Loop.
+
+/// Returns the sum of the numbers from `lo` to `hi`, both included, or 0
+/// when `lo` is greater than `hi`. Use a `for` loop over a range.
+pub fn sum_range(lo: u32, hi: u32) -> u32 {
+    let mut sum = 0;
+    for i in lo..=hi {
+        sum += i;
+    }
+    sum
+}
+
+/// Returns `n!`, the product of the numbers from 1 to `n`; `0!` is 1.
+pub fn factorial(n: u64) -> u64 {
+    let mut product = 1;
+    for i in 1..=n {
+        product *= i;
+    }
+    product
+}
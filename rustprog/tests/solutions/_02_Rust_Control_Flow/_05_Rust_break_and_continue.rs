@@ -0,0 +1,27 @@
+// Reference solutions for the exercises of lesson 2.5, Rust break and continue.
+
+/// Returns the sum of the numbers from 1 to `limit` that are not
+/// multiples of 3, skipping those with `continue`.
+pub fn sum_without_threes(limit: u32) -> u32 {
+    let mut sum = 0;
+    for i in 1..=limit {
+        if i % 3 == 0 {
+            continue;
+        }
+        sum += i;
+    }
+    sum
+}
+
+/// Returns the first number from `start` upwards that is divisible by
+/// both 7 and 11, stopping the loop with `break`.
+pub fn first_multiple_of_77(start: u32) -> u32 {
+    let mut number = start;
+    loop {
+        if number % 7 == 0 && number % 11 == 0 {
+            break;
+        }
+        number += 1;
+    }
+    number
+}
@@ -0,0 +1,21 @@
+// Reference solutions for the exercises of lesson 3.1, Rust Array.
+
+/// Returns the largest number in the array.
+pub fn largest(numbers: [i32; 5]) -> i32 {
+    let mut largest = numbers[0];
+    for number in numbers {
+        if number > largest {
+            largest = number;
+        }
+    }
+    largest
+}
+
+/// Returns the array with its elements in reverse order.
+pub fn reversed(numbers: [i32; 5]) -> [i32; 5] {
+    let mut reversed = [0; 5];
+    for index in 0..5 {
+        reversed[index] = numbers[4 - index];
+    }
+    reversed
+}
@@ -0,0 +1,20 @@
+// Reference solutions for the exercises of lesson 3.2, Rust slice.
+
+/// Returns the sum of the numbers in the slice.
+pub fn sum(numbers: &[i32]) -> i32 {
+    let mut sum = 0;
+    for number in numbers {
+        sum += number;
+    }
+    sum
+}
+
+/// Returns the slice without its first and last elements; the empty
+/// slice when it has fewer than three.
+pub fn inner(numbers: &[i32]) -> &[i32] {
+    if numbers.len() < 3 {
+        &[]
+    } else {
+        &numbers[1..numbers.len() - 1]
+    }
+}
@@ -0,0 +1,23 @@
+// Reference solutions for the exercises of lesson 3.3, Rust tuple.
+
+/// Returns the smallest and the largest number of a non-empty slice, as
+/// a tuple `(min, max)`.
+pub fn min_max(numbers: &[i32]) -> (i32, i32) {
+    let mut min = numbers[0];
+    let mut max = numbers[0];
+    for &number in numbers {
+        if number < min {
+            min = number;
+        }
+        if number > max {
+            max = number;
+        }
+    }
+    (min, max)
+}
+
+/// Returns the pair with its two elements swapped.
+pub fn swap(pair: (i32, String)) -> (String, i32) {
+    let (number, text) = pair;
+    (text, number)
+}
@@ -0,0 +1,23 @@
+// Reference solutions for the exercises of lesson 3.4, Rust struct.
+
+/// A rectangle, measured in whole units.
+#[derive(Debug)]
+pub struct Rectangle {
+    pub width: u32,
+    pub height: u32,
+}
+
+/// Returns a new `Rectangle` with the given sides.
+pub fn new_rectangle(width: u32, height: u32) -> Rectangle {
+    Rectangle { width, height }
+}
+
+/// Returns the area of the rectangle.
+pub fn area(rectangle: &Rectangle) -> u32 {
+    rectangle.width * rectangle.height
+}
+
+/// Returns whether `inner` fits inside `outer` without turning it.
+pub fn can_hold(outer: &Rectangle, inner: &Rectangle) -> bool {
+    outer.width >= inner.width && outer.height >= inner.height
+}
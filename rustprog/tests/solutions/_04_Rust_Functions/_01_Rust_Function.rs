@@ -0,0 +1,23 @@
+// Reference solutions for the exercises of lesson 4.1, Rust function.
+
+/// Returns the temperature in degrees Fahrenheit, `celsius * 9 / 5 +
+/// 32`.
+pub fn celsius_to_fahrenheit(celsius: f64) -> f64 {
+    celsius * 9.0 / 5.0 + 32.0
+}
+
+/// Returns whether `number` is prime: greater than 1 and divisible only
+/// by 1 and itself.
+pub fn is_prime(number: u32) -> bool {
+    if number < 2 {
+        return false;
+    }
+    let mut divisor = 2;
+    while divisor * divisor <= number {
+        if number % divisor == 0 {
+            return false;
+        }
+        divisor += 1;
+    }
+    true
+}
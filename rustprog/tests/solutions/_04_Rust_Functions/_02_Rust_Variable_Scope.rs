@@ -0,0 +1,9 @@
+// Reference solutions for the exercises of lesson 4.2, Rust Variable scope.
+
+/// Returns twice the number written in `input`, e.g. 42 for `"21"`.
+/// Shadow `input` with the parsed `i32` rather than naming a new variable;
+/// `input` always holds a valid number.
+pub fn double_of(input: &str) -> i32 {
+    let input: i32 = input.trim().parse().unwrap();
+    input * 2
+}
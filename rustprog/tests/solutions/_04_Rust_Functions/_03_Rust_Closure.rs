@@ -0,0 +1,17 @@
+// Reference solutions for the exercises of lesson 4.3, Rust closure.
+
+/// Returns `f(f(x))`: the closure `f` applied twice.
+pub fn apply_twice(f: impl Fn(i32) -> i32, x: i32) -> i32 {
+    f(f(x))
+}
+
+/// Returns how many of the numbers `condition` holds for.
+pub fn count_matching(numbers: &[i32], condition: impl Fn(i32) -> bool) -> usize {
+    let mut count = 0;
+    for &number in numbers {
+        if condition(number) {
+            count += 1;
+        }
+    }
+    count
+}
@@ -0,0 +1,14 @@
+// Reference solutions for the exercises of lesson 5.1, Rust Ownership.
+
+/// Takes the ownership of `text` and gives it back with `suffix` added
+/// to its end.
+pub fn append(mut text: String, suffix: &str) -> String {
+    text.push_str(suffix);
+    text
+}
+
+/// Takes the ownership of both Strings and returns them in the opposite
+/// order.
+pub fn swap_owners(first: String, second: String) -> (String, String) {
+    (second, first)
+}
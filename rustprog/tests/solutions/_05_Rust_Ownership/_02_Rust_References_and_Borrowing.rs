@@ -0,0 +1,23 @@
+// Reference solutions for the exercises of lesson 5.2, Rust References and Borrowing.
+
+/// Returns how many times `letter` appears in the borrowed `text`.
+pub fn count_letter(text: &String, letter: char) -> usize {
+    let mut count = 0;
+    for c in text.chars() {
+        if c == letter {
+            count += 1;
+        }
+    }
+    count
+}
+
+/// Doubles the number behind the mutable reference.
+pub fn double(number: &mut i32) {
+    *number *= 2;
+}
+
+/// Adds `", "` and then `name` to the end of the borrowed `greeting`.
+pub fn greet(greeting: &mut String, name: &str) {
+    greeting.push_str(", ");
+    greeting.push_str(name);
+}
@@ -0,0 +1,23 @@
+// Reference solutions for the exercises of lesson 5.3, Rust Clone and Copy.
+
+/// Returns the original `word` together with a clone of it in upper
+/// case, so that the caller keeps both.
+pub fn with_shout(word: String) -> (String, String) {
+    let shout = word.to_uppercase();
+    (word, shout)
+}
+
+/// A point on a grid. It is a Copy type, so passing it to a function
+/// copies it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Point {
+    pub x: i32,
+    pub y: i32,
+}
+
+/// Returns `point` moved `dx` to the right and `dy` up.
+pub fn shift(mut point: Point, dx: i32, dy: i32) -> Point {
+    point.x += dx;
+    point.y += dy;
+    point
+}
@@ -0,0 +1,29 @@
+// Reference solutions for the exercises of lesson 6.1, Rust Enum.
+
+/// One of the four points of a compass.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+/// Returns the direction we face after turning right from `direction`:
+/// North turns into East, East into South and so on.
+pub fn turn_right(direction: Direction) -> Direction {
+    if direction == Direction::North {
+        Direction::East
+    } else if direction == Direction::East {
+        Direction::South
+    } else if direction == Direction::South {
+        Direction::West
+    } else {
+        Direction::North
+    }
+}
+
+/// Returns whether `direction` is North or South.
+pub fn is_vertical(direction: Direction) -> bool {
+    direction == Direction::North || direction == Direction::South
+}
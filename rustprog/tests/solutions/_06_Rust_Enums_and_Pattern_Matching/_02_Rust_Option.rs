@@ -0,0 +1,21 @@
+// Reference solutions for the exercises of lesson 6.2, Rust Option.
+
+/// Returns the first negative number of `numbers`, or `None` if there is
+/// none.
+pub fn first_negative(numbers: &[i32]) -> Option<i32> {
+    for &number in numbers {
+        if number < 0 {
+            return Some(number);
+        }
+    }
+    None
+}
+
+/// Returns `dividend / divisor`, or `None` if `divisor` is zero.
+pub fn divide(dividend: i32, divisor: i32) -> Option<i32> {
+    if divisor == 0 {
+        None
+    } else {
+        Some(dividend / divisor)
+    }
+}
@@ -0,0 +1,43 @@
+// Reference solutions for the exercises of lesson 6.3, Rust match.
+
+/// A shape, measured in whole units.
+#[derive(Debug)]
+pub enum Shape {
+    Square(f64),
+    Rectangle { width: f64, height: f64 },
+    Triangle { base: f64, height: f64 },
+}
+
+/// Returns the area of the shape.
+pub fn area(shape: &Shape) -> f64 {
+    match shape {
+        Shape::Square(side) => side * side,
+        Shape::Rectangle { width, height } => width * height,
+        Shape::Triangle { base, height } => base * height / 2.0,
+    }
+}
+
+/// Returns the letter grade of a score out of 100: 'A' for 90 and
+/// above, 'B' for 80 to 89, 'C' for 70 to 79, 'D' for 60 to 69 and 'F'
+/// below 60.
+pub fn grade(score: u32) -> char {
+    match score {
+        90.. => 'A',
+        80..=89 => 'B',
+        70..=79 => 'C',
+        60..=69 => 'D',
+        _ => 'F',
+    }
+}
+
+/// Returns where the point `(x, y)` lies: "origin", "x axis", "y axis"
+/// or "elsewhere".
+pub fn locate(point: (i32, i32)) -> String {
+    let place = match point {
+        (0, 0) => "origin",
+        (_, 0) => "x axis",
+        (0, _) => "y axis",
+        _ => "elsewhere",
+    };
+    place.to_string()
+}
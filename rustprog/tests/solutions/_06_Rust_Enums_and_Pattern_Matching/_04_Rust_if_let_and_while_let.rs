@@ -0,0 +1,21 @@
+// Reference solutions for the exercises of lesson 6.4, Rust if let and while let.
+
+/// Returns the sum of the values up to the first `None`, leaving out the
+/// values after it.
+pub fn sum_until_none(values: &[Option<i32>]) -> i32 {
+    let mut sum = 0;
+    let mut index = 0;
+    while let Some(Some(value)) = values.get(index) {
+        sum += value;
+        index += 1;
+    }
+    sum
+}
+
+/// Returns "<age> years old", or "unknown age" if `age` is `None`.
+pub fn describe_age(age: Option<u32>) -> String {
+    let Some(years) = age else {
+        return String::from("unknown age");
+    };
+    format!("{years} years old")
+}
@@ -0,0 +1,25 @@
+// Reference solutions for the exercises of lesson 7.1, Rust Vector.
+
+/// Returns the even numbers of `numbers`, in their original order.
+pub fn evens(numbers: &[i32]) -> Vec<i32> {
+    let mut evens = Vec::new();
+    for &number in numbers {
+        if number % 2 == 0 {
+            evens.push(number);
+        }
+    }
+    evens
+}
+
+/// Returns the running totals of `numbers`: each element is the sum of
+/// the numbers up to and including that position, so `[1, 2, 3]` gives
+/// `[1, 3, 6]`.
+pub fn running_total(numbers: &[i32]) -> Vec<i32> {
+    let mut totals = Vec::with_capacity(numbers.len());
+    let mut total = 0;
+    for &number in numbers {
+        total += number;
+        totals.push(total);
+    }
+    totals
+}
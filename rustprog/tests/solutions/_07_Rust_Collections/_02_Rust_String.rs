@@ -0,0 +1,26 @@
+// Reference solutions for the exercises of lesson 7.2, Rust String.
+
+/// Returns the first letter of every word of `name`, in upper case, so
+/// "John ronald Tolkien" gives "JRT".
+pub fn initials(name: &str) -> String {
+    let mut initials = String::new();
+    for word in name.split_whitespace() {
+        if let Some(first) = word.chars().next() {
+            initials.push_str(&first.to_uppercase().to_string());
+        }
+    }
+    initials
+}
+
+/// Returns the words of `text` in the opposite order, separated by a
+/// single space, so "one  two three" gives "three two one".
+pub fn reverse_words(text: &str) -> String {
+    let mut reversed = String::new();
+    for word in text.split_whitespace().rev() {
+        if !reversed.is_empty() {
+            reversed.push(' ');
+        }
+        reversed.push_str(word);
+    }
+    reversed
+}
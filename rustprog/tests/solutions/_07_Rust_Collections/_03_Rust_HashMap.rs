@@ -0,0 +1,25 @@
+// Reference solutions for the exercises of lesson 7.3, Rust HashMap.
+
+use std::collections::HashMap;
+
+/// Returns how many times each character other than a space appears in
+/// `text`.
+pub fn char_counts(text: &str) -> HashMap<char, usize> {
+    let mut counts = HashMap::new();
+    for c in text.chars() {
+        if c != ' ' {
+            *counts.entry(c).or_insert(0) += 1;
+        }
+    }
+    counts
+}
+
+/// Returns the total price of every category of the `(category, price)`
+/// items.
+pub fn totals_by_category(items: &[(&str, u32)]) -> HashMap<String, u32> {
+    let mut totals = HashMap::new();
+    for &(category, price) in items {
+        *totals.entry(category.to_string()).or_insert(0) += price;
+    }
+    totals
+}
@@ -0,0 +1,33 @@
+// Reference solutions for the exercises of lesson 7.4, Rust HashSet.
+
+use std::collections::HashSet;
+
+/// Returns whether any value appears more than once in `values`.
+pub fn has_duplicates(values: &[i32]) -> bool {
+    let mut seen = HashSet::new();
+    for &value in values {
+        if !seen.insert(value) {
+            return true;
+        }
+    }
+    false
+}
+
+/// Returns the characters that appear in both `first` and `second`,
+/// each one once, sorted.
+pub fn common_chars(first: &str, second: &str) -> Vec<char> {
+    let first: HashSet<char> = first.chars().collect();
+    let second: HashSet<char> = second.chars().collect();
+    let mut common: Vec<char> = first.intersection(&second).copied().collect();
+    common.sort();
+    common
+}
+
+/// Returns the set of words of `text`, in lower case.
+pub fn vocabulary(text: &str) -> HashSet<String> {
+    let mut words = HashSet::new();
+    for word in text.split_whitespace() {
+        words.insert(word.to_lowercase());
+    }
+    words
+}
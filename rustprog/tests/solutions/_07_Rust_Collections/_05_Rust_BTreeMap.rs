@@ -0,0 +1,23 @@
+// Reference solutions for the exercises of lesson 7.5, Rust BTreeMap.
+
+use std::collections::BTreeMap;
+
+/// Returns the words grouped by their length in characters, each group
+/// in the order the words appear in `words`.
+pub fn group_by_length(words: &[&str]) -> BTreeMap<usize, Vec<String>> {
+    let mut groups: BTreeMap<usize, Vec<String>> = BTreeMap::new();
+    for word in words {
+        groups
+            .entry(word.chars().count())
+            .or_default()
+            .push(word.to_string());
+    }
+    groups
+}
+
+/// Returns the last event of the schedule that starts before `time`, or
+/// `None` if there is none. The keys of the schedule are start times.
+pub fn last_before(schedule: &BTreeMap<u32, String>, time: u32) -> Option<String> {
+    let (_, event) = schedule.range(..time).next_back()?;
+    Some(event.clone())
+}
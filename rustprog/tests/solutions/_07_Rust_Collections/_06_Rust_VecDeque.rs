@@ -0,0 +1,29 @@
+// Reference solutions for the exercises of lesson 7.6, Rust VecDeque.
+
+use std::collections::VecDeque;
+
+/// Returns `values` after moving the front element to the back `steps`
+/// times, so `[1, 2, 3, 4]` rotated by 1 gives `[2, 3, 4, 1]`.
+pub fn rotate(values: &[i32], steps: usize) -> Vec<i32> {
+    let mut queue = VecDeque::from(values.to_vec());
+    for _ in 0..steps {
+        if let Some(front) = queue.pop_front() {
+            queue.push_back(front);
+        }
+    }
+    Vec::from(queue)
+}
+
+/// Returns the last `n` values of `values`, reading them one by one and
+/// only keeping `n` of them at a time. All the values are returned if
+/// there are fewer than `n`.
+pub fn last_n(values: &[i32], n: usize) -> Vec<i32> {
+    let mut window = VecDeque::with_capacity(n + 1);
+    for &value in values {
+        window.push_back(value);
+        if window.len() > n {
+            window.pop_front();
+        }
+    }
+    Vec::from(window)
+}
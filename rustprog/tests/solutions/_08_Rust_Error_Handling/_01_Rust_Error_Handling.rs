@@ -0,0 +1,16 @@
+// Reference solutions for the exercises of lesson 8.1, Rust Error Handling.
+
+/// Returns `a` divided by `b`, or the error "cannot divide by zero" when
+/// `b` is 0.
+pub fn safe_divide(a: i32, b: i32) -> Result<i32, String> {
+    if b == 0 {
+        return Err(String::from("cannot divide by zero"));
+    }
+    Ok(a / b)
+}
+
+/// Returns the number written in `text`, ignoring spaces around it, or 0
+/// if the text is not a number.
+pub fn parse_or_zero(text: &str) -> i32 {
+    text.trim().parse::<i32>().unwrap_or(0)
+}
@@ -0,0 +1,20 @@
+// Reference solutions for the exercises of lesson 8.2, Rust Question Mark Operator.
+
+use std::num::ParseIntError;
+
+/// Returns the sum of the numbers in `text`, which are separated by
+/// spaces, or the error of the first word that is not a number. An
+/// empty text sums to 0.
+pub fn sum_words(text: &str) -> Result<i32, ParseIntError> {
+    let mut sum = 0;
+    for word in text.split_whitespace() {
+        sum += word.parse::<i32>()?;
+    }
+    Ok(sum)
+}
+
+/// Returns the value of the last character of `text` as a decimal digit,
+/// or None if the text is empty or ends with something else.
+pub fn last_digit(text: &str) -> Option<u32> {
+    text.chars().last()?.to_digit(10)
+}
@@ -0,0 +1,14 @@
+// Reference solutions for the exercises of lesson 8.3, Rust Safe Conversions.
+
+/// Returns `value` as a u8, or None if it is not between 0 and 255.
+pub fn to_byte(value: i64) -> Option<u8> {
+    u8::try_from(value).ok()
+}
+
+/// Returns the number of seconds in `hours` hours and `minutes` minutes,
+/// or None if the result does not fit a u32.
+pub fn total_seconds(hours: u32, minutes: u32) -> Option<u32> {
+    let from_hours = hours.checked_mul(3600)?;
+    let from_minutes = minutes.checked_mul(60)?;
+    from_hours.checked_add(from_minutes)
+}
@@ -0,0 +1,50 @@
+// Reference solutions for the exercises of lesson 8.4, Rust Custom Error Types.
+
+use std::fmt;
+use std::num::ParseIntError;
+
+/// What can be wrong with a temperature written in Celsius.
+#[derive(Debug, PartialEq)]
+pub enum TemperatureError {
+    Empty,
+    NotANumber(ParseIntError),
+    BelowAbsoluteZero(i32),
+}
+
+impl fmt::Display for TemperatureError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", message(self))
+    }
+}
+
+/// Returns the message for `error`: "no temperature was given", "not a
+/// number: " followed by the ParseIntError, or for example "-300 is
+/// below absolute zero". Printing an error with {} shows this message.
+pub fn message(error: &TemperatureError) -> String {
+    match error {
+        TemperatureError::Empty => String::from("no temperature was given"),
+        TemperatureError::NotANumber(error) => format!("not a number: {}", error),
+        TemperatureError::BelowAbsoluteZero(value) => format!("{} is below absolute zero", value),
+    }
+}
+
+impl From<ParseIntError> for TemperatureError {
+    fn from(error: ParseIntError) -> TemperatureError {
+        TemperatureError::NotANumber(error)
+    }
+}
+
+/// Returns the temperature in `text`, ignoring spaces around it. The
+/// text must not be empty, must be a number, and must not be below
+/// -273.
+pub fn parse_celsius(text: &str) -> Result<i32, TemperatureError> {
+    let text = text.trim();
+    if text.is_empty() {
+        return Err(TemperatureError::Empty);
+    }
+    let value: i32 = text.parse()?;
+    if value < -273 {
+        return Err(TemperatureError::BelowAbsoluteZero(value));
+    }
+    Ok(value)
+}
@@ -0,0 +1,29 @@
+// Reference solutions for the exercises of lesson 8.5, Rust Error Handling Example.
+
+/// Returns the name and score of a line like "ana,87". The errors are
+/// "missing score" when there is no comma, and for example "invalid
+/// score 'x'" when the score is not a number.
+pub fn parse_score(line: &str) -> Result<(String, u32), String> {
+    let mut fields = line.split(',');
+    let name = fields.next().unwrap_or("").trim();
+    let score = fields.next().ok_or("missing score")?.trim();
+    let score = score
+        .parse::<u32>()
+        .map_err(|_| format!("invalid score '{}'", score))?;
+    Ok((name.to_string(), score))
+}
+
+/// Returns the name and score of every line of `text`, skipping empty
+/// lines. On the first bad line, returns its error with the line number
+/// in front, like "line 3: missing score".
+pub fn parse_scores(text: &str) -> Result<Vec<(String, u32)>, String> {
+    let mut scores = Vec::new();
+    for (index, line) in text.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let score = parse_score(line).map_err(|error| format!("line {}: {}", index + 1, error))?;
+        scores.push(score);
+    }
+    Ok(scores)
+}
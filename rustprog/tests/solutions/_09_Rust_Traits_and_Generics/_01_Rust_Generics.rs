@@ -0,0 +1,24 @@
+// Reference solutions for the exercises of lesson 9.1, Rust Generics.
+
+use std::ops::Add;
+
+/// Returns the smallest element of `list`, or None if it is empty.
+pub fn smallest<T: PartialOrd + Copy>(list: &[T]) -> Option<T> {
+    let mut smallest = *list.first()?;
+    for &item in list {
+        if item < smallest {
+            smallest = item;
+        }
+    }
+    Some(smallest)
+}
+
+/// Returns the sum of the elements of `list`, starting from `zero`, so
+/// that it works for integers (with 0) and floats (with 0.0) alike.
+pub fn sum_all<T: Add<Output = T> + Copy>(list: &[T], zero: T) -> T {
+    let mut sum = zero;
+    for &item in list {
+        sum = sum + item;
+    }
+    sum
+}
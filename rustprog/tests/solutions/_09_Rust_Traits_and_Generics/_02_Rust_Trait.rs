@@ -0,0 +1,41 @@
+// Reference solutions for the exercises of lesson 9.2, Rust Trait.
+
+/// Something that has a name and can describe itself.
+pub trait Describe {
+    fn name(&self) -> String;
+
+    fn describe(&self) -> String {
+        format!("this is {}", self.name())
+    }
+}
+
+/// A book, described by its title.
+pub struct Book {
+    pub title: String,
+    pub author: String,
+}
+
+impl Describe for Book {
+    fn name(&self) -> String {
+        self.title.clone()
+    }
+}
+
+/// Returns the description of each item of `items`, in order.
+pub fn describe_all<T: Describe>(items: &[T]) -> Vec<String> {
+    items.iter().map(|item| item.describe()).collect()
+}
+
+/// Returns the longest name of the items, the first one if several are
+/// as long, or None if there are no items.
+pub fn longest_name<T: Describe>(items: &[T]) -> Option<String> {
+    let mut longest: Option<String> = None;
+    for item in items {
+        let name = item.name();
+        match &longest {
+            Some(current) if current.len() >= name.len() => {}
+            _ => longest = Some(name),
+        }
+    }
+    longest
+}
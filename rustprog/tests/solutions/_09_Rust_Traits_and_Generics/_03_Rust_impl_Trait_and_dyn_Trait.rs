@@ -0,0 +1,39 @@
+// Reference solutions for the exercises of lesson 9.3, Rust impl Trait and dyn Trait.
+
+use std::f64::consts::PI;
+
+/// A flat shape with an area.
+pub trait Shape {
+    fn area(&self) -> f64;
+}
+
+pub struct Circle {
+    pub radius: f64,
+}
+
+pub struct Rectangle {
+    pub width: f64,
+    pub height: f64,
+}
+
+impl Shape for Circle {
+    fn area(&self) -> f64 {
+        PI * self.radius * self.radius
+    }
+}
+
+impl Shape for Rectangle {
+    fn area(&self) -> f64 {
+        self.width * self.height
+    }
+}
+
+/// Returns the area of all the shapes together.
+pub fn total_area(shapes: &[Box<dyn Shape>]) -> f64 {
+    shapes.iter().map(|shape| shape.area()).sum()
+}
+
+/// Returns the larger of the areas of the two shapes.
+pub fn larger_area(first: &impl Shape, second: &impl Shape) -> f64 {
+    first.area().max(second.area())
+}
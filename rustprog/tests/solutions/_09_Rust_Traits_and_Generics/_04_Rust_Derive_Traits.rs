@@ -0,0 +1,24 @@
+// Reference solutions for the exercises of lesson 9.4, Rust Derive Traits.
+
+/// A playing card, like the 10 of hearts.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Card {
+    pub rank: u8,
+    pub suit: char,
+}
+
+/// Returns how many elements of `list` are equal to `target`.
+pub fn count_equal<T: PartialEq>(list: &[T], target: &T) -> usize {
+    list.iter().filter(|item| *item == target).count()
+}
+
+/// Returns a new list with every element of `list` twice in a row, so
+/// `[a, b]` gives `[a, a, b, b]`.
+pub fn doubled<T: Clone>(list: &[T]) -> Vec<T> {
+    let mut result = Vec::with_capacity(list.len() * 2);
+    for item in list {
+        result.push(item.clone());
+        result.push(item.clone());
+    }
+    result
+}
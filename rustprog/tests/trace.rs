@@ -0,0 +1,95 @@
+//! The tracer steps through the loops and branches of the lessons.
+
+use std::fs;
+use std::path::Path;
+
+use rustprog::trace::{self, Point, Trace};
+use rustprog::Course;
+
+fn trace_lesson(query: &str) -> (String, Trace) {
+    let course = Course::discover(Path::new(env!("CARGO_MANIFEST_DIR")).join("..")).unwrap();
+    let text = fs::read_to_string(&course.find(query).unwrap().path).unwrap();
+    let trace = trace::trace(&text, &trace::LIMITS, trace::STEPS)
+        .unwrap()
+        .unwrap();
+    (text, trace)
+}
+
+/// The statement whose first line contains `code`.
+fn statement(text: &str, trace: &Trace, code: &str) -> usize {
+    let line = text.lines().position(|line| line.contains(code)).unwrap() + 1;
+    trace.statement_at(line).unwrap()
+}
+
+#[test]
+fn breaks_out_of_the_inner_loop() {
+    let (text, trace) = trace_lesson("break_and_continue");
+    assert!(trace.has_values && !trace.stopped);
+    let nested = statement(&text, &trace, "while i <= 5 {");
+    let lines: Vec<&str> = text.lines().collect();
+
+    let breaks: Vec<Vec<(String, String)>> = trace
+        .steps_of(nested)
+        .filter_map(|step| match &trace.points[step.point].2 {
+            Point::Jump { keyword, to } if keyword == "break" => {
+                // Right after the inner loop, still inside the outer one.
+                assert_eq!(lines[*to - 1].trim(), "println!(\"\");");
+                Some(step.values.clone())
+            }
+            _ => None,
+        })
+        .collect();
+    let expected: Vec<Vec<(String, String)>> = (1..=5)
+        .map(|i| {
+            vec![
+                ("i".to_string(), i.to_string()),
+                ("j".to_string(), "3".to_string()),
+            ]
+        })
+        .collect();
+    assert_eq!(breaks, expected);
+
+    let table = trace.table(nested);
+    assert!(table.contains("if j == 3: true "), "{table}");
+    assert!(table.contains("while i <= 5: false  6"), "{table}");
+}
+
+#[test]
+fn branches_show_which_way_they_went() {
+    let (text, trace) = trace_lesson("if_else");
+    let single = statement(&text, &trace, "if number > 0 {");
+    let events: Vec<String> = trace
+        .steps_of(single)
+        .map(|step| trace.describe(step))
+        .collect();
+    assert_eq!(events[..2], ["if number > 0: true", "end"]);
+
+    let chain = statement(&text, &trace, "} else if number < 0 {");
+    let events: Vec<String> = trace
+        .steps_of(chain)
+        .map(|step| trace.describe(step))
+        .collect();
+    assert_eq!(
+        events,
+        ["if number > 0: false", "else if number < 0: true", "end"]
+    );
+}
+
+#[test]
+fn endless_loops_are_stopped() {
+    let text =
+        "fn main() {\n    let mut n = 0;\n    loop { // forever\n        n += 1;\n    }\n}\n";
+    let instrumented = trace::instrument(text, 20);
+    // The lines stay where they are in the lesson.
+    assert_eq!(
+        instrumented.with_values.lines().nth(2),
+        Some("    loop { __step(0, &[(\"n\", format!(\"{:?}\", n))]); // forever")
+    );
+    let trace = trace::trace(text, &trace::LIMITS, 20).unwrap().unwrap();
+    assert!(trace.stopped);
+    assert_eq!(trace.steps.len(), 20);
+    assert_eq!(
+        trace.steps[19].values,
+        [("n".to_string(), "19".to_string())]
+    );
+}